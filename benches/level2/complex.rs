@@ -1,4 +1,5 @@
 use libblas::level2;
+use libblas::{Diag, Trans, Uplo};
 use num_complex::Complex;
 use rand::Rng;
 use test::Bencher;
@@ -16,7 +17,7 @@ fn gbmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::gbmv(
-            'n',
+            Trans::NoTrans,
             6,
             6,
             1,
@@ -46,7 +47,7 @@ fn gemv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::gemv(
-            'n',
+            Trans::NoTrans,
             6,
             6,
             Complex::new(0.2, 0.8),
@@ -126,7 +127,7 @@ fn hbmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::hbmv(
-            'u',
+            Uplo::Upper,
             6,
             3,
             Complex::new(0.2, 0.8),
@@ -154,7 +155,7 @@ fn hemv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::hemv(
-            'u',
+            Uplo::Upper,
             6,
             Complex::new(0.2, 0.8),
             &m.clone(),
@@ -182,7 +183,7 @@ fn her(bh: &mut Bencher) {
 
     bh.iter(|| {
         test::black_box(level2::complex::her(
-            'u',
+            Uplo::Upper,
             6,
             1.0,
             &m2.clone(),
@@ -206,7 +207,7 @@ fn her2(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::her2(
-            'u',
+            Uplo::Upper,
             6,
             Complex::new(0.2, 0.8),
             &m2.clone(),
@@ -232,7 +233,7 @@ fn hpmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::hpmv(
-            'u',
+            Uplo::Upper,
             6,
             Complex::new(0.2, -0.8),
             &m,
@@ -258,7 +259,7 @@ fn hpr(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::hpr(
-            'u',
+            Uplo::Upper,
             8,
             1.0,
             &m2.clone(),
@@ -281,7 +282,7 @@ fn hpr2(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::hpr2(
-            'u',
+            Uplo::Upper,
             8,
             Complex::new(0.2, -0.8),
             &m2.clone(),
@@ -306,9 +307,9 @@ fn tbmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::tbmv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             5,
             &m.clone(),
@@ -332,9 +333,9 @@ fn tbsv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::tbsv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             5,
             &m.clone(),
@@ -358,9 +359,9 @@ fn tpmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::tpmv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             &mut m2.clone(),
@@ -382,9 +383,9 @@ fn tpsv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::tpsv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             &mut m2.clone(),
@@ -406,9 +407,9 @@ fn trmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::trmv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             6,
@@ -431,9 +432,9 @@ fn trsv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::complex::trsv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             6,
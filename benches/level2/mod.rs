@@ -1,4 +1,5 @@
 use libblas::level2;
+use libblas::{Diag, Trans, Uplo};
 use rand::Rng;
 use test::Bencher;
 
@@ -17,7 +18,7 @@ fn gbmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::gbmv(
-            'n',
+            Trans::NoTrans,
             6,
             6,
             1,
@@ -47,7 +48,7 @@ fn gemv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::gemv(
-            'n',
+            Trans::NoTrans,
             6,
             6,
             1.5,
@@ -101,7 +102,7 @@ fn sbmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::sbmv(
-            'u',
+            Uplo::Upper,
             6,
             5,
             1.5,
@@ -129,7 +130,7 @@ fn spmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::spmv(
-            'u',
+            Uplo::Upper,
             8,
             1.0,
             &m,
@@ -153,7 +154,16 @@ fn spr(bh: &mut Bencher) {
     for _ in 0..8 {
         m2.push(rng.gen::<f64>())
     }
-    bh.iter(|| test::black_box(level2::spr('u', 8, 1.0, &m2.clone(), 1, &mut m.clone())))
+    bh.iter(|| {
+        test::black_box(level2::spr(
+            Uplo::Upper,
+            8,
+            1.0,
+            &m2.clone(),
+            1,
+            &mut m.clone(),
+        ))
+    })
 }
 
 #[bench]
@@ -169,7 +179,7 @@ fn spr2(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::spr2(
-            'u',
+            Uplo::Upper,
             8,
             1.0,
             &m2.clone(),
@@ -194,7 +204,7 @@ fn symv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::symv(
-            'u',
+            Uplo::Upper,
             6,
             1.0,
             &m.clone(),
@@ -219,7 +229,17 @@ fn syr(bh: &mut Bencher) {
     for _ in 0..6 {
         m2.push(rng.gen::<f64>())
     }
-    bh.iter(|| test::black_box(level2::syr('u', 6, 1.0, &m2.clone(), 1, &mut m.clone(), 6)))
+    bh.iter(|| {
+        test::black_box(level2::syr(
+            Uplo::Upper,
+            6,
+            1.0,
+            &m2.clone(),
+            1,
+            &mut m.clone(),
+            6,
+        ))
+    })
 }
 
 #[bench]
@@ -235,7 +255,7 @@ fn syr2(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::syr2(
-            'u',
+            Uplo::Upper,
             6,
             1.0,
             &m2.clone(),
@@ -261,9 +281,9 @@ fn tbmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::tbmv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             5,
             &m.clone(),
@@ -287,9 +307,9 @@ fn tbsv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::tbsv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             5,
             &m.clone(),
@@ -313,9 +333,9 @@ fn tpmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::tpmv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             &mut m2.clone(),
@@ -337,9 +357,9 @@ fn tpsv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::tpsv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             &mut m2.clone(),
@@ -361,9 +381,9 @@ fn trmv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::trmv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             6,
@@ -386,9 +406,9 @@ fn trsv(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level2::trsv(
-            'u',
-            'n',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             6,
             &m.clone(),
             6,
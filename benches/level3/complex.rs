@@ -1,4 +1,5 @@
 use libblas::level3;
+use libblas::{Diag, Side, Trans, Uplo};
 use num_complex::Complex;
 use rand::Rng;
 use test::Bencher;
@@ -12,8 +13,8 @@ fn gemm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::gemm(
-            'n',
-            'n',
+            Trans::NoTrans,
+            Trans::NoTrans,
             6,
             6,
             4,
@@ -38,8 +39,8 @@ fn hemm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::hemm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             6,
             6,
             Complex::new(0.2, 0.8),
@@ -63,8 +64,8 @@ fn symm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::symm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             6,
             6,
             Complex::new(0.2, 0.8),
@@ -88,8 +89,8 @@ fn syrk(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::syrk(
-            'l',
-            't',
+            Uplo::Lower,
+            Trans::Trans,
             6,
             3,
             Complex::new(0.2, 0.8),
@@ -111,8 +112,8 @@ fn syr2k(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::syr2k(
-            'l',
-            't',
+            Uplo::Lower,
+            Trans::Trans,
             6,
             3,
             Complex::new(0.2, 0.8),
@@ -136,8 +137,8 @@ fn herk(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::herk(
-            'u',
-            'n',
+            Uplo::Upper,
+            Trans::NoTrans,
             6,
             3,
             0.8,
@@ -159,8 +160,8 @@ fn her2k(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::her2k(
-            'l',
-            'n',
+            Uplo::Lower,
+            Trans::NoTrans,
             6,
             3,
             Complex::new(0.2, 0.8),
@@ -184,10 +185,10 @@ fn trmm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::trmm(
-            'l',
-            'u',
-            'n',
-            'u',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
             3,
             0,
             Complex::new(0.2, 0.8),
@@ -208,10 +209,10 @@ fn trsm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::complex::trsm(
-            'l',
-            'u',
-            'n',
-            'u',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
             3,
             0,
             Complex::new(0.2, 0.8),
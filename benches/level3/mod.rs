@@ -1,4 +1,5 @@
 use libblas::level3;
+use libblas::{Diag, Side, Trans, Uplo};
 use rand::Rng;
 use test::Bencher;
 
@@ -13,8 +14,8 @@ fn gemm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::gemm(
-            'n',
-            'n',
+            Trans::NoTrans,
+            Trans::NoTrans,
             6,
             6,
             4,
@@ -30,6 +31,33 @@ fn gemm(bh: &mut Bencher) {
     })
 }
 
+#[bench]
+fn gemm_512(bh: &mut Bencher) {
+    let mut rng = rand::thread_rng();
+    let mut m: Vec<f64> = Vec::new();
+    for _ in 0..512 * 512 {
+        m.push(rng.gen::<f64>())
+    }
+    let mut c = m.clone();
+    bh.iter(|| {
+        test::black_box(level3::gemm(
+            Trans::NoTrans,
+            Trans::NoTrans,
+            512,
+            512,
+            512,
+            1.5,
+            &m,
+            512,
+            &m,
+            512,
+            2.5,
+            &mut c,
+            512,
+        ))
+    })
+}
+
 #[bench]
 fn symm(bh: &mut Bencher) {
     let mut rng = rand::thread_rng();
@@ -39,8 +67,8 @@ fn symm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::symm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             6,
             6,
             1.5,
@@ -64,8 +92,8 @@ fn syrk(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::syrk(
-            'l',
-            't',
+            Uplo::Lower,
+            Trans::Trans,
             6,
             3,
             0.2,
@@ -87,8 +115,8 @@ fn syr2k(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::syr2k(
-            'l',
-            't',
+            Uplo::Lower,
+            Trans::Trans,
             6,
             3,
             0.2,
@@ -112,10 +140,10 @@ fn trmm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::trmm(
-            'l',
-            'u',
-            'n',
-            'u',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
             3,
             0,
             0.25,
@@ -136,10 +164,10 @@ fn trsm(bh: &mut Bencher) {
     }
     bh.iter(|| {
         test::black_box(level3::trsm(
-            'l',
-            'u',
-            'n',
-            'u',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
             3,
             0,
             0.25,
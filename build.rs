@@ -0,0 +1,29 @@
+fn main() {
+    #[cfg(feature = "cblas")]
+    cblas::header();
+}
+
+/// Generates `cblas.h` into `OUT_DIR` from the exports in `src/cblas/ffi`, the source tree is never written to.
+/// The directory is passed to the build scripts of dependents as `DEP_LIBBLAS_INCLUDE`, `libblas-capi` copies the header
+/// next to the libraries it builds.
+#[cfg(feature = "cblas")]
+mod cblas {
+    use std::env;
+    use std::path::Path;
+
+    pub fn header() {
+        println!("cargo:rerun-if-changed=src/cblas/ffi");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+        let dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out = env::var("OUT_DIR").unwrap();
+        let config = cbindgen::Config::from_file(Path::new(&dir).join("cbindgen.toml"))
+            .expect("cbindgen.toml is invalid");
+        cbindgen::Builder::new()
+            .with_src(Path::new(&dir).join("src/cblas/ffi/mod.rs"))
+            .with_config(config)
+            .generate()
+            .expect("cannot generate the CBLAS header")
+            .write_to_file(Path::new(&out).join("cblas.h"));
+        println!("cargo:include={}", out);
+    }
+}
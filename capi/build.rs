@@ -0,0 +1,28 @@
+//! Copies the `cblas.h` generated by the libblas build script to `include/cblas.h` next to the libraries,
+//! e.g. `target/release/include/cblas.h`, and passes that directory to the build scripts of dependents as `DEP_BLAS_INCLUDE`.
+//! There is no header without the `cblas` feature.
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    let header = match env::var_os("DEP_LIBBLAS_INCLUDE") {
+        Some(dir) => PathBuf::from(dir).join("cblas.h"),
+        None => return,
+    };
+    // OUT_DIR is <target>/<profile>/build/libblas-capi-<hash>/out and the libraries are built in <target>/<profile>.
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let include = out
+        .ancestors()
+        .nth(3)
+        .expect("OUT_DIR is not in a target directory")
+        .join("include");
+    println!("cargo:rerun-if-changed={}", header.display());
+    println!(
+        "cargo:rerun-if-changed={}",
+        include.join("cblas.h").display()
+    );
+    fs::create_dir_all(&include).expect("cannot create the include directory");
+    fs::copy(&header, include.join("cblas.h")).expect("cannot copy cblas.h");
+    println!("cargo:include={}", include.display());
+}
@@ -0,0 +1,7 @@
+//! Builds libblas as `libblas.so` and `libblas.a`, so that C and Fortran programs can link against it
+//! in place of another BLAS. The `cblas` feature, on by default, exports the CBLAS interface and
+//! the `fortran` feature the Fortran 77 interface of the reference BLAS, see the features of libblas.
+#[cfg(feature = "cblas")]
+pub use libblas::cblas::ffi::*;
+#[cfg(feature = "fortran")]
+pub use libblas::fortran::*;
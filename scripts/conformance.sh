@@ -2,6 +2,7 @@
 DCABS1=./tests/conformance/_shared/dcabs1.f
 XERBLA=./tests/conformance/_shared/xerbla.f
 LSAME=./tests/conformance/_shared/lsame.f
+LA_CONSTANTS=./tests/conformance/_shared/la_constants.f90
 test -d ./jsonfortran-gnu-7.0.0 && L=./jsonfortran-gnu-7.0.0/lib/libjsonfortran.a
 test -d ./jsonfortran-gnu-7.0.0 && I=-I./jsonfortran-gnu-7.0.0/lib
 test -d $HOME/json-fortran/build/lib  && L=$HOME/json-fortran/build/lib/libjsonfortran.a || L=/usr/local/Cellar/json-fortran/6.10.0/lib/libjsonfortran.a
@@ -9,6 +10,7 @@ test -d $HOME/json-fortran/build/include  && I=-I$HOME/json-fortran/build/includ
 LIB=-ljsonfortran
 GEN=/gen
 FIXTURE=/fixture.f90
+FIXTURE_F32=/fixture_f32.f90
 
 LEVEL1=./tests/conformance/level1/
 LEVEL1C=./tests/conformance/level1/complex/
@@ -67,12 +69,19 @@ test -f $LEVEL1C$IAMAX$GEN && $LEVEL1C$IAMAX$GEN
 test -f $LEVEL1C$IAMAX$GEN && rm $LEVEL1C$IAMAX$GEN
 
 NRM2=nrm2
-gfortran $LEVEL1$NRM2/d$NRM2.f $LEVEL1$NRM2$FIXTURE $LSAME $XERBLA -o $LEVEL1$NRM2$GEN $L $I
+gfortran $LA_CONSTANTS $LEVEL1$NRM2/d$NRM2.f90 $LEVEL1$NRM2$FIXTURE $LSAME $XERBLA -o $LEVEL1$NRM2$GEN $L $I
 test -f $LEVEL1$NRM2$GEN && $LEVEL1$NRM2$GEN
 test -f $LEVEL1$NRM2$GEN && rm $LEVEL1$NRM2$GEN
-gfortran $LEVEL1C$NRM2/dz$NRM2.f $LEVEL1C$NRM2$FIXTURE $LSAME $XERBLA $DCABS1 -o $LEVEL1C$NRM2$GEN $L $I
+gfortran $LA_CONSTANTS $LEVEL1$NRM2/s$NRM2.f90 $LEVEL1$NRM2$FIXTURE_F32 $LSAME $XERBLA -o $LEVEL1$NRM2$GEN $L $I
+test -f $LEVEL1$NRM2$GEN && $LEVEL1$NRM2$GEN
+test -f $LEVEL1$NRM2$GEN && rm $LEVEL1$NRM2$GEN
+gfortran $LA_CONSTANTS $LEVEL1C$NRM2/dz$NRM2.f90 $LEVEL1C$NRM2$FIXTURE $LSAME $XERBLA -o $LEVEL1C$NRM2$GEN $L $I
+test -f $LEVEL1C$NRM2$GEN && $LEVEL1C$NRM2$GEN
+test -f $LEVEL1C$NRM2$GEN && rm $LEVEL1C$NRM2$GEN
+gfortran $LA_CONSTANTS $LEVEL1C$NRM2/sc$NRM2.f90 $LEVEL1C$NRM2$FIXTURE_F32 $LSAME $XERBLA -o $LEVEL1C$NRM2$GEN $L $I
 test -f $LEVEL1C$NRM2$GEN && $LEVEL1C$NRM2$GEN
 test -f $LEVEL1C$NRM2$GEN && rm $LEVEL1C$NRM2$GEN
+test -f ./la_constants.mod && rm ./la_constants.mod
 
 ROT=rot
 gfortran $LEVEL1$ROT/d$ROT.f $LEVEL1$ROT$FIXTURE $LSAME $XERBLA -o $LEVEL1$ROT$GEN $L $I
@@ -199,11 +208,17 @@ SPMV=spmv
 gfortran $LEVEL2$SPMV/d$SPMV.f $LEVEL2$SPMV$FIXTURE $LSAME $XERBLA -o $LEVEL2$SPMV$GEN $L $I
 test -f $LEVEL2$SPMV$GEN && $LEVEL2$SPMV$GEN
 test -f $LEVEL2$SPMV$GEN && rm $LEVEL2$SPMV$GEN
+gfortran $LEVEL2C$SPMV/z$SPMV.f $LEVEL2C$SPMV$FIXTURE $LSAME $XERBLA -o $LEVEL2C$SPMV$GEN $L $I
+test -f $LEVEL2C$SPMV$GEN && $LEVEL2C$SPMV$GEN
+test -f $LEVEL2C$SPMV$GEN && rm $LEVEL2C$SPMV$GEN
 
 SPR=spr
 gfortran $LEVEL2$SPR/d$SPR.f $LEVEL2$SPR$FIXTURE $LSAME $XERBLA -o $LEVEL2$SPR$GEN $L $I
 test -f $LEVEL2$SPR$GEN && $LEVEL2$SPR$GEN
 test -f $LEVEL2$SPR$GEN && rm $LEVEL2$SPR$GEN
+gfortran $LEVEL2C$SPR/z$SPR.f $LEVEL2C$SPR$FIXTURE $LSAME $XERBLA -o $LEVEL2C$SPR$GEN $L $I
+test -f $LEVEL2C$SPR$GEN && $LEVEL2C$SPR$GEN
+test -f $LEVEL2C$SPR$GEN && rm $LEVEL2C$SPR$GEN
 
 SPR2=spr2
 gfortran $LEVEL2$SPR2/d$SPR2.f $LEVEL2$SPR2$FIXTURE $LSAME $XERBLA -o $LEVEL2$SPR2$GEN $L $I
@@ -214,11 +229,17 @@ SYMV=symv
 gfortran $LEVEL2$SYMV/d$SYMV.f $LEVEL2$SYMV$FIXTURE $LSAME $XERBLA -o $LEVEL2$SYMV$GEN $L $I
 test -f $LEVEL2$SYMV$GEN && $LEVEL2$SYMV$GEN
 test -f $LEVEL2$SYMV$GEN && rm $LEVEL2$SYMV$GEN
+gfortran $LEVEL2C$SYMV/z$SYMV.f $LEVEL2C$SYMV$FIXTURE $LSAME $XERBLA -o $LEVEL2C$SYMV$GEN $L $I
+test -f $LEVEL2C$SYMV$GEN && $LEVEL2C$SYMV$GEN
+test -f $LEVEL2C$SYMV$GEN && rm $LEVEL2C$SYMV$GEN
 
 SYR=syr
 gfortran $LEVEL2$SYR/d$SYR.f $LEVEL2$SYR$FIXTURE $LSAME $XERBLA -o $LEVEL2$SYR$GEN $L $I
 test -f $LEVEL2$SYR$GEN && $LEVEL2$SYR$GEN
 test -f $LEVEL2$SYR$GEN && rm $LEVEL2$SYR$GEN
+gfortran $LEVEL2C$SYR/z$SYR.f $LEVEL2C$SYR$FIXTURE $LSAME $XERBLA -o $LEVEL2C$SYR$GEN $L $I
+test -f $LEVEL2C$SYR$GEN && $LEVEL2C$SYR$GEN
+test -f $LEVEL2C$SYR$GEN && rm $LEVEL2C$SYR$GEN
 
 SYR2=syr2
 gfortran $LEVEL2$SYR2/d$SYR2.f $LEVEL2$SYR2$FIXTURE $LSAME $XERBLA -o $LEVEL2$SYR2$GEN $L $I
@@ -0,0 +1,205 @@
+use super::super::CBLAS_INDEX;
+use crate::ffi::level1::complex;
+use std::os::raw::{c_int, c_void};
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cdotu_sub(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    dotu: *mut c_void,
+) {
+    complex::dot::<f32>(false, n, x, incx, y, incy, dotu)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cdotc_sub(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    dotc: *mut c_void,
+) {
+    complex::dot::<f32>(true, n, x, incx, y, incy, dotc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdotu_sub(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    dotu: *mut c_void,
+) {
+    complex::dot::<f64>(false, n, x, incx, y, incy, dotu)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdotc_sub(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    dotc: *mut c_void,
+) {
+    complex::dot::<f64>(true, n, x, incx, y, incy, dotc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_scnrm2(n: c_int, x: *const c_void, incx: c_int) -> f32 {
+    complex::nrm2(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_scasum(n: c_int, x: *const c_void, incx: c_int) -> f32 {
+    complex::asum(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dznrm2(n: c_int, x: *const c_void, incx: c_int) -> f64 {
+    complex::nrm2(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dzasum(n: c_int, x: *const c_void, incx: c_int) -> f64 {
+    complex::asum(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_icamax(n: c_int, x: *const c_void, incx: c_int) -> CBLAS_INDEX {
+    complex::iamax::<f32>(n, x, incx).saturating_sub(1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_izamax(n: c_int, x: *const c_void, incx: c_int) -> CBLAS_INDEX {
+    complex::iamax::<f64>(n, x, incx).saturating_sub(1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cswap(
+    n: c_int,
+    x: *mut c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    complex::swap::<f32>(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ccopy(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    complex::copy::<f32>(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_caxpy(
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    complex::axpy::<f32>(n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zswap(
+    n: c_int,
+    x: *mut c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    complex::swap::<f64>(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zcopy(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    complex::copy::<f64>(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zaxpy(
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    complex::axpy::<f64>(n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_crotg(a: *mut c_void, b: *mut c_void, c: *mut f32, s: *mut c_void) {
+    complex::rotg(a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zrotg(a: *mut c_void, b: *mut c_void, c: *mut f64, s: *mut c_void) {
+    complex::rotg(a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csrot(
+    n: c_int,
+    x: *mut c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+    c: f32,
+    s: f32,
+) {
+    complex::rot(n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdrot(
+    n: c_int,
+    x: *mut c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+    c: f64,
+    s: f64,
+) {
+    complex::rot(n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cscal(n: c_int, alpha: *const c_void, x: *mut c_void, incx: c_int) {
+    complex::scal::<f32>(n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zscal(n: c_int, alpha: *const c_void, x: *mut c_void, incx: c_int) {
+    complex::scal::<f64>(n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csscal(n: c_int, alpha: f32, x: *mut c_void, incx: c_int) {
+    complex::sscal(n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdscal(n: c_int, alpha: f64, x: *mut c_void, incx: c_int) {
+    complex::sscal(n, alpha, x, incx)
+}
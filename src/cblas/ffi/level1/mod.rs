@@ -0,0 +1,228 @@
+use super::CBLAS_INDEX;
+use crate::ffi::level1;
+use std::os::raw::c_int;
+
+pub mod complex;
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sdsdot(
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+) -> f32 {
+    level1::sdsdot(n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsdot(
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+) -> f64 {
+    level1::dsdot(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sdot(
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+) -> f32 {
+    level1::dot(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ddot(
+    n: c_int,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+) -> f64 {
+    level1::dot(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_snrm2(n: c_int, x: *const f32, incx: c_int) -> f32 {
+    level1::nrm2(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sasum(n: c_int, x: *const f32, incx: c_int) -> f32 {
+    level1::asum(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dnrm2(n: c_int, x: *const f64, incx: c_int) -> f64 {
+    level1::nrm2(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dasum(n: c_int, x: *const f64, incx: c_int) -> f64 {
+    level1::asum(n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_isamax(n: c_int, x: *const f32, incx: c_int) -> CBLAS_INDEX {
+    level1::iamax(n, x, incx).saturating_sub(1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_idamax(n: c_int, x: *const f64, incx: c_int) -> CBLAS_INDEX {
+    level1::iamax(n, x, incx).saturating_sub(1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sswap(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int) {
+    level1::swap(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_scopy(
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+) {
+    level1::copy(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_saxpy(
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+) {
+    level1::axpy(n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dswap(n: c_int, x: *mut f64, incx: c_int, y: *mut f64, incy: c_int) {
+    level1::swap(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dcopy(
+    n: c_int,
+    x: *const f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+) {
+    level1::copy(n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_daxpy(
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+) {
+    level1::axpy(n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    crate::level1::rotg(&mut *a, &mut *b, &mut *c, &mut *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotmg(
+    d1: *mut f32,
+    d2: *mut f32,
+    b1: *mut f32,
+    b2: f32,
+    p: *mut f32,
+) {
+    level1::rotmg(d1, d2, b1, b2, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srot(
+    n: c_int,
+    x: *mut f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+    c: f32,
+    s: f32,
+) {
+    level1::rot(n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotm(
+    n: c_int,
+    x: *mut f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+    p: *const f32,
+) {
+    level1::rotm(n, x, incx, y, incy, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotg(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64) {
+    crate::level1::rotg(&mut *a, &mut *b, &mut *c, &mut *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotmg(
+    d1: *mut f64,
+    d2: *mut f64,
+    b1: *mut f64,
+    b2: f64,
+    p: *mut f64,
+) {
+    level1::rotmg(d1, d2, b1, b2, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drot(
+    n: c_int,
+    x: *mut f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+    c: f64,
+    s: f64,
+) {
+    level1::rot(n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotm(
+    n: c_int,
+    x: *mut f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+    p: *const f64,
+) {
+    level1::rotm(n, x, incx, y, incy, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sscal(n: c_int, alpha: f32, x: *mut f32, incx: c_int) {
+    level1::scal(n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dscal(n: c_int, alpha: f64, x: *mut f64, incx: c_int) {
+    level1::scal(n, alpha, x, incx)
+}
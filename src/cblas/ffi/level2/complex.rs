@@ -0,0 +1,1414 @@
+use super::super::{
+    to_diag, to_layout, to_trans, to_uplo, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+};
+use crate::cblas::level2::complex;
+use crate::ffi::{array, array_mut, band_extent, extent, lengths, packed, report, size, span};
+use crate::BlasError;
+use num_complex::Complex;
+use num_traits::{Float, NumAssignOps};
+use std::os::raw::{c_int, c_void};
+
+unsafe fn gemv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let trans = to_trans(routine, 2, trans)?;
+    let (m, n, lda) = (
+        size(routine, 3, m)?,
+        size(routine, 4, n)?,
+        size(routine, 7, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(a.cast(), extent(layout, m, n, lda));
+    let x = array(x.cast(), span(lenx, incx));
+    let y = array_mut(y.cast(), span(leny, incy));
+    complex::try_gemv(
+        layout,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn gbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let trans = to_trans(routine, 2, trans)?;
+    let (m, n) = (size(routine, 3, m)?, size(routine, 4, n)?);
+    let (kl, ku, lda) = (
+        size(routine, 5, kl)?,
+        size(routine, 6, ku)?,
+        size(routine, 9, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(a.cast(), band_extent(layout, m, n, kl + ku + 1, lda));
+    let x = array(x.cast(), span(lenx, incx));
+    let y = array_mut(y.cast(), span(leny, incy));
+    complex::try_gbmv(
+        layout,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 2, uplo)?,
+        to_trans(routine, 3, trans)?,
+        to_diag(routine, 4, diag)?,
+    );
+    let (n, lda) = (size(routine, 5, n)?, size(routine, 7, lda)?);
+    let a: &[Complex<T>] = array(a.cast(), extent(layout, n, n, lda));
+    let x = array_mut(x.cast(), span(n, incx));
+    if solve {
+        complex::try_trsv(layout, uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_trmv(layout, uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 2, uplo)?,
+        to_trans(routine, 3, trans)?,
+        to_diag(routine, 4, diag)?,
+    );
+    let (n, k, lda) = (
+        size(routine, 5, n)?,
+        size(routine, 6, k)?,
+        size(routine, 8, lda)?,
+    );
+    let a: &[Complex<T>] = array(a.cast(), band_extent(layout, n, n, k + 1, lda));
+    let x = array_mut(x.cast(), span(n, incx));
+    if solve {
+        complex::try_tbsv(layout, uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_tbmv(layout, uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tpmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 2, uplo)?,
+        to_trans(routine, 3, trans)?,
+        to_diag(routine, 4, diag)?,
+    );
+    let n = size(routine, 5, n)?;
+    let ap: &[Complex<T>] = array(ap.cast(), packed(n));
+    let x = array_mut(x.cast(), span(n, incx));
+    if solve {
+        complex::try_tpsv(layout, uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_tpmv(layout, uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn hemv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, lda) = (size(routine, 3, n)?, size(routine, 6, lda)?);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(a.cast(), extent(layout, n, n, lda));
+    let x = array(x.cast(), span(n, incx));
+    let y = array_mut(y.cast(), span(n, incy));
+    complex::try_hemv(
+        layout,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, k, lda) = (
+        size(routine, 3, n)?,
+        size(routine, 4, k)?,
+        size(routine, 7, lda)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(a.cast(), band_extent(layout, n, n, k + 1, lda));
+    let x = array(x.cast(), span(n, incx));
+    let y = array_mut(y.cast(), span(n, incy));
+    complex::try_hbmv(
+        layout,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hpmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    ap: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let n = size(routine, 3, n)?;
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let ap = array(ap.cast(), packed(n));
+    let x = array(x.cast(), span(n, incx));
+    let y = array_mut(y.cast(), span(n, incy));
+    complex::try_hpmv(
+        layout,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn ger<T: Float + NumAssignOps>(
+    routine: &'static str,
+    conj: bool,
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (m, n, lda) = (
+        size(routine, 2, m)?,
+        size(routine, 3, n)?,
+        size(routine, 10, lda)?,
+    );
+    let alpha = *alpha.cast::<Complex<T>>();
+    let x = array(x.cast(), span(m, incx));
+    let y = array(y.cast(), span(n, incy));
+    let a = array_mut(a.cast(), extent(layout, m, n, lda));
+    if conj {
+        complex::try_gerc(
+            layout,
+            m,
+            n,
+            alpha,
+            x,
+            incx as isize,
+            y,
+            incy as isize,
+            a,
+            lda,
+        )
+        .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_geru(
+            layout,
+            m,
+            n,
+            alpha,
+            x,
+            incx as isize,
+            y,
+            incy as isize,
+            a,
+            lda,
+        )
+        .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn her<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    x: *const c_void,
+    incx: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, lda) = (size(routine, 3, n)?, size(routine, 8, lda)?);
+    let x = array(x.cast(), span(n, incx));
+    let a = array_mut(a.cast(), extent(layout, n, n, lda));
+    complex::try_her(layout, uplo, n, alpha, x, incx as isize, a, lda)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hpr<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    x: *const c_void,
+    incx: c_int,
+    ap: *mut c_void,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let n = size(routine, 3, n)?;
+    let x = array(x.cast(), span(n, incx));
+    let ap = array_mut(ap.cast(), packed(n));
+    complex::try_hpr(layout, uplo, n, alpha, x, incx as isize, ap)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn her2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, lda) = (size(routine, 3, n)?, size(routine, 10, lda)?);
+    let alpha = *alpha.cast::<Complex<T>>();
+    let x = array(x.cast(), span(n, incx));
+    let y = array(y.cast(), span(n, incy));
+    let a = array_mut(a.cast(), extent(layout, n, n, lda));
+    complex::try_her2(
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hpr2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    ap: *mut c_void,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let n = size(routine, 3, n)?;
+    let alpha = *alpha.cast::<Complex<T>>();
+    let x = array(x.cast(), span(n, incx));
+    let y = array(y.cast(), span(n, incy));
+    let ap = array_mut(ap.cast(), packed(n));
+    complex::try_hpr2(
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        ap,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgemv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(gemv::<f32>(
+        "cblas_cgemv",
+        order,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgbmv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(gbmv::<f32>(
+        "cblas_cgbmv",
+        order,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(trmv::<f32>(
+        "cblas_ctrmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tbmv::<f32>(
+        "cblas_ctbmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tpmv::<f32>(
+        "cblas_ctpmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(trmv::<f32>(
+        "cblas_ctrsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tbmv::<f32>(
+        "cblas_ctbsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tpmv::<f32>(
+        "cblas_ctpsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chemv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(hemv::<f32>(
+        "cblas_chemv",
+        order,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(hbmv::<f32>(
+        "cblas_chbmv",
+        order,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    ap: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(hpmv::<f32>(
+        "cblas_chpmv",
+        order,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgeru(
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(ger::<f32>(
+        "cblas_cgeru",
+        false,
+        order,
+        m,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgerc(
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(ger::<f32>(
+        "cblas_cgerc",
+        true,
+        order,
+        m,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cher(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const c_void,
+    incx: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(her::<f32>(
+        "cblas_cher",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chpr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const c_void,
+    incx: c_int,
+    ap: *mut c_void,
+) {
+    report(hpr::<f32>("cblas_chpr", order, uplo, n, alpha, x, incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cher2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(her2::<f32>(
+        "cblas_cher2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chpr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    ap: *mut c_void,
+) {
+    report(hpr2::<f32>(
+        "cblas_chpr2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        ap,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgemv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(gemv::<f64>(
+        "cblas_zgemv",
+        order,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgbmv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(gbmv::<f64>(
+        "cblas_zgbmv",
+        order,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(trmv::<f64>(
+        "cblas_ztrmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tbmv::<f64>(
+        "cblas_ztbmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tpmv::<f64>(
+        "cblas_ztpmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(trmv::<f64>(
+        "cblas_ztrsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tbmv::<f64>(
+        "cblas_ztbsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    report(tpmv::<f64>(
+        "cblas_ztpsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhemv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(hemv::<f64>(
+        "cblas_zhemv",
+        order,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(hbmv::<f64>(
+        "cblas_zhbmv",
+        order,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    ap: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    report(hpmv::<f64>(
+        "cblas_zhpmv",
+        order,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgeru(
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(ger::<f64>(
+        "cblas_zgeru",
+        false,
+        order,
+        m,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgerc(
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(ger::<f64>(
+        "cblas_zgerc",
+        true,
+        order,
+        m,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zher(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    x: *const c_void,
+    incx: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(her::<f64>(
+        "cblas_zher",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhpr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    x: *const c_void,
+    incx: c_int,
+    ap: *mut c_void,
+) {
+    report(hpr::<f64>("cblas_zhpr", order, uplo, n, alpha, x, incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zher2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) {
+    report(her2::<f64>(
+        "cblas_zher2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhpr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    ap: *mut c_void,
+) {
+    report(hpr2::<f64>(
+        "cblas_zhpr2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        ap,
+    ))
+}
@@ -0,0 +1,1310 @@
+use super::{
+    to_diag, to_layout, to_trans, to_uplo, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+};
+use crate::cblas::level2;
+use crate::ffi::{array, array_mut, band_extent, extent, lengths, packed, report, size, span};
+use crate::{BlasError, Ring};
+use num_traits::{Float, NumAssignOps};
+use std::os::raw::c_int;
+
+pub mod complex;
+
+unsafe fn gemv<T: Ring + Float + NumAssignOps + 'static>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let trans = to_trans(routine, 2, trans)?;
+    let (m, n, lda) = (
+        size(routine, 3, m)?,
+        size(routine, 4, n)?,
+        size(routine, 7, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let a = array(a, extent(layout, m, n, lda));
+    let x = array(x, span(lenx, incx));
+    let y = array_mut(y, span(leny, incy));
+    level2::try_gemv(
+        layout,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn gbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let trans = to_trans(routine, 2, trans)?;
+    let (m, n) = (size(routine, 3, m)?, size(routine, 4, n)?);
+    let (kl, ku, lda) = (
+        size(routine, 5, kl)?,
+        size(routine, 6, ku)?,
+        size(routine, 9, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let a = array(a, band_extent(layout, m, n, kl + ku + 1, lda));
+    let x = array(x, span(lenx, incx));
+    let y = array_mut(y, span(leny, incy));
+    level2::try_gbmv(
+        layout,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const T,
+    lda: c_int,
+    x: *mut T,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 2, uplo)?,
+        to_trans(routine, 3, trans)?,
+        to_diag(routine, 4, diag)?,
+    );
+    let (n, lda) = (size(routine, 5, n)?, size(routine, 7, lda)?);
+    let a = array(a, extent(layout, n, n, lda));
+    let x = array_mut(x, span(n, incx));
+    if solve {
+        level2::try_trsv(layout, uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        level2::try_trmv(layout, uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const T,
+    lda: c_int,
+    x: *mut T,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 2, uplo)?,
+        to_trans(routine, 3, trans)?,
+        to_diag(routine, 4, diag)?,
+    );
+    let (n, k, lda) = (
+        size(routine, 5, n)?,
+        size(routine, 6, k)?,
+        size(routine, 8, lda)?,
+    );
+    let a = array(a, band_extent(layout, n, n, k + 1, lda));
+    let x = array_mut(x, span(n, incx));
+    if solve {
+        level2::try_tbsv(layout, uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        level2::try_tbmv(layout, uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tpmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const T,
+    x: *mut T,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 2, uplo)?,
+        to_trans(routine, 3, trans)?,
+        to_diag(routine, 4, diag)?,
+    );
+    let n = size(routine, 5, n)?;
+    let ap = array(ap, packed(n));
+    let x = array_mut(x, span(n, incx));
+    if solve {
+        level2::try_tpsv(layout, uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        level2::try_tpmv(layout, uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn symv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, lda) = (size(routine, 3, n)?, size(routine, 6, lda)?);
+    let a = array(a, extent(layout, n, n, lda));
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level2::try_symv(
+        layout,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn sbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, k, lda) = (
+        size(routine, 3, n)?,
+        size(routine, 4, k)?,
+        size(routine, 7, lda)?,
+    );
+    let a = array(a, band_extent(layout, n, n, k + 1, lda));
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level2::try_sbmv(
+        layout,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn spmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    ap: *const T,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let n = size(routine, 3, n)?;
+    let ap = array(ap, packed(n));
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level2::try_spmv(
+        layout,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn ger<T: Ring + Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    a: *mut T,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (m, n, lda) = (
+        size(routine, 2, m)?,
+        size(routine, 3, n)?,
+        size(routine, 10, lda)?,
+    );
+    let x = array(x, span(m, incx));
+    let y = array(y, span(n, incy));
+    let a = array_mut(a, extent(layout, m, n, lda));
+    level2::try_ger(
+        layout,
+        m,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    a: *mut T,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, lda) = (size(routine, 3, n)?, size(routine, 8, lda)?);
+    let x = array(x, span(n, incx));
+    let a = array_mut(a, extent(layout, n, n, lda));
+    level2::try_syr(layout, uplo, n, alpha, x, incx as isize, a, lda)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn spr<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    ap: *mut T,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let n = size(routine, 3, n)?;
+    let x = array(x, span(n, incx));
+    let ap = array_mut(ap, packed(n));
+    level2::try_spr(layout, uplo, n, alpha, x, incx as isize, ap)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    a: *mut T,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let (n, lda) = (size(routine, 3, n)?, size(routine, 10, lda)?);
+    let x = array(x, span(n, incx));
+    let y = array(y, span(n, incy));
+    let a = array_mut(a, extent(layout, n, n, lda));
+    level2::try_syr2(
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn spr2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    ap: *mut T,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let uplo = to_uplo(routine, 2, uplo)?;
+    let n = size(routine, 3, n)?;
+    let x = array(x, span(n, incx));
+    let y = array(y, span(n, incy));
+    let ap = array_mut(ap, packed(n));
+    level2::try_spr2(
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        ap,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgemv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    report(gemv(
+        "cblas_sgemv",
+        order,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgbmv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    report(gbmv(
+        "cblas_sgbmv",
+        order,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    report(trmv(
+        "cblas_strmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    report(tbmv(
+        "cblas_stbmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) {
+    report(tpmv(
+        "cblas_stpmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    report(trmv(
+        "cblas_strsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    report(tbmv(
+        "cblas_stbsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) {
+    report(tpmv(
+        "cblas_stpsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssymv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    report(symv(
+        "cblas_ssymv",
+        order,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    report(sbmv(
+        "cblas_ssbmv",
+        order,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    report(spmv(
+        "cblas_sspmv",
+        order,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sger(
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    report(ger(
+        "cblas_sger",
+        order,
+        m,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    report(syr("cblas_ssyr", order, uplo, n, alpha, x, incx, a, lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    ap: *mut f32,
+) {
+    report(spr("cblas_sspr", order, uplo, n, alpha, x, incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    report(syr2(
+        "cblas_ssyr2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    ap: *mut f32,
+) {
+    report(spr2(
+        "cblas_sspr2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        ap,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgemv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    report(gemv(
+        "cblas_dgemv",
+        order,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgbmv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    report(gbmv(
+        "cblas_dgbmv",
+        order,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    report(trmv(
+        "cblas_dtrmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    report(tbmv(
+        "cblas_dtbmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) {
+    report(tpmv(
+        "cblas_dtpmv",
+        false,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    report(trmv(
+        "cblas_dtrsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    report(tbmv(
+        "cblas_dtbsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) {
+    report(tpmv(
+        "cblas_dtpsv",
+        true,
+        order,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsymv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    report(symv(
+        "cblas_dsymv",
+        order,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    report(sbmv(
+        "cblas_dsbmv",
+        order,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    report(spmv(
+        "cblas_dspmv",
+        order,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dger(
+    order: CBLAS_ORDER,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) {
+    report(ger(
+        "cblas_dger",
+        order,
+        m,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    a: *mut f64,
+    lda: c_int,
+) {
+    report(syr("cblas_dsyr", order, uplo, n, alpha, x, incx, a, lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    ap: *mut f64,
+) {
+    report(spr("cblas_dspr", order, uplo, n, alpha, x, incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) {
+    report(syr2(
+        "cblas_dsyr2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    ap: *mut f64,
+) {
+    report(spr2(
+        "cblas_dspr2",
+        order,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        ap,
+    ))
+}
@@ -0,0 +1,856 @@
+use super::super::{
+    to_diag, to_layout, to_side, to_trans, to_uplo, CBLAS_DIAG, CBLAS_ORDER, CBLAS_SIDE,
+    CBLAS_TRANSPOSE, CBLAS_UPLO,
+};
+use crate::cblas::level3::complex;
+use crate::ffi::{array, array_mut, extent, order_of_a, report, size, stored};
+use crate::{BlasError, RealScalar};
+use num_complex::Complex;
+use std::os::raw::{c_int, c_void};
+
+unsafe fn gemm<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    trans_a: CBLAS_TRANSPOSE,
+    trans_b: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (trans_a, trans_b) = (
+        to_trans(routine, 2, trans_a)?,
+        to_trans(routine, 3, trans_b)?,
+    );
+    let (m, n, k) = (
+        size(routine, 4, m)?,
+        size(routine, 5, n)?,
+        size(routine, 6, k)?,
+    );
+    let (lda, ldb, ldc) = (
+        size(routine, 9, lda)?,
+        size(routine, 11, ldb)?,
+        size(routine, 14, ldc)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let (rows_a, cols_a) = stored(trans_a, m, k);
+    let (rows_b, cols_b) = stored(trans_b, k, n);
+    let a = array(a.cast(), extent(layout, rows_a, cols_a, lda));
+    let b = array(b.cast(), extent(layout, rows_b, cols_b, ldb));
+    let c = array_mut(c.cast(), extent(layout, m, n, ldc));
+    complex::try_gemm(
+        layout, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+/// SYMM, or HEMM when `hermitian` is true.
+unsafe fn symm<T: RealScalar>(
+    routine: &'static str,
+    hermitian: bool,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (side, uplo) = (to_side(routine, 2, side)?, to_uplo(routine, 3, uplo)?);
+    let (m, n) = (size(routine, 4, m)?, size(routine, 5, n)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 8, lda)?,
+        size(routine, 10, ldb)?,
+        size(routine, 13, ldc)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let ka = order_of_a(side, m, n);
+    let a = array(a.cast(), extent(layout, ka, ka, lda));
+    let b = array(b.cast(), extent(layout, m, n, ldb));
+    let c = array_mut(c.cast(), extent(layout, m, n, ldc));
+    if hermitian {
+        complex::try_hemm(
+            layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+        .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_symm(
+            layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+        .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn syrk<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans) = (to_uplo(routine, 2, uplo)?, to_trans(routine, 3, trans)?);
+    let (n, k) = (size(routine, 4, n)?, size(routine, 5, k)?);
+    let (lda, ldc) = (size(routine, 8, lda)?, size(routine, 11, ldc)?);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let (rows_a, cols_a) = stored(trans, n, k);
+    let a = array(a.cast(), extent(layout, rows_a, cols_a, lda));
+    let c = array_mut(c.cast(), extent(layout, n, n, ldc));
+    complex::try_syrk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn herk<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const c_void,
+    lda: c_int,
+    beta: T,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans) = (to_uplo(routine, 2, uplo)?, to_trans(routine, 3, trans)?);
+    let (n, k) = (size(routine, 4, n)?, size(routine, 5, k)?);
+    let (lda, ldc) = (size(routine, 8, lda)?, size(routine, 11, ldc)?);
+    let (rows_a, cols_a) = stored(trans, n, k);
+    let a = array(a.cast(), extent(layout, rows_a, cols_a, lda));
+    let c = array_mut(c.cast(), extent(layout, n, n, ldc));
+    complex::try_herk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr2k<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans) = (to_uplo(routine, 2, uplo)?, to_trans(routine, 3, trans)?);
+    let (n, k) = (size(routine, 4, n)?, size(routine, 5, k)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 8, lda)?,
+        size(routine, 10, ldb)?,
+        size(routine, 13, ldc)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let (rows, cols) = stored(trans, n, k);
+    let a = array(a.cast(), extent(layout, rows, cols, lda));
+    let b = array(b.cast(), extent(layout, rows, cols, ldb));
+    let c = array_mut(c.cast(), extent(layout, n, n, ldc));
+    complex::try_syr2k(
+        layout, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn her2k<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: T,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans) = (to_uplo(routine, 2, uplo)?, to_trans(routine, 3, trans)?);
+    let (n, k) = (size(routine, 4, n)?, size(routine, 5, k)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 8, lda)?,
+        size(routine, 10, ldb)?,
+        size(routine, 13, ldc)?,
+    );
+    let alpha = *alpha.cast::<Complex<T>>();
+    let (rows, cols) = stored(trans, n, k);
+    let a = array(a.cast(), extent(layout, rows, cols, lda));
+    let b = array(b.cast(), extent(layout, rows, cols, ldb));
+    let c = array_mut(c.cast(), extent(layout, n, n, ldc));
+    complex::try_her2k(
+        layout, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmm<T: RealScalar>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *mut c_void,
+    ldb: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (side, uplo) = (to_side(routine, 2, side)?, to_uplo(routine, 3, uplo)?);
+    let (trans_a, diag) = (to_trans(routine, 4, trans_a)?, to_diag(routine, 5, diag)?);
+    let (m, n) = (size(routine, 6, m)?, size(routine, 7, n)?);
+    let (lda, ldb) = (size(routine, 10, lda)?, size(routine, 12, ldb)?);
+    let alpha = *alpha.cast::<Complex<T>>();
+    let ka = order_of_a(side, m, n);
+    let a = array(a.cast(), extent(layout, ka, ka, lda));
+    let b = array_mut(b.cast(), extent(layout, m, n, ldb));
+    if solve {
+        complex::try_trsm(
+            layout, side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb,
+        )
+        .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_trmm(
+            layout, side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb,
+        )
+        .map_err(|err| err.with_routine(routine))
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgemm(
+    order: CBLAS_ORDER,
+    trans_a: CBLAS_TRANSPOSE,
+    trans_b: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(gemm::<f32>(
+        "cblas_cgemm",
+        order,
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(symm::<f32>(
+        "cblas_csymm",
+        false,
+        order,
+        side,
+        uplo,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chemm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(symm::<f32>(
+        "cblas_chemm",
+        true,
+        order,
+        side,
+        uplo,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(syrk::<f32>(
+        "cblas_csyrk",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cherk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const c_void,
+    lda: c_int,
+    beta: f32,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(herk::<f32>(
+        "cblas_cherk",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(syr2k::<f32>(
+        "cblas_csyr2k",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cher2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: f32,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(her2k::<f32>(
+        "cblas_cher2k",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *mut c_void,
+    ldb: c_int,
+) {
+    report(trmm::<f32>(
+        "cblas_ctrmm",
+        false,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *mut c_void,
+    ldb: c_int,
+) {
+    report(trmm::<f32>(
+        "cblas_ctrsm",
+        true,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgemm(
+    order: CBLAS_ORDER,
+    trans_a: CBLAS_TRANSPOSE,
+    trans_b: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(gemm::<f64>(
+        "cblas_zgemm",
+        order,
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zsymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(symm::<f64>(
+        "cblas_zsymm",
+        false,
+        order,
+        side,
+        uplo,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhemm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(symm::<f64>(
+        "cblas_zhemm",
+        true,
+        order,
+        side,
+        uplo,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zsyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(syrk::<f64>(
+        "cblas_zsyrk",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zherk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const c_void,
+    lda: c_int,
+    beta: f64,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(herk::<f64>(
+        "cblas_zherk",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zsyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(syr2k::<f64>(
+        "cblas_zsyr2k",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zher2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: f64,
+    c: *mut c_void,
+    ldc: c_int,
+) {
+    report(her2k::<f64>(
+        "cblas_zher2k",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *mut c_void,
+    ldb: c_int,
+) {
+    report(trmm::<f64>(
+        "cblas_ztrmm",
+        false,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *mut c_void,
+    ldb: c_int,
+) {
+    report(trmm::<f64>(
+        "cblas_ztrsm",
+        true,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
@@ -0,0 +1,583 @@
+use super::{
+    to_diag, to_layout, to_side, to_trans, to_uplo, CBLAS_DIAG, CBLAS_ORDER, CBLAS_SIDE,
+    CBLAS_TRANSPOSE, CBLAS_UPLO,
+};
+use crate::cblas::level3;
+use crate::ffi::{array, array_mut, extent, order_of_a, report, size, stored};
+use crate::{BlasError, RealScalar};
+use std::os::raw::c_int;
+
+pub mod complex;
+
+unsafe fn gemm<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    trans_a: CBLAS_TRANSPOSE,
+    trans_b: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (trans_a, trans_b) = (
+        to_trans(routine, 2, trans_a)?,
+        to_trans(routine, 3, trans_b)?,
+    );
+    let (m, n, k) = (
+        size(routine, 4, m)?,
+        size(routine, 5, n)?,
+        size(routine, 6, k)?,
+    );
+    let (lda, ldb, ldc) = (
+        size(routine, 9, lda)?,
+        size(routine, 11, ldb)?,
+        size(routine, 14, ldc)?,
+    );
+    let (rows_a, cols_a) = stored(trans_a, m, k);
+    let (rows_b, cols_b) = stored(trans_b, k, n);
+    let a = array(a, extent(layout, rows_a, cols_a, lda));
+    let b = array(b, extent(layout, rows_b, cols_b, ldb));
+    let c = array_mut(c, extent(layout, m, n, ldc));
+    level3::try_gemm(
+        layout, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn symm<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (side, uplo) = (to_side(routine, 2, side)?, to_uplo(routine, 3, uplo)?);
+    let (m, n) = (size(routine, 4, m)?, size(routine, 5, n)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 8, lda)?,
+        size(routine, 10, ldb)?,
+        size(routine, 13, ldc)?,
+    );
+    let ka = order_of_a(side, m, n);
+    let a = array(a, extent(layout, ka, ka, lda));
+    let b = array(b, extent(layout, m, n, ldb));
+    let c = array_mut(c, extent(layout, m, n, ldc));
+    level3::try_symm(
+        layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syrk<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans) = (to_uplo(routine, 2, uplo)?, to_trans(routine, 3, trans)?);
+    let (n, k) = (size(routine, 4, n)?, size(routine, 5, k)?);
+    let (lda, ldc) = (size(routine, 8, lda)?, size(routine, 11, ldc)?);
+    let (rows_a, cols_a) = stored(trans, n, k);
+    let a = array(a, extent(layout, rows_a, cols_a, lda));
+    let c = array_mut(c, extent(layout, n, n, ldc));
+    level3::try_syrk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr2k<T: RealScalar>(
+    routine: &'static str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (uplo, trans) = (to_uplo(routine, 2, uplo)?, to_trans(routine, 3, trans)?);
+    let (n, k) = (size(routine, 4, n)?, size(routine, 5, k)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 8, lda)?,
+        size(routine, 10, ldb)?,
+        size(routine, 13, ldc)?,
+    );
+    let (rows, cols) = stored(trans, n, k);
+    let a = array(a, extent(layout, rows, cols, lda));
+    let b = array(b, extent(layout, rows, cols, ldb));
+    let c = array_mut(c, extent(layout, n, n, ldc));
+    level3::try_syr2k(
+        layout, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmm<T: RealScalar>(
+    routine: &'static str,
+    solve: bool,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *mut T,
+    ldb: c_int,
+) -> Result<(), BlasError> {
+    let layout = to_layout(routine, order)?;
+    let (side, uplo) = (to_side(routine, 2, side)?, to_uplo(routine, 3, uplo)?);
+    let (trans_a, diag) = (to_trans(routine, 4, trans_a)?, to_diag(routine, 5, diag)?);
+    let (m, n) = (size(routine, 6, m)?, size(routine, 7, n)?);
+    let (lda, ldb) = (size(routine, 10, lda)?, size(routine, 12, ldb)?);
+    let ka = order_of_a(side, m, n);
+    let a = array(a, extent(layout, ka, ka, lda));
+    let b = array_mut(b, extent(layout, m, n, ldb));
+    if solve {
+        level3::try_trsm(
+            layout, side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb,
+        )
+        .map_err(|err| err.with_routine(routine))
+    } else {
+        level3::try_trmm(
+            layout, side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb,
+        )
+        .map_err(|err| err.with_routine(routine))
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgemm(
+    order: CBLAS_ORDER,
+    trans_a: CBLAS_TRANSPOSE,
+    trans_b: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    b: *const f32,
+    ldb: c_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: c_int,
+) {
+    report(gemm(
+        "cblas_sgemm",
+        order,
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    b: *const f32,
+    ldb: c_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: c_int,
+) {
+    report(symm(
+        "cblas_ssymm",
+        order,
+        side,
+        uplo,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: c_int,
+) {
+    report(syrk(
+        "cblas_ssyrk",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    b: *const f32,
+    ldb: c_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: c_int,
+) {
+    report(syr2k(
+        "cblas_ssyr2k",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    b: *mut f32,
+    ldb: c_int,
+) {
+    report(trmm(
+        "cblas_strmm",
+        false,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    b: *mut f32,
+    ldb: c_int,
+) {
+    report(trmm(
+        "cblas_strsm",
+        true,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgemm(
+    order: CBLAS_ORDER,
+    trans_a: CBLAS_TRANSPOSE,
+    trans_b: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *const f64,
+    ldb: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) {
+    report(gemm(
+        "cblas_dgemm",
+        order,
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *const f64,
+    ldb: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) {
+    report(symm(
+        "cblas_dsymm",
+        order,
+        side,
+        uplo,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) {
+    report(syrk(
+        "cblas_dsyrk",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *const f64,
+    ldb: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) {
+    report(syr2k(
+        "cblas_dsyr2k",
+        order,
+        uplo,
+        trans,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *mut f64,
+    ldb: c_int,
+) {
+    report(trmm(
+        "cblas_dtrmm",
+        false,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans_a: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *mut f64,
+    ldb: c_int,
+) {
+    report(trmm(
+        "cblas_dtrsm",
+        true,
+        order,
+        side,
+        uplo,
+        trans_a,
+        diag,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+    ))
+}
@@ -0,0 +1,119 @@
+//! The C interface of CBLAS, exported with the `cblas` feature.
+//!
+//! Every routine of the reference `cblas.h` is exported under its C name, e.g. `cblas_dgemm`, `cblas_zhemm` or `cblas_ddot`,
+//! so that libblas, built as a `cdylib` or `staticlib` by the `libblas-capi` crate, can replace another CBLAS implementation.
+//! The header is generated with the feature and copied by `libblas-capi` to `include/cblas.h` next to the libraries,
+//! e.g. `target/release/include/cblas.h`, build scripts of crates depending on `libblas-capi` find its directory in `DEP_BLAS_INCLUDE`.
+//!
+//! The routines check the `CBLAS_ORDER`, `CBLAS_TRANSPOSE`, `CBLAS_UPLO`, `CBLAS_DIAG` and `CBLAS_SIDE` flags
+//! and the sizes before calling the generic routines in [`cblas`](crate::cblas) and [`level1`](crate::level1),
+//! an illegal argument is reported to the [`xerbla`] handler. Parameters are numbered as in CBLAS.
+//! As in the reference CBLAS the level 1 routines return early when n is not positive.
+//!
+//! # Safety
+//! Every pointer must point to an array that is at least as large as the reference BLAS expects for the given sizes,
+//! and an array that is written to must not overlap any other array argument.
+//! Complex scalars and arrays are passed as `void *` to interleaved pairs of `float` or `double`.
+#![allow(
+    non_camel_case_types,
+    non_upper_case_globals,
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments
+)]
+
+use crate::{BlasError, Diag, Layout, Side, Trans, Uplo};
+use std::os::raw::c_int;
+
+pub mod level1;
+pub mod level2;
+pub mod level3;
+
+/// CBLAS_ORDER is the storage order of a matrix, `CblasRowMajor` or `CblasColMajor`.
+pub type CBLAS_ORDER = c_int;
+/// CBLAS_TRANSPOSE is the operation applied to a matrix, `CblasNoTrans`, `CblasTrans` or `CblasConjTrans`.
+pub type CBLAS_TRANSPOSE = c_int;
+/// CBLAS_UPLO is the referenced triangle of a matrix, `CblasUpper` or `CblasLower`.
+pub type CBLAS_UPLO = c_int;
+/// CBLAS_DIAG tells whether a triangular matrix is unit triangular, `CblasNonUnit` or `CblasUnit`.
+pub type CBLAS_DIAG = c_int;
+/// CBLAS_SIDE is the side of a matrix in a product, `CblasLeft` or `CblasRight`.
+pub type CBLAS_SIDE = c_int;
+/// CBLAS_INDEX is the zero-based index returned by the `cblas_i?amax` routines.
+pub type CBLAS_INDEX = usize;
+
+pub const CblasRowMajor: CBLAS_ORDER = 101;
+pub const CblasColMajor: CBLAS_ORDER = 102;
+pub const CblasNoTrans: CBLAS_TRANSPOSE = 111;
+pub const CblasTrans: CBLAS_TRANSPOSE = 112;
+pub const CblasConjTrans: CBLAS_TRANSPOSE = 113;
+pub const CblasUpper: CBLAS_UPLO = 121;
+pub const CblasLower: CBLAS_UPLO = 122;
+pub const CblasNonUnit: CBLAS_DIAG = 131;
+pub const CblasUnit: CBLAS_DIAG = 132;
+pub const CblasLeft: CBLAS_SIDE = 141;
+pub const CblasRight: CBLAS_SIDE = 142;
+
+fn to_layout(routine: &'static str, order: CBLAS_ORDER) -> Result<Layout, BlasError> {
+    match order {
+        CblasRowMajor => Ok(Layout::RowMajor),
+        CblasColMajor => Ok(Layout::ColMajor),
+        _ => Err(BlasError::new(
+            routine,
+            1,
+            "order must be CblasRowMajor or CblasColMajor",
+        )),
+    }
+}
+
+fn to_trans(
+    routine: &'static str,
+    param: usize,
+    trans: CBLAS_TRANSPOSE,
+) -> Result<Trans, BlasError> {
+    match trans {
+        CblasNoTrans => Ok(Trans::NoTrans),
+        CblasTrans => Ok(Trans::Trans),
+        CblasConjTrans => Ok(Trans::ConjTrans),
+        _ => Err(BlasError::new(
+            routine,
+            param,
+            "trans must be CblasNoTrans, CblasTrans or CblasConjTrans",
+        )),
+    }
+}
+
+fn to_uplo(routine: &'static str, param: usize, uplo: CBLAS_UPLO) -> Result<Uplo, BlasError> {
+    match uplo {
+        CblasUpper => Ok(Uplo::Upper),
+        CblasLower => Ok(Uplo::Lower),
+        _ => Err(BlasError::new(
+            routine,
+            param,
+            "uplo must be CblasUpper or CblasLower",
+        )),
+    }
+}
+
+fn to_diag(routine: &'static str, param: usize, diag: CBLAS_DIAG) -> Result<Diag, BlasError> {
+    match diag {
+        CblasNonUnit => Ok(Diag::NonUnit),
+        CblasUnit => Ok(Diag::Unit),
+        _ => Err(BlasError::new(
+            routine,
+            param,
+            "diag must be CblasNonUnit or CblasUnit",
+        )),
+    }
+}
+
+fn to_side(routine: &'static str, param: usize, side: CBLAS_SIDE) -> Result<Side, BlasError> {
+    match side {
+        CblasLeft => Ok(Side::Left),
+        CblasRight => Ok(Side::Right),
+        _ => Err(BlasError::new(
+            routine,
+            param,
+            "side must be CblasLeft or CblasRight",
+        )),
+    }
+}
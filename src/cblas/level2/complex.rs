@@ -0,0 +1,884 @@
+use super::super::{swap_uplo, swap_vectors, transpose};
+use crate::level2::complex as level2;
+use crate::{xerbla, BlasError, Diag, Layout, Trans, Uplo};
+use num_complex::Complex;
+use num_traits::{Float, NumAssignOps};
+
+// A row-major Hermitian or ConjTrans operation is computed on the conjugated vectors, since for a row-major A
+// the column-major view is A**T and op( A )*x = conj( A**T*conj( x ) ) when op( A ) = A**H or A is Hermitian.
+// Both helpers stop at the end of a short slice, which the routine then reports.
+
+fn conj<T: Float + NumAssignOps>(n: usize, x: &mut [Complex<T>], incx: isize) {
+    if incx == 0 {
+        return;
+    }
+    let step = incx.unsigned_abs();
+    let mut ix = 0;
+    let mut i = 0;
+    while i < n && ix < x.len() {
+        x[ix] = x[ix].conj();
+        ix += step;
+        i += 1;
+    }
+}
+
+/// Returns the conjugated elements of x with an increment of +1 or -1, keeping the order of the elements.
+fn conj_copy<T: Float + NumAssignOps>(
+    n: usize,
+    x: &[Complex<T>],
+    incx: isize,
+) -> (Vec<Complex<T>>, isize) {
+    if incx == 0 {
+        return (Vec::new(), 0);
+    }
+    let step = incx.unsigned_abs();
+    let mut copy = Vec::with_capacity(n);
+    let mut ix = 0;
+    let mut i = 0;
+    while i < n {
+        match x.get(ix) {
+            Some(xi) => copy.push(xi.conj()),
+            None => break,
+        }
+        ix += step;
+        i += 1;
+    }
+    (copy, incx.signum())
+}
+
+/// GBMV with a [`Layout`], see [`level2::gbmv`].
+/// A row-major band matrix is stored as the column-major band matrix of A**T, so `kl` and `ku` are swapped.
+#[inline]
+pub fn gbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) {
+    if let Err(err) = try_gbmv(
+        layout, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    match (layout, trans) {
+        (Layout::ColMajor, _) => {
+            level2::try_gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        }
+        (Layout::RowMajor, Trans::ConjTrans) => {
+            let (x, incx) = conj_copy(m, x, incx);
+            conj(n, y, incy);
+            let result = level2::try_gbmv(
+                Trans::NoTrans,
+                n,
+                m,
+                ku,
+                kl,
+                alpha.conj(),
+                a,
+                lda,
+                &x,
+                incx,
+                beta.conj(),
+                y,
+                incy,
+            );
+            conj(n, y, incy);
+            result
+        }
+        (Layout::RowMajor, _) => level2::try_gbmv(
+            transpose(trans),
+            n,
+            m,
+            ku,
+            kl,
+            alpha,
+            a,
+            lda,
+            x,
+            incx,
+            beta,
+            y,
+            incy,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// GEMV with a [`Layout`], see [`level2::gemv`].
+#[inline]
+pub fn gemv<T: Float + NumAssignOps>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) {
+    if let Err(err) = try_gemv(layout, trans, m, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemv<T: Float + NumAssignOps>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    match (layout, trans) {
+        (Layout::ColMajor, _) => {
+            level2::try_gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+        }
+        (Layout::RowMajor, Trans::ConjTrans) => {
+            let (x, incx) = conj_copy(m, x, incx);
+            conj(n, y, incy);
+            let result = level2::try_gemv(
+                Trans::NoTrans,
+                n,
+                m,
+                alpha.conj(),
+                a,
+                lda,
+                &x,
+                incx,
+                beta.conj(),
+                y,
+                incy,
+            );
+            conj(n, y, incy);
+            result
+        }
+        (Layout::RowMajor, _) => level2::try_gemv(
+            transpose(trans),
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            x,
+            incx,
+            beta,
+            y,
+            incy,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// GERC with a [`Layout`], see [`level2::gerc`].
+/// A row-major A is updated as A**T := alpha*conj( y )*x**T + A**T.
+#[inline]
+pub fn gerc<T: Float + NumAssignOps>(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) {
+    if let Err(err) = try_gerc(layout, m, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gerc`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gerc<T: Float + NumAssignOps>(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_gerc(m, n, alpha, x, incx, y, incy, a, lda)
+            .map_err(|err| err.map_param(|p| p + 1)),
+        Layout::RowMajor => {
+            let (y, incy) = conj_copy(n, y, incy);
+            level2::try_geru(n, m, alpha, &y, incy, x, incx, a, lda)
+                .map_err(|err| err.map_param(swap_vectors))
+        }
+    }
+}
+
+/// GERU with a [`Layout`], see [`level2::geru`].
+/// A row-major A is updated as A**T := alpha*y*x**T + A**T.
+#[inline]
+pub fn geru<T: Float + NumAssignOps>(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) {
+    if let Err(err) = try_geru(layout, m, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`geru`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_geru<T: Float + NumAssignOps>(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_geru(m, n, alpha, x, incx, y, incy, a, lda)
+            .map_err(|err| err.map_param(|p| p + 1)),
+        Layout::RowMajor => level2::try_geru(n, m, alpha, y, incy, x, incx, a, lda)
+            .map_err(|err| err.map_param(swap_vectors)),
+    }
+}
+
+/// HBMV with a [`Layout`], see [`level2::hbmv`].
+#[inline]
+pub fn hbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) {
+    if let Err(err) = try_hbmv(layout, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_hbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy),
+        Layout::RowMajor => {
+            let (x, incx) = conj_copy(n, x, incx);
+            conj(n, y, incy);
+            let result = level2::try_hbmv(
+                swap_uplo(uplo),
+                n,
+                k,
+                alpha.conj(),
+                a,
+                lda,
+                &x,
+                incx,
+                beta.conj(),
+                y,
+                incy,
+            );
+            conj(n, y, incy);
+            result
+        }
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HEMV with a [`Layout`], see [`level2::hemv`].
+#[inline]
+pub fn hemv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) {
+    if let Err(err) = try_hemv(layout, uplo, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hemv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hemv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_hemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy),
+        Layout::RowMajor => {
+            let (x, incx) = conj_copy(n, x, incx);
+            conj(n, y, incy);
+            let result = level2::try_hemv(
+                swap_uplo(uplo),
+                n,
+                alpha.conj(),
+                a,
+                lda,
+                &x,
+                incx,
+                beta.conj(),
+                y,
+                incy,
+            );
+            conj(n, y, incy);
+            result
+        }
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HER with a [`Layout`], see [`level2::her`].
+#[inline]
+pub fn her<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[Complex<T>],
+    incx: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) {
+    if let Err(err) = try_her(layout, uplo, n, alpha, x, incx, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`her`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_her<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[Complex<T>],
+    incx: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_her(uplo, n, alpha, x, incx, a, lda),
+        Layout::RowMajor => {
+            let (x, incx) = conj_copy(n, x, incx);
+            level2::try_her(swap_uplo(uplo), n, alpha, &x, incx, a, lda)
+        }
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HER2 with a [`Layout`], see [`level2::her2`].
+#[inline]
+pub fn her2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) {
+    if let Err(err) = try_her2(layout, uplo, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`her2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_her2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_her2(uplo, n, alpha, x, incx, y, incy, a, lda)
+            .map_err(|err| err.map_param(|p| p + 1)),
+        Layout::RowMajor => {
+            let (x, incx) = conj_copy(n, x, incx);
+            let (y, incy) = conj_copy(n, y, incy);
+            level2::try_her2(swap_uplo(uplo), n, alpha, &y, incy, &x, incx, a, lda)
+                .map_err(|err| err.map_param(swap_vectors))
+        }
+    }
+}
+
+/// HPMV with a [`Layout`], see [`level2::hpmv`].
+#[inline]
+pub fn hpmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    ap: &[Complex<T>],
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) {
+    if let Err(err) = try_hpmv(layout, uplo, n, alpha, ap, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hpmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hpmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    ap: &[Complex<T>],
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_hpmv(uplo, n, alpha, ap, x, incx, beta, y, incy),
+        Layout::RowMajor => {
+            let (x, incx) = conj_copy(n, x, incx);
+            conj(n, y, incy);
+            let result = level2::try_hpmv(
+                swap_uplo(uplo),
+                n,
+                alpha.conj(),
+                ap,
+                &x,
+                incx,
+                beta.conj(),
+                y,
+                incy,
+            );
+            conj(n, y, incy);
+            result
+        }
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HPR with a [`Layout`], see [`level2::hpr`].
+#[inline]
+pub fn hpr<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[Complex<T>],
+    incx: isize,
+    ap: &mut [Complex<T>],
+) {
+    if let Err(err) = try_hpr(layout, uplo, n, alpha, x, incx, ap) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hpr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hpr<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[Complex<T>],
+    incx: isize,
+    ap: &mut [Complex<T>],
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_hpr(uplo, n, alpha, x, incx, ap),
+        Layout::RowMajor => {
+            let (x, incx) = conj_copy(n, x, incx);
+            level2::try_hpr(swap_uplo(uplo), n, alpha, &x, incx, ap)
+        }
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HPR2 with a [`Layout`], see [`level2::hpr2`].
+#[inline]
+pub fn hpr2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    ap: &mut [Complex<T>],
+) {
+    if let Err(err) = try_hpr2(layout, uplo, n, alpha, x, incx, y, incy, ap) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hpr2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hpr2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    ap: &mut [Complex<T>],
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_hpr2(uplo, n, alpha, x, incx, y, incy, ap)
+            .map_err(|err| err.map_param(|p| p + 1)),
+        Layout::RowMajor => {
+            let (x, incx) = conj_copy(n, x, incx);
+            let (y, incy) = conj_copy(n, y, incy);
+            level2::try_hpr2(swap_uplo(uplo), n, alpha, &y, incy, &x, incx, ap)
+                .map_err(|err| err.map_param(swap_vectors))
+        }
+    }
+}
+
+/// TBMV with a [`Layout`], see [`level2::tbmv`].
+#[inline]
+pub fn tbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) {
+    if let Err(err) = try_tbmv(layout, uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    triangular(layout, uplo, trans, n, x, incx, |uplo, trans, x| {
+        level2::try_tbmv(uplo, trans, diag, n, k, a, lda, x, incx)
+    })
+}
+
+/// TBSV with a [`Layout`], see [`level2::tbsv`].
+#[inline]
+pub fn tbsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) {
+    if let Err(err) = try_tbsv(layout, uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    triangular(layout, uplo, trans, n, x, incx, |uplo, trans, x| {
+        level2::try_tbsv(uplo, trans, diag, n, k, a, lda, x, incx)
+    })
+}
+
+/// TPMV with a [`Layout`], see [`level2::tpmv`].
+#[inline]
+pub fn tpmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[Complex<T>],
+    x: &mut [Complex<T>],
+    incx: isize,
+) {
+    if let Err(err) = try_tpmv(layout, uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[Complex<T>],
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    triangular(layout, uplo, trans, n, x, incx, |uplo, trans, x| {
+        level2::try_tpmv(uplo, trans, diag, n, ap, x, incx)
+    })
+}
+
+/// TPSV with a [`Layout`], see [`level2::tpsv`].
+#[inline]
+pub fn tpsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[Complex<T>],
+    x: &mut [Complex<T>],
+    incx: isize,
+) {
+    if let Err(err) = try_tpsv(layout, uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[Complex<T>],
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    triangular(layout, uplo, trans, n, x, incx, |uplo, trans, x| {
+        level2::try_tpsv(uplo, trans, diag, n, ap, x, incx)
+    })
+}
+
+/// TRMV with a [`Layout`], see [`level2::trmv`].
+#[inline]
+pub fn trmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) {
+    if let Err(err) = try_trmv(layout, uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    triangular(layout, uplo, trans, n, x, incx, |uplo, trans, x| {
+        level2::try_trmv(uplo, trans, diag, n, a, lda, x, incx)
+    })
+}
+
+/// TRSV with a [`Layout`], see [`level2::trsv`].
+#[inline]
+pub fn trsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) {
+    if let Err(err) = try_trsv(layout, uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    triangular(layout, uplo, trans, n, x, incx, |uplo, trans, x| {
+        level2::try_trsv(uplo, trans, diag, n, a, lda, x, incx)
+    })
+}
+
+/// Calls the triangular routine `f` with the column-major `uplo` and `trans`,
+/// a row-major ConjTrans is computed as conj( A**T*conj( x ) ).
+fn triangular<T, F>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+    f: F,
+) -> Result<(), BlasError>
+where
+    T: Float + NumAssignOps,
+    F: FnOnce(Uplo, Trans, &mut [Complex<T>]) -> Result<(), BlasError>,
+{
+    match (layout, trans) {
+        (Layout::ColMajor, _) => f(uplo, trans, x),
+        (Layout::RowMajor, Trans::ConjTrans) => {
+            conj(n, x, incx);
+            let result = f(swap_uplo(uplo), Trans::NoTrans, x);
+            conj(n, x, incx);
+            result
+        }
+        (Layout::RowMajor, _) => f(swap_uplo(uplo), transpose(trans), x),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
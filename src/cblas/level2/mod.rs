@@ -0,0 +1,665 @@
+use super::{swap_uplo, swap_vectors, transpose, uplo_for};
+use crate::{level2, xerbla, BlasError, Diag, Layout, Ring, Trans, Uplo};
+use num_traits::{Float, NumAssignOps};
+
+pub mod complex;
+
+/// GBMV with a [`Layout`], see [`level2::gbmv`].
+/// A row-major band matrix is stored as the column-major band matrix of A**T, so `kl` and `ku` are swapped.
+#[inline]
+pub fn gbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) {
+    if let Err(err) = try_gbmv(
+        layout, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => {
+            level2::try_gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        }
+        Layout::RowMajor => level2::try_gbmv(
+            transpose(trans),
+            n,
+            m,
+            ku,
+            kl,
+            alpha,
+            a,
+            lda,
+            x,
+            incx,
+            beta,
+            y,
+            incy,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// GEMV with a [`Layout`], see [`level2::gemv`].
+#[inline]
+pub fn gemv<T: Ring + 'static>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) {
+    if let Err(err) = try_gemv(layout, trans, m, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemv<T: Ring + 'static>(
+    layout: Layout,
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy),
+        Layout::RowMajor => level2::try_gemv(
+            transpose(trans),
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            x,
+            incx,
+            beta,
+            y,
+            incy,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// GER with a [`Layout`], see [`level2::ger`].
+/// A row-major A is updated as A**T := alpha*y*x**T + A**T.
+#[inline]
+pub fn ger<T: Ring>(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    a: &mut [T],
+    lda: usize,
+) {
+    if let Err(err) = try_ger(layout, m, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`ger`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_ger<T: Ring>(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    a: &mut [T],
+    lda: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level2::try_ger(m, n, alpha, x, incx, y, incy, a, lda)
+            .map_err(|err| err.map_param(|p| p + 1)),
+        Layout::RowMajor => level2::try_ger(n, m, alpha, y, incy, x, incx, a, lda)
+            .map_err(|err| err.map_param(swap_vectors)),
+    }
+}
+
+/// SBMV with a [`Layout`], see [`level2::sbmv`].
+#[inline]
+pub fn sbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) {
+    if let Err(err) = try_sbmv(layout, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`sbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_sbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    level2::try_sbmv(
+        uplo_for(layout, uplo),
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    )
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SPMV with a [`Layout`], see [`level2::spmv`].
+#[inline]
+pub fn spmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    ap: &[T],
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) {
+    if let Err(err) = try_spmv(layout, uplo, n, alpha, ap, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`spmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    ap: &[T],
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    level2::try_spmv(uplo_for(layout, uplo), n, alpha, ap, x, incx, beta, y, incy)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SPR with a [`Layout`], see [`level2::spr`].
+#[inline]
+pub fn spr<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    ap: &mut [T],
+) {
+    if let Err(err) = try_spr(layout, uplo, n, alpha, x, incx, ap) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`spr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spr<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    ap: &mut [T],
+) -> Result<(), BlasError> {
+    level2::try_spr(uplo_for(layout, uplo), n, alpha, x, incx, ap)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SPR2 with a [`Layout`], see [`level2::spr2`].
+#[inline]
+pub fn spr2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    ap: &mut [T],
+) {
+    if let Err(err) = try_spr2(layout, uplo, n, alpha, x, incx, y, incy, ap) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`spr2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spr2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    ap: &mut [T],
+) -> Result<(), BlasError> {
+    level2::try_spr2(uplo_for(layout, uplo), n, alpha, x, incx, y, incy, ap)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SYMV with a [`Layout`], see [`level2::symv`].
+#[inline]
+pub fn symv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) {
+    if let Err(err) = try_symv(layout, uplo, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`symv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_symv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    level2::try_symv(
+        uplo_for(layout, uplo),
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    )
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SYR with a [`Layout`], see [`level2::syr`].
+#[inline]
+pub fn syr<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    a: &mut [T],
+    lda: usize,
+) {
+    if let Err(err) = try_syr(layout, uplo, n, alpha, x, incx, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`syr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    a: &mut [T],
+    lda: usize,
+) -> Result<(), BlasError> {
+    level2::try_syr(uplo_for(layout, uplo), n, alpha, x, incx, a, lda)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SYR2 with a [`Layout`], see [`level2::syr2`].
+#[inline]
+pub fn syr2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    a: &mut [T],
+    lda: usize,
+) {
+    if let Err(err) = try_syr2(layout, uplo, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`syr2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr2<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    a: &mut [T],
+    lda: usize,
+) -> Result<(), BlasError> {
+    level2::try_syr2(uplo_for(layout, uplo), n, alpha, x, incx, y, incy, a, lda)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TBMV with a [`Layout`], see [`level2::tbmv`].
+#[inline]
+pub fn tbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) {
+    if let Err(err) = try_tbmv(layout, uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = triangular_for(layout, uplo, trans);
+    level2::try_tbmv(uplo, trans, diag, n, k, a, lda, x, incx)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TBSV with a [`Layout`], see [`level2::tbsv`].
+#[inline]
+pub fn tbsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) {
+    if let Err(err) = try_tbsv(layout, uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = triangular_for(layout, uplo, trans);
+    level2::try_tbsv(uplo, trans, diag, n, k, a, lda, x, incx)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TPMV with a [`Layout`], see [`level2::tpmv`].
+#[inline]
+pub fn tpmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[T],
+    x: &mut [T],
+    incx: isize,
+) {
+    if let Err(err) = try_tpmv(layout, uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[T],
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = triangular_for(layout, uplo, trans);
+    level2::try_tpmv(uplo, trans, diag, n, ap, x, incx).map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TPSV with a [`Layout`], see [`level2::tpsv`].
+#[inline]
+pub fn tpsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[T],
+    x: &mut [T],
+    incx: isize,
+) {
+    if let Err(err) = try_tpsv(layout, uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[T],
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = triangular_for(layout, uplo, trans);
+    level2::try_tpsv(uplo, trans, diag, n, ap, x, incx).map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TRMV with a [`Layout`], see [`level2::trmv`].
+#[inline]
+pub fn trmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) {
+    if let Err(err) = try_trmv(layout, uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = triangular_for(layout, uplo, trans);
+    level2::try_trmv(uplo, trans, diag, n, a, lda, x, incx).map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TRSV with a [`Layout`], see [`level2::trsv`].
+#[inline]
+pub fn trsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) {
+    if let Err(err) = try_trsv(layout, uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsv<T: Float + NumAssignOps>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = triangular_for(layout, uplo, trans);
+    level2::try_trsv(uplo, trans, diag, n, a, lda, x, incx).map_err(|err| err.map_param(|p| p + 1))
+}
+
+fn triangular_for(layout: Layout, uplo: Uplo, trans: Trans) -> (Uplo, Trans) {
+    match layout {
+        Layout::ColMajor => (uplo, trans),
+        Layout::RowMajor => (swap_uplo(uplo), transpose(trans)),
+    }
+}
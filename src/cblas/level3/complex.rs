@@ -0,0 +1,508 @@
+use super::super::{swap_side, swap_uplo};
+use super::{rank_for, swap_operands};
+use crate::level3::complex as level3;
+use crate::{xerbla, BlasError, Diag, Layout, RealScalar, Side, Trans, Uplo};
+use num_complex::Complex;
+
+/// GEMM with a [`Layout`], see [`level3::gemm`].
+/// A row-major C is computed as C**T := alpha*op( B )**T*op( A )**T + beta*C**T.
+#[inline]
+pub fn gemm<T: RealScalar>(
+    layout: Layout,
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_gemm(
+        layout, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemm<T: RealScalar>(
+    layout: Layout,
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_gemm(
+            trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+        .map_err(|err| err.map_param(|p| p + 1)),
+        Layout::RowMajor => level3::try_gemm(
+            trans_b, trans_a, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc,
+        )
+        .map_err(|err| err.map_param(swap_operands)),
+    }
+}
+
+/// SYMM with a [`Layout`], see [`level3::symm`].
+#[inline]
+pub fn symm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_symm(
+        layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`symm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_symm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc),
+        Layout::RowMajor => level3::try_symm(
+            swap_side(side),
+            swap_uplo(uplo),
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HEMM with a [`Layout`], see [`level3::hemm`].
+#[inline]
+pub fn hemm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_hemm(
+        layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hemm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_hemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc),
+        Layout::RowMajor => level3::try_hemm(
+            swap_side(side),
+            swap_uplo(uplo),
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HER2K with a [`Layout`], see [`level3::her2k`].
+#[inline]
+pub fn her2k<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_her2k(
+        layout, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`her2k`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+/// A row-major C is computed as conj( C ), which swaps alpha with conj( alpha ).
+#[inline]
+pub fn try_her2k<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => {
+            level3::try_her2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        }
+        Layout::RowMajor => level3::try_her2k(
+            swap_uplo(uplo),
+            conj_transpose(trans),
+            n,
+            k,
+            alpha.conj(),
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// HERK with a [`Layout`], see [`level3::herk`].
+#[inline]
+pub fn herk<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_herk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`herk`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_herk<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_herk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc),
+        Layout::RowMajor => level3::try_herk(
+            swap_uplo(uplo),
+            conj_transpose(trans),
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            beta,
+            c,
+            ldc,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SYR2K with a [`Layout`], see [`level3::syr2k`].
+#[inline]
+pub fn syr2k<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_syr2k(
+        layout, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`syr2k`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr2k<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = rank_for(layout, uplo, trans);
+    level3::try_syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SYRK with a [`Layout`], see [`level3::syrk`].
+#[inline]
+pub fn syrk<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_syrk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`syrk`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syrk<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = rank_for(layout, uplo, trans);
+    level3::try_syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TRMM with a [`Layout`], see [`level3::trmm`].
+#[inline]
+pub fn trmm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) {
+    if let Err(err) = try_trmm(layout, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_trmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb),
+        Layout::RowMajor => level3::try_trmm(
+            swap_side(side),
+            swap_uplo(uplo),
+            trans,
+            diag,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TRSM with a [`Layout`], see [`level3::trsm`].
+#[inline]
+pub fn trsm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) {
+    if let Err(err) = try_trsm(layout, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb),
+        Layout::RowMajor => level3::try_trsm(
+            swap_side(side),
+            swap_uplo(uplo),
+            trans,
+            diag,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// The `trans` of the column-major call equivalent to a row-major Hermitian rank k update,
+/// `Trans` is kept so that it's still rejected.
+fn conj_transpose(trans: Trans) -> Trans {
+    match trans {
+        Trans::NoTrans => Trans::ConjTrans,
+        Trans::Trans => Trans::Trans,
+        Trans::ConjTrans => Trans::NoTrans,
+    }
+}
@@ -0,0 +1,339 @@
+use super::{swap_side, swap_uplo, transpose};
+use crate::threading::ThreadSafe;
+use crate::{level3, xerbla, BlasError, Diag, Layout, RealScalar, Ring, Side, Trans, Uplo};
+
+pub mod complex;
+
+/// GEMM with a [`Layout`], see [`level3::gemm`].
+/// A row-major C is computed as C**T := alpha*op( B )**T*op( A )**T + beta*C**T.
+#[inline]
+pub fn gemm<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    if let Err(err) = try_gemm(
+        layout, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemm<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_gemm(
+            trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+        .map_err(|err| err.map_param(|p| p + 1)),
+        Layout::RowMajor => level3::try_gemm(
+            trans_b, trans_a, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc,
+        )
+        .map_err(|err| err.map_param(swap_operands)),
+    }
+}
+
+/// SYMM with a [`Layout`], see [`level3::symm`].
+#[inline]
+pub fn symm<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    if let Err(err) = try_symm(
+        layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`symm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_symm<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc),
+        Layout::RowMajor => level3::try_symm(
+            swap_side(side),
+            swap_uplo(uplo),
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SYR2K with a [`Layout`], see [`level3::syr2k`].
+#[inline]
+pub fn syr2k<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    if let Err(err) = try_syr2k(
+        layout, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`syr2k`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr2k<T: RealScalar>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = rank_for(layout, uplo, trans);
+    level3::try_syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// SYRK with a [`Layout`], see [`level3::syrk`].
+#[inline]
+pub fn syrk<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    if let Err(err) = try_syrk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`syrk`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syrk<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = rank_for(layout, uplo, trans);
+    level3::try_syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TRMM with a [`Layout`], see [`level3::trmm`].
+#[inline]
+pub fn trmm<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) {
+    if let Err(err) = try_trmm(layout, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmm<T: Ring + ThreadSafe + 'static>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_trmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb),
+        Layout::RowMajor => level3::try_trmm(
+            swap_side(side),
+            swap_uplo(uplo),
+            trans,
+            diag,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// TRSM with a [`Layout`], see [`level3::trsm`].
+#[inline]
+pub fn trsm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) {
+    if let Err(err) = try_trsm(layout, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsm<T: RealScalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    match layout {
+        Layout::ColMajor => level3::try_trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb),
+        Layout::RowMajor => level3::try_trsm(
+            swap_side(side),
+            swap_uplo(uplo),
+            trans,
+            diag,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+        ),
+    }
+    .map_err(|err| err.map_param(|p| p + 1))
+}
+
+/// Maps a parameter of a row-major GEMM, which swaps A and B, from its column-major position.
+fn swap_operands(param: usize) -> usize {
+    match param {
+        7 => 10,
+        8 => 11,
+        9 => 8,
+        10 => 9,
+        p => p + 1,
+    }
+}
+
+/// The `uplo` and `trans` of the column-major call equivalent to a symmetric rank k update with `layout`.
+fn rank_for(layout: Layout, uplo: Uplo, trans: Trans) -> (Uplo, Trans) {
+    match layout {
+        Layout::ColMajor => (uplo, trans),
+        Layout::RowMajor => (swap_uplo(uplo), transpose(trans)),
+    }
+}
@@ -0,0 +1,54 @@
+//! The level 2 and level 3 routines following the CBLAS convention, every routine takes a [`Layout`](crate::Layout) as its first argument.
+//!
+//! `Layout::ColMajor` calls the routine of the same name in [`level2`](crate::level2) and [`level3`](crate::level3).
+//! `Layout::RowMajor` reinterprets the row-major matrices as the transpose of a column-major matrix,
+//! swapping the dimensions, `uplo`, `side` and the operands where needed, the same way the reference CBLAS does.
+//!
+//! Parameters are numbered as in CBLAS, so the layout is parameter number 1.
+use crate::{Layout, Side, Trans, Uplo};
+
+#[cfg(feature = "cblas")]
+pub mod ffi;
+pub mod level2;
+pub mod level3;
+
+/// The transpose flag of the column-major call equivalent to a row-major call with `trans`.
+/// For real routines `ConjTrans` is the same as `Trans`.
+fn transpose(trans: Trans) -> Trans {
+    match trans {
+        Trans::NoTrans => Trans::Trans,
+        Trans::Trans | Trans::ConjTrans => Trans::NoTrans,
+    }
+}
+
+fn swap_uplo(uplo: Uplo) -> Uplo {
+    match uplo {
+        Uplo::Upper => Uplo::Lower,
+        Uplo::Lower => Uplo::Upper,
+    }
+}
+
+fn swap_side(side: Side) -> Side {
+    match side {
+        Side::Left => Side::Right,
+        Side::Right => Side::Left,
+    }
+}
+
+fn uplo_for(layout: Layout, uplo: Uplo) -> Uplo {
+    match layout {
+        Layout::ColMajor => uplo,
+        Layout::RowMajor => swap_uplo(uplo),
+    }
+}
+
+/// Maps a parameter of a row-major rank 1 or rank 2 update, which swaps x and y, from its column-major position.
+fn swap_vectors(param: usize) -> usize {
+    match param {
+        4 => 7,
+        5 => 8,
+        6 => 5,
+        7 => 6,
+        p => p + 1,
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// BlasError is returned by the `try_` routines, or reported to the [`xerbla`](crate::xerbla) handler, when an argument has an illegal value.
+/// `param` is the position of the offending argument, numbered the same way as the INFO reported to XERBLA by the reference BLAS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlasError {
+    routine: &'static str,
+    param: usize,
+    reason: &'static str,
+}
+
+impl BlasError {
+    pub(crate) fn new(routine: &'static str, param: usize, reason: &'static str) -> Self {
+        BlasError {
+            routine,
+            param,
+            reason,
+        }
+    }
+
+    pub(crate) fn map_param<F: FnOnce(usize) -> usize>(self, f: F) -> Self {
+        BlasError {
+            param: f(self.param),
+            ..self
+        }
+    }
+
+    #[cfg(any(feature = "cblas", feature = "fortran"))]
+    pub(crate) fn with_routine(self, routine: &'static str) -> Self {
+        BlasError { routine, ..self }
+    }
+
+    /// The name of the routine that detected the error, e.g. `"gemm"`.
+    pub fn routine(&self) -> &'static str {
+        self.routine
+    }
+
+    /// The position of the illegal argument.
+    pub fn param(&self) -> usize {
+        self.param
+    }
+
+    /// Why the argument was rejected.
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for BlasError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} parameter number {} had an illegal value: {}",
+            self.routine, self.param, self.reason
+        )
+    }
+}
+
+impl std::error::Error for BlasError {}
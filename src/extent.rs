@@ -0,0 +1,78 @@
+//! The number of elements spanned by the matrix and vector arguments of the level 2 and level 3 routines,
+//! which the `try_` routines check against the length of every slice before reading it.
+//! The checks come after the quick returns, which read none of the slices, as in the reference BLAS.
+
+use crate::BlasError;
+
+/// The number of elements spanned by a `rows` by `cols` matrix with leading dimension `ld`.
+pub(crate) fn extent(rows: usize, cols: usize, ld: usize) -> usize {
+    if rows == 0 || cols == 0 {
+        0
+    } else {
+        (cols - 1).saturating_mul(ld).saturating_add(rows)
+    }
+}
+
+/// The number of elements spanned by `n` elements of a vector with increment `inc`.
+pub(crate) fn span(n: usize, inc: isize) -> usize {
+    if n == 0 {
+        0
+    } else {
+        (n - 1).saturating_mul(inc.unsigned_abs()).saturating_add(1)
+    }
+}
+
+/// Checks that `a` holds a `rows` by `cols` matrix with leading dimension `ld`.
+/// A band matrix passes its number of stored diagonals as `rows`.
+pub(crate) fn matrix<T>(
+    routine: &'static str,
+    param: usize,
+    a: &[T],
+    rows: usize,
+    cols: usize,
+    ld: usize,
+) -> Result<(), BlasError> {
+    if a.len() < extent(rows, cols, ld) {
+        return Err(BlasError::new(
+            routine,
+            param,
+            "the slice must hold (cols-1)*ld + rows elements",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that `x` holds `n` elements with increment `inc`.
+pub(crate) fn vector<T>(
+    routine: &'static str,
+    param: usize,
+    x: &[T],
+    n: usize,
+    inc: isize,
+) -> Result<(), BlasError> {
+    if x.len() < span(n, inc) {
+        return Err(BlasError::new(
+            routine,
+            param,
+            "the slice must hold 1 + (n-1)*abs(inc) elements",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that `ap` holds a packed triangular `n` by `n` matrix.
+pub(crate) fn packed<T>(
+    routine: &'static str,
+    param: usize,
+    ap: &[T],
+    n: usize,
+) -> Result<(), BlasError> {
+    if ap.len() < n.saturating_mul(n.saturating_add(1)) / 2 {
+        return Err(BlasError::new(
+            routine,
+            param,
+            "the slice must hold n*(n+1)/2 elements",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use super::super::{array, array_mut, len, positive, span};
+use crate::level1::complex;
+use num_complex::Complex;
+use num_traits::{Float, NumAssignOps};
+use std::os::raw::{c_int, c_void};
+
+pub(crate) unsafe fn dot<T: Float + NumAssignOps>(
+    conj: bool,
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    dot: *mut c_void,
+) {
+    let n = len(n);
+    let x = array(x as *const Complex<T>, span(n, incx));
+    let y = array(y as *const Complex<T>, span(n, incy));
+    *(dot as *mut Complex<T>) = if conj {
+        complex::dotc(n, x, incx as isize, y, incy as isize)
+    } else {
+        complex::dotu(n, x, incx as isize, y, incy as isize)
+    };
+}
+
+pub(crate) unsafe fn nrm2<T: Float + NumAssignOps>(n: c_int, x: *const c_void, incx: c_int) -> T {
+    let n = len(n);
+    match positive(incx) {
+        Some(inc) => complex::nrm2(n, array(x as *const Complex<T>, span(n, incx)), inc),
+        None => T::zero(),
+    }
+}
+
+pub(crate) unsafe fn asum<T: Float + NumAssignOps>(n: c_int, x: *const c_void, incx: c_int) -> T {
+    let n = len(n);
+    match positive(incx) {
+        Some(inc) => complex::asum(n, array(x as *const Complex<T>, span(n, incx)), inc),
+        None => T::zero(),
+    }
+}
+
+/// The one-based index of the reference IAMAX, zero when n or incx is not positive.
+pub(crate) unsafe fn iamax<T: Float + NumAssignOps>(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+) -> usize {
+    let n = len(n);
+    match positive(incx) {
+        Some(inc) => complex::iamax(n, array(x as *const Complex<T>, span(n, incx)), inc),
+        None => 0,
+    }
+}
+
+pub(crate) unsafe fn swap<T: Float + NumAssignOps>(
+    n: c_int,
+    x: *mut c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    let n = len(n);
+    let x = array_mut(x as *mut Complex<T>, span(n, incx));
+    let y = array_mut(y as *mut Complex<T>, span(n, incy));
+    complex::swap(n, x, incx as isize, y, incy as isize)
+}
+
+pub(crate) unsafe fn copy<T: Float + NumAssignOps>(
+    n: c_int,
+    x: *const c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    let n = len(n);
+    let x = array(x as *const Complex<T>, span(n, incx));
+    let y = array_mut(y as *mut Complex<T>, span(n, incy));
+    complex::copy(n, x, incx as isize, y, incy as isize)
+}
+
+pub(crate) unsafe fn axpy<T: Float + NumAssignOps>(
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+) {
+    let n = len(n);
+    let x = array(x as *const Complex<T>, span(n, incx));
+    let y = array_mut(y as *mut Complex<T>, span(n, incy));
+    complex::axpy(
+        n,
+        &*(alpha as *const Complex<T>),
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+    )
+}
+
+pub(crate) unsafe fn rotg<T: Float + NumAssignOps>(
+    a: *mut c_void,
+    b: *mut c_void,
+    c: *mut T,
+    s: *mut c_void,
+) {
+    complex::rotg(
+        &mut *(a as *mut Complex<T>),
+        &mut *(b as *mut Complex<T>),
+        &mut *c,
+        &mut *(s as *mut Complex<T>),
+    )
+}
+
+pub(crate) unsafe fn rot<T: Float + NumAssignOps>(
+    n: c_int,
+    x: *mut c_void,
+    incx: c_int,
+    y: *mut c_void,
+    incy: c_int,
+    c: T,
+    s: T,
+) {
+    let n = len(n);
+    let x = array_mut(x as *mut Complex<T>, span(n, incx));
+    let y = array_mut(y as *mut Complex<T>, span(n, incy));
+    complex::rot(n, x, incx as isize, y, incy as isize, c, s)
+}
+
+pub(crate) unsafe fn scal<T: Float + NumAssignOps>(
+    n: c_int,
+    alpha: *const c_void,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    let n = len(n);
+    if let Some(inc) = positive(incx) {
+        let x = array_mut(x as *mut Complex<T>, span(n, incx));
+        complex::scal(n, *(alpha as *const Complex<T>), x, inc)
+    }
+}
+
+pub(crate) unsafe fn sscal<T: Float + NumAssignOps>(
+    n: c_int,
+    alpha: T,
+    x: *mut c_void,
+    incx: c_int,
+) {
+    let n = len(n);
+    if let Some(inc) = positive(incx) {
+        complex::sscal(
+            n,
+            alpha,
+            array_mut(x as *mut Complex<T>, span(n, incx)),
+            inc,
+        )
+    }
+}
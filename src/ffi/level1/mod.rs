@@ -0,0 +1,163 @@
+use super::{array, array_mut, len, positive, span};
+use crate::{level1, Ring};
+use num_traits::{Float, FromPrimitive, NumAssignOps};
+use std::os::raw::c_int;
+
+pub(crate) mod complex;
+
+pub(crate) unsafe fn dot<T: Ring + Float + NumAssignOps + 'static>(
+    n: c_int,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+) -> T {
+    let n = len(n);
+    let x = array(x, span(n, incx));
+    let y = array(y, span(n, incy));
+    level1::dot(n, x, incx as isize, y, incy as isize)
+}
+
+pub(crate) unsafe fn nrm2<T: Float + NumAssignOps>(n: c_int, x: *const T, incx: c_int) -> T {
+    let n = len(n);
+    match positive(incx) {
+        Some(inc) => level1::nrm2(n, array(x, span(n, incx)), inc),
+        None => T::zero(),
+    }
+}
+
+pub(crate) unsafe fn asum<T: Float + NumAssignOps>(n: c_int, x: *const T, incx: c_int) -> T {
+    let n = len(n);
+    match positive(incx) {
+        Some(inc) => level1::asum(n, array(x, span(n, incx)), inc),
+        None => T::zero(),
+    }
+}
+
+/// The one-based index of the reference IAMAX, zero when n or incx is not positive.
+pub(crate) unsafe fn iamax<T: Float + NumAssignOps>(n: c_int, x: *const T, incx: c_int) -> usize {
+    let n = len(n);
+    match positive(incx) {
+        Some(inc) => level1::iamax(n, array(x, span(n, incx)), inc),
+        None => 0,
+    }
+}
+
+pub(crate) unsafe fn swap<T: Ring + Float + NumAssignOps>(
+    n: c_int,
+    x: *mut T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+) {
+    let n = len(n);
+    let x = array_mut(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level1::swap(n, x, incx as isize, y, incy as isize)
+}
+
+pub(crate) unsafe fn copy<T: Ring + Float + NumAssignOps>(
+    n: c_int,
+    x: *const T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+) {
+    let n = len(n);
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level1::copy(n, x, incx as isize, y, incy as isize)
+}
+
+pub(crate) unsafe fn axpy<T: Ring + Float + NumAssignOps + 'static>(
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+) {
+    let n = len(n);
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level1::axpy(n, alpha, x, incx as isize, y, incy as isize)
+}
+
+pub(crate) unsafe fn rotmg<T: Float + NumAssignOps + FromPrimitive>(
+    d1: *mut T,
+    d2: *mut T,
+    b1: *mut T,
+    b2: T,
+    p: *mut T,
+) {
+    let mut b2 = b2;
+    level1::rotmg(&mut *d1, &mut *d2, &mut *b1, &mut b2, array_mut(p, 5))
+}
+
+pub(crate) unsafe fn rot<T: Float + NumAssignOps>(
+    n: c_int,
+    x: *mut T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+    c: T,
+    s: T,
+) {
+    let n = len(n);
+    let x = array_mut(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level1::rot(n, x, incx as isize, y, incy as isize, c, s)
+}
+
+pub(crate) unsafe fn rotm<T: Float + NumAssignOps>(
+    n: c_int,
+    x: *mut T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+    p: *const T,
+) {
+    let n = len(n);
+    let x = array_mut(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level1::rotm(n, x, incx as isize, y, incy as isize, array(p, 5))
+}
+
+pub(crate) unsafe fn scal<T: Ring + Float + NumAssignOps + 'static>(
+    n: c_int,
+    alpha: T,
+    x: *mut T,
+    incx: c_int,
+) {
+    let n = len(n);
+    if let Some(inc) = positive(incx) {
+        level1::scal(n, alpha, array_mut(x, span(n, incx)), inc)
+    }
+}
+
+pub(crate) unsafe fn sdsdot(
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+) -> f32 {
+    let n = len(n);
+    let x = array(x, span(n, incx));
+    let y = array(y, span(n, incy));
+    level1::ddot(n, alpha, x, incx as isize, y, incy as isize) as f32
+}
+
+pub(crate) unsafe fn dsdot(
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+) -> f64 {
+    let n = len(n);
+    let x = array(x, span(n, incx));
+    let y = array(y, span(n, incy));
+    level1::ddot(n, 0.0, x, incx as isize, y, incy as isize)
+}
@@ -0,0 +1,127 @@
+//! Helpers shared by the C interface in [`cblas::ffi`](crate::cblas::ffi) and the Fortran interface in
+//! [`fortran`](crate::fortran), which turn raw arguments into the sizes and slices of the generic routines.
+
+use crate::{xerbla, BlasError, Layout, Side, Trans};
+use std::os::raw::c_int;
+use std::slice;
+
+pub(crate) mod level1;
+
+/// Reports the error, if any, to the [`xerbla`] handler.
+pub(crate) fn report(result: Result<(), BlasError>) {
+    if let Err(err) = result {
+        xerbla::report(&err);
+    }
+}
+
+/// Converts a dimension or a leading dimension of a level 2 or level 3 routine.
+pub(crate) fn size(routine: &'static str, param: usize, value: c_int) -> Result<usize, BlasError> {
+    if value < 0 {
+        return Err(BlasError::new(routine, param, "must not be negative"));
+    }
+    Ok(value as usize)
+}
+
+/// The number of elements of a level 1 vector, zero when n is not positive.
+pub(crate) fn len(n: c_int) -> usize {
+    n.max(0) as usize
+}
+
+/// The number of elements spanned by n elements of a vector with increment inc.
+pub(crate) fn span(n: usize, inc: c_int) -> usize {
+    if n == 0 {
+        0
+    } else {
+        1 + (n - 1) * inc.unsigned_abs() as usize
+    }
+}
+
+/// The number of elements spanned by a rows by cols matrix with leading dimension ld.
+/// An illegal ld gives an empty matrix, the routine rejects it before reading the matrix.
+pub(crate) fn extent(layout: Layout, rows: usize, cols: usize, ld: usize) -> usize {
+    let (inner, outer) = match layout {
+        Layout::ColMajor => (rows, cols),
+        Layout::RowMajor => (cols, rows),
+    };
+    if inner == 0 || outer == 0 || ld < inner {
+        0
+    } else {
+        (outer - 1) * ld + inner
+    }
+}
+
+/// The number of elements spanned by a band matrix with `band` stored diagonals, see [`extent`].
+pub(crate) fn band_extent(
+    layout: Layout,
+    rows: usize,
+    cols: usize,
+    band: usize,
+    ld: usize,
+) -> usize {
+    let outer = match layout {
+        Layout::ColMajor => cols,
+        Layout::RowMajor => rows,
+    };
+    if outer == 0 || ld < band {
+        0
+    } else {
+        (outer - 1) * ld + band
+    }
+}
+
+/// The number of elements of a packed triangular n by n matrix.
+pub(crate) fn packed(n: usize) -> usize {
+    n * (n + 1) / 2
+}
+
+pub(crate) unsafe fn array<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len)
+    }
+}
+
+pub(crate) unsafe fn array_mut<'a, T>(ptr: *mut T, len: usize) -> &'a mut [T] {
+    if len == 0 {
+        &mut []
+    } else {
+        slice::from_raw_parts_mut(ptr, len)
+    }
+}
+
+/// The increment of a routine that only accepts positive increments, None when the routine must return early.
+pub(crate) fn positive(inc: c_int) -> Option<usize> {
+    if inc > 0 {
+        Some(inc as usize)
+    } else {
+        None
+    }
+}
+
+/// The lengths of x and y of op( A )*x where A is m by n.
+pub(crate) fn lengths(trans: Trans, m: usize, n: usize) -> (usize, usize) {
+    if trans == Trans::NoTrans {
+        (n, m)
+    } else {
+        (m, n)
+    }
+}
+
+/// The dimensions of op( X ) = rows by cols as stored in X.
+pub(crate) fn stored(trans: Trans, rows: usize, cols: usize) -> (usize, usize) {
+    if trans == Trans::NoTrans {
+        (rows, cols)
+    } else {
+        (cols, rows)
+    }
+}
+
+/// The order of the triangular or symmetric A of a routine with a side argument.
+pub(crate) fn order_of_a(side: Side, m: usize, n: usize) -> usize {
+    if side == Side::Left {
+        m
+    } else {
+        n
+    }
+}
@@ -0,0 +1,273 @@
+use crate::ffi::level1::complex;
+use num_complex::Complex;
+use std::os::raw::{c_int, c_void};
+
+#[no_mangle]
+pub unsafe extern "C" fn cdotc_(
+    n: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+) -> Complex<f32> {
+    let mut dot = Complex::new(0.0, 0.0);
+    complex::dot::<f32>(
+        true,
+        *n,
+        x,
+        *incx,
+        y,
+        *incy,
+        &mut dot as *mut _ as *mut c_void,
+    );
+    dot
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cdotu_(
+    n: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+) -> Complex<f32> {
+    let mut dot = Complex::new(0.0, 0.0);
+    complex::dot::<f32>(
+        false,
+        *n,
+        x,
+        *incx,
+        y,
+        *incy,
+        &mut dot as *mut _ as *mut c_void,
+    );
+    dot
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn scnrm2_(n: *const c_int, x: *const c_void, incx: *const c_int) -> f32 {
+    complex::nrm2(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn scasum_(n: *const c_int, x: *const c_void, incx: *const c_int) -> f32 {
+    complex::asum(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn icamax_(n: *const c_int, x: *const c_void, incx: *const c_int) -> c_int {
+    complex::iamax::<f32>(*n, x, *incx) as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cswap_(
+    n: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    complex::swap::<f32>(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ccopy_(
+    n: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    complex::copy::<f32>(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn caxpy_(
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    complex::axpy::<f32>(*n, alpha, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn crotg_(a: *mut c_void, b: *mut c_void, c: *mut f32, s: *mut c_void) {
+    complex::rotg::<f32>(a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn csrot_(
+    n: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+    c: *const f32,
+    s: *const f32,
+) {
+    complex::rot::<f32>(*n, x, *incx, y, *incy, *c, *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cscal_(
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    complex::scal::<f32>(*n, alpha, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn csscal_(
+    n: *const c_int,
+    alpha: *const f32,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    complex::sscal(*n, *alpha, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn scabs1_(z: *const c_void) -> f32 {
+    let z = *(z as *const Complex<f32>);
+    z.re.abs() + z.im.abs()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zdotc_(
+    n: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+) -> Complex<f64> {
+    let mut dot = Complex::new(0.0, 0.0);
+    complex::dot::<f64>(
+        true,
+        *n,
+        x,
+        *incx,
+        y,
+        *incy,
+        &mut dot as *mut _ as *mut c_void,
+    );
+    dot
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zdotu_(
+    n: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+) -> Complex<f64> {
+    let mut dot = Complex::new(0.0, 0.0);
+    complex::dot::<f64>(
+        false,
+        *n,
+        x,
+        *incx,
+        y,
+        *incy,
+        &mut dot as *mut _ as *mut c_void,
+    );
+    dot
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dznrm2_(n: *const c_int, x: *const c_void, incx: *const c_int) -> f64 {
+    complex::nrm2(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dzasum_(n: *const c_int, x: *const c_void, incx: *const c_int) -> f64 {
+    complex::asum(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn izamax_(n: *const c_int, x: *const c_void, incx: *const c_int) -> c_int {
+    complex::iamax::<f64>(*n, x, *incx) as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zswap_(
+    n: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    complex::swap::<f64>(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zcopy_(
+    n: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    complex::copy::<f64>(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zaxpy_(
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    complex::axpy::<f64>(*n, alpha, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zrotg_(a: *mut c_void, b: *mut c_void, c: *mut f64, s: *mut c_void) {
+    complex::rotg::<f64>(a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zdrot_(
+    n: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+    y: *mut c_void,
+    incy: *const c_int,
+    c: *const f64,
+    s: *const f64,
+) {
+    complex::rot::<f64>(*n, x, *incx, y, *incy, *c, *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zscal_(
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    complex::scal::<f64>(*n, alpha, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zdscal_(
+    n: *const c_int,
+    alpha: *const f64,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    complex::sscal(*n, *alpha, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dcabs1_(z: *const c_void) -> f64 {
+    let z = *(z as *const Complex<f64>);
+    z.re.abs() + z.im.abs()
+}
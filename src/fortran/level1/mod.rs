@@ -0,0 +1,249 @@
+use crate::ffi::level1;
+use std::os::raw::c_int;
+
+pub mod complex;
+
+#[no_mangle]
+pub unsafe extern "C" fn sdot_(
+    n: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+) -> f32 {
+    level1::dot(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sdsdot_(
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+) -> f32 {
+    level1::sdsdot(*n, *alpha, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn snrm2_(n: *const c_int, x: *const f32, incx: *const c_int) -> f32 {
+    level1::nrm2(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sasum_(n: *const c_int, x: *const f32, incx: *const c_int) -> f32 {
+    level1::asum(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn isamax_(n: *const c_int, x: *const f32, incx: *const c_int) -> c_int {
+    level1::iamax(*n, x, *incx) as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sswap_(
+    n: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    level1::swap(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn scopy_(
+    n: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    level1::copy(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn saxpy_(
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    level1::axpy(*n, *alpha, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn srotg_(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    crate::level1::rotg(&mut *a, &mut *b, &mut *c, &mut *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn srotmg_(
+    d1: *mut f32,
+    d2: *mut f32,
+    b1: *mut f32,
+    b2: *const f32,
+    p: *mut f32,
+) {
+    level1::rotmg(d1, d2, b1, *b2, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn srot_(
+    n: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+    c: *const f32,
+    s: *const f32,
+) {
+    level1::rot(*n, x, *incx, y, *incy, *c, *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn srotm_(
+    n: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+    p: *const f32,
+) {
+    level1::rotm(*n, x, *incx, y, *incy, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sscal_(
+    n: *const c_int,
+    alpha: *const f32,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    level1::scal(*n, *alpha, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ddot_(
+    n: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+) -> f64 {
+    level1::dot(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsdot_(
+    n: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+) -> f64 {
+    level1::dsdot(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dnrm2_(n: *const c_int, x: *const f64, incx: *const c_int) -> f64 {
+    level1::nrm2(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dasum_(n: *const c_int, x: *const f64, incx: *const c_int) -> f64 {
+    level1::asum(*n, x, *incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn idamax_(n: *const c_int, x: *const f64, incx: *const c_int) -> c_int {
+    level1::iamax(*n, x, *incx) as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dswap_(
+    n: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    level1::swap(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dcopy_(
+    n: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    level1::copy(*n, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn daxpy_(
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    level1::axpy(*n, *alpha, x, *incx, y, *incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn drotg_(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64) {
+    crate::level1::rotg(&mut *a, &mut *b, &mut *c, &mut *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn drotmg_(
+    d1: *mut f64,
+    d2: *mut f64,
+    b1: *mut f64,
+    b2: *const f64,
+    p: *mut f64,
+) {
+    level1::rotmg(d1, d2, b1, *b2, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn drot_(
+    n: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+    c: *const f64,
+    s: *const f64,
+) {
+    level1::rot(*n, x, *incx, y, *incy, *c, *s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn drotm_(
+    n: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+    p: *const f64,
+) {
+    level1::rotm(*n, x, *incx, y, *incy, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dscal_(
+    n: *const c_int,
+    alpha: *const f64,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    level1::scal(*n, *alpha, x, *incx)
+}
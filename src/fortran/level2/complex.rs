@@ -0,0 +1,956 @@
+use super::super::{to_diag, to_trans, to_uplo};
+use crate::ffi::{array, array_mut, band_extent, extent, lengths, packed, report, size, span};
+use crate::level2::complex;
+use crate::{BlasError, Layout};
+use num_complex::Complex;
+use num_traits::{Float, NumAssignOps};
+use std::os::raw::{c_char, c_int, c_void};
+
+unsafe fn gemv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    trans: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = to_trans(routine, 1, trans)?;
+    let (m, n, lda) = (
+        size(routine, 2, m)?,
+        size(routine, 3, n)?,
+        size(routine, 6, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(a.cast(), extent(Layout::ColMajor, m, n, lda));
+    let x = array(x.cast(), span(lenx, incx));
+    let y = array_mut(y.cast(), span(leny, incy));
+    complex::try_gemv(
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn gbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    trans: c_char,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = to_trans(routine, 1, trans)?;
+    let (m, n) = (size(routine, 2, m)?, size(routine, 3, n)?);
+    let (kl, ku, lda) = (
+        size(routine, 4, kl)?,
+        size(routine, 5, ku)?,
+        size(routine, 8, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(
+        a.cast(),
+        band_extent(Layout::ColMajor, m, n, kl + ku + 1, lda),
+    );
+    let x = array(x.cast(), span(lenx, incx));
+    let y = array_mut(y.cast(), span(leny, incy));
+    complex::try_gbmv(
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 1, uplo)?,
+        to_trans(routine, 2, trans)?,
+        to_diag(routine, 3, diag)?,
+    );
+    let (n, lda) = (size(routine, 4, n)?, size(routine, 6, lda)?);
+    let a: &[Complex<T>] = array(a.cast(), extent(Layout::ColMajor, n, n, lda));
+    let x = array_mut(x.cast(), span(n, incx));
+    if solve {
+        complex::try_trsv(uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_trmv(uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: c_int,
+    k: c_int,
+    a: *const c_void,
+    lda: c_int,
+    x: *mut c_void,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 1, uplo)?,
+        to_trans(routine, 2, trans)?,
+        to_diag(routine, 3, diag)?,
+    );
+    let (n, k, lda) = (
+        size(routine, 4, n)?,
+        size(routine, 5, k)?,
+        size(routine, 7, lda)?,
+    );
+    let a: &[Complex<T>] = array(a.cast(), band_extent(Layout::ColMajor, n, n, k + 1, lda));
+    let x = array_mut(x.cast(), span(n, incx));
+    if solve {
+        complex::try_tbsv(uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_tbmv(uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tpmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 1, uplo)?,
+        to_trans(routine, 2, trans)?,
+        to_diag(routine, 3, diag)?,
+    );
+    let n = size(routine, 4, n)?;
+    let ap: &[Complex<T>] = array(ap.cast(), packed(n));
+    let x = array_mut(x.cast(), span(n, incx));
+    if solve {
+        complex::try_tpsv(uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_tpmv(uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn hemv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, lda) = (size(routine, 2, n)?, size(routine, 5, lda)?);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(a.cast(), extent(Layout::ColMajor, n, n, lda));
+    let x = array(x.cast(), span(n, incx));
+    let y = array_mut(y.cast(), span(n, incy));
+    complex::try_hemv(
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, k, lda) = (
+        size(routine, 2, n)?,
+        size(routine, 3, k)?,
+        size(routine, 6, lda)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let a = array(a.cast(), band_extent(Layout::ColMajor, n, n, k + 1, lda));
+    let x = array(x.cast(), span(n, incx));
+    let y = array_mut(y.cast(), span(n, incy));
+    complex::try_hbmv(
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hpmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: *const c_void,
+    ap: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let n = size(routine, 2, n)?;
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let ap = array(ap.cast(), packed(n));
+    let x = array(x.cast(), span(n, incx));
+    let y = array_mut(y.cast(), span(n, incy));
+    complex::try_hpmv(uplo, n, alpha, ap, x, incx as isize, beta, y, incy as isize)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn ger<T: Float + NumAssignOps>(
+    routine: &'static str,
+    conj: bool,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let (m, n, lda) = (
+        size(routine, 1, m)?,
+        size(routine, 2, n)?,
+        size(routine, 9, lda)?,
+    );
+    let alpha = *alpha.cast::<Complex<T>>();
+    let x = array(x.cast(), span(m, incx));
+    let y = array(y.cast(), span(n, incy));
+    let a = array_mut(a.cast(), extent(Layout::ColMajor, m, n, lda));
+    if conj {
+        complex::try_gerc(m, n, alpha, x, incx as isize, y, incy as isize, a, lda)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_geru(m, n, alpha, x, incx as isize, y, incy as isize, a, lda)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn her<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    x: *const c_void,
+    incx: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, lda) = (size(routine, 2, n)?, size(routine, 7, lda)?);
+    let x = array(x.cast(), span(n, incx));
+    let a = array_mut(a.cast(), extent(Layout::ColMajor, n, n, lda));
+    complex::try_her(uplo, n, alpha, x, incx as isize, a, lda)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hpr<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    x: *const c_void,
+    incx: c_int,
+    ap: *mut c_void,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let n = size(routine, 2, n)?;
+    let x = array(x.cast(), span(n, incx));
+    let ap = array_mut(ap.cast(), packed(n));
+    complex::try_hpr(uplo, n, alpha, x, incx as isize, ap).map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn her2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    a: *mut c_void,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, lda) = (size(routine, 2, n)?, size(routine, 9, lda)?);
+    let alpha = *alpha.cast::<Complex<T>>();
+    let x = array(x.cast(), span(n, incx));
+    let y = array(y.cast(), span(n, incy));
+    let a = array_mut(a.cast(), extent(Layout::ColMajor, n, n, lda));
+    complex::try_her2(uplo, n, alpha, x, incx as isize, y, incy as isize, a, lda)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn hpr2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: c_int,
+    y: *const c_void,
+    incy: c_int,
+    ap: *mut c_void,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let n = size(routine, 2, n)?;
+    let alpha = *alpha.cast::<Complex<T>>();
+    let x = array(x.cast(), span(n, incx));
+    let y = array(y.cast(), span(n, incy));
+    let ap = array_mut(ap.cast(), packed(n));
+    complex::try_hpr2(uplo, n, alpha, x, incx as isize, y, incy as isize, ap)
+        .map_err(|err| err.with_routine(routine))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(gemv::<f32>(
+        "CGEMV", *trans, *m, *n, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(gbmv::<f32>(
+        "CGBMV", *trans, *m, *n, *kl, *ku, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctrmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(trmv::<f32>(
+        "CTRMV", false, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tbmv::<f32>(
+        "CTBMV", false, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tpmv::<f32>(
+        "CTPMV", false, *uplo, *trans, *diag, *n, ap, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(trmv::<f32>(
+        "CTRSV", true, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tbmv::<f32>(
+        "CTBSV", true, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tpmv::<f32>(
+        "CTPSV", true, *uplo, *trans, *diag, *n, ap, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn chemv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(hemv::<f32>(
+        "CHEMV", *uplo, *n, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn chbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(hbmv::<f32>(
+        "CHBMV", *uplo, *n, *k, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn chpmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    ap: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(hpmv::<f32>(
+        "CHPMV", *uplo, *n, alpha, ap, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cgeru_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(ger::<f32>(
+        "CGERU", false, *m, *n, alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cgerc_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(ger::<f32>(
+        "CGERC", true, *m, *n, alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cher_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const c_void,
+    incx: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(her::<f32>("CHER", *uplo, *n, *alpha, x, *incx, a, *lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn chpr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const c_void,
+    incx: *const c_int,
+    ap: *mut c_void,
+) {
+    report(hpr::<f32>("CHPR", *uplo, *n, *alpha, x, *incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cher2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(her2::<f32>(
+        "CHER2", *uplo, *n, alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn chpr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    ap: *mut c_void,
+) {
+    report(hpr2::<f32>(
+        "CHPR2", *uplo, *n, alpha, x, *incx, y, *incy, ap,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(gemv::<f64>(
+        "ZGEMV", *trans, *m, *n, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(gbmv::<f64>(
+        "ZGBMV", *trans, *m, *n, *kl, *ku, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztrmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(trmv::<f64>(
+        "ZTRMV", false, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tbmv::<f64>(
+        "ZTBMV", false, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tpmv::<f64>(
+        "ZTPMV", false, *uplo, *trans, *diag, *n, ap, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(trmv::<f64>(
+        "ZTRSV", true, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tbmv::<f64>(
+        "ZTBSV", true, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_void,
+    x: *mut c_void,
+    incx: *const c_int,
+) {
+    report(tpmv::<f64>(
+        "ZTPSV", true, *uplo, *trans, *diag, *n, ap, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zhemv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(hemv::<f64>(
+        "ZHEMV", *uplo, *n, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zhbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(hbmv::<f64>(
+        "ZHBMV", *uplo, *n, *k, alpha, a, *lda, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zhpmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    ap: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    beta: *const c_void,
+    y: *mut c_void,
+    incy: *const c_int,
+) {
+    report(hpmv::<f64>(
+        "ZHPMV", *uplo, *n, alpha, ap, x, *incx, beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zgeru_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(ger::<f64>(
+        "ZGERU", false, *m, *n, alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zgerc_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(ger::<f64>(
+        "ZGERC", true, *m, *n, alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zher_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const c_void,
+    incx: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(her::<f64>("ZHER", *uplo, *n, *alpha, x, *incx, a, *lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zhpr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const c_void,
+    incx: *const c_int,
+    ap: *mut c_void,
+) {
+    report(hpr::<f64>("ZHPR", *uplo, *n, *alpha, x, *incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zher2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    a: *mut c_void,
+    lda: *const c_int,
+) {
+    report(her2::<f64>(
+        "ZHER2", *uplo, *n, alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zhpr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_void,
+    x: *const c_void,
+    incx: *const c_int,
+    y: *const c_void,
+    incy: *const c_int,
+    ap: *mut c_void,
+) {
+    report(hpr2::<f64>(
+        "ZHPR2", *uplo, *n, alpha, x, *incx, y, *incy, ap,
+    ))
+}
@@ -0,0 +1,890 @@
+use super::{to_diag, to_trans, to_uplo};
+use crate::ffi::{array, array_mut, band_extent, extent, lengths, packed, report, size, span};
+use crate::level2;
+use crate::{BlasError, Layout, Ring};
+use num_traits::{Float, NumAssignOps};
+use std::os::raw::{c_char, c_int};
+
+pub mod complex;
+
+unsafe fn gemv<T: Ring + Float + NumAssignOps + 'static>(
+    routine: &'static str,
+    trans: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = to_trans(routine, 1, trans)?;
+    let (m, n, lda) = (
+        size(routine, 2, m)?,
+        size(routine, 3, n)?,
+        size(routine, 6, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let a = array(a, extent(Layout::ColMajor, m, n, lda));
+    let x = array(x, span(lenx, incx));
+    let y = array_mut(y, span(leny, incy));
+    level2::try_gemv(
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn gbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    trans: c_char,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = to_trans(routine, 1, trans)?;
+    let (m, n) = (size(routine, 2, m)?, size(routine, 3, n)?);
+    let (kl, ku, lda) = (
+        size(routine, 4, kl)?,
+        size(routine, 5, ku)?,
+        size(routine, 8, lda)?,
+    );
+    let (lenx, leny) = lengths(trans, m, n);
+    let a = array(a, band_extent(Layout::ColMajor, m, n, kl + ku + 1, lda));
+    let x = array(x, span(lenx, incx));
+    let y = array_mut(y, span(leny, incy));
+    level2::try_gbmv(
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: c_int,
+    a: *const T,
+    lda: c_int,
+    x: *mut T,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 1, uplo)?,
+        to_trans(routine, 2, trans)?,
+        to_diag(routine, 3, diag)?,
+    );
+    let (n, lda) = (size(routine, 4, n)?, size(routine, 6, lda)?);
+    let a = array(a, extent(Layout::ColMajor, n, n, lda));
+    let x = array_mut(x, span(n, incx));
+    if solve {
+        level2::try_trsv(uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        level2::try_trmv(uplo, trans, diag, n, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: c_int,
+    k: c_int,
+    a: *const T,
+    lda: c_int,
+    x: *mut T,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 1, uplo)?,
+        to_trans(routine, 2, trans)?,
+        to_diag(routine, 3, diag)?,
+    );
+    let (n, k, lda) = (
+        size(routine, 4, n)?,
+        size(routine, 5, k)?,
+        size(routine, 7, lda)?,
+    );
+    let a = array(a, band_extent(Layout::ColMajor, n, n, k + 1, lda));
+    let x = array_mut(x, span(n, incx));
+    if solve {
+        level2::try_tbsv(uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        level2::try_tbmv(uplo, trans, diag, n, k, a, lda, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn tpmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    solve: bool,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: c_int,
+    ap: *const T,
+    x: *mut T,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans, diag) = (
+        to_uplo(routine, 1, uplo)?,
+        to_trans(routine, 2, trans)?,
+        to_diag(routine, 3, diag)?,
+    );
+    let n = size(routine, 4, n)?;
+    let ap = array(ap, packed(n));
+    let x = array_mut(x, span(n, incx));
+    if solve {
+        level2::try_tpsv(uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        level2::try_tpmv(uplo, trans, diag, n, ap, x, incx as isize)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn symv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, lda) = (size(routine, 2, n)?, size(routine, 5, lda)?);
+    let a = array(a, extent(Layout::ColMajor, n, n, lda));
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level2::try_symv(
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn sbmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, k, lda) = (
+        size(routine, 2, n)?,
+        size(routine, 3, k)?,
+        size(routine, 6, lda)?,
+    );
+    let a = array(a, band_extent(Layout::ColMajor, n, n, k + 1, lda));
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level2::try_sbmv(
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn spmv<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    ap: *const T,
+    x: *const T,
+    incx: c_int,
+    beta: T,
+    y: *mut T,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let n = size(routine, 2, n)?;
+    let ap = array(ap, packed(n));
+    let x = array(x, span(n, incx));
+    let y = array_mut(y, span(n, incy));
+    level2::try_spmv(uplo, n, alpha, ap, x, incx as isize, beta, y, incy as isize)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn ger<T: Ring + Float + NumAssignOps>(
+    routine: &'static str,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    a: *mut T,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let (m, n, lda) = (
+        size(routine, 1, m)?,
+        size(routine, 2, n)?,
+        size(routine, 9, lda)?,
+    );
+    let x = array(x, span(m, incx));
+    let y = array(y, span(n, incy));
+    let a = array_mut(a, extent(Layout::ColMajor, m, n, lda));
+    level2::try_ger(m, n, alpha, x, incx as isize, y, incy as isize, a, lda)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    a: *mut T,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, lda) = (size(routine, 2, n)?, size(routine, 7, lda)?);
+    let x = array(x, span(n, incx));
+    let a = array_mut(a, extent(Layout::ColMajor, n, n, lda));
+    level2::try_syr(uplo, n, alpha, x, incx as isize, a, lda)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn spr<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    ap: *mut T,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let n = size(routine, 2, n)?;
+    let x = array(x, span(n, incx));
+    let ap = array_mut(ap, packed(n));
+    level2::try_spr(uplo, n, alpha, x, incx as isize, ap).map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    a: *mut T,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let (n, lda) = (size(routine, 2, n)?, size(routine, 9, lda)?);
+    let x = array(x, span(n, incx));
+    let y = array(y, span(n, incy));
+    let a = array_mut(a, extent(Layout::ColMajor, n, n, lda));
+    level2::try_syr2(uplo, n, alpha, x, incx as isize, y, incy as isize, a, lda)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn spr2<T: Float + NumAssignOps>(
+    routine: &'static str,
+    uplo: c_char,
+    n: c_int,
+    alpha: T,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    ap: *mut T,
+) -> Result<(), BlasError> {
+    let uplo = to_uplo(routine, 1, uplo)?;
+    let n = size(routine, 2, n)?;
+    let x = array(x, span(n, incx));
+    let y = array(y, span(n, incy));
+    let ap = array_mut(ap, packed(n));
+    level2::try_spr2(uplo, n, alpha, x, incx as isize, y, incy as isize, ap)
+        .map_err(|err| err.with_routine(routine))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    report(gemv(
+        "SGEMV", *trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    report(gbmv(
+        "SGBMV", *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    report(trmv(
+        "STRMV", false, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    report(tbmv(
+        "STBMV", false, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    report(tpmv("STPMV", false, *uplo, *trans, *diag, *n, ap, x, *incx))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    report(trmv(
+        "STRSV", true, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    report(tbmv(
+        "STBSV", true, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    report(tpmv("STPSV", true, *uplo, *trans, *diag, *n, ap, x, *incx))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssymv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    report(symv(
+        "SSYMV", *uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    report(sbmv(
+        "SSBMV", *uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sspmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    report(spmv(
+        "SSPMV", *uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sger_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+    a: *mut f32,
+    lda: *const c_int,
+) {
+    report(ger("SGER", *m, *n, *alpha, x, *incx, y, *incy, a, *lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssyr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    a: *mut f32,
+    lda: *const c_int,
+) {
+    report(syr("SSYR", *uplo, *n, *alpha, x, *incx, a, *lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sspr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    ap: *mut f32,
+) {
+    report(spr("SSPR", *uplo, *n, *alpha, x, *incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssyr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+    a: *mut f32,
+    lda: *const c_int,
+) {
+    report(syr2(
+        "SSYR2", *uplo, *n, *alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sspr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+    ap: *mut f32,
+) {
+    report(spr2("SSPR2", *uplo, *n, *alpha, x, *incx, y, *incy, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    report(gemv(
+        "DGEMV", *trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    report(gbmv(
+        "DGBMV", *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtrmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    report(trmv(
+        "DTRMV", false, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    report(tbmv(
+        "DTBMV", false, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    report(tpmv("DTPMV", false, *uplo, *trans, *diag, *n, ap, x, *incx))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    report(trmv(
+        "DTRSV", true, *uplo, *trans, *diag, *n, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    report(tbmv(
+        "DTBSV", true, *uplo, *trans, *diag, *n, *k, a, *lda, x, *incx,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    report(tpmv("DTPSV", true, *uplo, *trans, *diag, *n, ap, x, *incx))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsymv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    report(symv(
+        "DSYMV", *uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    report(sbmv(
+        "DSBMV", *uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dspmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    report(spmv(
+        "DSPMV", *uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dger_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+    a: *mut f64,
+    lda: *const c_int,
+) {
+    report(ger("DGER", *m, *n, *alpha, x, *incx, y, *incy, a, *lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsyr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    a: *mut f64,
+    lda: *const c_int,
+) {
+    report(syr("DSYR", *uplo, *n, *alpha, x, *incx, a, *lda))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dspr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    ap: *mut f64,
+) {
+    report(spr("DSPR", *uplo, *n, *alpha, x, *incx, ap))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsyr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+    a: *mut f64,
+    lda: *const c_int,
+) {
+    report(syr2(
+        "DSYR2", *uplo, *n, *alpha, x, *incx, y, *incy, a, *lda,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dspr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+    ap: *mut f64,
+) {
+    report(spr2("DSPR2", *uplo, *n, *alpha, x, *incx, y, *incy, ap))
+}
@@ -0,0 +1,577 @@
+use super::super::{to_diag, to_side, to_trans, to_uplo};
+use crate::ffi::{array, array_mut, extent, order_of_a, report, size, stored};
+use crate::level3::complex;
+use crate::{BlasError, Layout, RealScalar};
+use num_complex::Complex;
+use std::os::raw::{c_char, c_int, c_void};
+
+unsafe fn gemm<T: RealScalar>(
+    routine: &'static str,
+    trans_a: c_char,
+    trans_b: c_char,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (trans_a, trans_b) = (
+        to_trans(routine, 1, trans_a)?,
+        to_trans(routine, 2, trans_b)?,
+    );
+    let (m, n, k) = (
+        size(routine, 3, m)?,
+        size(routine, 4, n)?,
+        size(routine, 5, k)?,
+    );
+    let (lda, ldb, ldc) = (
+        size(routine, 8, lda)?,
+        size(routine, 10, ldb)?,
+        size(routine, 13, ldc)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let (rows_a, cols_a) = stored(trans_a, m, k);
+    let (rows_b, cols_b) = stored(trans_b, k, n);
+    let a = array(a.cast(), extent(Layout::ColMajor, rows_a, cols_a, lda));
+    let b = array(b.cast(), extent(Layout::ColMajor, rows_b, cols_b, ldb));
+    let c = array_mut(c.cast(), extent(Layout::ColMajor, m, n, ldc));
+    complex::try_gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+/// SYMM, or HEMM when `hermitian` is true.
+unsafe fn symm<T: RealScalar>(
+    routine: &'static str,
+    hermitian: bool,
+    side: c_char,
+    uplo: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (side, uplo) = (to_side(routine, 1, side)?, to_uplo(routine, 2, uplo)?);
+    let (m, n) = (size(routine, 3, m)?, size(routine, 4, n)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 7, lda)?,
+        size(routine, 9, ldb)?,
+        size(routine, 12, ldc)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let ka = order_of_a(side, m, n);
+    let a = array(a.cast(), extent(Layout::ColMajor, ka, ka, lda));
+    let b = array(b.cast(), extent(Layout::ColMajor, m, n, ldb));
+    let c = array_mut(c.cast(), extent(Layout::ColMajor, m, n, ldc));
+    if hermitian {
+        complex::try_hemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+unsafe fn syrk<T: RealScalar>(
+    routine: &'static str,
+    uplo: c_char,
+    trans: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = (to_uplo(routine, 1, uplo)?, to_trans(routine, 2, trans)?);
+    let (n, k) = (size(routine, 3, n)?, size(routine, 4, k)?);
+    let (lda, ldc) = (size(routine, 7, lda)?, size(routine, 10, ldc)?);
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let (rows_a, cols_a) = stored(trans, n, k);
+    let a = array(a.cast(), extent(Layout::ColMajor, rows_a, cols_a, lda));
+    let c = array_mut(c.cast(), extent(Layout::ColMajor, n, n, ldc));
+    complex::try_syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn herk<T: RealScalar>(
+    routine: &'static str,
+    uplo: c_char,
+    trans: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const c_void,
+    lda: c_int,
+    beta: T,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = (to_uplo(routine, 1, uplo)?, to_trans(routine, 2, trans)?);
+    let (n, k) = (size(routine, 3, n)?, size(routine, 4, k)?);
+    let (lda, ldc) = (size(routine, 7, lda)?, size(routine, 10, ldc)?);
+    let (rows_a, cols_a) = stored(trans, n, k);
+    let a = array(a.cast(), extent(Layout::ColMajor, rows_a, cols_a, lda));
+    let c = array_mut(c.cast(), extent(Layout::ColMajor, n, n, ldc));
+    complex::try_herk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr2k<T: RealScalar>(
+    routine: &'static str,
+    uplo: c_char,
+    trans: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = (to_uplo(routine, 1, uplo)?, to_trans(routine, 2, trans)?);
+    let (n, k) = (size(routine, 3, n)?, size(routine, 4, k)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 7, lda)?,
+        size(routine, 9, ldb)?,
+        size(routine, 12, ldc)?,
+    );
+    let (alpha, beta) = (*alpha.cast::<Complex<T>>(), *beta.cast::<Complex<T>>());
+    let (rows, cols) = stored(trans, n, k);
+    let a = array(a.cast(), extent(Layout::ColMajor, rows, cols, lda));
+    let b = array(b.cast(), extent(Layout::ColMajor, rows, cols, ldb));
+    let c = array_mut(c.cast(), extent(Layout::ColMajor, n, n, ldc));
+    complex::try_syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn her2k<T: RealScalar>(
+    routine: &'static str,
+    uplo: c_char,
+    trans: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *const c_void,
+    ldb: c_int,
+    beta: T,
+    c: *mut c_void,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = (to_uplo(routine, 1, uplo)?, to_trans(routine, 2, trans)?);
+    let (n, k) = (size(routine, 3, n)?, size(routine, 4, k)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 7, lda)?,
+        size(routine, 9, ldb)?,
+        size(routine, 12, ldc)?,
+    );
+    let alpha = *alpha.cast::<Complex<T>>();
+    let (rows, cols) = stored(trans, n, k);
+    let a = array(a.cast(), extent(Layout::ColMajor, rows, cols, lda));
+    let b = array(b.cast(), extent(Layout::ColMajor, rows, cols, ldb));
+    let c = array_mut(c.cast(), extent(Layout::ColMajor, n, n, ldc));
+    complex::try_her2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmm<T: RealScalar>(
+    routine: &'static str,
+    solve: bool,
+    side: c_char,
+    uplo: c_char,
+    trans_a: c_char,
+    diag: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: c_int,
+    b: *mut c_void,
+    ldb: c_int,
+) -> Result<(), BlasError> {
+    let (side, uplo) = (to_side(routine, 1, side)?, to_uplo(routine, 2, uplo)?);
+    let (trans_a, diag) = (to_trans(routine, 3, trans_a)?, to_diag(routine, 4, diag)?);
+    let (m, n) = (size(routine, 5, m)?, size(routine, 6, n)?);
+    let (lda, ldb) = (size(routine, 9, lda)?, size(routine, 11, ldb)?);
+    let alpha = *alpha.cast::<Complex<T>>();
+    let ka = order_of_a(side, m, n);
+    let a = array(a.cast(), extent(Layout::ColMajor, ka, ka, lda));
+    let b = array_mut(b.cast(), extent(Layout::ColMajor, m, n, ldb));
+    if solve {
+        complex::try_trsm(side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        complex::try_trmm(side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cgemm_(
+    trans_a: *const c_char,
+    trans_b: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(gemm::<f32>(
+        "CGEMM", *trans_a, *trans_b, *m, *n, *k, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn csymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(symm::<f32>(
+        "CSYMM", false, *side, *uplo, *m, *n, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn chemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(symm::<f32>(
+        "CHEMM", true, *side, *uplo, *m, *n, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn csyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(syrk::<f32>(
+        "CSYRK", *uplo, *trans, *n, *k, alpha, a, *lda, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f32,
+    a: *const c_void,
+    lda: *const c_int,
+    beta: *const f32,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(herk::<f32>(
+        "CHERK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn csyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(syr2k::<f32>(
+        "CSYR2K", *uplo, *trans, *n, *k, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const f32,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(her2k::<f32>(
+        "CHER2K", *uplo, *trans, *n, *k, alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *mut c_void,
+    ldb: *const c_int,
+) {
+    report(trmm::<f32>(
+        "CTRMM", false, *side, *uplo, *trans_a, *diag, *m, *n, alpha, a, *lda, b, *ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ctrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *mut c_void,
+    ldb: *const c_int,
+) {
+    report(trmm::<f32>(
+        "CTRSM", true, *side, *uplo, *trans_a, *diag, *m, *n, alpha, a, *lda, b, *ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zgemm_(
+    trans_a: *const c_char,
+    trans_b: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(gemm::<f64>(
+        "ZGEMM", *trans_a, *trans_b, *m, *n, *k, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(symm::<f64>(
+        "ZSYMM", false, *side, *uplo, *m, *n, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zhemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(symm::<f64>(
+        "ZHEMM", true, *side, *uplo, *m, *n, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(syrk::<f64>(
+        "ZSYRK", *uplo, *trans, *n, *k, alpha, a, *lda, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f64,
+    a: *const c_void,
+    lda: *const c_int,
+    beta: *const f64,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(herk::<f64>(
+        "ZHERK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(syr2k::<f64>(
+        "ZSYR2K", *uplo, *trans, *n, *k, alpha, a, *lda, b, *ldb, beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *const c_void,
+    ldb: *const c_int,
+    beta: *const f64,
+    c: *mut c_void,
+    ldc: *const c_int,
+) {
+    report(her2k::<f64>(
+        "ZHER2K", *uplo, *trans, *n, *k, alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *mut c_void,
+    ldb: *const c_int,
+) {
+    report(trmm::<f64>(
+        "ZTRMM", false, *side, *uplo, *trans_a, *diag, *m, *n, alpha, a, *lda, b, *ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ztrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    lda: *const c_int,
+    b: *mut c_void,
+    ldb: *const c_int,
+) {
+    report(trmm::<f64>(
+        "ZTRSM", true, *side, *uplo, *trans_a, *diag, *m, *n, alpha, a, *lda, b, *ldb,
+    ))
+}
@@ -0,0 +1,396 @@
+use super::{to_diag, to_side, to_trans, to_uplo};
+use crate::ffi::{array, array_mut, extent, order_of_a, report, size, stored};
+use crate::level3;
+use crate::{BlasError, Layout, RealScalar};
+use std::os::raw::{c_char, c_int};
+
+pub mod complex;
+
+unsafe fn gemm<T: RealScalar>(
+    routine: &'static str,
+    trans_a: c_char,
+    trans_b: c_char,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (trans_a, trans_b) = (
+        to_trans(routine, 1, trans_a)?,
+        to_trans(routine, 2, trans_b)?,
+    );
+    let (m, n, k) = (
+        size(routine, 3, m)?,
+        size(routine, 4, n)?,
+        size(routine, 5, k)?,
+    );
+    let (lda, ldb, ldc) = (
+        size(routine, 8, lda)?,
+        size(routine, 10, ldb)?,
+        size(routine, 13, ldc)?,
+    );
+    let (rows_a, cols_a) = stored(trans_a, m, k);
+    let (rows_b, cols_b) = stored(trans_b, k, n);
+    let a = array(a, extent(Layout::ColMajor, rows_a, cols_a, lda));
+    let b = array(b, extent(Layout::ColMajor, rows_b, cols_b, ldb));
+    let c = array_mut(c, extent(Layout::ColMajor, m, n, ldc));
+    level3::try_gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+    .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn symm<T: RealScalar>(
+    routine: &'static str,
+    side: c_char,
+    uplo: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (side, uplo) = (to_side(routine, 1, side)?, to_uplo(routine, 2, uplo)?);
+    let (m, n) = (size(routine, 3, m)?, size(routine, 4, n)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 7, lda)?,
+        size(routine, 9, ldb)?,
+        size(routine, 12, ldc)?,
+    );
+    let ka = order_of_a(side, m, n);
+    let a = array(a, extent(Layout::ColMajor, ka, ka, lda));
+    let b = array(b, extent(Layout::ColMajor, m, n, ldb));
+    let c = array_mut(c, extent(Layout::ColMajor, m, n, ldc));
+    level3::try_symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syrk<T: RealScalar>(
+    routine: &'static str,
+    uplo: c_char,
+    trans: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = (to_uplo(routine, 1, uplo)?, to_trans(routine, 2, trans)?);
+    let (n, k) = (size(routine, 3, n)?, size(routine, 4, k)?);
+    let (lda, ldc) = (size(routine, 7, lda)?, size(routine, 10, ldc)?);
+    let (rows_a, cols_a) = stored(trans, n, k);
+    let a = array(a, extent(Layout::ColMajor, rows_a, cols_a, lda));
+    let c = array_mut(c, extent(Layout::ColMajor, n, n, ldc));
+    level3::try_syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn syr2k<T: RealScalar>(
+    routine: &'static str,
+    uplo: c_char,
+    trans: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let (uplo, trans) = (to_uplo(routine, 1, uplo)?, to_trans(routine, 2, trans)?);
+    let (n, k) = (size(routine, 3, n)?, size(routine, 4, k)?);
+    let (lda, ldb, ldc) = (
+        size(routine, 7, lda)?,
+        size(routine, 9, ldb)?,
+        size(routine, 12, ldc)?,
+    );
+    let (rows, cols) = stored(trans, n, k);
+    let a = array(a, extent(Layout::ColMajor, rows, cols, lda));
+    let b = array(b, extent(Layout::ColMajor, rows, cols, ldb));
+    let c = array_mut(c, extent(Layout::ColMajor, n, n, ldc));
+    level3::try_syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        .map_err(|err| err.with_routine(routine))
+}
+
+unsafe fn trmm<T: RealScalar>(
+    routine: &'static str,
+    solve: bool,
+    side: c_char,
+    uplo: c_char,
+    trans_a: c_char,
+    diag: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: T,
+    a: *const T,
+    lda: c_int,
+    b: *mut T,
+    ldb: c_int,
+) -> Result<(), BlasError> {
+    let (side, uplo) = (to_side(routine, 1, side)?, to_uplo(routine, 2, uplo)?);
+    let (trans_a, diag) = (to_trans(routine, 3, trans_a)?, to_diag(routine, 4, diag)?);
+    let (m, n) = (size(routine, 5, m)?, size(routine, 6, n)?);
+    let (lda, ldb) = (size(routine, 9, lda)?, size(routine, 11, ldb)?);
+    let ka = order_of_a(side, m, n);
+    let a = array(a, extent(Layout::ColMajor, ka, ka, lda));
+    let b = array_mut(b, extent(Layout::ColMajor, m, n, ldb));
+    if solve {
+        level3::try_trsm(side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb)
+            .map_err(|err| err.with_routine(routine))
+    } else {
+        level3::try_trmm(side, uplo, trans_a, diag, m, n, alpha, a, lda, b, ldb)
+            .map_err(|err| err.with_routine(routine))
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sgemm_(
+    trans_a: *const c_char,
+    trans_b: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    b: *const f32,
+    ldb: *const c_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const c_int,
+) {
+    report(gemm(
+        "SGEMM", *trans_a, *trans_b, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    b: *const f32,
+    ldb: *const c_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const c_int,
+) {
+    report(symm(
+        "SSYMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const c_int,
+) {
+    report(syrk(
+        "SSYRK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    b: *const f32,
+    ldb: *const c_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const c_int,
+) {
+    report(syr2k(
+        "SSYR2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    b: *mut f32,
+    ldb: *const c_int,
+) {
+    report(trmm(
+        "STRMM", false, *side, *uplo, *trans_a, *diag, *m, *n, *alpha, a, *lda, b, *ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    b: *mut f32,
+    ldb: *const c_int,
+) {
+    report(trmm(
+        "STRSM", true, *side, *uplo, *trans_a, *diag, *m, *n, *alpha, a, *lda, b, *ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dgemm_(
+    trans_a: *const c_char,
+    trans_b: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    b: *const f64,
+    ldb: *const c_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const c_int,
+) {
+    report(gemm(
+        "DGEMM", *trans_a, *trans_b, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    b: *const f64,
+    ldb: *const c_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const c_int,
+) {
+    report(symm(
+        "DSYMM", *side, *uplo, *m, *n, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const c_int,
+) {
+    report(syrk(
+        "DSYRK", *uplo, *trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    b: *const f64,
+    ldb: *const c_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const c_int,
+) {
+    report(syr2k(
+        "DSYR2K", *uplo, *trans, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    b: *mut f64,
+    ldb: *const c_int,
+) {
+    report(trmm(
+        "DTRMM", false, *side, *uplo, *trans_a, *diag, *m, *n, *alpha, a, *lda, b, *ldb,
+    ))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    trans_a: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    b: *mut f64,
+    ldb: *const c_int,
+) {
+    report(trmm(
+        "DTRSM", true, *side, *uplo, *trans_a, *diag, *m, *n, *alpha, a, *lda, b, *ldb,
+    ))
+}
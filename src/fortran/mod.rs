@@ -0,0 +1,46 @@
+//! The Fortran 77 interface of the reference BLAS 3.8, exported with the `fortran` feature.
+//!
+//! Every routine is exported under the name gfortran gives it, e.g. `dgemm_`, `zgemv_` or `idamax_`,
+//! so that Fortran programs written against the reference BLAS can link against libblas instead.
+//! As in Fortran every argument is passed by reference and INTEGER is a 32-bit `int`.
+//! Only the first character of a CHARACTER argument is read, so the hidden string lengths may be passed or not.
+//! `i?amax_` return one-based indices, and the COMPLEX functions `cdotc_`, `cdotu_`, `zdotc_` and `zdotu_`
+//! return their result by value as gfortran does.
+//!
+//! Illegal arguments are reported to the [`xerbla`](crate::xerbla) handler with the routine name and the parameter number
+//! that the reference BLAS passes to XERBLA, e.g. `DGEMM` and 8 for an illegal LDA.
+//! The auxiliary LSAME and XERBLA aren't exported, so that they can still be supplied by the program.
+//!
+//! # Safety
+//! Every pointer must point to an array that is at least as large as the reference BLAS expects for the given sizes,
+//! and an array that is written to must not overlap any other array argument.
+//! Complex scalars and arrays are interleaved pairs of REAL or DOUBLE PRECISION.
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use crate::{BlasError, Diag, Side, Trans, Uplo};
+use std::convert::TryFrom;
+use std::os::raw::c_char;
+
+pub mod level1;
+pub mod level2;
+pub mod level3;
+
+fn to_trans(routine: &'static str, param: usize, trans: c_char) -> Result<Trans, BlasError> {
+    Trans::try_from(trans as u8 as char)
+        .map_err(|_| BlasError::new(routine, param, "trans must be 'N', 'T' or 'C'"))
+}
+
+fn to_uplo(routine: &'static str, param: usize, uplo: c_char) -> Result<Uplo, BlasError> {
+    Uplo::try_from(uplo as u8 as char)
+        .map_err(|_| BlasError::new(routine, param, "uplo must be 'U' or 'L'"))
+}
+
+fn to_diag(routine: &'static str, param: usize, diag: c_char) -> Result<Diag, BlasError> {
+    Diag::try_from(diag as u8 as char)
+        .map_err(|_| BlasError::new(routine, param, "diag must be 'N' or 'U'"))
+}
+
+fn to_side(routine: &'static str, param: usize, side: c_char) -> Result<Side, BlasError> {
+    Side::try_from(side as u8 as char)
+        .map_err(|_| BlasError::new(routine, param, "side must be 'L' or 'R'"))
+}
@@ -1,6 +1,14 @@
 // use std::arch::x86_64::*;
+use crate::NanPolicy;
 use num_complex::Complex;
-use num_traits::{Float, NumAssignOps, One, Zero};
+use num_traits::{Float, FromPrimitive, NumAssignOps, One, Zero};
+
+/// CABS1 computes |Re(z)| + |Im(z)|, the cheap modulus the reference BLAS uses for [`iamax`], [`asum`] and the quick return of [`axpy`].
+/// This is [SCABS1](http://www.netlib.org/lapack/explore-html/d0/d1b/scabs1_8f.html) and [DCABS1](http://www.netlib.org/lapack/explore-html/de/d89/dcabs1_8f.html) combined in one function.
+#[inline]
+pub fn cabs1<T: Float>(z: Complex<T>) -> T {
+    z.re.abs() + z.im.abs()
+}
 
 /// AXPY constant times a vector plus a vector.
 /// This is [CAXPY](http://www.netlib.org/lapack/explore-html/de/da2/caxpy_8f.html) and [ZAXPY](http://www.netlib.org/lapack/explore-html/d7/db2/zaxpy_8f.html) combined in one function.
@@ -13,7 +21,7 @@ pub fn axpy<T: Float + NumAssignOps>(
     y: &mut [Complex<T>],
     incy: isize,
 ) {
-    if n == 0 {
+    if n == 0 || cabs1(*a).is_zero() {
         return;
     }
     let mut ix = 0;
@@ -163,6 +171,42 @@ pub fn rot<T: Float + NumAssignOps>(
     }
 }
 
+/// CROT applies a plane rotation, where the cos (c) is real and the sin (s) is complex, and the vectors x and y are complex.
+/// ( x ) := (       c   s ) ( x )
+/// ( y )    ( -conj(s)  c ) ( y ), which applies the rotation constructed by [`rotg`].
+/// This is the CROT and ZROT auxiliary routine of LAPACK combined in one function.
+#[inline]
+pub fn crot<T: Float + NumAssignOps>(
+    n: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+    y: &mut [Complex<T>],
+    incy: isize,
+    c: T,
+    s: Complex<T>,
+) {
+    if n == 0 {
+        return;
+    }
+    let mut ix = 0;
+    let mut iy = 0;
+    let mut i = 0;
+    if incx < 0 {
+        ix = (-(n as isize) * incx) + incx;
+    }
+    if incy < 0 {
+        iy = (-(n as isize) * incy) + incy;
+    }
+    while i < n {
+        let tmp = x[ix as usize] * c + s * y[iy as usize];
+        y[iy as usize] = y[iy as usize] * c - s.conj() * x[ix as usize];
+        x[ix as usize] = tmp;
+        i += 1;
+        ix += incx;
+        iy += incy;
+    }
+}
+
 /// ROTG determines a complex Givens rotation.
 /// This is [CROTG](http://www.netlib.org/lapack/explore-html/dc/de6/crotg_8f.html) and [ZROTG](http://www.netlib.org/lapack/explore-html/dc/dfe/zrotg_8f.html) combined in one function.
 #[inline]
@@ -186,6 +230,174 @@ pub fn rotg<T: Float + NumAssignOps>(
     *a = alpha * norm;
 }
 
+/// ROTM applies the complex modified Givens transformation H constructed by [`rotmg`] to the 2 by n matrix
+/// ( x**T )
+/// ( y**T ), with FLAG = Re(PARAM(1)) and H as in [`level1::rotm`](super::rotm).
+/// This is an extension of SROTM and DROTM to complex vectors.
+#[inline]
+pub fn rotm<T: Float + NumAssignOps>(
+    n: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+    y: &mut [Complex<T>],
+    incy: isize,
+    param: &[Complex<T>],
+) {
+    let flag = param[0].re;
+    let two = T::one() + T::one();
+    if n == 0 || flag + two == T::zero() {
+        return;
+    }
+    let (h11, h21, h12, h22) = if flag < T::zero() {
+        (param[1], param[2], param[3], param[4])
+    } else if flag.is_zero() {
+        (Complex::one(), param[2], param[3], Complex::one())
+    } else {
+        (param[1], -Complex::one(), Complex::one(), param[4])
+    };
+
+    let mut ix = 0;
+    let mut iy = 0;
+    let mut i = 0;
+    if incx < 0 {
+        ix = (-(n as isize) * incx) + incx;
+    }
+    if incy < 0 {
+        iy = (-(n as isize) * incy) + incy;
+    }
+    while i < n {
+        let w = x[ix as usize];
+        let z = y[iy as usize];
+        x[ix as usize] = h11 * w + h12 * z;
+        y[iy as usize] = h21 * w + h22 * z;
+        ix += incx;
+        iy += incy;
+        i += 1;
+    }
+}
+
+/// ROTMG constructs the complex modified Givens transformation H which zeros the second component of the 2-vector
+/// ( sqrt(d1)*x1, sqrt(d2)*y1 )**T, where d1 and d2 are real.
+/// H has the forms of [`level1::rotmg`](super::rotmg) with complex elements, and FLAG is stored in Re(PARAM(1)).
+/// H12 = conj( d2*y1 / (d1*x1) ) when FLAG is 0 and H11 = conj( d1*x1 / (d2*y1) ) when FLAG is 1,
+/// so that diag( sqrt(d1'), sqrt(d2') )*H*diag( 1/sqrt(d1), 1/sqrt(d2) ) is unitary and d1*|x|**2 + d2*|y|**2 is kept by [`rotm`].
+/// This is an extension of SROTMG and DROTMG to complex vectors. D1 and D2 are rescaled as in [`RotmgAlgorithm::Hopkins`](super::RotmgAlgorithm::Hopkins),
+/// so that every rescaling is kept in H.
+#[inline]
+pub fn rotmg<T: Float + NumAssignOps + FromPrimitive>(
+    d1: &mut T,
+    d2: &mut T,
+    x1: &mut Complex<T>,
+    y1: &mut Complex<T>,
+    param: &mut [Complex<T>],
+) {
+    let (gam, gamsq, rgamsq) = super::rotmg_constants::<T>();
+    let mut flag = -T::one();
+    let mut h11: Complex<T> = Complex::zero();
+    let mut h21: Complex<T> = Complex::zero();
+    let mut h12: Complex<T> = Complex::zero();
+    let mut h22: Complex<T> = Complex::zero();
+
+    if d2.is_zero() || y1.is_zero() {
+        param[0] = Complex::from(-(T::one() + T::one()));
+        return;
+    }
+
+    if *d1 < T::zero() {
+        *d1 = T::zero();
+        *d2 = T::zero();
+        *x1 = Complex::zero();
+    } else if (d1.is_zero() || x1.is_zero()) && *d2 > T::zero() {
+        flag = T::one();
+        h12 = Complex::one();
+        h21 = -Complex::one();
+        *x1 = *y1;
+        std::mem::swap(d1, d2);
+    } else {
+        let p2 = *y1 * *d2;
+        let p1 = *x1 * *d1;
+        let q2 = *d2 * y1.norm_sqr();
+        let q1 = *d1 * x1.norm_sqr();
+        if q1.abs() > q2.abs() {
+            h11 = Complex::one();
+            h22 = Complex::one();
+            h21 = -*y1 / *x1;
+            h12 = (p2 / p1).conj();
+            let u = T::one() - (h12 * h21).re;
+            if u > T::zero() {
+                flag = T::zero();
+                *d1 /= u;
+                *d2 /= u;
+                *x1 *= u;
+            } else {
+                h11 = Complex::zero();
+                h12 = Complex::zero();
+                h21 = Complex::zero();
+                h22 = Complex::zero();
+                *d1 = T::zero();
+                *d2 = T::zero();
+                *x1 = Complex::zero();
+            }
+        } else if q2 < T::zero() {
+            *d1 = T::zero();
+            *d2 = T::zero();
+            *x1 = Complex::zero();
+        } else {
+            flag = T::one();
+            h21 = -Complex::one();
+            h12 = Complex::one();
+            h11 = (p1 / p2).conj();
+            h22 = *x1 / *y1;
+            let u = T::one() + (h11 * h22).re;
+            let tmp = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = tmp;
+            *x1 = *y1 * u;
+        }
+
+        while *d1 <= rgamsq && !d1.is_zero() {
+            flag = -T::one();
+            *d1 *= gamsq;
+            *x1 /= gam;
+            h11 /= gam;
+            h12 /= gam;
+        }
+        while d1.abs() > gamsq {
+            flag = -T::one();
+            *d1 /= gamsq;
+            *x1 *= gam;
+            h11 *= gam;
+            h12 *= gam;
+        }
+        while d2.abs() <= rgamsq && !d2.is_zero() {
+            flag = -T::one();
+            *d2 *= gamsq;
+            h21 /= gam;
+            h22 /= gam;
+        }
+        while d2.abs() > gamsq {
+            flag = -T::one();
+            *d2 /= gamsq;
+            h21 *= gam;
+            h22 *= gam;
+        }
+    }
+
+    if flag < T::zero() {
+        param[1] = h11;
+        param[2] = h21;
+        param[3] = h12;
+        param[4] = h22;
+    } else if flag.is_zero() {
+        param[2] = h21;
+        param[3] = h12;
+    } else {
+        param[1] = h11;
+        param[4] = h22;
+    }
+    param[0] = Complex::from(flag);
+}
+
 /// SCAL scales a complex vector by a constant.
 /// This is [CSCAL](http://www.netlib.org/lapack/explore-html/dc/d81/cscal_8f.html) and [ZSCAL](http://www.netlib.org/lapack/explore-html/d2/d74/zscal_8f.html) combined in one function.
 #[inline]
@@ -262,6 +474,7 @@ pub fn swap<T: Float + NumAssignOps>(
 
 /// IAMAX finds the index of the first element having maximum |Re(.)| + |Im(.)|
 /// This is [ICAMAX](http://www.netlib.org/lapack/explore-html/dd/d51/icamax_8f.html) and [IZAMAX](http://www.netlib.org/lapack/explore-html/d0/da5/izamax_8f.html) combined in one function.
+/// A NaN is only returned when it is the first element, [`iamax_nan`] picks a policy for NaNs.
 #[inline]
 pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -> usize {
     let mut iamax = 0;
@@ -273,13 +486,11 @@ pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -
         return iamax;
     }
 
-    // FIXME use l1_norm()
-    let mut max = x[0].norm();
-    let mut i = 2;
+    let mut max = cabs1(x[0]);
+    let mut i = 1;
     if incx == 1 {
         while i < n {
-            // FIXME use l1_norm()
-            let tmp = x[i].norm();
+            let tmp = cabs1(x[i]);
             i += 1;
             if tmp > max {
                 iamax = i;
@@ -287,10 +498,9 @@ pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -
             }
         }
     } else {
-        let mut ix = 1 + incx;
+        let mut ix = incx;
         while i < n {
-            // FIXME use l1_norm()
-            let tmp = x[ix].norm();
+            let tmp = cabs1(x[ix]);
             ix += incx;
             i += 1;
             if tmp > max {
@@ -302,6 +512,46 @@ pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -
     iamax
 }
 
+/// IAMAX_NAN finds the index of the first element having maximum |Re(.)| + |Im(.)|, an element with a NaN part is handled as `nan` says.
+/// There is no reference BLAS routine for it, the index is one-based and 0 when n or incx is 0, see [`NanPolicy`].
+#[inline]
+pub fn iamax_nan<T: Float + NumAssignOps>(
+    n: usize,
+    x: &[Complex<T>],
+    incx: usize,
+    nan: NanPolicy,
+) -> usize {
+    if n == 0 || incx == 0 {
+        return 0;
+    }
+    super::first_max_nan(x.iter().step_by(incx).take(n).map(|v| cabs1(*v)), nan)
+}
+
+/// IAMAX_NORM finds the index of the first element having maximum modulus |x(i)|, unlike [`iamax`] which uses |Re(.)| + |Im(.)|.
+/// There is no reference BLAS routine for it, the index is one-based and 0 when n or incx is 0.
+#[inline]
+pub fn iamax_norm<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -> usize {
+    let mut iamax = 0;
+    if n == 0 || incx == 0 {
+        return iamax;
+    }
+    iamax = 1;
+
+    let mut max = x[0].norm();
+    let mut i = 1;
+    let mut ix = incx;
+    while i < n {
+        let tmp = x[ix].norm();
+        ix += incx;
+        i += 1;
+        if tmp > max {
+            iamax = i;
+            max = tmp;
+        }
+    }
+    iamax
+}
+
 /// ASUM takes the sum of the (|Re(.)| + |Im(.)|)'s of a complex vector and returns a single or double precision result.
 /// This is [SCASUM](http://www.netlib.org/lapack/explore-html/db/d53/scasum_8f.html) and [DZASUM](http://www.netlib.org/lapack/explore-html/df/d0f/dzasum_8f.html) combined in one function.
 #[inline]
@@ -314,9 +564,7 @@ pub fn asum<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) ->
     let mut i = 0;
     let nincx = n * incx;
     while i < nincx {
-        let Complex { re, im } = x[i];
-        // FIXME use l1_norm()
-        sum += re.abs() + im.abs();
+        sum += cabs1(x[i]);
         i += incx;
     }
     sum
@@ -324,40 +572,173 @@ pub fn asum<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) ->
 
 /// NRM2 returns the euclidean norm of a vector via the function name, so that NRM2 := sqrt( x**H*x )
 /// This is [SCNRM2](http://www.netlib.org/lapack/explore-html/db/d66/scnrm2_8f.html) and [DZNRM2](http://www.netlib.org/lapack/explore-html/d9/d19/dznrm2_8f.html) combined in one function.
+/// It uses the algorithm of Blue of the reference BLAS 3.10 on the real and imaginary parts, see [`level1::nrm2`](super::nrm2).
 pub fn nrm2<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -> T {
     if n == 0 || incx == 0 {
         return T::zero();
     }
-    //  The following loop is equivalent to this call to the LAPACK auxiliary routine:
-    //  CALL CLASSQ( N, X, INCX, SCALE, SSQ )
-    let mut scale = T::zero();
-    let mut ssq = T::one();
+    let mut blue = super::Blue::new();
     let mut ix = 0;
     while ix < n * incx {
         let Complex { re, im } = x[ix];
-        if !re.is_zero() {
-            let tmp = re.abs();
-            if scale < tmp {
-                let t1 = scale / tmp;
-                ssq = T::one() + ssq * t1 * t1;
-                scale = tmp;
-            } else {
-                let t1 = tmp / scale;
-                ssq += t1 * t1;
-            }
+        blue.add(re.abs());
+        blue.add(im.abs());
+        ix += incx
+    }
+    blue.norm()
+}
+
+/// IAMIN finds the index of the first element having minimum |Re(.)| + |Im(.)|
+/// This is the ICAMIN and IZAMIN extension of OpenBLAS and MKL, the index is one-based and 0 when n or incx is 0.
+#[inline]
+pub fn iamin<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -> usize {
+    let mut iamin = 0;
+    if n == 0 || incx == 0 {
+        return iamin;
+    }
+    iamin = 1;
+
+    let mut min = cabs1(x[0]);
+    let mut i = 1;
+    let mut ix = incx;
+    while i < n {
+        let tmp = cabs1(x[ix]);
+        ix += incx;
+        i += 1;
+        if tmp < min {
+            iamin = i;
+            min = tmp;
         }
-        if !im.is_zero() {
-            let tmp = im.abs();
-            if scale < tmp {
-                let t1 = scale / tmp;
-                ssq = T::one() + ssq * t1 * t1;
-                scale = tmp;
-            } else {
-                let t1 = tmp / scale;
-                ssq += t1 * t1;
-            }
+    }
+    iamin
+}
+
+/// AMAX returns the maximum |Re(.)| + |Im(.)| of a complex vector, 0 when n or incx is 0.
+/// This is the SCAMAX and DZAMAX extension of OpenBLAS.
+#[inline]
+pub fn amax<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -> T {
+    let mut max = T::zero();
+    if n == 0 || incx == 0 {
+        return max;
+    }
+
+    let mut i = 0;
+    let nincx = n * incx;
+    while i < nincx {
+        let tmp = cabs1(x[i]);
+        if tmp > max {
+            max = tmp;
         }
-        ix += incx
+        i += incx;
+    }
+    max
+}
+
+/// AMIN returns the minimum |Re(.)| + |Im(.)| of a complex vector, 0 when n or incx is 0.
+/// This is the SCAMIN and DZAMIN extension of OpenBLAS.
+#[inline]
+pub fn amin<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -> T {
+    match iamin(n, x, incx) {
+        0 => T::zero(),
+        i => cabs1(x[(i - 1) * incx]),
+    }
+}
+
+/// SUM takes the sum of the Re(.) + Im(.)'s of a complex vector and returns a single or double precision result.
+/// This is the SCSUM and DZSUM extension of OpenBLAS, Re(.) + Im(.) of [`dotu`] with a vector of ones is the same sum.
+#[inline]
+pub fn sum<T: Float + NumAssignOps>(n: usize, x: &[Complex<T>], incx: usize) -> T {
+    let mut sum = T::zero();
+    if n == 0 || incx == 0 {
+        return sum;
+    }
+
+    let mut i = 0;
+    let nincx = n * incx;
+    while i < nincx {
+        sum += x[i].re + x[i].im;
+        i += incx;
+    }
+    sum
+}
+
+/// AXPBY scales two complex vectors and adds them, y := a*x + b*y, in one pass over y.
+/// y isn't read when b is zero, so it may hold NaN. This is the CAXPBY and ZAXPBY extension of OpenBLAS and MKL.
+#[inline]
+pub fn axpby<T: Float + NumAssignOps>(
+    n: usize,
+    a: &Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    b: &Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) {
+    if n == 0 {
+        return;
+    }
+    let mut ix = 0;
+    let mut iy = 0;
+    let mut i = 0;
+    if incx < 0 {
+        ix = (-(n as isize) * incx) + incx;
+    }
+    if incy < 0 {
+        iy = (-(n as isize) * incy) + incy;
+    }
+    while i < n {
+        let tmp = a * x[ix as usize];
+        y[iy as usize] = if b.is_zero() {
+            tmp
+        } else {
+            tmp + b * y[iy as usize]
+        };
+        ix += incx;
+        iy += incy;
+        i += 1;
+    }
+}
+
+/// WAXPBY scales two complex vectors and writes their sum into a third one, w := a*x + b*y.
+/// y isn't read when b is zero. This is the CWAXPBY and ZWAXPBY routine of the BLAS technical forum standard.
+#[inline]
+pub fn waxpby<T: Float + NumAssignOps>(
+    n: usize,
+    a: &Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    b: &Complex<T>,
+    y: &[Complex<T>],
+    incy: isize,
+    w: &mut [Complex<T>],
+    incw: isize,
+) {
+    if n == 0 {
+        return;
+    }
+    let mut ix = 0;
+    let mut iy = 0;
+    let mut iw = 0;
+    let mut i = 0;
+    if incx < 0 {
+        ix = (-(n as isize) * incx) + incx;
+    }
+    if incy < 0 {
+        iy = (-(n as isize) * incy) + incy;
+    }
+    if incw < 0 {
+        iw = (-(n as isize) * incw) + incw;
+    }
+    while i < n {
+        let tmp = a * x[ix as usize];
+        w[iw as usize] = if b.is_zero() {
+            tmp
+        } else {
+            tmp + b * y[iy as usize]
+        };
+        ix += incx;
+        iy += incy;
+        iw += incw;
+        i += 1;
     }
-    scale * ssq.sqrt()
 }
@@ -1,10 +1,11 @@
-// use std::arch::x86_64::*;
-use num_traits::{Float, NumAssignOps};
+use crate::{simd, NanPolicy, Ring};
+use num_traits::{Float, FromPrimitive, NumAssignOps};
 
 pub mod complex;
 
 /// IAMAX finds the index of the first element having maximum absolute value.
 /// This is [ISAMAX](http://www.netlib.org/lapack/explore-html/d6/d44/isamax_8f.html) and [IDAMAX](http://www.netlib.org/lapack/explore-html/dd/de0/idamax_8f.html) comined in one function
+/// A NaN is only returned when it is the first element, [`iamax_nan`] picks a policy for NaNs.
 #[inline]
 pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> usize {
     let mut iamax = 0;
@@ -16,7 +17,7 @@ pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> usize {
     if n == 1 {
         return iamax;
     }
-    let mut i = 2;
+    let mut i = 1;
     let mut max = x[0].abs();
     if incx == 1 {
         while i < n {
@@ -28,7 +29,7 @@ pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> usize {
             }
         }
     } else {
-        let mut ix = 1 + incx;
+        let mut ix = incx;
         while i < n {
             let tmp = x[ix].abs();
             ix += incx;
@@ -42,6 +43,82 @@ pub fn iamax<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> usize {
     iamax
 }
 
+/// IAMAX_NAN finds the index of the first element having maximum absolute value, a NaN is handled as `nan` says.
+/// There is no reference BLAS routine for it, the index is one-based and 0 when n or incx is 0, see [`NanPolicy`].
+#[inline]
+pub fn iamax_nan<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize, nan: NanPolicy) -> usize {
+    if n == 0 || incx == 0 {
+        return 0;
+    }
+    first_max_nan(x.iter().step_by(incx).take(n).map(|v| v.abs()), nan)
+}
+
+/// The one-based index of the first maximum of `abs`, a NaN is handled as `nan` says, or 0 when `abs` is empty.
+pub(crate) fn first_max_nan<T: Float, I: Iterator<Item = T>>(abs: I, nan: NanPolicy) -> usize {
+    let mut iamax = 0;
+    // max starts at -inf, so the first element that isn't NaN always wins.
+    // Under Ignore, a NaN only sets the index 1 when no number has been seen yet.
+    let mut max = T::neg_infinity();
+    for (i, tmp) in abs.enumerate() {
+        if tmp.is_nan() {
+            match nan {
+                NanPolicy::First => return i + 1,
+                NanPolicy::Ignore if iamax == 0 => iamax = 1,
+                NanPolicy::Ignore => {}
+            }
+        } else if tmp > max {
+            iamax = i + 1;
+            max = tmp;
+        }
+    }
+    iamax
+}
+
+/// IAMIN finds the index of the first element having minimum absolute value.
+/// This is the ISAMIN and IDAMIN extension of OpenBLAS and MKL, the index is one-based and 0 when n or incx is 0.
+#[inline]
+pub fn iamin<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> usize {
+    let mut iamin = 0;
+    if n == 0 || incx == 0 {
+        return iamin;
+    }
+
+    iamin = 1;
+    let mut min = x[0].abs();
+    let mut i = 1;
+    let mut ix = incx;
+    while i < n {
+        let tmp = x[ix].abs();
+        ix += incx;
+        i += 1;
+        if tmp < min {
+            iamin = i;
+            min = tmp;
+        }
+    }
+    iamin
+}
+
+/// AMAX returns the maximum absolute value of a vector, 0 when n or incx is 0.
+/// This is the SAMAX and DAMAX extension of OpenBLAS.
+#[inline]
+pub fn amax<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> T {
+    match iamax(n, x, incx) {
+        0 => T::zero(),
+        i => x[(i - 1) * incx].abs(),
+    }
+}
+
+/// AMIN returns the minimum absolute value of a vector, 0 when n or incx is 0.
+/// This is the SAMIN and DAMIN extension of OpenBLAS.
+#[inline]
+pub fn amin<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> T {
+    match iamin(n, x, incx) {
+        0 => T::zero(),
+        i => x[(i - 1) * incx].abs(),
+    }
+}
+
 /// ASUM takes the sum of the absolute values.
 /// This is [SASUM](http://www.netlib.org/lapack/explore-html/df/d1f/sasum_8f.html) and [DASUM](http://www.netlib.org/lapack/explore-html/de/d05/dasum_8f.html) comined in one function
 #[inline]
@@ -59,26 +136,39 @@ pub fn asum<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> T {
     asum
 }
 
+/// SUM takes the sum of the values, without taking their absolute values as [`asum`] does.
+/// This is the SSUM and DSUM extension of OpenBLAS.
+#[inline]
+pub fn sum<T: Ring>(n: usize, x: &[T], incx: usize) -> T {
+    let mut sum = T::zero();
+    if n == 0 || incx == 0 {
+        return sum;
+    }
+    let mut i = 0;
+    let nincx = n * incx;
+    while i < nincx {
+        sum = sum + x[i];
+        i += incx;
+    }
+    sum
+}
+
 /// AXPY constant times a vector plus a vector.
 /// This is [SAXPY](http://www.netlib.org/lapack/explore-html/d8/daf/saxpy_8f.html) and [DAXPY](http://www.netlib.org/lapack/explore-html/d9/dcd/daxpy_8f.html) comined in one function
 #[inline]
-pub fn axpy<T: Float + NumAssignOps>(
-    n: usize,
-    a: T,
-    x: &[T],
-    incx: isize,
-    y: &mut [T],
-    incy: isize,
-) {
+pub fn axpy<T: Ring + 'static>(n: usize, a: T, x: &[T], incx: isize, y: &mut [T], incy: isize) {
     if n == 0 || a.is_zero() {
         return;
     };
     let mut i = 0;
     if incx == 1 && incy == 1 {
+        if simd::axpy(a, &x[..n], &mut y[..n]) {
+            return;
+        }
         let m = n % 4;
         if m != 0 {
             while i < m {
-                y[i] += a * x[i];
+                y[i] = y[i] + a * x[i];
                 i += 1;
             }
         }
@@ -87,13 +177,13 @@ pub fn axpy<T: Float + NumAssignOps>(
         };
         let mut i = m;
         while i < n {
-            y[i] += a * x[i];
+            y[i] = y[i] + a * x[i];
             i += 1;
-            y[i] += a * x[i];
+            y[i] = y[i] + a * x[i];
             i += 1;
-            y[i] += a * x[i];
+            y[i] = y[i] + a * x[i];
             i += 1;
-            y[i] += a * x[i];
+            y[i] = y[i] + a * x[i];
             i += 1;
         }
     } else {
@@ -106,7 +196,7 @@ pub fn axpy<T: Float + NumAssignOps>(
             iy = (-(n as isize) * incy) + incy;
         }
         while i < n {
-            y[iy as usize] += a * x[ix as usize];
+            y[iy as usize] = y[iy as usize] + a * x[ix as usize];
             ix += incx;
             iy += incy;
             i += 1;
@@ -114,44 +204,193 @@ pub fn axpy<T: Float + NumAssignOps>(
     }
 }
 
+/// AXPBY scales two vectors and adds them, y := a*x + b*y, in one pass over y.
+/// y isn't read when b is zero, so it may hold NaN. This is the SAXPBY and DAXPBY extension of OpenBLAS and MKL.
+#[inline]
+pub fn axpby<T: Ring>(n: usize, a: T, x: &[T], incx: isize, b: T, y: &mut [T], incy: isize) {
+    if n == 0 {
+        return;
+    }
+    let mut ix = 0;
+    let mut iy = 0;
+    if incx < 0 {
+        ix = (-(n as isize) * incx) + incx;
+    }
+    if incy < 0 {
+        iy = (-(n as isize) * incy) + incy;
+    }
+    let mut i = 0;
+    while i < n {
+        let tmp = a * x[ix as usize];
+        y[iy as usize] = if b.is_zero() {
+            tmp
+        } else {
+            tmp + b * y[iy as usize]
+        };
+        ix += incx;
+        iy += incy;
+        i += 1;
+    }
+}
+
+/// WAXPBY scales two vectors and writes their sum into a third one, w := a*x + b*y.
+/// y isn't read when b is zero. This is the SWAXPBY and DWAXPBY routine of the BLAS technical forum standard.
+#[inline]
+pub fn waxpby<T: Ring>(
+    n: usize,
+    a: T,
+    x: &[T],
+    incx: isize,
+    b: T,
+    y: &[T],
+    incy: isize,
+    w: &mut [T],
+    incw: isize,
+) {
+    if n == 0 {
+        return;
+    }
+    let mut ix = 0;
+    let mut iy = 0;
+    let mut iw = 0;
+    if incx < 0 {
+        ix = (-(n as isize) * incx) + incx;
+    }
+    if incy < 0 {
+        iy = (-(n as isize) * incy) + incy;
+    }
+    if incw < 0 {
+        iw = (-(n as isize) * incw) + incw;
+    }
+    let mut i = 0;
+    while i < n {
+        let tmp = a * x[ix as usize];
+        w[iw as usize] = if b.is_zero() {
+            tmp
+        } else {
+            tmp + b * y[iy as usize]
+        };
+        ix += incx;
+        iy += incy;
+        iw += incw;
+        i += 1;
+    }
+}
+
 /// NRM2 returns the euclidean norm of a vector via the function name, so that NRM2 := sqrt( x'*x ).
 /// This is [SNRM2](http://www.netlib.org/lapack/explore-html/d7/df1/snrm2_8f.html) and [DNRM2](http://www.netlib.org/lapack/explore-html/da/d7f/dnrm2_8f.html) comined in one function
+/// It uses the algorithm of Blue adopted by the reference BLAS 3.10, which neither overflows nor underflows
+/// unless the norm does, and returns NaN when x holds a NaN and Inf when it holds an Inf but no NaN.
 #[inline]
 pub fn nrm2<T: Float + NumAssignOps>(n: usize, x: &[T], incx: usize) -> T {
-    let mut scale = T::zero();
-    let mut ssq = T::one();
     if n == 0 || incx == 0 {
-        return scale;
+        return T::zero();
     }
-    if n == 1 {
-        return x[0].abs();
-    }
-
-    //  The following loop is equivalent to this call to the LAPACK auxiliary routine:
-    //  CALL SLASSQ( N, X, INCX, SCALE, SSQ )
+    let mut blue = Blue::new();
     let mut ix = 0;
     while ix < n * incx {
-        let r = x[ix];
-        if r != T::zero() {
-            let tmp = r.abs();
-            let ratio = scale / tmp;
-            let ratiop2 = ratio * ratio;
-            if scale < tmp {
-                ssq = T::one() + ssq * ratiop2;
-                scale = tmp;
-            } else {
-                ssq += T::one() / ratiop2;
+        blue.add(x[ix].abs());
+        ix += incx
+    }
+    blue.norm()
+}
+
+/// The three accumulators of Blue's algorithm for [`nrm2`] and [`complex::nrm2`], from DNRM2 of the reference BLAS 3.10.
+/// The squares of the magnitudes below TSML are scaled up by SSML, those above TBIG are scaled down by SBIG,
+/// and the small ones are dropped as soon as a big one is seen.
+struct Blue<T> {
+    tsml: T,
+    tbig: T,
+    ssml: T,
+    sbig: T,
+    asml: T,
+    amed: T,
+    abig: T,
+    notbig: bool,
+}
+
+impl<T: Float + NumAssignOps> Blue<T> {
+    /// The thresholds and scaling constants of LA_CONSTANTS, computed from the exponent range of `T`
+    /// as the MINEXPONENT, MAXEXPONENT and DIGITS intrinsics of Fortran give it.
+    fn new() -> Self {
+        let (mantissa, exponent, _) = T::min_positive_value().integer_decode();
+        let digits = 64 - mantissa.leading_zeros() as i32;
+        let minexponent = i32::from(exponent) + digits;
+        let (_, exponent, _) = T::max_value().integer_decode();
+        let maxexponent = i32::from(exponent) + digits;
+        let floor_half = |e: i32| e.div_euclid(2);
+        let ceil_half = |e: i32| -(-e).div_euclid(2);
+        let two = T::one() + T::one();
+        Blue {
+            tsml: two.powi(ceil_half(minexponent - 1)),
+            tbig: two.powi(floor_half(maxexponent - digits + 1)),
+            ssml: two.powi(-floor_half(minexponent - digits)),
+            sbig: two.powi(-ceil_half(maxexponent + digits - 1)),
+            asml: T::zero(),
+            amed: T::zero(),
+            abig: T::zero(),
+            notbig: true,
+        }
+    }
+
+    /// Accumulates the square of the magnitude `ax`.
+    fn add(&mut self, ax: T) {
+        if ax > self.tbig {
+            let tmp = ax * self.sbig;
+            self.abig += tmp * tmp;
+            self.notbig = false;
+        } else if ax < self.tsml {
+            if self.notbig {
+                let tmp = ax * self.ssml;
+                self.asml += tmp * tmp;
             }
+        } else {
+            self.amed += ax * ax;
         }
-        ix += incx
     }
-    scale * ssq.sqrt()
+
+    /// Combines the accumulators, abig and amed or amed and asml if more than one was used.
+    fn norm(self) -> T {
+        let Blue {
+            ssml,
+            sbig,
+            mut asml,
+            mut amed,
+            mut abig,
+            ..
+        } = self;
+        // amed is positive, Inf or NaN.
+        let used = amed > T::zero() || amed.is_nan();
+        let (scl, sumsq) = if abig > T::zero() {
+            if used {
+                abig += (amed * sbig) * sbig;
+            }
+            (T::one() / sbig, abig)
+        } else if asml > T::zero() {
+            if used {
+                amed = amed.sqrt();
+                asml = asml.sqrt() / ssml;
+                let (ymin, ymax) = if asml > amed {
+                    (amed, asml)
+                } else {
+                    (asml, amed)
+                };
+                let ratio = ymin / ymax;
+                (T::one(), ymax * ymax * (T::one() + ratio * ratio))
+            } else {
+                (T::one() / ssml, asml)
+            }
+        } else {
+            (T::one(), amed)
+        };
+        scl * sumsq.sqrt()
+    }
 }
 
 /// COPY copies a vector, x, to a vector, y. uses unrolled loops for increments equal to 1.
 /// This is [SCOPY](http://www.netlib.org/lapack/explore-html/de/dc0/scopy_8f.html) and [DCOPY](http://www.netlib.org/lapack/explore-html/da/d6c/dcopy_8f.html) comined in one function
 #[inline]
-pub fn copy<T: Float + NumAssignOps>(n: usize, x: &[T], incx: isize, y: &mut [T], incy: isize) {
+pub fn copy<T: Ring>(n: usize, x: &[T], incx: isize, y: &mut [T], incy: isize) {
     if n == 0 {
         return;
     }
@@ -205,17 +444,20 @@ pub fn copy<T: Float + NumAssignOps>(n: usize, x: &[T], incx: isize, y: &mut [T]
 /// DOT forms the dot product of two vectors. uses unrolled loops for increments equal to one.
 /// This is [SDOT](http://www.netlib.org/lapack/explore-html/d0/d16/sdot_8f.html) and [DDOT](http://www.netlib.org/lapack/explore-html/d5/df6/ddot_8f.html) comined in one function
 #[inline]
-pub fn dot<T: Float + NumAssignOps>(n: usize, x: &[T], incx: isize, y: &[T], incy: isize) -> T {
+pub fn dot<T: Ring + 'static>(n: usize, x: &[T], incx: isize, y: &[T], incy: isize) -> T {
     let mut dot = T::zero();
     if n == 0 {
         return dot;
     }
     let mut i = 0;
     if incx == 1 && incy == 1 {
+        if let Some(dot) = simd::dot(&x[..n], &y[..n]) {
+            return dot;
+        }
         let m = n % 5;
         if m != 0 {
             while i < m {
-                dot += x[i] * y[i];
+                dot = dot + x[i] * y[i];
                 i += 1;
             }
             if n < 5 {
@@ -224,15 +466,15 @@ pub fn dot<T: Float + NumAssignOps>(n: usize, x: &[T], incx: isize, y: &[T], inc
         }
         let mut i = m;
         while i < n {
-            dot += x[i] * y[i];
+            dot = dot + x[i] * y[i];
             i += 1;
-            dot += x[i] * y[i];
+            dot = dot + x[i] * y[i];
             i += 1;
-            dot += x[i] * y[i];
+            dot = dot + x[i] * y[i];
             i += 1;
-            dot += x[i] * y[i];
+            dot = dot + x[i] * y[i];
             i += 1;
-            dot += x[i] * y[i];
+            dot = dot + x[i] * y[i];
             i += 1;
         }
     } else {
@@ -245,7 +487,7 @@ pub fn dot<T: Float + NumAssignOps>(n: usize, x: &[T], incx: isize, y: &[T], inc
             iy = (-(n as isize) * incy) + incy;
         }
         while i < n {
-            dot += x[ix as usize] * y[iy as usize];
+            dot = dot + x[ix as usize] * y[iy as usize];
             ix += incx;
             iy += incy;
             i += 1;
@@ -447,6 +689,19 @@ pub fn rotm<T: Float + NumAssignOps>(
     }
 }
 
+/// RotmgAlgorithm selects how [`rotmg_with`] constructs the modified Givens transformation.
+/// The two algorithms agree unless D1 or D2 has to be rescaled, or 1 - H12*H21 isn't positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RotmgAlgorithm {
+    /// The algorithm of the reference BLAS 3.8, which [`rotmg`] uses.
+    /// When D1 and D2 are rescaled more than once, H12 and H21 are reset to the values implied by FLAG=1 as in the reference.
+    Reference,
+    /// The algorithm proposed by Tim Hopkins in 1998, which [OpenBLAS](https://github.com/xianyi/OpenBLAS/pull/1480) uses.
+    /// The implied elements of H are stored before D1 and D2 are rescaled, so every rescaling is kept in H.
+    /// D1 and D2 are only scaled down when they are larger than GAMSQ, and H is zeroed when 1 - H12*H21 isn't positive.
+    Hopkins,
+}
+
 /// ROTMG
 /// CONSTRUCT THE MODIFIED GIVENS TRANSFORMATION [T] H WHICH ZEROS
 /// THE SECOND COMPONENT OF THE 2-VECTOR  (SQRT(D1)*X1,SQRT(D2)*>    Y2)**T.
@@ -464,25 +719,58 @@ pub fn rotm<T: Float + NumAssignOps>(
 /// THE VALUES OF GAMSQ AND RGAMSQ SET IN THE DATA STATEMENT MAY BE
 /// INEXACT.  THIS IS OK AS THEY ARE ONLY USED FOR TESTING THE SIZE
 /// OF D1 AND D2.  ALL ACTUAL SCALING OF DATA IS DONE USING GAM.
-/// This is SROTMG and DROTMG comined in one function.
 /// This is [SROTMG](http://www.netlib.org/lapack/explore-html/dd/d48/srotmg_8f.html) and [DROTMG](http://www.netlib.org/lapack/explore-html/df/deb/drotmg_8f.html) comined in one function.
+/// It uses [`RotmgAlgorithm::Reference`], see [`rotmg_with`] to select the algorithm.
 #[inline]
-pub fn rotmg<T: Float + NumAssignOps + num_traits::cast::FromPrimitive>(
+pub fn rotmg<T: Float + NumAssignOps + FromPrimitive>(
+    d1: &mut T,
+    d2: &mut T,
+    x1: &mut T,
+    y1: &mut T,
+    param: &mut [T],
+) {
+    rotmg_with(RotmgAlgorithm::Reference, d1, d2, x1, y1, param)
+}
+
+/// ROTMG with a selectable algorithm, see [`rotmg`] for the arguments and [`RotmgAlgorithm`] for how the algorithms differ.
+/// RGAMSQ is rounded to the precision of `T`, so that `f32` and `f64` rescale exactly when SROTMG and DROTMG do.
+#[inline]
+pub fn rotmg_with<T: Float + NumAssignOps + FromPrimitive>(
+    algorithm: RotmgAlgorithm,
+    d1: &mut T,
+    d2: &mut T,
+    x1: &mut T,
+    y1: &mut T,
+    param: &mut [T],
+) {
+    match algorithm {
+        RotmgAlgorithm::Reference => reference_rotmg(d1, d2, x1, y1, param),
+        RotmgAlgorithm::Hopkins => hopkins_rotmg(d1, d2, x1, y1, param),
+    }
+}
+
+/// GAM, GAMSQ and RGAMSQ from the DATA statement of SROTMG and DROTMG.
+fn rotmg_constants<T: Float + FromPrimitive>() -> (T, T, T) {
+    (
+        T::from_f64(4096.0).unwrap(),
+        T::from_f64(16_777_216.0).unwrap(),
+        T::from_f64(5.960_464_5E-8).unwrap(),
+    )
+}
+
+fn reference_rotmg<T: Float + NumAssignOps + FromPrimitive>(
     d1: &mut T,
     d2: &mut T,
     x1: &mut T,
     y1: &mut T,
     param: &mut [T],
 ) {
-    // https://github.com/xianyi/OpenBLAS/pull/1480
     let mut flag = param[0];
     let mut h11 = param[1];
     let mut h12 = param[3];
     let mut h21 = param[2];
     let mut h22 = param[4];
-    let gam = T::from_f32(4096.0).unwrap();
-    let gamsq = T::from_f32(16_777_216.0).unwrap();
-    let rgamsq = T::from_f32(5.960_464_5E-8).unwrap();
+    let (gam, gamsq, rgamsq) = rotmg_constants::<T>();
 
     if *d1 < T::zero() {
         flag = -T::one();
@@ -578,6 +866,116 @@ pub fn rotmg<T: Float + NumAssignOps + num_traits::cast::FromPrimitive>(
             }
         }
     }
+    store_rotmg(flag, h11, h21, h12, h22, param);
+}
+
+fn hopkins_rotmg<T: Float + NumAssignOps + FromPrimitive>(
+    d1: &mut T,
+    d2: &mut T,
+    x1: &mut T,
+    y1: &mut T,
+    param: &mut [T],
+) {
+    let (gam, gamsq, rgamsq) = rotmg_constants::<T>();
+    let mut flag = -T::one();
+    let mut h11 = T::zero();
+    let mut h21 = T::zero();
+    let mut h12 = T::zero();
+    let mut h22 = T::zero();
+
+    if d2.is_zero() || y1.is_zero() {
+        param[0] = -(T::one() + T::one());
+        return;
+    }
+
+    if *d1 < T::zero() {
+        *d1 = T::zero();
+        *d2 = T::zero();
+        *x1 = T::zero();
+    } else if (d1.is_zero() || x1.is_zero()) && *d2 > T::zero() {
+        flag = T::one();
+        h12 = T::one();
+        h21 = -T::one();
+        *x1 = *y1;
+        std::mem::swap(d1, d2);
+    } else {
+        let p2 = *d2 * *y1;
+        if p2.is_zero() {
+            param[0] = -(T::one() + T::one());
+            return;
+        }
+        let p1 = *d1 * *x1;
+        let q2 = p2 * *y1;
+        let q1 = p1 * *x1;
+        if q1.abs() > q2.abs() {
+            h11 = T::one();
+            h22 = T::one();
+            h21 = -*y1 / *x1;
+            h12 = p2 / p1;
+            let u = T::one() - h12 * h21;
+            if u > T::zero() {
+                flag = T::zero();
+                *d1 /= u;
+                *d2 /= u;
+                *x1 *= u;
+            } else {
+                h11 = T::zero();
+                h12 = T::zero();
+                h21 = T::zero();
+                h22 = T::zero();
+                *d1 = T::zero();
+                *d2 = T::zero();
+                *x1 = T::zero();
+            }
+        } else if q2 < T::zero() {
+            *d1 = T::zero();
+            *d2 = T::zero();
+            *x1 = T::zero();
+        } else {
+            flag = T::one();
+            h21 = -T::one();
+            h12 = T::one();
+            h11 = p1 / p2;
+            h22 = *x1 / *y1;
+            let u = T::one() + h11 * h22;
+            let tmp = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = tmp;
+            *x1 = *y1 * u;
+        }
+
+        while *d1 <= rgamsq && !d1.is_zero() {
+            flag = -T::one();
+            *d1 *= gamsq;
+            *x1 /= gam;
+            h11 /= gam;
+            h12 /= gam;
+        }
+        while d1.abs() > gamsq {
+            flag = -T::one();
+            *d1 /= gamsq;
+            *x1 *= gam;
+            h11 *= gam;
+            h12 *= gam;
+        }
+        while d2.abs() <= rgamsq && !d2.is_zero() {
+            flag = -T::one();
+            *d2 *= gamsq;
+            h21 /= gam;
+            h22 /= gam;
+        }
+        while d2.abs() > gamsq {
+            flag = -T::one();
+            *d2 /= gamsq;
+            h21 *= gam;
+            h22 *= gam;
+        }
+    }
+    store_rotmg(flag, h11, h21, h12, h22, param);
+}
+
+/// Stores FLAG and the elements of H that aren't implied by it in PARAM.
+fn store_rotmg<T: Float>(flag: T, h11: T, h21: T, h12: T, h22: T, param: &mut [T]) {
     if flag < T::zero() {
         param[1] = h11;
         param[2] = h21;
@@ -596,7 +994,7 @@ pub fn rotmg<T: Float + NumAssignOps + num_traits::cast::FromPrimitive>(
 /// SCAL scales a vector by a constant. uses unrolled loops for increment equal to 1.
 /// This is [SSCAL](http://www.netlib.org/lapack/explore-html/d9/d04/sscal_8f.html) and [DSCAL](http://www.netlib.org/lapack/explore-html/d4/dd0/dscal_8f.html) comined in one function.
 #[inline]
-pub fn scal<T: Float + NumAssignOps>(n: usize, a: T, x: &mut [T], incx: usize) {
+pub fn scal<T: Ring + 'static>(n: usize, a: T, x: &mut [T], incx: usize) {
     if n == 0 || incx == 0 {
         return;
     };
@@ -605,10 +1003,13 @@ pub fn scal<T: Float + NumAssignOps>(n: usize, a: T, x: &mut [T], incx: usize) {
     };
     let mut i = 0;
     if incx == 1 {
+        if simd::scal(a, &mut x[..n]) {
+            return;
+        }
         let m = n % 5;
         if m != 0 {
             while i < m {
-                x[i] *= a;
+                x[i] = x[i] * a;
                 i += 1;
             }
             if n < 5 {
@@ -617,21 +1018,21 @@ pub fn scal<T: Float + NumAssignOps>(n: usize, a: T, x: &mut [T], incx: usize) {
         }
         let mut mp1 = m;
         while mp1 < n {
-            x[mp1] *= a;
+            x[mp1] = x[mp1] * a;
             mp1 += 1;
-            x[mp1] *= a;
+            x[mp1] = x[mp1] * a;
             mp1 += 1;
-            x[mp1] *= a;
+            x[mp1] = x[mp1] * a;
             mp1 += 1;
-            x[mp1] *= a;
+            x[mp1] = x[mp1] * a;
             mp1 += 1;
-            x[mp1] *= a;
+            x[mp1] = x[mp1] * a;
             mp1 += 1;
         }
     } else {
         let nincx = n * incx;
         while i < nincx {
-            x[i] *= a;
+            x[i] = x[i] * a;
             i += incx;
         }
     }
@@ -640,7 +1041,7 @@ pub fn scal<T: Float + NumAssignOps>(n: usize, a: T, x: &mut [T], incx: usize) {
 /// SWAP interchanges two vectors. uses unrolled loops for increment equal to 1.
 /// This is [SSWAP](http://www.netlib.org/lapack/explore-html/d9/da9/sswap_8f.html) and [DSWAP](http://www.netlib.org/lapack/explore-html/db/dd4/dswap_8f.html) comined in one function.
 #[inline]
-pub fn swap<T: Float + NumAssignOps>(n: usize, x: &mut [T], incx: isize, y: &mut [T], incy: isize) {
+pub fn swap<T: Ring>(n: usize, x: &mut [T], incx: isize, y: &mut [T], incy: isize) {
     if n == 0 {
         return;
     };
@@ -1,4 +1,5 @@
 // use std::arch::x86_64::*;
+use crate::{extent, xerbla, BlasError, Diag, Trans, Uplo};
 use num_complex::Complex;
 use num_traits::{Float, NumAssignOps, One, Zero};
 use std::cmp::{max, min};
@@ -42,7 +43,7 @@ fn zero<T: Float + NumAssignOps>(
 /// This is [CGBMV](http://www.netlib.org/lapack/explore-html/d0/d75/cgbmv_8f.html) and [ZGBMV](http://www.netlib.org/lapack/explore-html/d9/d46/zgbmv_8f.html) comined in one function
 #[inline]
 pub fn gbmv<T: Float + NumAssignOps>(
-    trans: char,
+    trans: Trans,
     m: usize,
     n: usize,
     kl: usize,
@@ -56,30 +57,53 @@ pub fn gbmv<T: Float + NumAssignOps>(
     y: &mut [Complex<T>],
     incy: isize,
 ) {
-    let mut info = 0;
-    if trans != 'c' && trans != 'C' && trans != 'n' && trans != 'N' && trans != 't' && trans != 'T'
-    {
-        info = 1;
-    } else if lda < (kl + ku + 1) {
-        info = 8;
-    } else if incx == 0 {
-        info = 10;
-    } else if incy == 0 {
-        info = 13;
+    if let Err(err) = try_gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("gbmv {}", info);
+/// Checked variant of [`gbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gbmv<T: Float + NumAssignOps>(
+    trans: Trans,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < (kl + ku + 1) {
+        return Err(BlasError::new(
+            "gbmv",
+            8,
+            "lda must be at least kl + ku + 1",
+        ));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("gbmv", 10, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("gbmv", 13, "incy must not be zero"));
     }
 
     if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
-    let lenx = if trans == 'n' || trans == 'N' { n } else { m };
-    let leny = if trans == 'n' || trans == 'N' { m } else { n };
+    let lenx = if trans == Trans::NoTrans { n } else { m };
+    let leny = if trans == Trans::NoTrans { m } else { n };
+    extent::matrix("gbmv", 7, a, kl + ku + 1, n, lda)?;
+    extent::vector("gbmv", 9, x, lenx, incx)?;
+    extent::vector("gbmv", 12, y, leny, incy)?;
 
-    let noconj = trans == 't' || trans == 'T';
+    let noconj = trans == Trans::Trans;
 
     let mut kx = 0;
     let mut ky = 0;
@@ -94,10 +118,10 @@ pub fn gbmv<T: Float + NumAssignOps>(
         multiply(y, beta, leny as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
+    if trans == Trans::NoTrans {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -147,6 +171,7 @@ pub fn gbmv<T: Float + NumAssignOps>(
             //
         }
     }
+    Ok(())
 }
 
 /// GEMV  performs one of the matrix-vector operations
@@ -155,7 +180,7 @@ pub fn gbmv<T: Float + NumAssignOps>(
 /// This is [CGEMV](http://www.netlib.org/lapack/explore-html/d4/d8a/cgemv_8f.html) and [ZGEMV](http://www.netlib.org/lapack/explore-html/db/d40/zgemv_8f.html) comined in one function
 #[inline]
 pub fn gemv<T: Float + NumAssignOps>(
-    trans: char,
+    trans: Trans,
     m: usize,
     n: usize,
     alpha: Complex<T>,
@@ -167,28 +192,47 @@ pub fn gemv<T: Float + NumAssignOps>(
     y: &mut [Complex<T>],
     incy: isize,
 ) {
-    let mut info = 0;
-    if trans != 'c' && trans != 'C' && trans != 'n' && trans != 'N' && trans != 't' && trans != 'T'
-    {
-        info = 1;
-    } else if lda < max(1, m) {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    } else if incy == 0 {
-        info = 11;
+    if let Err(err) = try_gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemv<T: Float + NumAssignOps>(
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, m) {
+        return Err(BlasError::new("gemv", 6, "lda must be at least max(1, m)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("gemv", 8, "incx must not be zero"));
     }
-    if info != 0 {
-        panic!("gemv {}", info);
+    if incy == 0 {
+        return Err(BlasError::new("gemv", 11, "incy must not be zero"));
     }
 
-    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_zero()) {
-        return;
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
     }
 
-    let noconj = trans == 't' || trans == 'T';
-    let lenx = if trans == 'n' || trans == 'N' { n } else { m };
-    let leny = if trans == 'n' || trans == 'N' { m } else { n };
+    let lenx = if trans == Trans::NoTrans { n } else { m };
+    let leny = if trans == Trans::NoTrans { m } else { n };
+    extent::matrix("gemv", 5, a, m, n, lda)?;
+    extent::vector("gemv", 7, x, lenx, incx)?;
+    extent::vector("gemv", 10, y, leny, incy)?;
+
+    let noconj = trans == Trans::Trans;
 
     let mut kx = 0;
     let mut ky = 0;
@@ -196,16 +240,16 @@ pub fn gemv<T: Float + NumAssignOps>(
         kx = (-(lenx as isize) * incx) + incx
     };
     if incy < 0 {
-        ky = (-(leny as isize) * incx) + incy
+        ky = (-(leny as isize) * incy) + incy
     };
 
     if !beta.is_one() {
         multiply(y, beta, leny as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
-    if trans == 'n' || trans == 'N' {
+    if trans == Trans::NoTrans {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -244,6 +288,7 @@ pub fn gemv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// GERC  performs the rank 1 operation
@@ -262,22 +307,42 @@ pub fn gerc<T: Float + NumAssignOps>(
     a: &mut [Complex<T>],
     lda: usize,
 ) {
-    let mut info = 0;
+    if let Err(err) = try_gerc(m, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gerc`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gerc<T: Float + NumAssignOps>(
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
     if incx == 0 {
-        info = 5;
-    } else if incy == 0 {
-        info = 7;
-    } else if lda < max(1, m) {
-        info = 9;
+        return Err(BlasError::new("gerc", 5, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("gerc", 7, "incy must not be zero"));
     }
-    if info != 0 {
-        panic!("gerc {}", info);
+    if lda < max(1, m) {
+        return Err(BlasError::new("gerc", 9, "lda must be at least max(1, m)"));
     }
 
     if m == 0 || n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("gerc", 4, x, m, incx)?;
+    extent::vector("gerc", 6, y, n, incy)?;
+    extent::matrix("gerc", 8, a, m, n, lda)?;
+
     let mut kx = 0;
     let mut jy = 0;
     if incx < 0 {
@@ -303,6 +368,7 @@ pub fn gerc<T: Float + NumAssignOps>(
         jy += incy;
         j += 1;
     }
+    Ok(())
 }
 
 /// GERU  performs the rank 1 operation
@@ -321,22 +387,42 @@ pub fn geru<T: Float + NumAssignOps>(
     a: &mut [Complex<T>],
     lda: usize,
 ) {
-    let mut info = 0;
+    if let Err(err) = try_geru(m, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`geru`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_geru<T: Float + NumAssignOps>(
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
     if incx == 0 {
-        info = 5;
-    } else if incy == 0 {
-        info = 7;
-    } else if lda < max(1, m) {
-        info = 9;
+        return Err(BlasError::new("geru", 5, "incx must not be zero"));
     }
-    if info != 0 {
-        panic!("geru {}", info);
+    if incy == 0 {
+        return Err(BlasError::new("geru", 7, "incy must not be zero"));
+    }
+    if lda < max(1, m) {
+        return Err(BlasError::new("geru", 9, "lda must be at least max(1, m)"));
     }
 
     if m == 0 || n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("geru", 4, x, m, incx)?;
+    extent::vector("geru", 6, y, n, incy)?;
+    extent::matrix("geru", 8, a, m, n, lda)?;
+
     let mut kx = 0;
     let mut jy = 0;
     if incx < 0 {
@@ -362,6 +448,7 @@ pub fn geru<T: Float + NumAssignOps>(
         jy += incy;
         j += 1;
     }
+    Ok(())
 }
 
 /// HBMV  performs the matrix-vector  operation
@@ -370,7 +457,7 @@ pub fn geru<T: Float + NumAssignOps>(
 /// This is [CHBMV](http://www.netlib.org/lapack/explore-html/db/dc2/chbmv_8f.html) and [ZHBMV](http://www.netlib.org/lapack/explore-html/d3/d1a/zhbmv_8f.html) comined in one function
 #[inline]
 pub fn hbmv<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     k: usize,
     alpha: Complex<T>,
@@ -382,24 +469,44 @@ pub fn hbmv<T: Float + NumAssignOps>(
     y: &mut [Complex<T>],
     incy: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if lda < k + 1 {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    } else if incy == 0 {
-        info = 11;
+    if let Err(err) = try_hbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hbmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < k + 1 {
+        return Err(BlasError::new("hbmv", 6, "lda must be at least k + 1"));
     }
-    if info != 0 {
-        panic!("hbmv {}", info);
+    if incx == 0 {
+        return Err(BlasError::new("hbmv", 8, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("hbmv", 11, "incy must not be zero"));
     }
 
     if n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
+    extent::matrix("hbmv", 5, a, k + 1, n, lda)?;
+    extent::vector("hbmv", 7, x, n, incx)?;
+    extent::vector("hbmv", 10, y, n, incy)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -413,11 +520,11 @@ pub fn hbmv<T: Float + NumAssignOps>(
         multiply(y, beta, n as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
     let mut jx = kx;
     let mut jy = ky;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut j = 0;
         while j < n {
             let tmp = alpha * x[jx as usize];
@@ -465,6 +572,7 @@ pub fn hbmv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// HEMV  performs the matrix-vector  operation
@@ -473,7 +581,7 @@ pub fn hbmv<T: Float + NumAssignOps>(
 /// This is [CHEMV](http://www.netlib.org/lapack/explore-html/d7/d51/chemv_8f.html) and [ZHEMV](http://www.netlib.org/lapack/explore-html/d0/ddd/zhemv_8f.html) comined in one function
 #[inline]
 pub fn hemv<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: Complex<T>,
     a: &[Complex<T>],
@@ -484,24 +592,43 @@ pub fn hemv<T: Float + NumAssignOps>(
     y: &mut [Complex<T>],
     incy: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if lda < max(1, n) {
-        info = 5;
-    } else if incx == 0 {
-        info = 7;
-    } else if incy == 0 {
-        info = 9;
+    if let Err(err) = try_hemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hemv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hemv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, n) {
+        return Err(BlasError::new("hemv", 5, "lda must be at least max(1, n)"));
     }
-    if info != 0 {
-        panic!("hemv {}", info);
+    if incx == 0 {
+        return Err(BlasError::new("hemv", 7, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("hemv", 10, "incy must not be zero"));
     }
 
     if n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
+    extent::matrix("hemv", 4, a, n, n, lda)?;
+    extent::vector("hemv", 6, x, n, incx)?;
+    extent::vector("hemv", 9, y, n, incy)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -515,12 +642,12 @@ pub fn hemv<T: Float + NumAssignOps>(
         multiply(y, beta, n as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
     let mut jx = kx;
     let mut jy = ky;
     let mut j = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         while j < n {
             let tmp = alpha * x[jx as usize];
             let mut tmp2: Complex<T> = Complex::zero();
@@ -564,6 +691,7 @@ pub fn hemv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// HER   performs the hermitian rank 1 operation
@@ -572,7 +700,7 @@ pub fn hemv<T: Float + NumAssignOps>(
 /// This is [CHER](http://www.netlib.org/lapack/explore-html/d3/d6d/cher_8f.html) and [ZHER](http://www.netlib.org/lapack/explore-html/de/d0e/zher_8f.html) comined in one function
 #[inline]
 pub fn her<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     x: &[Complex<T>],
@@ -580,28 +708,41 @@ pub fn her<T: Float + NumAssignOps>(
     a: &mut [Complex<T>],
     lda: usize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 5;
-    } else if lda < max(1, n) {
-        info = 7;
+    if let Err(err) = try_her(uplo, n, alpha, x, incx, a, lda) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("her {}", info);
+/// Checked variant of [`her`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_her<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[Complex<T>],
+    incx: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("her", 5, "incx must not be zero"));
+    }
+    if lda < max(1, n) {
+        return Err(BlasError::new("her", 7, "lda must be at least max(1, n)"));
     }
 
     if n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("her", 4, x, n, incx)?;
+    extent::matrix("her", 6, a, n, n, lda)?;
+
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -654,6 +795,7 @@ pub fn her<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// HER2  performs the hermitian rank 2 operation
@@ -662,7 +804,7 @@ pub fn her<T: Float + NumAssignOps>(
 /// This is [CHER2](http://www.netlib.org/lapack/explore-html/db/d87/cher2_8f.html) and [ZHER2](http://www.netlib.org/lapack/explore-html/da/d8a/zher2_8f.html) comined in one function
 #[inline]
 pub fn her2<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: Complex<T>,
     x: &[Complex<T>],
@@ -672,25 +814,42 @@ pub fn her2<T: Float + NumAssignOps>(
     a: &mut [Complex<T>],
     lda: usize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 3;
-    } else if incy == 0 {
-        info = 5;
-    } else if lda < max(1, n) {
-        info = 9;
+    if let Err(err) = try_her2(uplo, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("her2 {}", info);
+/// Checked variant of [`her2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_her2<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("her2", 5, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("her2", 7, "incy must not be zero"));
+    }
+    if lda < max(1, n) {
+        return Err(BlasError::new("her2", 9, "lda must be at least max(1, n)"));
     }
 
     if n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("her2", 4, x, n, incx)?;
+    extent::vector("her2", 6, y, n, incy)?;
+    extent::matrix("her2", 8, a, n, n, lda)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -702,7 +861,7 @@ pub fn her2<T: Float + NumAssignOps>(
     let mut jx = kx;
     let mut jy = ky;
     let mut j = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         while j < n {
             let aj = j * lda;
             let y1 = y[jy as usize];
@@ -764,6 +923,7 @@ pub fn her2<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// HPMV  performs the matrix-vector operation
@@ -772,7 +932,7 @@ pub fn her2<T: Float + NumAssignOps>(
 /// This is [CHPMV](http://www.netlib.org/lapack/explore-html/d2/d06/chpmv_8f.html) and [ZHPMV](http://www.netlib.org/lapack/explore-html/d0/d60/zhpmv_8f.html) comined in one function
 #[inline]
 pub fn hpmv<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: Complex<T>,
     ap: &[Complex<T>],
@@ -782,22 +942,39 @@ pub fn hpmv<T: Float + NumAssignOps>(
     y: &mut [Complex<T>],
     incy: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 6;
-    } else if incy == 0 {
-        info = 9;
+    if let Err(err) = try_hpmv(uplo, n, alpha, ap, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hpmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hpmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    ap: &[Complex<T>],
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("hpmv", 6, "incx must not be zero"));
     }
-    if info != 0 {
-        panic!("hpmv {}", info);
+    if incy == 0 {
+        return Err(BlasError::new("hpmv", 9, "incy must not be zero"));
     }
 
     if n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
+    extent::packed("hpmv", 4, ap, n)?;
+    extent::vector("hpmv", 5, x, n, incx)?;
+    extent::vector("hpmv", 8, y, n, incy)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -811,10 +988,10 @@ pub fn hpmv<T: Float + NumAssignOps>(
         multiply(y, beta, n as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
     let mut kk = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut jy = ky;
         let mut j = 0;
@@ -864,6 +1041,7 @@ pub fn hpmv<T: Float + NumAssignOps>(
             kk += 1;
         }
     }
+    Ok(())
 }
 
 /// HPR    performs the hermitian rank 1 operation
@@ -872,32 +1050,45 @@ pub fn hpmv<T: Float + NumAssignOps>(
 /// This is [CHPR](http://www.netlib.org/lapack/explore-html/db/dcd/chpr_8f.html) and [ZHPR](http://www.netlib.org/lapack/explore-html/de/de1/zhpr_8f.html) comined in one function
 #[inline]
 pub fn hpr<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     x: &[Complex<T>],
     incx: isize,
     ap: &mut [Complex<T>],
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 6;
+    if let Err(err) = try_hpr(uplo, n, alpha, x, incx, ap) {
+        xerbla::report(&err);
     }
-    if info != 0 {
-        panic!("hpr {}", info);
+}
+
+/// Checked variant of [`hpr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hpr<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[Complex<T>],
+    incx: isize,
+    ap: &mut [Complex<T>],
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("hpr", 5, "incx must not be zero"));
     }
 
     if n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
+
+    extent::vector("hpr", 4, x, n, incx)?;
+    extent::packed("hpr", 6, ap, n)?;
+
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
     let mut kk = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -952,6 +1143,7 @@ pub fn hpr<T: Float + NumAssignOps>(
             kk += 1;
         }
     }
+    Ok(())
 }
 
 /// HPR2  performs the hermitian rank 2 operation
@@ -960,7 +1152,7 @@ pub fn hpr<T: Float + NumAssignOps>(
 /// This is [CHPR2](http://www.netlib.org/lapack/explore-html/d6/d44/chpr2_8f.html) and [ZHPR2](http://www.netlib.org/lapack/explore-html/d5/d52/zhpr2_8f.html) comined in one function
 #[inline]
 pub fn hpr2<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: Complex<T>,
     x: &[Complex<T>],
@@ -969,24 +1161,40 @@ pub fn hpr2<T: Float + NumAssignOps>(
     incy: isize,
     ap: &mut [Complex<T>],
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 5;
-    } else if incy == 0 {
-        info = 7;
+    if let Err(err) = try_hpr2(uplo, n, alpha, x, incx, y, incy, ap) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hpr2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hpr2<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    y: &[Complex<T>],
+    incy: isize,
+    ap: &mut [Complex<T>],
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("hpr2", 5, "incx must not be zero"));
     }
-    if info != 0 {
-        panic!("hpr2 {}", info);
+    if incy == 0 {
+        return Err(BlasError::new("hpr2", 7, "incy must not be zero"));
     }
 
     let alpha_is_zero = alpha.is_zero();
 
     if n == 0 || alpha_is_zero {
-        return;
+        return Ok(());
     }
 
+    extent::vector("hpr2", 4, x, n, incx)?;
+    extent::vector("hpr2", 6, y, n, incy)?;
+    extent::packed("hpr2", 8, ap, n)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -999,7 +1207,7 @@ pub fn hpr2<T: Float + NumAssignOps>(
     let mut jx = kx;
     let mut jy = ky;
     let mut kk = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut j = 0;
         while j < n {
             let x1 = x[jx as usize];
@@ -1065,92 +1273,510 @@ pub fn hpr2<T: Float + NumAssignOps>(
             kk += 1;
         }
     }
+    Ok(())
 }
 
-/// TBMV  performs one of the matrix-vector operations
-/// x := A*x,   or   x := A**T*x,   or   x := A**H*x,
-/// where x is an n element vector and  A is an n by n unit, or non-unit, upper or lower triangular band matrix, with ( k + 1 ) diagonals.
-/// This is [CTBMV](http://www.netlib.org/lapack/explore-html/d3/dcd/ctbmv_8f.html) and [ZTBMV](http://www.netlib.org/lapack/explore-html/d3/d39/ztbmv_8f.html) comined in one function
+/// SPMV  performs the matrix-vector operation
+/// y := alpha*A*x + beta*y,
+/// where alpha and beta are scalars, x and y are n element vectors and A is an n by n complex symmetric matrix, supplied in packed form.
+/// Unlike [`hpmv`], A isn't conjugated. This is the CSPMV and ZSPMV auxiliary routine of LAPACK combined in one function
 #[inline]
-pub fn tbmv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+pub fn spmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
     n: usize,
-    k: usize,
-    a: &[Complex<T>],
-    lda: usize,
-    x: &mut [Complex<T>],
+    alpha: Complex<T>,
+    ap: &[Complex<T>],
+    x: &[Complex<T>],
     incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < k + 1 {
-        info = 7;
-    } else if incx == 0 {
-        info = 9;
-    }
-    if info != 0 {
-        panic!("tbmv {}", info);
+    if let Err(err) = try_spmv(uplo, n, alpha, ap, x, incx, beta, y, incy) {
+        xerbla::report(&err);
     }
+}
 
-    if n == 0 {
-        return;
+/// Checked variant of [`spmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    ap: &[Complex<T>],
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("spmv", 6, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("spmv", 9, "incy must not be zero"));
+    }
+
+    if n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
     }
-    let noconj = trans == 't' || trans == 'T';
-    let nounit = diag == 'n' || diag == 'N';
+
+    extent::packed("spmv", 4, ap, n)?;
+    extent::vector("spmv", 5, x, n, incx)?;
+    extent::vector("spmv", 8, y, n, incy)?;
+
     let mut kx = 0;
+    let mut ky = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
+    if incy < 0 {
+        ky = (-(n as isize) * incy) + incy
+    };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
-            let mut jx = kx;
-            let mut j = 0;
-            while j < n {
-                let tmp = x[jx as usize];
-                if !tmp.is_zero() {
-                    let mut ix = kx;
-                    let aj = j * lda;
-                    let mut i = j.saturating_sub(k);
-                    while i < j {
-                        x[ix as usize] += tmp * a[aj + i + k - j];
-                        ix += incx;
-                        i += 1;
-                    }
-                    if nounit {
-                        x[jx as usize] = tmp * a[aj + k]
-                    }
-                }
-                jx += incx;
-                j += 1;
-                if j > k {
-                    kx += incx;
-                }
+    if !beta.is_one() {
+        multiply(y, beta, n as isize, ky, incy)
+    }
+    if alpha.is_zero() {
+        return Ok(());
+    }
+    let mut kk = 0;
+    if uplo == Uplo::Upper {
+        let mut jx = kx;
+        let mut jy = ky;
+        let mut j = 0;
+        while j < n {
+            j += 1;
+            let tmp = alpha * x[jx as usize];
+            let mut tmp2: Complex<T> = Complex::zero();
+            let mut ix = kx;
+            let mut iy = ky;
+            let mut k = kk;
+            kk += j;
+            while k < kk - 1 {
+                // KK + J - 2
+                y[iy as usize] += tmp * ap[k];
+                tmp2 += ap[k] * x[ix as usize];
+                ix += incx;
+                iy += incy;
+                k += 1;
             }
-        } else {
-            // FIXME
-            kx += (n as isize - 1) * incx;
-            let mut jx = kx;
-            let mut j = n;
-            while j >= 1 {
-                j -= 1;
-                let aj = j * lda;
-                let tmp = x[jx as usize];
-                if !tmp.is_zero() {
-                    let mut ix = kx;
+            y[jy as usize] += tmp * ap[kk - 1] + alpha * tmp2;
+            jx += incx;
+            jy += incy;
+        }
+    } else {
+        let mut jx = kx;
+        let mut jy = ky;
+        let mut j = 0;
+        while j < n {
+            j += 1;
+            let tmp = alpha * x[jx as usize];
+            let mut tmp2: Complex<T> = Complex::zero();
+            let mut ix = jx;
+            let mut iy = jy;
+            let mut k = kk;
+            kk += n - j;
+            y[jy as usize] += tmp * ap[k];
+            while k < kk {
+                k += 1;
+                ix += incx;
+                iy += incy;
+                y[iy as usize] += tmp * ap[k];
+                tmp2 += ap[k] * x[ix as usize];
+            }
+            y[jy as usize] += alpha * tmp2;
+            jx += incx;
+            jy += incy;
+            kk += 1;
+        }
+    }
+    Ok(())
+}
+
+/// SPR    performs the symmetric rank 1 operation
+/// A := alpha*x*x**T + A,
+/// where alpha is a complex scalar, x is an n element vector and A is an n by n complex symmetric matrix, supplied in packed form.
+/// Unlike [`hpr`], x isn't conjugated. This is the CSPR and ZSPR auxiliary routine of LAPACK combined in one function
+#[inline]
+pub fn spr<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    ap: &mut [Complex<T>],
+) {
+    if let Err(err) = try_spr(uplo, n, alpha, x, incx, ap) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`spr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spr<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    ap: &mut [Complex<T>],
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("spr", 5, "incx must not be zero"));
+    }
+
+    if n == 0 || alpha.is_zero() {
+        return Ok(());
+    }
+
+    extent::vector("spr", 4, x, n, incx)?;
+    extent::packed("spr", 6, ap, n)?;
+
+    let mut kx = 0;
+    if incx < 0 {
+        kx = (-(n as isize) * incx) + incx
+    };
+    let mut kk = 0;
+    if uplo == Uplo::Upper {
+        let mut jx = kx;
+        let mut j = 0;
+        while j < n {
+            let x1 = x[jx as usize];
+            let mut k = kk;
+            kk += j;
+            if !x1.is_zero() {
+                let tmp = alpha * x1;
+                let mut ix = kx;
+                while k <= kk {
+                    ap[k] += x[ix as usize] * tmp;
+                    ix += incx;
+                    k += 1;
+                }
+            }
+            jx += incx;
+            kk += 1;
+            j += 1;
+        }
+    } else {
+        let mut jx = kx;
+        let mut j = 0;
+        while j < n {
+            j += 1;
+            let x1 = x[jx as usize];
+            let mut k = kk;
+            kk += n - j;
+            if !x1.is_zero() {
+                let tmp = alpha * x1;
+                let mut ix = jx;
+                ap[k] += x1 * tmp;
+                while k < kk {
+                    k += 1;
+                    ix += incx;
+                    ap[k] += x[ix as usize] * tmp;
+                }
+            }
+            jx += incx;
+            kk += 1;
+        }
+    }
+    Ok(())
+}
+
+/// SYMV  performs the matrix-vector  operation
+/// y := alpha*A*x + beta*y,
+/// where alpha and beta are scalars, x and y are n element vectors and A is an n by n complex symmetric matrix.
+/// Unlike [`hemv`], A isn't conjugated. This is the CSYMV and ZSYMV auxiliary routine of LAPACK combined in one function
+#[inline]
+pub fn symv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) {
+    if let Err(err) = try_symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`symv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_symv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &[Complex<T>],
+    incx: isize,
+    beta: Complex<T>,
+    y: &mut [Complex<T>],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, n) {
+        return Err(BlasError::new("symv", 5, "lda must be at least max(1, n)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("symv", 7, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("symv", 10, "incy must not be zero"));
+    }
+
+    if n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
+    }
+
+    extent::matrix("symv", 4, a, n, n, lda)?;
+    extent::vector("symv", 6, x, n, incx)?;
+    extent::vector("symv", 9, y, n, incy)?;
+
+    let mut kx = 0;
+    let mut ky = 0;
+    if incx < 0 {
+        kx = (-(n as isize) * incx) + incx
+    };
+    if incy < 0 {
+        ky = (-(n as isize) * incy) + incy
+    };
+
+    if !beta.is_one() {
+        multiply(y, beta, n as isize, ky, incy)
+    }
+    if alpha.is_zero() {
+        return Ok(());
+    }
+    let mut jx = kx;
+    let mut jy = ky;
+    let mut j = 0;
+    if uplo == Uplo::Upper {
+        while j < n {
+            let tmp = alpha * x[jx as usize];
+            let mut tmp2: Complex<T> = Complex::zero();
+            let mut ix = kx;
+            let mut iy = ky;
+            let aj = j * lda;
+            let mut i = 0;
+            while i < j {
+                y[iy as usize] += tmp * a[aj + i];
+                tmp2 += a[aj + i] * x[ix as usize];
+                ix += incx;
+                iy += incy;
+                i += 1;
+            }
+            y[jy as usize] += tmp * a[aj + j] + alpha * tmp2;
+            jx += incx;
+            jy += incy;
+            j += 1;
+        }
+    } else {
+        while j < n {
+            let aj = j * lda;
+            let tmp = alpha * x[jx as usize];
+            let mut tmp2: Complex<T> = Complex::zero();
+            y[jy as usize] += tmp * a[aj + j];
+            let mut ix = jx;
+            let mut iy = jy;
+            let mut i = j + 1;
+            while i < n {
+                ix += incx;
+                iy += incy;
+                y[iy as usize] += tmp * a[aj + i];
+                tmp2 += a[aj + i] * x[ix as usize];
+                i += 1;
+            }
+            y[jy as usize] += alpha * tmp2;
+            jx += incx;
+            jy += incy;
+            j += 1;
+        }
+    }
+    Ok(())
+}
+
+/// SYR   performs the symmetric rank 1 operation
+/// A := alpha*x*x**T + A,
+/// where alpha is a complex scalar, x is an n element vector and A is an n by n complex symmetric matrix.
+/// Unlike [`her`], x isn't conjugated. This is the CSYR and ZSYR auxiliary routine of LAPACK combined in one function
+#[inline]
+pub fn syr<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) {
+    if let Err(err) = try_syr(uplo, n, alpha, x, incx, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`syr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: Complex<T>,
+    x: &[Complex<T>],
+    incx: isize,
+    a: &mut [Complex<T>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("syr", 5, "incx must not be zero"));
+    }
+    if lda < max(1, n) {
+        return Err(BlasError::new("syr", 7, "lda must be at least max(1, n)"));
+    }
+
+    if n == 0 || alpha.is_zero() {
+        return Ok(());
+    }
+
+    extent::vector("syr", 4, x, n, incx)?;
+    extent::matrix("syr", 6, a, n, n, lda)?;
+
+    let mut kx = 0;
+    if incx < 0 {
+        kx = (-(n as isize) * incx) + incx
+    };
+    let mut jx = kx;
+    let mut j = 0;
+    if uplo == Uplo::Upper {
+        while j < n {
+            let aj = j * lda;
+            if !x[jx as usize].is_zero() {
+                let tmp = alpha * x[jx as usize];
+                let mut ix = kx;
+                let mut i = 0;
+                while i <= j {
+                    a[aj + i] += x[ix as usize] * tmp;
+                    ix += incx;
+                    i += 1;
+                }
+            }
+            jx += incx;
+            j += 1;
+        }
+    } else {
+        while j < n {
+            let aj = j * lda;
+            if !x[jx as usize].is_zero() {
+                let tmp = alpha * x[jx as usize];
+                let mut ix = jx;
+                let mut i = j;
+                while i < n {
+                    a[aj + i] += x[ix as usize] * tmp;
+                    ix += incx;
+                    i += 1;
+                }
+            }
+            jx += incx;
+            j += 1;
+        }
+    }
+    Ok(())
+}
+
+/// TBMV  performs one of the matrix-vector operations
+/// x := A*x,   or   x := A**T*x,   or   x := A**H*x,
+/// where x is an n element vector and  A is an n by n unit, or non-unit, upper or lower triangular band matrix, with ( k + 1 ) diagonals.
+/// This is [CTBMV](http://www.netlib.org/lapack/explore-html/d3/dcd/ctbmv_8f.html) and [ZTBMV](http://www.netlib.org/lapack/explore-html/d3/d39/ztbmv_8f.html) comined in one function
+#[inline]
+pub fn tbmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) {
+    if let Err(err) = try_tbmv(uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < k + 1 {
+        return Err(BlasError::new("tbmv", 7, "lda must be at least k + 1"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("tbmv", 9, "incx must not be zero"));
+    }
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    extent::matrix("tbmv", 6, a, k + 1, n, lda)?;
+    extent::vector("tbmv", 8, x, n, incx)?;
+
+    let noconj = trans == Trans::Trans;
+    let nounit = diag == Diag::NonUnit;
+    let mut kx = 0;
+    if incx < 0 {
+        kx = (-(n as isize) * incx) + incx
+    };
+
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
+            let mut jx = kx;
+            let mut j = 0;
+            while j < n {
+                let tmp = x[jx as usize];
+                if !tmp.is_zero() {
+                    let mut ix = kx;
+                    let aj = j * lda;
+                    let mut i = j.saturating_sub(k);
+                    while i < j {
+                        x[ix as usize] += tmp * a[aj + i + k - j];
+                        ix += incx;
+                        i += 1;
+                    }
+                    if nounit {
+                        x[jx as usize] = tmp * a[aj + k]
+                    }
+                }
+                jx += incx;
+                j += 1;
+                if j > k {
+                    kx += incx;
+                }
+            }
+        } else {
+            // FIXME
+            kx += (n as isize - 1) * incx;
+            let mut jx = kx;
+            let mut j = n;
+            while j >= 1 {
+                j -= 1;
+                let aj = j * lda;
+                let tmp = x[jx as usize];
+                if !tmp.is_zero() {
+                    let mut ix = kx;
                     let mut i = min(n - 1, j + k); // NOTE
                     while i >= j + 1 {
                         x[ix as usize] += tmp * a[aj - j + i];
@@ -1167,7 +1793,7 @@ pub fn tbmv<T: Float + NumAssignOps>(
                 }
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         kx += (n as isize - 1) * incx;
         let mut jx = kx;
         let mut j = n;
@@ -1229,6 +1855,7 @@ pub fn tbmv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// TBSV  solves one of the systems of equations
@@ -1238,9 +1865,9 @@ pub fn tbmv<T: Float + NumAssignOps>(
 /// This is [CTBSV](http://www.netlib.org/lapack/explore-html/d9/d5f/ctbsv_8f.html) and [ZTBSV](http://www.netlib.org/lapack/explore-html/d4/d5a/ztbsv_8f.html) comined in one function
 #[inline]
 pub fn tbsv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     k: usize,
     a: &[Complex<T>],
@@ -1248,40 +1875,47 @@ pub fn tbsv<T: Float + NumAssignOps>(
     x: &mut [Complex<T>],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < k + 1 {
-        info = 7;
-    } else if incx == 0 {
-        info = 9;
-    }
-    if info != 0 {
-        panic!("tbsv {}", info);
+    if let Err(err) = try_tbsv(uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < k + 1 {
+        return Err(BlasError::new("tbsv", 7, "lda must be at least k + 1"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("tbsv", 9, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
+
+    extent::matrix("tbsv", 6, a, k + 1, n, lda)?;
+    extent::vector("tbsv", 8, x, n, incx)?;
+
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    let noconj = trans == 't' || trans == 'T';
-    let nounit = diag == 'n' || diag == 'N';
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    let noconj = trans == Trans::Trans;
+    let nounit = diag == Diag::NonUnit;
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             kx += (n as isize - 1) * incx;
             let mut jx = kx;
             let mut j = n;
@@ -1327,7 +1961,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
                 j += 1;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -1393,6 +2027,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
             }
         }
     }
+    Ok(())
 }
 
 /// TPMV  performs one of the matrix-vector operations
@@ -1401,47 +2036,50 @@ pub fn tbsv<T: Float + NumAssignOps>(
 /// This is [CTPMV](http://www.netlib.org/lapack/explore-html/d4/dbb/ctpmv_8f.html) and [ZTPMV](http://www.netlib.org/lapack/explore-html/d2/d9e/ztpmv_8f.html) comined in one function
 #[inline]
 pub fn tpmv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     ap: &[Complex<T>],
     x: &mut [Complex<T>],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if incx == 0 {
-        info = 7;
-    }
-    if info != 0 {
-        panic!("tmpv {}", info);
+    if let Err(err) = try_tpmv(uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[Complex<T>],
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("tpmv", 7, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
 
-    let noconj = trans == 't' || trans == 'T';
-    let nounit = diag == 'n' || diag == 'N';
+    extent::packed("tpmv", 5, ap, n)?;
+    extent::vector("tpmv", 6, x, n, incx)?;
+
+    let noconj = trans == Trans::Trans;
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut kk = 0;
             let mut jx = kx;
             let mut j = 0;
@@ -1490,7 +2128,7 @@ pub fn tpmv<T: Float + NumAssignOps>(
                 //
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut kk = n * (n + 1) / 2;
         let mut jx = kx + (n as isize - 1) * incx;
         let mut j = n;
@@ -1550,6 +2188,7 @@ pub fn tpmv<T: Float + NumAssignOps>(
             kk += 1;
         }
     }
+    Ok(())
 }
 
 /// TPSV  solves one of the systems of equations
@@ -1559,46 +2198,50 @@ pub fn tpmv<T: Float + NumAssignOps>(
 /// This is [CTPSV](http://www.netlib.org/lapack/explore-html/d8/d56/ctpsv_8f.html) and [ZTPSV](http://www.netlib.org/lapack/explore-html/da/d57/ztpsv_8f.html) comined in one function
 #[inline]
 pub fn tpsv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     ap: &[Complex<T>],
     x: &mut [Complex<T>],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if incx == 0 {
-        info = 7;
-    }
-    if info != 0 {
-        panic!("tpsv {}", info);
+    if let Err(err) = try_tpsv(uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[Complex<T>],
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("tpsv", 7, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
-    let noconj = trans == 't' || trans == 'T';
-    let nounit = diag == 'n' || diag == 'N';
+
+    extent::packed("tpsv", 5, ap, n)?;
+    extent::vector("tpsv", 6, x, n, incx)?;
+
+    let noconj = trans == Trans::Trans;
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut kk = n * (n + 1) / 2;
             let mut jx = kx + (n as isize - 1) * incx;
             let mut j = n;
@@ -1644,7 +2287,7 @@ pub fn tpsv<T: Float + NumAssignOps>(
                 kk += 1;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut kk = 0;
         let mut jx = kx;
         let mut j = 0;
@@ -1707,6 +2350,7 @@ pub fn tpsv<T: Float + NumAssignOps>(
             jx -= incx;
         }
     }
+    Ok(())
 }
 
 /// TRMV  performs one of the matrix-vector operations
@@ -1715,50 +2359,55 @@ pub fn tpsv<T: Float + NumAssignOps>(
 /// This is [CTRMV](http://www.netlib.org/lapack/explore-html/df/d78/ctrmv_8f.html) and [ZTRMV](http://www.netlib.org/lapack/explore-html/d0/dd1/ztrmv_8f.html) comined in one function
 #[inline]
 pub fn trmv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     a: &[Complex<T>],
     lda: usize,
     x: &mut [Complex<T>],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < max(1, n) {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    }
-    if info != 0 {
-        panic!("trmv {}", info);
+    if let Err(err) = try_trmv(uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, n) {
+        return Err(BlasError::new("trmv", 6, "lda must be at least max(1, n)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("trmv", 8, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
 
-    let noconj = trans == 't' || trans == 'T';
-    let nounit = diag == 'n' || diag == 'N';
+    extent::matrix("trmv", 5, a, n, n, lda)?;
+    extent::vector("trmv", 7, x, n, incx)?;
+
+    let noconj = trans == Trans::Trans;
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut jx = kx;
             let mut j = 0;
             while j < n {
@@ -1802,7 +2451,7 @@ pub fn trmv<T: Float + NumAssignOps>(
                 jx -= incx;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut jx = kx + (n as isize - 1) * incx;
         let mut j = n;
         while j >= 1 {
@@ -1865,6 +2514,7 @@ pub fn trmv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// TRSV  solves one of the systems of equations
@@ -1874,50 +2524,55 @@ pub fn trmv<T: Float + NumAssignOps>(
 /// This is [CTRSV](http://www.netlib.org/lapack/explore-html/d4/dc8/ctrsv_8f.html) and [ZTRSV](http://www.netlib.org/lapack/explore-html/d1/d2f/ztrsv_8f.html) comined in one function
 #[inline]
 pub fn trsv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     a: &[Complex<T>],
     lda: usize,
     x: &mut [Complex<T>],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < max(1, n) {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    }
-    if info != 0 {
-        panic!("trsv {}", info);
+    if let Err(err) = try_trsv(uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[Complex<T>],
+    lda: usize,
+    x: &mut [Complex<T>],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, n) {
+        return Err(BlasError::new("trsv", 6, "lda must be at least max(1, n)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("trsv", 8, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
 
-    let noconj = trans == 't' || trans == 'T';
-    let nounit = diag == 'n' || diag == 'N';
+    extent::matrix("trsv", 5, a, n, n, lda)?;
+    extent::vector("trsv", 7, x, n, incx)?;
+
+    let noconj = trans == Trans::Trans;
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut jx = kx + (n as isize - 1) * incx;
             let mut j = n;
             while j >= 1 {
@@ -1962,7 +2617,7 @@ pub fn trsv<T: Float + NumAssignOps>(
                 j += 1;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -2020,4 +2675,5 @@ pub fn trsv<T: Float + NumAssignOps>(
             jx -= incx;
         }
     }
+    Ok(())
 }
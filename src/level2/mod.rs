@@ -1,10 +1,11 @@
-// use std::arch::x86_64::*;
-use num_traits::{Float, NumAssignOps};
+use crate::{extent, simd, xerbla, BlasError, Diag, Ring, Trans, Uplo};
+use num_traits::{Float, NumAssignOps, Zero};
 use std::cmp::{max, min};
+use std::ops::Mul;
 
 pub mod complex;
 
-fn multiply<T: Float + NumAssignOps>(
+fn multiply<T: Zero + Mul<Output = T> + Copy>(
     left: &mut [T],
     right: T,
     len: isize,
@@ -16,14 +17,14 @@ fn multiply<T: Float + NumAssignOps>(
     } else {
         let mut i = 0;
         while i < len {
-            left[index as usize] *= right;
+            left[index as usize] = left[index as usize] * right;
             index += inc;
             i += 1;
         }
     }
 }
 
-fn zero<T: Float + NumAssignOps>(left: &mut [T], len: isize, mut index: isize, inc: isize) {
+fn zero<T: Zero + Copy>(left: &mut [T], len: isize, mut index: isize, inc: isize) {
     let mut i = 0;
     while i < len {
         left[index as usize] = T::zero();
@@ -38,7 +39,7 @@ fn zero<T: Float + NumAssignOps>(left: &mut [T], len: isize, mut index: isize, i
 /// This is [SGBMV](http://www.netlib.org/lapack/explore-html/d6/d46/sgbmv_8f.html) and [DGBMV](http://www.netlib.org/lapack/explore-html/d2/d3f/dgbmv_8f.html) comined in one function
 #[inline]
 pub fn gbmv<T: Float + NumAssignOps>(
-    trans: char,
+    trans: Trans,
     m: usize,
     n: usize,
     kl: usize,
@@ -52,28 +53,51 @@ pub fn gbmv<T: Float + NumAssignOps>(
     y: &mut [T],
     incy: isize,
 ) {
-    let mut info = 0;
-    if trans != 'c' && trans != 'C' && trans != 'n' && trans != 'N' && trans != 't' && trans != 'T'
-    {
-        info = 1;
-    } else if lda < (kl + ku + 1) {
-        info = 8;
-    } else if incx == 0 {
-        info = 10;
-    } else if incy == 0 {
-        info = 13;
+    if let Err(err) = try_gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("gbmv {}", info);
+/// Checked variant of [`gbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gbmv<T: Float + NumAssignOps>(
+    trans: Trans,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < (kl + ku + 1) {
+        return Err(BlasError::new(
+            "gbmv",
+            8,
+            "lda must be at least kl + ku + 1",
+        ));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("gbmv", 10, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("gbmv", 13, "incy must not be zero"));
     }
 
     if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
-    let lenx = if trans == 'n' || trans == 'N' { n } else { m };
-    let leny = if trans == 'n' || trans == 'N' { m } else { n };
+    let lenx = if trans == Trans::NoTrans { n } else { m };
+    let leny = if trans == Trans::NoTrans { m } else { n };
+    extent::matrix("gbmv", 7, a, kl + ku + 1, n, lda)?;
+    extent::vector("gbmv", 9, x, lenx, incx)?;
+    extent::vector("gbmv", 12, y, leny, incy)?;
 
     let mut kx = 0;
     let mut ky = 0;
@@ -88,10 +112,10 @@ pub fn gbmv<T: Float + NumAssignOps>(
         multiply(y, beta, leny as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
+    if trans == Trans::NoTrans {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -135,15 +159,17 @@ pub fn gbmv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// GEMV  performs one of the matrix-vector operations
 /// y := alpha*A*x + beta*y,   or   y := alpha*A**T*x + beta*y,
 /// where alpha and beta are scalars, x and y are vectors and A is an m by n matrix.
 /// This is [SGEMV](http://www.netlib.org/lapack/explore-html/db/d58/sgemv_8f.html) and [DGEMV](http://www.netlib.org/lapack/explore-html/dc/da8/dgemv_8f.html) comined in one function
+/// `ConjTrans` conjugates the elements of A with [`Ring::conj`], so for real element types it is the same as `Trans`.
 #[inline]
-pub fn gemv<T: Float + NumAssignOps>(
-    trans: char,
+pub fn gemv<T: Ring + 'static>(
+    trans: Trans,
     m: usize,
     n: usize,
     alpha: T,
@@ -155,27 +181,45 @@ pub fn gemv<T: Float + NumAssignOps>(
     y: &mut [T],
     incy: isize,
 ) {
-    let mut info = 0;
-    if trans != 'c' && trans != 'C' && trans != 'n' && trans != 'N' && trans != 't' && trans != 'T'
-    {
-        info = 1;
-    } else if lda < max(1, m) {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    } else if incy == 0 {
-        info = 11;
+    if let Err(err) = try_gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemv<T: Ring + 'static>(
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, m) {
+        return Err(BlasError::new("gemv", 6, "lda must be at least max(1, m)"));
     }
-    if info != 0 {
-        panic!("gemv {}", info);
+    if incx == 0 {
+        return Err(BlasError::new("gemv", 8, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("gemv", 11, "incy must not be zero"));
     }
 
     if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
-    let lenx = if trans == 'n' || trans == 'N' { n } else { m };
-    let leny = if trans == 'n' || trans == 'N' { m } else { n };
+    let lenx = if trans == Trans::NoTrans { n } else { m };
+    let leny = if trans == Trans::NoTrans { m } else { n };
+    extent::matrix("gemv", 5, a, m, n, lda)?;
+    extent::vector("gemv", 7, x, lenx, incx)?;
+    extent::vector("gemv", 10, y, leny, incy)?;
 
     let mut kx = 0;
     let mut ky = 0;
@@ -183,7 +227,7 @@ pub fn gemv<T: Float + NumAssignOps>(
         kx = (-(lenx as isize) * incx) + incx
     };
     if incy < 0 {
-        ky = (-(leny as isize) * incx) + incy
+        ky = (-(leny as isize) * incy) + incy
     };
 
     if !beta.is_one() {
@@ -194,42 +238,56 @@ pub fn gemv<T: Float + NumAssignOps>(
         }
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
-    if trans == 'n' || trans == 'N' {
+    if trans == Trans::NoTrans {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
             let tmp = alpha * x[jx as usize];
-            let mut iy = ky;
             let aj = j * lda;
-            let mut i = 0;
-            while i < m {
-                y[iy as usize] += tmp * a[aj + i];
-                iy += incy;
-                i += 1;
+            if incy != 1 || !simd::axpy(tmp, &a[aj..aj + m], &mut y[..m]) {
+                let mut iy = ky;
+                let mut i = 0;
+                while i < m {
+                    y[iy as usize] = y[iy as usize] + tmp * a[aj + i];
+                    iy += incy;
+                    i += 1;
+                }
             }
             jx += incx;
             j += 1;
         }
     } else {
+        let conj = trans == Trans::ConjTrans;
         let mut jy = ky;
         let mut j = 0;
         while j < n {
-            let mut tmp = T::zero();
-            let mut ix = kx;
             let aj = j * lda;
-            let mut i = 0;
-            while i < m {
-                tmp += a[aj + i] * x[ix as usize];
-                ix += incx;
-                i += 1;
-            }
-            y[jy as usize] += alpha * tmp;
+            // The SIMD dot is only taken for f32 and f64, whose conjugate is the identity.
+            let dot = if incx == 1 {
+                simd::dot(&a[aj..aj + m], &x[..m])
+            } else {
+                None
+            };
+            let tmp = dot.unwrap_or_else(|| {
+                let mut tmp = T::zero();
+                let mut ix = kx;
+                let mut i = 0;
+                while i < m {
+                    let aij = if conj { a[aj + i].conj() } else { a[aj + i] };
+                    tmp = tmp + aij * x[ix as usize];
+                    ix += incx;
+                    i += 1;
+                }
+                tmp
+            });
+            y[jy as usize] = y[jy as usize] + alpha * tmp;
             jy += incy;
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// SGER   performs the rank 1 operation
@@ -237,7 +295,7 @@ pub fn gemv<T: Float + NumAssignOps>(
 /// where alpha is a scalar, x is an m element vector, y is an n element vector and A is an m by n matrix.
 /// This is [SGER](http://www.netlib.org/lapack/explore-html/db/d5c/sger_8f.html) and [DGER](http://www.netlib.org/lapack/explore-html/dc/da8/dger_8f.html) comined in one function
 #[inline]
-pub fn ger<T: Float + NumAssignOps>(
+pub fn ger<T: Ring>(
     m: usize,
     n: usize,
     alpha: T,
@@ -248,22 +306,42 @@ pub fn ger<T: Float + NumAssignOps>(
     a: &mut [T],
     lda: usize,
 ) {
-    let mut info = 0;
+    if let Err(err) = try_ger(m, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`ger`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_ger<T: Ring>(
+    m: usize,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    a: &mut [T],
+    lda: usize,
+) -> Result<(), BlasError> {
     if incx == 0 {
-        info = 5;
-    } else if incy == 0 {
-        info = 7;
-    } else if lda < max(1, m) {
-        info = 9;
+        return Err(BlasError::new("ger", 5, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("ger", 7, "incy must not be zero"));
     }
-    if info != 0 {
-        panic!("ger {}", info);
+    if lda < max(1, m) {
+        return Err(BlasError::new("ger", 9, "lda must be at least max(1, m)"));
     }
 
     if m == 0 || n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("ger", 4, x, m, incx)?;
+    extent::vector("ger", 6, y, n, incy)?;
+    extent::matrix("ger", 8, a, m, n, lda)?;
+
     let mut kx = 0;
     let mut jy = 0;
     if incx < 0 {
@@ -276,12 +354,12 @@ pub fn ger<T: Float + NumAssignOps>(
     while j < n {
         let mut tmp = y[jy as usize];
         if !tmp.is_zero() {
-            tmp *= alpha;
+            tmp = tmp * alpha;
             let mut ix = kx;
             let aj = j * lda;
             let mut i = 0;
             while i < m {
-                a[aj + i] += x[ix as usize] * tmp;
+                a[aj + i] = a[aj + i] + x[ix as usize] * tmp;
                 ix += incx;
                 i += 1;
             }
@@ -289,6 +367,7 @@ pub fn ger<T: Float + NumAssignOps>(
         jy += incy;
         j += 1;
     }
+    Ok(())
 }
 
 /// SBMV  performs the matrix-vector  operation
@@ -297,7 +376,7 @@ pub fn ger<T: Float + NumAssignOps>(
 /// This is [SSBMV](http://www.netlib.org/lapack/explore-html/d3/da1/ssbmv_8f.html) and [DSBMV](hhttp://www.netlib.org/lapack/explore-html/d8/d1e/dsbmv_8f.html) comined in one function
 #[inline]
 pub fn sbmv<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     k: usize,
     alpha: T,
@@ -309,24 +388,44 @@ pub fn sbmv<T: Float + NumAssignOps>(
     y: &mut [T],
     incy: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if lda < k + 1 {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    } else if incy == 0 {
-        info = 11;
+    if let Err(err) = try_sbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
     }
-    if info != 0 {
-        panic!("sbmv {}", info);
+}
+
+/// Checked variant of [`sbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_sbmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < k + 1 {
+        return Err(BlasError::new("sbmv", 6, "lda must be at least k + 1"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("sbmv", 8, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("sbmv", 11, "incy must not be zero"));
     }
 
     if n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
+    extent::matrix("sbmv", 5, a, k + 1, n, lda)?;
+    extent::vector("sbmv", 7, x, n, incx)?;
+    extent::vector("sbmv", 10, y, n, incy)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -340,9 +439,9 @@ pub fn sbmv<T: Float + NumAssignOps>(
         multiply(y, beta, n as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut jy = ky;
         let mut j = 0;
@@ -394,6 +493,7 @@ pub fn sbmv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// SPMV  performs the matrix-vector operation
@@ -402,7 +502,7 @@ pub fn sbmv<T: Float + NumAssignOps>(
 /// This is [SSPMV](http://www.netlib.org/lapack/explore-html/d8/d68/sspmv_8f.html) and [DSPMV](http://www.netlib.org/lapack/explore-html/d4/d85/dspmv_8f.html) comined in one function
 #[inline]
 pub fn spmv<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     ap: &[T],
@@ -412,22 +512,39 @@ pub fn spmv<T: Float + NumAssignOps>(
     y: &mut [T],
     incy: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 6;
-    } else if incy == 0 {
-        info = 9;
+    if let Err(err) = try_spmv(uplo, n, alpha, ap, x, incx, beta, y, incy) {
+        xerbla::report(&err);
     }
-    if info != 0 {
-        panic!("spmv {}", info);
+}
+
+/// Checked variant of [`spmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    ap: &[T],
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("spmv", 6, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("spmv", 9, "incy must not be zero"));
     }
 
     if n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
+    extent::packed("spmv", 4, ap, n)?;
+    extent::vector("spmv", 5, x, n, incx)?;
+    extent::vector("spmv", 8, y, n, incy)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -441,10 +558,10 @@ pub fn spmv<T: Float + NumAssignOps>(
         multiply(y, beta, n as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
     let mut kk = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut jy = ky;
         let mut j = 0;
@@ -493,6 +610,7 @@ pub fn spmv<T: Float + NumAssignOps>(
             kk += 1;
         }
     }
+    Ok(())
 }
 
 /// SPR    performs the symmetric rank 1 operation
@@ -501,34 +619,46 @@ pub fn spmv<T: Float + NumAssignOps>(
 /// This is [SSPR](http://www.netlib.org/lapack/explore-html/d2/d9b/sspr_8f.html) and [DSPR](http://www.netlib.org/lapack/explore-html/dd/dba/dspr_8f.html) comined in one function
 #[inline]
 pub fn spr<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     x: &[T],
     incx: isize,
     ap: &mut [T],
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 6;
+    if let Err(err) = try_spr(uplo, n, alpha, x, incx, ap) {
+        xerbla::report(&err);
     }
-    if info != 0 {
-        panic!("spr {}", info);
+}
+
+/// Checked variant of [`spr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spr<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    ap: &mut [T],
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("spr", 5, "incx must not be zero"));
     }
 
     if n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("spr", 4, x, n, incx)?;
+    extent::packed("spr", 6, ap, n)?;
+
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
     let mut kk = 0;
     let mut jx = kx;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -563,6 +693,7 @@ pub fn spr<T: Float + NumAssignOps>(
             jx += incx;
         }
     }
+    Ok(())
 }
 
 /// SPR2  performs the symmetric rank 2 operation
@@ -571,7 +702,7 @@ pub fn spr<T: Float + NumAssignOps>(
 /// This is [SSPR2](http://www.netlib.org/lapack/explore-html/db/d3e/sspr2_8f.html) and [DSPR2](http://www.netlib.org/lapack/explore-html/dd/d9e/dspr2_8f.html) comined in one function
 #[inline]
 pub fn spr2<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     x: &[T],
@@ -580,22 +711,38 @@ pub fn spr2<T: Float + NumAssignOps>(
     incy: isize,
     ap: &mut [T],
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 5;
-    } else if incy == 0 {
-        info = 7;
+    if let Err(err) = try_spr2(uplo, n, alpha, x, incx, y, incy, ap) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`spr2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_spr2<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    ap: &mut [T],
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("spr2", 5, "incx must not be zero"));
     }
-    if info != 0 {
-        panic!("spr2 {}", info);
+    if incy == 0 {
+        return Err(BlasError::new("spr2", 7, "incy must not be zero"));
     }
 
     if n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("spr2", 4, x, n, incx)?;
+    extent::vector("spr2", 6, y, n, incy)?;
+    extent::packed("spr2", 8, ap, n)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -608,7 +755,7 @@ pub fn spr2<T: Float + NumAssignOps>(
     let mut jx = kx;
     let mut jy = ky;
     let mut kk = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         let mut j = 0;
         while j < n {
             j += 1;
@@ -651,6 +798,7 @@ pub fn spr2<T: Float + NumAssignOps>(
             jy += incy;
         }
     }
+    Ok(())
 }
 
 /// SYMV  performs the matrix-vector  operation
@@ -659,7 +807,7 @@ pub fn spr2<T: Float + NumAssignOps>(
 /// This is [SSYMV](http://www.netlib.org/lapack/explore-html/d2/d94/ssymv_8f.html) and [DSYMV](http://www.netlib.org/lapack/explore-html/d8/dbe/dsymv_8f.html) comined in one function
 #[inline]
 pub fn symv<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     a: &[T],
@@ -670,24 +818,43 @@ pub fn symv<T: Float + NumAssignOps>(
     y: &mut [T],
     incy: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if lda < max(1, n) {
-        info = 5;
-    } else if incx == 0 {
-        info = 7;
-    } else if incy == 0 {
-        info = 9;
+    if let Err(err) = try_symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
     }
-    if info != 0 {
-        panic!("symv {}", info);
+}
+
+/// Checked variant of [`symv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_symv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    incx: isize,
+    beta: T,
+    y: &mut [T],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, n) {
+        return Err(BlasError::new("symv", 5, "lda must be at least max(1, n)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("symv", 7, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("symv", 10, "incy must not be zero"));
     }
 
     if n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
+    extent::matrix("symv", 4, a, n, n, lda)?;
+    extent::vector("symv", 6, x, n, incx)?;
+    extent::vector("symv", 9, y, n, incy)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -701,12 +868,12 @@ pub fn symv<T: Float + NumAssignOps>(
         multiply(y, beta, n as isize, ky, incy)
     }
     if alpha.is_zero() {
-        return;
+        return Ok(());
     }
     let mut jx = kx;
     let mut jy = ky;
     let mut j = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         while j < n {
             let tmp = alpha * x[jx as usize];
             let mut tmp2 = T::zero();
@@ -748,6 +915,7 @@ pub fn symv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// SYR   performs the symmetric rank 1 operation
@@ -756,7 +924,7 @@ pub fn symv<T: Float + NumAssignOps>(
 /// This is [SSYR](http://www.netlib.org/lapack/explore-html/d6/dac/ssyr_8f.html) and [DSYR](http://www.netlib.org/lapack/explore-html/d3/d60/dsyr_8f.html) comined in one function
 #[inline]
 pub fn syr<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     x: &[T],
@@ -764,30 +932,43 @@ pub fn syr<T: Float + NumAssignOps>(
     a: &mut [T],
     lda: usize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 5;
-    } else if lda < max(1, n) {
-        info = 7;
+    if let Err(err) = try_syr(uplo, n, alpha, x, incx, a, lda) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("syr {}", info);
+/// Checked variant of [`syr`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    a: &mut [T],
+    lda: usize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("syr", 5, "incx must not be zero"));
+    }
+    if lda < max(1, n) {
+        return Err(BlasError::new("syr", 7, "lda must be at least max(1, n)"));
     }
 
     if n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("syr", 4, x, n, incx)?;
+    extent::matrix("syr", 6, a, n, n, lda)?;
+
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
     let mut jx = kx;
     let mut j = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         while j < n {
             // FIXME check the ASM else move back into if statement
             let aj = j * lda;
@@ -826,6 +1007,7 @@ pub fn syr<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// SYR2  performs the symmetric rank 2 operation
@@ -834,7 +1016,7 @@ pub fn syr<T: Float + NumAssignOps>(
 /// This is [SSYR2](http://www.netlib.org/lapack/explore-html/db/d99/ssyr2_8f.html) and [DSYR2](http://www.netlib.org/lapack/explore-html/db/d99/ssyr2_8f.html) comined in one function
 #[inline]
 pub fn syr2<T: Float + NumAssignOps>(
-    uplo: char,
+    uplo: Uplo,
     n: usize,
     alpha: T,
     x: &[T],
@@ -844,25 +1026,42 @@ pub fn syr2<T: Float + NumAssignOps>(
     a: &mut [T],
     lda: usize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if incx == 0 {
-        info = 3;
-    } else if incy == 0 {
-        info = 5;
-    } else if lda < max(1, n) {
-        info = 9;
+    if let Err(err) = try_syr2(uplo, n, alpha, x, incx, y, incy, a, lda) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("syr2 {}", info);
+/// Checked variant of [`syr2`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr2<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    n: usize,
+    alpha: T,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+    a: &mut [T],
+    lda: usize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("syr2", 5, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("syr2", 7, "incy must not be zero"));
+    }
+    if lda < max(1, n) {
+        return Err(BlasError::new("syr2", 9, "lda must be at least max(1, n)"));
     }
 
     if n == 0 || alpha.is_zero() {
-        return;
+        return Ok(());
     }
 
+    extent::vector("syr2", 4, x, n, incx)?;
+    extent::vector("syr2", 6, y, n, incy)?;
+    extent::matrix("syr2", 8, a, n, n, lda)?;
+
     let mut kx = 0;
     let mut ky = 0;
     if incx < 0 {
@@ -874,7 +1073,7 @@ pub fn syr2<T: Float + NumAssignOps>(
     let mut jx = kx;
     let mut jy = ky;
     let mut j = 0;
-    if uplo == 'u' || uplo == 'U' {
+    if uplo == Uplo::Upper {
         while j < n {
             let mut tmp = y[jy as usize];
             let mut tmp2 = x[jx as usize];
@@ -920,6 +1119,7 @@ pub fn syr2<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// TBMV  performs one of the matrix-vector operations
@@ -928,9 +1128,9 @@ pub fn syr2<T: Float + NumAssignOps>(
 /// This is [STMBV](http://www.netlib.org/lapack/explore-html/d6/d7d/stbmv_8f.html) and [DTMBV](http://www.netlib.org/lapack/explore-html/df/d29/dtbmv_8f.html) comined in one function
 #[inline]
 pub fn tbmv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     k: usize,
     a: &[T],
@@ -938,39 +1138,46 @@ pub fn tbmv<T: Float + NumAssignOps>(
     x: &mut [T],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < k + 1 {
-        info = 7;
-    } else if incx == 0 {
-        info = 9;
-    }
-    if info != 0 {
-        panic!("tbmv {}", info);
+    if let Err(err) = try_tbmv(uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < k + 1 {
+        return Err(BlasError::new("tbmv", 7, "lda must be at least k + 1"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("tbmv", 9, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
-    let nounit = diag == 'n' || diag == 'N';
+
+    extent::matrix("tbmv", 6, a, k + 1, n, lda)?;
+    extent::vector("tbmv", 8, x, n, incx)?;
+
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut jx = kx;
             let mut j = 0;
             while j < n {
@@ -1020,7 +1227,7 @@ pub fn tbmv<T: Float + NumAssignOps>(
                 }
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         kx += (n as isize - 1) * incx;
         let mut jx = kx;
         let mut j = n;
@@ -1065,6 +1272,7 @@ pub fn tbmv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// TBSV  solves one of the systems of equations
@@ -1075,9 +1283,9 @@ pub fn tbmv<T: Float + NumAssignOps>(
 /// This is [STBSV](http://www.netlib.org/lapack/explore-html/d0/d1f/stbsv_8f.html) and [DTBSV](http://www.netlib.org/lapack/explore-html/d4/dcf/dtbsv_8f.html) comined in one function
 #[inline]
 pub fn tbsv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     k: usize,
     a: &[T],
@@ -1085,38 +1293,45 @@ pub fn tbsv<T: Float + NumAssignOps>(
     x: &mut [T],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < k + 1 {
-        info = 7;
-    } else if incx == 0 {
-        info = 9;
-    }
-    if info != 0 {
-        panic!("tbsv {}", info);
+    if let Err(err) = try_tbsv(uplo, trans, diag, n, k, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tbsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tbsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < k + 1 {
+        return Err(BlasError::new("tbsv", 7, "lda must be at least k + 1"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("tbsv", 9, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
+
+    extent::matrix("tbsv", 6, a, k + 1, n, lda)?;
+    extent::vector("tbsv", 8, x, n, incx)?;
+
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             kx += (n as isize - 1) * incx;
             let mut jx = kx;
             let mut j = n;
@@ -1126,7 +1341,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
                 if !x[jx as usize].is_zero() {
                     let mut ix = kx;
                     let aj = j * lda;
-                    if diag == 'n' || diag == 'N' {
+                    if diag == Diag::NonUnit {
                         x[jx as usize] /= a[aj + k];
                     }
                     let tmp = x[jx as usize];
@@ -1147,7 +1362,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
                 if !x[jx as usize].is_zero() {
                     let mut ix = kx;
                     let aj = j * lda;
-                    if diag == 'n' || diag == 'N' {
+                    if diag == Diag::NonUnit {
                         x[jx as usize] /= a[aj];
                     }
                     let mut i = j + 1;
@@ -1162,7 +1377,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
                 j += 1;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -1175,7 +1390,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
                 ix += incx;
                 i += 1;
             }
-            if diag == 'n' || diag == 'N' {
+            if diag == Diag::NonUnit {
                 tmp /= a[aj + k];
             }
             x[jx as usize] = tmp;
@@ -1202,7 +1417,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
                 i -= 1;
             }
             //FIXME
-            if diag == 'n' || diag == 'N' {
+            if diag == Diag::NonUnit {
                 tmp /= a[aj];
             }
             x[jx as usize] = tmp;
@@ -1212,6 +1427,7 @@ pub fn tbsv<T: Float + NumAssignOps>(
             }
         }
     }
+    Ok(())
 }
 
 /// TPMV  performs one of the matrix-vector operations
@@ -1220,45 +1436,49 @@ pub fn tbsv<T: Float + NumAssignOps>(
 /// This is [STPMV](http://www.netlib.org/lapack/explore-html/db/db1/stpmv_8f.html) and [DTPMV](http://www.netlib.org/lapack/explore-html/dc/dcd/dtpmv_8f.html) comined in one function
 #[inline]
 pub fn tpmv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     ap: &[T],
     x: &mut [T],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if incx == 0 {
-        info = 7;
-    }
-    if info != 0 {
-        panic!("tmpv {}", info);
+    if let Err(err) = try_tpmv(uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[T],
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("tpmv", 7, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
-    let nounit = diag == 'n' || diag == 'N';
+
+    extent::packed("tpmv", 5, ap, n)?;
+    extent::vector("tpmv", 6, x, n, incx)?;
+
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut kk = 0;
             let mut jx = kx;
             let mut j = 0;
@@ -1306,7 +1526,7 @@ pub fn tpmv<T: Float + NumAssignOps>(
                 //
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut kk = n * (n + 1) / 2;
         let mut jx = kx + (n as isize - 1) * incx;
         let mut j = n;
@@ -1350,6 +1570,7 @@ pub fn tpmv<T: Float + NumAssignOps>(
             kk += 1;
         }
     }
+    Ok(())
 }
 
 /// TPSV  solves one of the systems of equations
@@ -1358,45 +1579,49 @@ pub fn tpmv<T: Float + NumAssignOps>(
 /// This is [STPSV](http://www.netlib.org/lapack/explore-html/d0/d7c/stpsv_8f.html) and [DTPSV](http://www.netlib.org/lapack/explore-html/d9/d84/dtpsv_8f.html) comined in one function
 #[inline]
 pub fn tpsv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     ap: &[T],
     x: &mut [T],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if incx == 0 {
-        info = 7;
-    }
-    if info != 0 {
-        panic!("tpsv {}", info);
+    if let Err(err) = try_tpsv(uplo, trans, diag, n, ap, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`tpsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_tpsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    ap: &[T],
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if incx == 0 {
+        return Err(BlasError::new("tpsv", 7, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
-    let nounit = diag == 'n' || diag == 'N';
+
+    extent::packed("tpsv", 5, ap, n)?;
+    extent::vector("tpsv", 6, x, n, incx)?;
+
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut kk = n * (n + 1) / 2;
             let mut jx = kx + (n as isize - 1) * incx;
             let mut j = n;
@@ -1442,7 +1667,7 @@ pub fn tpsv<T: Float + NumAssignOps>(
                 kk += 1;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut kk = 0;
         let mut jx = kx;
         let mut j = 0;
@@ -1488,6 +1713,7 @@ pub fn tpsv<T: Float + NumAssignOps>(
             jx -= incx;
         }
     }
+    Ok(())
 }
 
 /// TRMV  performs one of the matrix-vector operations
@@ -1496,48 +1722,54 @@ pub fn tpsv<T: Float + NumAssignOps>(
 /// This is [STRMV](http://www.netlib.org/lapack/explore-html/dc/d7e/dtrmv_8f.html) and [DTRMV](http://www.netlib.org/lapack/explore-html/de/d45/strmv_8f.html) comined in one function
 #[inline]
 pub fn trmv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     a: &[T],
     lda: usize,
     x: &mut [T],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < max(1, n) {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    }
-    if info != 0 {
-        panic!("trmv {}", info);
+    if let Err(err) = try_trmv(uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, n) {
+        return Err(BlasError::new("trmv", 6, "lda must be at least max(1, n)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("trmv", 8, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
-    let nounit = diag == 'n' || diag == 'N';
+
+    extent::matrix("trmv", 5, a, n, n, lda)?;
+    extent::vector("trmv", 7, x, n, incx)?;
+
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut jx = kx;
             let mut j = 0;
             while j < n {
@@ -1581,7 +1813,7 @@ pub fn trmv<T: Float + NumAssignOps>(
                 jx -= incx;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut jx = kx + (n as isize - 1) * incx;
         let mut j = n;
         while j >= 1 {
@@ -1622,6 +1854,7 @@ pub fn trmv<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// TRSV  solves one of the systems of equations
@@ -1631,48 +1864,54 @@ pub fn trmv<T: Float + NumAssignOps>(
 /// This is [STRSV](http://www.netlib.org/lapack/explore-html/d0/d2a/strsv_8f.html) and [DTRSV](http://www.netlib.org/lapack/explore-html/d6/d96/dtrsv_8f.html) comined in one function
 #[inline]
 pub fn trsv<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
-    diag: char,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     n: usize,
     a: &[T],
     lda: usize,
     x: &mut [T],
     incx: isize,
 ) {
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 3;
-    } else if lda < max(1, n) {
-        info = 6;
-    } else if incx == 0 {
-        info = 8;
-    }
-    if info != 0 {
-        panic!("trsv {}", info);
+    if let Err(err) = try_trsv(uplo, trans, diag, n, a, lda, x, incx) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    x: &mut [T],
+    incx: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, n) {
+        return Err(BlasError::new("trsv", 6, "lda must be at least max(1, n)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("trsv", 8, "incx must not be zero"));
     }
 
     if n == 0 {
-        return;
+        return Ok(());
     }
-    let nounit = diag == 'n' || diag == 'N';
+
+    extent::matrix("trsv", 5, a, n, n, lda)?;
+    extent::vector("trsv", 7, x, n, incx)?;
+
+    let nounit = diag == Diag::NonUnit;
     let mut kx = 0;
     if incx < 0 {
         kx = (-(n as isize) * incx) + incx
     };
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut jx = kx + (n as isize - 1) * incx;
             let mut j = n;
             while j >= 1 {
@@ -1715,7 +1954,7 @@ pub fn trsv<T: Float + NumAssignOps>(
                 j += 1;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut jx = kx;
         let mut j = 0;
         while j < n {
@@ -1757,4 +1996,5 @@ pub fn trsv<T: Float + NumAssignOps>(
             jx -= incx;
         }
     }
+    Ok(())
 }
@@ -0,0 +1,530 @@
+//! Batches of independent `gemm` and `trsm` problems, computed in parallel with the `rayon` feature,
+//! see [`threading`](crate::threading).
+//!
+//! The grouped routines take one slice per matrix and a list of groups, every group giving the arguments
+//! shared by its next `size` problems, like the group API of MKL. The strided routines take one buffer per operand
+//! and a fixed stride between the first elements of two problems; a zero stride shares A, or B for `gemm`,
+//! between all the problems. The arguments of every problem, and the length of every buffer, are checked before
+//! any of them is computed, so a short buffer is reported instead of panicking inside a worker.
+use super::{check_gemm, check_trsm, try_gemm, try_trsm};
+use crate::extent::extent;
+use crate::threading::ThreadSafe;
+use crate::{threading, xerbla, BlasError, Diag, RealScalar, Ring, Side, Trans, Uplo};
+use std::cmp::max;
+
+/// GemmGroup holds the arguments of [`gemm`](super::gemm) shared by `size` problems of [`gemm_batch`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GemmGroup<T> {
+    pub trans_a: Trans,
+    pub trans_b: Trans,
+    pub m: usize,
+    pub n: usize,
+    pub k: usize,
+    pub alpha: T,
+    pub lda: usize,
+    pub ldb: usize,
+    pub beta: T,
+    pub ldc: usize,
+    /// The number of problems in the group.
+    pub size: usize,
+}
+
+/// TrsmGroup holds the arguments of [`trsm`](super::trsm) shared by `size` problems of [`trsm_batch`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrsmGroup<T> {
+    pub side: Side,
+    pub uplo: Uplo,
+    pub trans: Trans,
+    pub diag: Diag,
+    pub m: usize,
+    pub n: usize,
+    pub alpha: T,
+    pub lda: usize,
+    pub ldb: usize,
+    /// The number of problems in the group.
+    pub size: usize,
+}
+
+fn checked(result: Result<(), BlasError>) {
+    result.expect("the arguments of a batch are checked before it is computed")
+}
+
+/// The group of every problem, in order.
+fn problems<G>(groups: &[G], size: impl Fn(&G) -> usize) -> Vec<&G> {
+    groups
+        .iter()
+        .flat_map(|g| std::iter::repeat_n(g, size(g)))
+        .collect()
+}
+
+/// Whether a buffer of `len` elements holds `batch` matrices of `extent` elements starting `stride` apart.
+fn holds(len: usize, stride: usize, extent: usize, batch: usize) -> bool {
+    batch == 0 || extent == 0 || (batch - 1).saturating_mul(stride).saturating_add(extent) <= len
+}
+
+/// The extents of A, B and C in a problem of `gemm`.
+fn gemm_extents(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    lda: usize,
+    ldb: usize,
+    ldc: usize,
+) -> (usize, usize, usize) {
+    let a = if trans_a == Trans::NoTrans {
+        extent(m, k, lda)
+    } else {
+        extent(k, m, lda)
+    };
+    let b = if trans_b == Trans::NoTrans {
+        extent(k, n, ldb)
+    } else {
+        extent(n, k, ldb)
+    };
+    (a, b, extent(m, n, ldc))
+}
+
+/// The extents of A and B in a problem of `trsm`.
+fn trsm_extents(side: Side, m: usize, n: usize, lda: usize, ldb: usize) -> (usize, usize) {
+    let nrowa = if side == Side::Left { m } else { n };
+    (extent(nrowa, nrowa, lda), extent(m, n, ldb))
+}
+
+/// The problem `p` of a strided buffer, empty when its matrices are empty and the buffer ends before it.
+fn problem<T>(x: &[T], p: usize, stride: usize) -> &[T] {
+    x.get(p * stride..).unwrap_or(&[])
+}
+
+/// Splits `x` into `batch` problems of `len` elements whose first elements are `stride` apart.
+/// The caller has checked that `x` holds them and, when there are several, that `len <= stride`.
+fn split<T>(mut x: &mut [T], stride: usize, len: usize, batch: usize) -> Vec<&mut [T]> {
+    let mut problems = Vec::with_capacity(batch);
+    let mut p = 0;
+    while p < batch {
+        let (head, tail) = std::mem::take(&mut x).split_at_mut(len);
+        problems.push(head);
+        x = if p + 1 < batch {
+            &mut tail[stride - len..]
+        } else {
+            tail
+        };
+        p += 1;
+    }
+    problems
+}
+
+/// GEMM_BATCH  performs the matrix-matrix operations
+/// C(p) := alpha*op( A(p) )*op( B(p) ) + beta*C(p),
+/// for every problem p, where the problems take the arguments of their groups in order, see [`gemm`](super::gemm).
+#[inline]
+pub fn gemm_batch<T: Ring + ThreadSafe + 'static>(
+    groups: &[GemmGroup<T>],
+    a: &[&[T]],
+    b: &[&[T]],
+    c: &mut [&mut [T]],
+) {
+    if let Err(err) = try_gemm_batch(groups, a, b, c) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemm_batch`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+/// An illegal argument of a group is reported as the [`BlasError`] of [`try_gemm`](super::try_gemm).
+#[inline]
+pub fn try_gemm_batch<T: Ring + ThreadSafe + 'static>(
+    groups: &[GemmGroup<T>],
+    a: &[&[T]],
+    b: &[&[T]],
+    c: &mut [&mut [T]],
+) -> Result<(), BlasError> {
+    let problems = problems(groups, |g| g.size);
+    if a.len() != problems.len() {
+        return Err(BlasError::new(
+            "gemm_batch",
+            2,
+            "a must hold one matrix per problem",
+        ));
+    }
+    if b.len() != problems.len() {
+        return Err(BlasError::new(
+            "gemm_batch",
+            3,
+            "b must hold one matrix per problem",
+        ));
+    }
+    if c.len() != problems.len() {
+        return Err(BlasError::new(
+            "gemm_batch",
+            4,
+            "c must hold one matrix per problem",
+        ));
+    }
+    for g in groups {
+        check_gemm(g.trans_a, g.trans_b, g.m, g.n, g.k, g.lda, g.ldb, g.ldc)?;
+    }
+    for (p, g) in problems.iter().enumerate() {
+        let (len_a, len_b, len_c) =
+            gemm_extents(g.trans_a, g.trans_b, g.m, g.n, g.k, g.lda, g.ldb, g.ldc);
+        if a[p].len() < len_a {
+            return Err(BlasError::new(
+                "gemm_batch",
+                2,
+                "every matrix of a must hold op( A ) of its group",
+            ));
+        }
+        if b[p].len() < len_b {
+            return Err(BlasError::new(
+                "gemm_batch",
+                3,
+                "every matrix of b must hold op( B ) of its group",
+            ));
+        }
+        if c[p].len() < len_c {
+            return Err(BlasError::new(
+                "gemm_batch",
+                4,
+                "every matrix of c must hold C of its group",
+            ));
+        }
+    }
+
+    let work = groups
+        .iter()
+        .map(|g| {
+            g.size
+                .saturating_mul(g.m)
+                .saturating_mul(g.n)
+                .saturating_mul(g.k)
+        })
+        .fold(0, usize::saturating_add);
+    let items = problems
+        .into_iter()
+        .zip(a.iter().zip(b.iter()))
+        .zip(c.iter_mut())
+        .collect();
+    threading::for_each(work, items, |((g, (a, b)), c)| {
+        checked(try_gemm(
+            g.trans_a, g.trans_b, g.m, g.n, g.k, g.alpha, a, g.lda, b, g.ldb, g.beta, c, g.ldc,
+        ))
+    });
+    Ok(())
+}
+
+/// GEMM_BATCH_STRIDED  performs the matrix-matrix operations
+/// C(p) := alpha*op( A(p) )*op( B(p) ) + beta*C(p),   p = 0, ..., batch - 1,
+/// where A(p) starts at `a[p*stride_a]`, B(p) at `b[p*stride_b]` and C(p) at `c[p*stride_c]`, see [`gemm`](super::gemm).
+#[inline]
+pub fn gemm_batch_strided<T: Ring + ThreadSafe + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    stride_a: usize,
+    b: &[T],
+    ldb: usize,
+    stride_b: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+    stride_c: usize,
+    batch: usize,
+) {
+    if let Err(err) = try_gemm_batch_strided(
+        trans_a, trans_b, m, n, k, alpha, a, lda, stride_a, b, ldb, stride_b, beta, c, ldc,
+        stride_c, batch,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemm_batch_strided`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemm_batch_strided<T: Ring + ThreadSafe + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    stride_a: usize,
+    b: &[T],
+    ldb: usize,
+    stride_b: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+    stride_c: usize,
+    batch: usize,
+) -> Result<(), BlasError> {
+    let nrow_a = if trans_a == Trans::NoTrans { m } else { k };
+    let nrow_b = if trans_b == Trans::NoTrans { k } else { n };
+
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "gemm_batch_strided",
+            8,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrow_b) {
+        return Err(BlasError::new(
+            "gemm_batch_strided",
+            11,
+            "ldb must be at least max(1, nrowb)",
+        ));
+    }
+    if ldc < max(1, m) {
+        return Err(BlasError::new(
+            "gemm_batch_strided",
+            15,
+            "ldc must be at least max(1, m)",
+        ));
+    }
+    if batch > 1 && stride_c < ldc.saturating_mul(n) {
+        return Err(BlasError::new(
+            "gemm_batch_strided",
+            16,
+            "stride_c must be at least ldc*n",
+        ));
+    }
+    let (len_a, len_b, len_c) = gemm_extents(trans_a, trans_b, m, n, k, lda, ldb, ldc);
+    if !holds(a.len(), stride_a, len_a, batch) {
+        return Err(BlasError::new(
+            "gemm_batch_strided",
+            7,
+            "a must hold (batch-1)*stride_a elements and op( A )",
+        ));
+    }
+    if !holds(b.len(), stride_b, len_b, batch) {
+        return Err(BlasError::new(
+            "gemm_batch_strided",
+            10,
+            "b must hold (batch-1)*stride_b elements and op( B )",
+        ));
+    }
+    if !holds(c.len(), stride_c, len_c, batch) {
+        return Err(BlasError::new(
+            "gemm_batch_strided",
+            14,
+            "c must hold (batch-1)*stride_c elements and C",
+        ));
+    }
+
+    let items = split(c, stride_c, len_c, batch)
+        .into_iter()
+        .enumerate()
+        .collect();
+    let work = batch.saturating_mul(m).saturating_mul(n).saturating_mul(k);
+    threading::for_each(work, items, |(p, c)| {
+        checked(try_gemm(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            problem(a, p, stride_a),
+            lda,
+            problem(b, p, stride_b),
+            ldb,
+            beta,
+            c,
+            ldc,
+        ))
+    });
+    Ok(())
+}
+
+/// TRSM_BATCH  solves the matrix equations
+/// op( A(p) )*X(p) = alpha*B(p),   or   X(p)*op( A(p) ) = alpha*B(p),
+/// for every problem p, where the problems take the arguments of their groups in order, see [`trsm`](super::trsm).
+#[inline]
+pub fn trsm_batch<T: RealScalar>(groups: &[TrsmGroup<T>], a: &[&[T]], b: &mut [&mut [T]]) {
+    if let Err(err) = try_trsm_batch(groups, a, b) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsm_batch`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+/// An illegal argument of a group is reported as the [`BlasError`] of [`try_trsm`](super::try_trsm).
+#[inline]
+pub fn try_trsm_batch<T: RealScalar>(
+    groups: &[TrsmGroup<T>],
+    a: &[&[T]],
+    b: &mut [&mut [T]],
+) -> Result<(), BlasError> {
+    let problems = problems(groups, |g| g.size);
+    if a.len() != problems.len() {
+        return Err(BlasError::new(
+            "trsm_batch",
+            2,
+            "a must hold one matrix per problem",
+        ));
+    }
+    if b.len() != problems.len() {
+        return Err(BlasError::new(
+            "trsm_batch",
+            3,
+            "b must hold one matrix per problem",
+        ));
+    }
+    for g in groups {
+        check_trsm(g.side, g.m, g.n, g.lda, g.ldb)?;
+    }
+    for (p, g) in problems.iter().enumerate() {
+        let (len_a, len_b) = trsm_extents(g.side, g.m, g.n, g.lda, g.ldb);
+        if a[p].len() < len_a {
+            return Err(BlasError::new(
+                "trsm_batch",
+                2,
+                "every matrix of a must hold A of its group",
+            ));
+        }
+        if b[p].len() < len_b {
+            return Err(BlasError::new(
+                "trsm_batch",
+                3,
+                "every matrix of b must hold B of its group",
+            ));
+        }
+    }
+
+    let work = groups
+        .iter()
+        .map(|g| g.size.saturating_mul(trsm_work(g.side, g.m, g.n)))
+        .fold(0, usize::saturating_add);
+    let items = problems
+        .into_iter()
+        .zip(a.iter())
+        .zip(b.iter_mut())
+        .collect();
+    threading::for_each(work, items, |((g, a), b)| {
+        checked(try_trsm(
+            g.side, g.uplo, g.trans, g.diag, g.m, g.n, g.alpha, a, g.lda, b, g.ldb,
+        ))
+    });
+    Ok(())
+}
+
+/// TRSM_BATCH_STRIDED  solves the matrix equations
+/// op( A(p) )*X(p) = alpha*B(p),   or   X(p)*op( A(p) ) = alpha*B(p),   p = 0, ..., batch - 1,
+/// where A(p) starts at `a[p*stride_a]` and B(p) at `b[p*stride_b]`, see [`trsm`](super::trsm).
+#[inline]
+pub fn trsm_batch_strided<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    stride_a: usize,
+    b: &mut [T],
+    ldb: usize,
+    stride_b: usize,
+    batch: usize,
+) {
+    if let Err(err) = try_trsm_batch_strided(
+        side, uplo, trans, diag, m, n, alpha, a, lda, stride_a, b, ldb, stride_b, batch,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsm_batch_strided`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsm_batch_strided<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    stride_a: usize,
+    b: &mut [T],
+    ldb: usize,
+    stride_b: usize,
+    batch: usize,
+) -> Result<(), BlasError> {
+    let nrowa = if side == Side::Left { m } else { n };
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "trsm_batch_strided",
+            9,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, m) {
+        return Err(BlasError::new(
+            "trsm_batch_strided",
+            12,
+            "ldb must be at least max(1, m)",
+        ));
+    }
+    if batch > 1 && stride_b < ldb.saturating_mul(n) {
+        return Err(BlasError::new(
+            "trsm_batch_strided",
+            13,
+            "stride_b must be at least ldb*n",
+        ));
+    }
+    let (len_a, len_b) = trsm_extents(side, m, n, lda, ldb);
+    if !holds(a.len(), stride_a, len_a, batch) {
+        return Err(BlasError::new(
+            "trsm_batch_strided",
+            8,
+            "a must hold (batch-1)*stride_a elements and A",
+        ));
+    }
+    if !holds(b.len(), stride_b, len_b, batch) {
+        return Err(BlasError::new(
+            "trsm_batch_strided",
+            11,
+            "b must hold (batch-1)*stride_b elements and B",
+        ));
+    }
+
+    let items = split(b, stride_b, len_b, batch)
+        .into_iter()
+        .enumerate()
+        .collect();
+    let work = batch.saturating_mul(trsm_work(side, m, n));
+    threading::for_each(work, items, |(p, b)| {
+        checked(try_trsm(
+            side,
+            uplo,
+            trans,
+            diag,
+            m,
+            n,
+            alpha,
+            problem(a, p, stride_a),
+            lda,
+            b,
+            ldb,
+        ))
+    });
+    Ok(())
+}
+
+/// The multiplications of a triangular solve, half of the triangle times the right hand sides.
+fn trsm_work(side: Side, m: usize, n: usize) -> usize {
+    if side == Side::Left {
+        m.saturating_mul(m).saturating_mul(n) / 2
+    } else {
+        m.saturating_mul(n).saturating_mul(n) / 2
+    }
+}
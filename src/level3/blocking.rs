@@ -0,0 +1,285 @@
+//! Cache blocking of [`gemm`](super::gemm).
+//!
+//! Large products are computed the GotoBLAS/BLIS way: op( B ) is packed into KC by NC panels that stay in the L3 cache,
+//! op( A ) into MC by KC blocks that stay in the L2 cache, and an MR by NR micro-kernel accumulates
+//! a block of C in registers while streaming through the packed micro-panels.
+//! The block sizes are process-wide and can be tuned for the cache sizes of the machine with [`set_block_sizes`].
+use crate::{simd, Ring, Trans};
+use std::sync::RwLock;
+
+/// Rows of C computed by the micro-kernel.
+pub(crate) const MR: usize = 8;
+/// Columns of C computed by the micro-kernel.
+pub(crate) const NR: usize = 4;
+/// Products with fewer multiplications than this are not worth packing.
+const MIN_SIZE: usize = 32 * 32 * 32;
+
+/// BlockSizes are the dimensions of the packed blocks of op( A ) and op( B ).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockSizes {
+    /// Rows of the packed block of op( A ).
+    pub mc: usize,
+    /// Columns of the packed block of op( A ) and rows of the packed panel of op( B ).
+    pub kc: usize,
+    /// Columns of the packed panel of op( B ).
+    pub nc: usize,
+}
+
+impl Default for BlockSizes {
+    fn default() -> Self {
+        DEFAULT
+    }
+}
+
+const DEFAULT: BlockSizes = BlockSizes {
+    mc: 128,
+    kc: 256,
+    nc: 4096,
+};
+
+static BLOCK_SIZES: RwLock<BlockSizes> = RwLock::new(DEFAULT);
+
+/// Sets the block sizes used by [`gemm`](super::gemm) and returns the previous ones.
+///
+/// # Panics
+/// Panics if any of the block sizes is zero.
+pub fn set_block_sizes(sizes: BlockSizes) -> BlockSizes {
+    assert!(
+        sizes.mc > 0 && sizes.kc > 0 && sizes.nc > 0,
+        "block sizes must be positive, got {:?}",
+        sizes
+    );
+    let mut current = BLOCK_SIZES.write().unwrap_or_else(|err| err.into_inner());
+    std::mem::replace(&mut *current, sizes)
+}
+
+/// Returns the block sizes used by [`gemm`](super::gemm).
+pub fn block_sizes() -> BlockSizes {
+    *BLOCK_SIZES.read().unwrap_or_else(|err| err.into_inner())
+}
+
+pub(crate) fn is_worth_it(m: usize, n: usize, k: usize) -> bool {
+    m.saturating_mul(n).saturating_mul(k) >= MIN_SIZE
+}
+
+/// Computes C := alpha*op( A )*op( B ) + beta*C for arguments that have already been checked by `try_gemm`.
+pub(crate) fn gemm<T: Ring + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    scale(m, n, beta, c, ldc);
+
+    let BlockSizes { mc, kc, nc } = block_sizes();
+    let mut packed_a = vec![T::zero(); round_up(mc.min(m), MR) * kc.min(k)];
+    let mut packed_b = vec![T::zero(); kc.min(k) * round_up(nc.min(n), NR)];
+
+    let mut jc = 0;
+    while jc < n {
+        let nb = nc.min(n - jc);
+        let mut pc = 0;
+        while pc < k {
+            let kb = kc.min(k - pc);
+            pack_b(trans_b, kb, nb, b, ldb, pc, jc, &mut packed_b);
+            let mut ic = 0;
+            while ic < m {
+                let mb = mc.min(m - ic);
+                pack_a(trans_a, mb, kb, a, lda, ic, pc, &mut packed_a);
+                macro_kernel(
+                    mb,
+                    nb,
+                    kb,
+                    alpha,
+                    &packed_a,
+                    &packed_b,
+                    &mut c[jc * ldc + ic..],
+                    ldc,
+                );
+                ic += mb;
+            }
+            pc += kb;
+        }
+        jc += nb;
+    }
+}
+
+fn round_up(x: usize, multiple: usize) -> usize {
+    x.div_ceil(multiple) * multiple
+}
+
+fn scale<T: Ring>(m: usize, n: usize, beta: T, c: &mut [T], ldc: usize) {
+    if beta.is_one() {
+        return;
+    }
+    let mut j = 0;
+    while j < n {
+        let cj = j * ldc;
+        let mut i = 0;
+        while i < m {
+            if beta.is_zero() {
+                c[cj + i] = T::zero();
+            } else {
+                c[cj + i] = c[cj + i] * beta;
+            }
+            i += 1;
+        }
+        j += 1;
+    }
+}
+
+/// Packs the mb by kb block of op( A ) starting at (ic, pc) into micro-panels of MR rows,
+/// each stored column by column and padded with zeros, conjugated for `Trans::ConjTrans`.
+fn pack_a<T: Ring>(
+    trans_a: Trans,
+    mb: usize,
+    kb: usize,
+    a: &[T],
+    lda: usize,
+    ic: usize,
+    pc: usize,
+    packed: &mut [T],
+) {
+    let mut dst = 0;
+    let mut ir = 0;
+    while ir < mb {
+        let mr = MR.min(mb - ir);
+        let mut p = 0;
+        while p < kb {
+            let mut i = 0;
+            while i < MR {
+                packed[dst + i] = if i >= mr {
+                    T::zero()
+                } else {
+                    match trans_a {
+                        Trans::NoTrans => a[(pc + p) * lda + ic + ir + i],
+                        Trans::Trans => a[(ic + ir + i) * lda + pc + p],
+                        Trans::ConjTrans => a[(ic + ir + i) * lda + pc + p].conj(),
+                    }
+                };
+                i += 1;
+            }
+            dst += MR;
+            p += 1;
+        }
+        ir += MR;
+    }
+}
+
+/// Packs the kb by nb panel of op( B ) starting at (pc, jc) into micro-panels of NR columns,
+/// each stored row by row and padded with zeros, conjugated for `Trans::ConjTrans`.
+fn pack_b<T: Ring>(
+    trans_b: Trans,
+    kb: usize,
+    nb: usize,
+    b: &[T],
+    ldb: usize,
+    pc: usize,
+    jc: usize,
+    packed: &mut [T],
+) {
+    let mut dst = 0;
+    let mut jr = 0;
+    while jr < nb {
+        let nr = NR.min(nb - jr);
+        let mut p = 0;
+        while p < kb {
+            let mut j = 0;
+            while j < NR {
+                packed[dst + j] = if j >= nr {
+                    T::zero()
+                } else {
+                    match trans_b {
+                        Trans::NoTrans => b[(jc + jr + j) * ldb + pc + p],
+                        Trans::Trans => b[(pc + p) * ldb + jc + jr + j],
+                        Trans::ConjTrans => b[(pc + p) * ldb + jc + jr + j].conj(),
+                    }
+                };
+                j += 1;
+            }
+            dst += NR;
+            p += 1;
+        }
+        jr += NR;
+    }
+}
+
+/// Multiplies a packed block of op( A ) with a packed panel of op( B ) into the mb by nb block of C.
+fn macro_kernel<T: Ring + 'static>(
+    mb: usize,
+    nb: usize,
+    kb: usize,
+    alpha: T,
+    packed_a: &[T],
+    packed_b: &[T],
+    c: &mut [T],
+    ldc: usize,
+) {
+    let mut jr = 0;
+    while jr < nb {
+        let nr = NR.min(nb - jr);
+        let b = &packed_b[jr * kb..(jr + NR) * kb];
+        let mut ir = 0;
+        while ir < mb {
+            let mr = MR.min(mb - ir);
+            let a = &packed_a[ir * kb..(ir + MR) * kb];
+            micro_kernel(kb, alpha, a, b, &mut c[jr * ldc + ir..], ldc, mr, nr);
+            ir += MR;
+        }
+        jr += NR;
+    }
+}
+
+/// Computes the MR by NR block C := alpha*A*B + C with the SIMD kernel when there is one,
+/// writing back only the mr by nr elements inside C.
+#[inline(always)]
+fn micro_kernel<T: Ring + 'static>(
+    kb: usize,
+    alpha: T,
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    ldc: usize,
+    mr: usize,
+    nr: usize,
+) {
+    let mut acc = [T::zero(); MR * NR];
+    if !simd::gemm_kernel(kb, a, b, &mut acc) {
+        let mut p = 0;
+        while p < kb {
+            let ap = &a[p * MR..p * MR + MR];
+            let bp = &b[p * NR..p * NR + NR];
+            let mut j = 0;
+            while j < NR {
+                let bj = bp[j];
+                let mut i = 0;
+                while i < MR {
+                    acc[j * MR + i] = acc[j * MR + i] + ap[i] * bj;
+                    i += 1;
+                }
+                j += 1;
+            }
+            p += 1;
+        }
+    }
+
+    let mut j = 0;
+    while j < nr {
+        let cj = j * ldc;
+        let mut i = 0;
+        while i < mr {
+            c[cj + i] = c[cj + i] + alpha * acc[j * MR + i];
+            i += 1;
+        }
+        j += 1;
+    }
+}
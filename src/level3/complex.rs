@@ -1,51 +1,18 @@
+use super::generic::{self, multiply, zero};
+use super::panels::complex as panels;
+use crate::{extent, xerbla, BlasError, Diag, RealScalar, Side, Trans, Uplo};
 use num_complex::Complex;
-use num_traits::{Float, NumAssignOps, One, Zero};
+use num_traits::{One, Zero};
 use std::cmp::max;
 
-fn multiply<T: Float + NumAssignOps>(
-    left: &mut [Complex<T>],
-    right: Complex<T>,
-    ld: usize,
-    n: usize,
-    m: usize,
-) {
-    if right.is_zero() {
-        zero(left, ld, n, m);
-    } else {
-        let mut j = 0;
-        while j < n {
-            let coords = j * ld;
-            let mut i = 0;
-            while i < m {
-                left[coords + i] *= right;
-                i += 1;
-            }
-            j += 1;
-        }
-    }
-}
-
-fn zero<T: Float + NumAssignOps>(left: &mut [Complex<T>], ld: usize, n: usize, m: usize) {
-    let mut j = 0;
-    while j < n {
-        let coords = j * ld;
-        let mut i = 0;
-        while i < m {
-            left[coords + i] = Complex::zero();
-            i += 1;
-        }
-        j += 1;
-    }
-}
-
 /// CGEMM  performs one of the matrix-matrix operations
 /// C := alpha*op( A )*op( B ) + beta*C, where  op( X ) is one of op( X ) = X   or   op( X ) = X**T   or   op( X ) = X**H,
 /// alpha and beta are scalars, and A, B and C are matrices, with op( A ) an m by k matrix,  op( B )  a  k by n matrix and  C an m by n matrix.
 /// This is [CGEMM](http://www.netlib.org/lapack/explore-html/d6/d5b/cgemm_8f.html) and [ZGEMM](http://www.netlib.org/lapack/explore-html/d7/d76/zgemm_8f.html) comined in one function
 #[inline]
-pub fn gemm<T: Float + NumAssignOps>(
-    trans_a: char,
-    trans_b: char,
+pub fn gemm<T: RealScalar>(
+    trans_a: Trans,
+    trans_b: Trans,
     m: usize,
     n: usize,
     k: usize,
@@ -58,304 +25,96 @@ pub fn gemm<T: Float + NumAssignOps>(
     c: &mut [Complex<T>],
     ldc: usize,
 ) {
-    let nrowa = if trans_a == 'n' || trans_a == 'N' {
-        m
-    } else {
-        k
-    };
-    let nrowb = if trans_b == 'n' || trans_b == 'N' {
-        k
-    } else {
-        n
-    };
-
-    let mut info = 0;
-    if !(trans_a == 'T' || trans_a == 't' || trans_a == 'n' || trans_a == 'C' || trans_a == 'c') {
-        info = 1;
-    } else if !(trans_b == 'T'
-        || trans_b == 't'
-        || trans_b == 'n'
-        || trans_b == 'C'
-        || trans_b == 'c')
-    {
-        info = 2;
-    } else if lda < max(1, nrowa) {
-        info = 8;
-    } else if ldb < max(1, nrowb) {
-        info = 10;
-    } else if ldc < max(1, m) {
-        info = 13;
-    }
-    if info != 0 {
-        panic!("gemm {}", info);
+    if let Err(err) = try_gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
     }
+}
 
-    let alpha_is_zero = alpha.is_zero();
-    let beta_is_zero = beta.is_zero();
-    let beta_is_one = beta.is_one();
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemm<T: RealScalar>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrowa = if trans_a == Trans::NoTrans { m } else { k };
+    let nrowb = if trans_b == Trans::NoTrans { k } else { n };
 
-    if m == 0 || n == 0 || (alpha_is_zero || k == 0) && beta_is_one {
-        return;
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "gemm",
+            8,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrowb) {
+        return Err(BlasError::new(
+            "gemm",
+            10,
+            "ldb must be at least max(1, nrowb)",
+        ));
+    }
+    if ldc < max(1, m) {
+        return Err(BlasError::new("gemm", 13, "ldc must be at least max(1, m)"));
     }
 
-    if alpha_is_zero {
-        multiply(c, beta, ldc, n, m);
-        return;
+    if m == 0 || n == 0 || (alpha.is_zero() || k == 0) && beta.is_one() {
+        return Ok(());
     }
 
-    if (trans_a == 'n' || trans_a == 'N') && (trans_b == 'n' || trans_b == 'N') {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            let bj = j * ldb;
-            if beta_is_zero {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] = Complex::zero();
-                    i += 1;
-                }
-            } else if !beta_is_one {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] *= beta;
-                    i += 1;
-                }
-            }
-            let mut l = 0;
-            while l < k {
-                let al = l * lda;
-                let tmp = alpha * b[bj + l];
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * a[al + i];
-                    i += 1;
-                }
-                l += 1;
-            }
-            j += 1;
-        }
-        return;
-    } else if (trans_a == 'n' || trans_a == 'N') && (trans_b == 'c' || trans_b == 'C') {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            if beta_is_zero {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] = Complex::zero();
-                    i += 1;
-                }
-            } else if !beta_is_one {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] *= beta;
-                    i += 1;
-                }
-            }
-            let mut l = 0;
-            while l < k {
-                let bl = l * ldb;
-                let al = l * lda;
-                let tmp = alpha * b[bl + j].conj();
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * a[al + i];
-                    i += 1;
-                }
-                l += 1;
-            }
-            j += 1;
-        }
-        return;
-    } else if (trans_a == 'n' || trans_a == 'N') && (trans_b == 't' || trans_b == 'T') {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            if beta_is_zero {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] = Complex::zero();
-                    i += 1;
-                }
-            } else if !beta_is_one {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] *= beta;
-                    i += 1;
-                }
-            }
-            let mut l = 0;
-            while l < k {
-                let bl = l * ldb;
-                let al = l * lda;
-                let tmp = alpha * b[bl + j];
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * a[al + i];
-                    i += 1;
-                }
-                l += 1;
-            }
-            j += 1;
-        }
-        return;
-    } else if (trans_a == 'c' || trans_a == 'C') && (trans_b == 'n' || trans_b == 'N') {
-        let mut j = 0;
-        while j < n {
-            let bj = j * ldb;
-            let cj = j * ldc;
-            let mut i = 0;
-            while i < m {
-                let ai = i * lda;
-                let mut tmp = Complex::zero();
-                let mut l = 0;
-                while l < k {
-                    tmp += a[ai + l].conj() * b[bj + l];
-                    l += 1;
-                }
-                tmp *= alpha;
-                if !beta_is_zero {
-                    tmp += beta * c[cj + i]
-                }
-                c[cj + i] = tmp;
-                i += 1;
-            }
-            j += 1;
-        }
-        return;
-    } else if (trans_a == 'c' || trans_a == 'C') && (trans_b == 'c' || trans_b == 'C') {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            let mut i = 0;
-            while i < m {
-                let ai = i * lda;
-                let mut tmp = Complex::zero();
-                let mut l = 0;
-                while l < k {
-                    let bl = l * ldb;
-                    tmp += a[ai + l].conj() * b[bl + j].conj();
-                    l += 1;
-                }
-                tmp *= alpha;
-                if !beta_is_zero {
-                    tmp += beta * c[cj + i];
-                }
-                c[cj + i] = tmp;
-                i += 1;
-            }
-            j += 1;
-        }
-        return;
-    } else if (trans_a == 'c' || trans_a == 'C') && (trans_b == 't' || trans_b == 'T') {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            let mut i = 0;
-            while i < m {
-                let ai = i * lda;
-                let mut tmp = Complex::zero();
-                let mut l = 0;
-                while l < k {
-                    let bl = l * ldb;
-                    tmp += a[ai + l].conj() * b[bl + j];
-                    l += 1;
-                }
-                tmp *= alpha;
-                if !beta_is_zero {
-                    tmp += beta * c[cj + i];
-                }
-                c[cj + i] = tmp;
-                i += 1;
-            }
-            j += 1;
-        }
-        return;
-    } else if (trans_a == 't' || trans_a == 'T') && (trans_b == 'n' || trans_b == 'N') {
-        let mut j = 0;
-        while j < n {
-            let bj = j * ldb;
-            let cj = j * ldc;
-            let mut i = 0;
-            while i < m {
-                let ai = i * lda;
-                let mut tmp = Complex::zero();
-                let mut l = 0;
-                while l < k {
-                    tmp += a[ai + l] * b[bj + l];
-                    l += 1;
-                }
-                tmp *= alpha;
-                if !beta_is_zero {
-                    tmp += beta * c[cj + i];
-                }
-                c[cj + i] = tmp;
-                i += 1;
-            }
-            j += 1;
-        }
-        return;
-    } else if (trans_a == 't' || trans_a == 'T') && (trans_b == 'c' || trans_b == 'C') {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            let mut i = 0;
-            while i < m {
-                let ai = i * lda;
-                let mut tmp = Complex::zero();
-                let mut l = 0;
-                while l < k {
-                    let bl = l * ldb;
-                    tmp += a[ai + l] * b[bl + j].conj();
-                    l += 1;
-                }
-                tmp *= alpha;
-                if !beta_is_zero {
-                    tmp += beta * c[cj + i];
-                }
-                c[cj + i] = tmp;
-                i += 1;
-            }
-            j += 1;
-        }
-        return;
+    if trans_a == Trans::NoTrans {
+        extent::matrix("gemm", 7, a, m, k, lda)?;
     } else {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            let mut i = 0;
-            while i < m {
-                let ai = i * lda;
-                let mut tmp = Complex::zero();
-                let mut l = 0;
-                while l < k {
-                    let bl = l * ldb;
-                    tmp += a[ai + l] * b[bl + j];
-                    l += 1;
-                }
-                tmp *= alpha;
-                if !beta_is_zero {
-                    tmp += beta * c[cj + i];
-                }
-                c[cj + i] = tmp;
-                i += 1;
-            }
-            j += 1;
-        }
+        extent::matrix("gemm", 7, a, k, m, lda)?;
+    }
+    if trans_b == Trans::NoTrans {
+        extent::matrix("gemm", 9, b, k, n, ldb)?;
+    } else {
+        extent::matrix("gemm", 9, b, n, k, ldb)?;
+    }
+    extent::matrix("gemm", 12, c, m, n, ldc)?;
+
+    if alpha.is_zero() {
+        multiply(c, beta, ldc, n, m);
+        return Ok(());
+    }
+
+    if panels::gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        return Ok(());
     }
-    return;
+
+    generic::gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    );
+    Ok(())
 }
 
-/// CSYMM  performs one of the matrix-matrix operations
-/// C := alpha*A*B + beta*C or C := alpha*B*A + beta*C,
-/// where  alpha and beta are scalars, A is a symmetric matrix and  B and C are m by n matrices.
-/// This is [CSYMM](http://www.netlib.org/lapack/explore-html/db/d59/csymm_8f.html) and [ZSYMM](http://www.netlib.org/lapack/explore-html/df/d51/zsymm_8f.html) comined in one function
+/// CGEMMT  performs one of the matrix-matrix operations
+/// C := alpha*op( A )*op( B ) + beta*C, where  op( X ) is one of op( X ) = X   or   op( X ) = X**T   or   op( X ) = X**H,
+/// alpha and beta are scalars, op( A ) is an n by k matrix, op( B ) a k by n matrix and C an n by n matrix
+/// of which only the upper or lower triangle is computed, the other triangle isn't referenced.
+/// This is CGEMMT and ZGEMMT of the reference BLAS 3.11 combined in one function
 #[inline]
-pub fn symm<T: Float + NumAssignOps>(
-    side: char,
-    uplo: char,
-    m: usize,
+pub fn gemmt<T: RealScalar>(
+    uplo: Uplo,
+    trans_a: Trans,
+    trans_b: Trans,
     n: usize,
+    k: usize,
     alpha: Complex<T>,
     a: &[Complex<T>],
     lda: usize,
@@ -365,153 +124,111 @@ pub fn symm<T: Float + NumAssignOps>(
     c: &mut [Complex<T>],
     ldc: usize,
 ) {
-    let nrowa = if side == 'l' || side == 'L' { m } else { n };
-    let upper = uplo == 'u' || uplo == 'U';
-    let mut info = 0;
-    if side != 'l' && side != 'L' && side != 'r' && side != 'R' {
-        info = 1;
-    } else if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 2;
-    } else if lda < max(1, nrowa) {
-        info = 7;
-    } else if ldb < max(1, m) {
-        info = 9;
-    } else if ldc < max(1, m) {
-        info = 12;
-    }
-    if info != 0 {
-        panic!("symm {}", info);
+    if let Err(err) = try_gemmt(
+        uplo, trans_a, trans_b, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
     }
+}
 
-    let alpha_is_zero = alpha.is_zero();
-    let beta_is_zero = beta.is_zero();
-    let beta_is_one = beta.is_one();
+/// Checked variant of [`gemmt`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemmt<T: RealScalar>(
+    uplo: Uplo,
+    trans_a: Trans,
+    trans_b: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let not_a = trans_a == Trans::NoTrans;
+    let not_b = trans_b == Trans::NoTrans;
+    let nrowa = if not_a { n } else { k };
+    let nrowb = if not_b { k } else { n };
 
-    if m == 0 || n == 0 || (alpha_is_zero && beta_is_one) {
-        return;
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "gemmt",
+            8,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrowb) {
+        return Err(BlasError::new(
+            "gemmt",
+            10,
+            "ldb must be at least max(1, nrowb)",
+        ));
+    }
+    if ldc < max(1, n) {
+        return Err(BlasError::new(
+            "gemmt",
+            13,
+            "ldc must be at least max(1, n)",
+        ));
     }
 
-    if alpha_is_zero {
-        multiply(c, beta, ldc, n, m);
-        return;
+    if n == 0 || (alpha.is_zero() || k == 0) && beta.is_one() {
+        return Ok(());
     }
 
-    if side == 'l' || side == 'L' {
-        if upper {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let cj = j * ldc;
-                let mut i = 0;
-                while i < m {
-                    let ai = i * ldc;
-                    let mut tmp = alpha * b[bj + i];
-                    let mut tmp2 = Complex::zero();
-                    let mut k = 0;
-                    while k < i {
-                        c[cj + k] += tmp * a[ai + k];
-                        tmp2 += b[bj + k] * a[ai + k];
-                        k += 1
-                    }
-                    tmp = tmp * a[ai + i] + alpha * tmp2;
-                    if !beta_is_zero {
-                        tmp += beta * c[cj + i];
-                    }
-                    c[cj + i] = tmp;
-                    i += 1;
-                }
-                j += 1;
-            }
-        } else {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let cj = j * ldc;
-                let mut i = m;
-                while i >= 1 {
-                    i -= 1;
-                    let ai = i * lda;
-                    let mut tmp = alpha * b[bj + i];
-                    let mut tmp2 = Complex::zero();
-                    let mut k = i + 1;
-                    while k < m {
-                        c[cj + k] += tmp * a[ai + k];
-                        tmp2 += b[bj + k] * a[ai + k];
-                        k += 1
-                    }
-                    tmp = tmp * a[ai + i] + alpha * tmp2;
-                    if !beta_is_zero {
-                        tmp += beta * c[cj + i];
-                    }
-                    c[cj + i] = tmp;
-                }
-                j += 1;
-            }
-        }
+    if trans_a == Trans::NoTrans {
+        extent::matrix("gemmt", 7, a, n, k, lda)?;
     } else {
-        let mut j = 0;
-        while j < n {
-            let aj = j * lda;
-            let bj = j * ldb;
-            let cj = j * ldc;
-            let mut tmp = alpha * a[aj + j];
-            let mut i = 0;
-            while i < m {
-                let mut tmp2 = tmp * b[bj + i];
-                if !beta_is_zero {
-                    tmp2 += beta * c[cj + i];
-                }
-                c[cj + i] = tmp2;
-                i += 1;
-            }
-            let mut k = 0;
-            while k < j {
-                let ak = k * lda;
-                let bk = k * ldb;
-                if upper {
-                    tmp = alpha * a[aj + k];
-                } else {
-                    tmp = alpha * a[ak + j];
-                }
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * b[bk + i];
-                    i += 1;
-                }
-                k += 1;
-            }
-            let mut k = j + 1;
-            while k < n {
-                let ak = k * lda;
-                let bk = k * ldb;
-                if upper {
-                    tmp = alpha * a[ak + j];
-                } else {
-                    tmp = alpha * a[aj + k];
-                }
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * b[bk + i];
-                    i += 1;
-                }
-                k += 1;
-            }
-            j += 1;
-        }
+        extent::matrix("gemmt", 7, a, k, n, lda)?;
+    }
+    if trans_b == Trans::NoTrans {
+        extent::matrix("gemmt", 9, b, k, n, ldb)?;
+    } else {
+        extent::matrix("gemmt", 9, b, n, k, ldb)?;
+    }
+    extent::matrix("gemmt", 12, c, n, n, ldc)?;
+
+    if alpha.is_zero() || k == 0 {
+        generic::multiply_triangle(uplo, c, beta, ldc, n);
+        return Ok(());
     }
+
+    generic::triangle(uplo, n, |i, j, rows, cols| {
+        let a = &a[if not_a { i } else { i * lda }..];
+        let b = &b[if not_b { j * ldb } else { j }..];
+        try_gemm(
+            trans_a,
+            trans_b,
+            rows,
+            cols,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            &mut c[j * ldc + i..],
+            ldc,
+        )
+        .expect("the arguments of a block are checked by gemmt")
+    });
+    Ok(())
 }
 
-/// SYR2K  performs one of the symmetric rank 2k operations
-/// C := alpha*A*B**T + alpha*B*A**T + beta*C, or C := alpha*A**T*B + alpha*B**T*A + beta*C,
-/// where  alpha and beta  are scalars,  C is an  n by n symmetric matrix
-/// and  A and B  are  n by k  matrices  in the  first  case  and  k by n matrices in the second case.
-/// This is [CSYR2K](http://www.netlib.org/lapack/explore-html/de/d7e/csyr2k_8f.html) and [ZSYR2K](http://www.netlib.org/lapack/explore-html/df/d20/zsyr2k_8f.html) comined in one function
+/// CSYMM  performs one of the matrix-matrix operations
+/// C := alpha*A*B + beta*C or C := alpha*B*A + beta*C,
+/// where  alpha and beta are scalars, A is a symmetric matrix and  B and C are m by n matrices.
+/// This is [CSYMM](http://www.netlib.org/lapack/explore-html/db/d59/csymm_8f.html) and [ZSYMM](http://www.netlib.org/lapack/explore-html/df/d51/zsymm_8f.html) comined in one function
 #[inline]
-pub fn syr2k<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
+pub fn symm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
     n: usize,
-    k: usize,
     alpha: Complex<T>,
     a: &[Complex<T>],
     lda: usize,
@@ -521,177 +238,145 @@ pub fn syr2k<T: Float + NumAssignOps>(
     c: &mut [Complex<T>],
     ldc: usize,
 ) {
-    let nrowa = if trans == 'n' || trans == 'N' { n } else { k };
-    let upper = uplo == 'u' || uplo == 'U';
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if lda < max(1, nrowa) {
-        info = 7;
-    } else if ldb < max(1, nrowa) {
-        info = 9;
-    } else if ldc < max(1, n) {
-        info = 12;
-    }
-
-    if info != 0 {
-        panic!("syr2k {}", info);
+    if let Err(err) = try_symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) {
+        xerbla::report(&err);
     }
+}
 
-    let alpha_is_zero = alpha.is_zero();
-    let beta_is_one = beta.is_one();
-    let beta_is_zero = beta.is_zero();
+/// Checked variant of [`symm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_symm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrowa = if side == Side::Left { m } else { n };
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "symm",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, m) {
+        return Err(BlasError::new("symm", 9, "ldb must be at least max(1, m)"));
+    }
+    if ldc < max(1, m) {
+        return Err(BlasError::new("symm", 12, "ldc must be at least max(1, m)"));
+    }
 
-    if n == 0 || ((alpha_is_zero || k == 0) && beta_is_one) {
-        return;
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
     }
 
-    if alpha_is_zero {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            let start = if upper { 0 } else { j };
-            let stop = if upper { j + 1 } else { n };
-            let mut i = start;
-            while i < stop {
-                if beta_is_zero {
-                    c[cj + i] = Complex::zero();
-                } else {
-                    c[cj + i] *= beta;
-                }
-                i += 1;
-            }
-            j += 1;
-        }
-        return;
+    let ka = if side == Side::Left { m } else { n };
+    extent::matrix("symm", 6, a, ka, ka, lda)?;
+    extent::matrix("symm", 8, b, m, n, ldb)?;
+    extent::matrix("symm", 11, c, m, n, ldc)?;
+
+    if alpha.is_zero() {
+        multiply(c, beta, ldc, n, m);
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            let start = if upper { 0 } else { j };
-            let stop = if upper { j + 1 } else { n };
-            if beta_is_zero {
-                let mut i = start;
-                while i < stop {
-                    c[cj + i] = Complex::zero();
-                    i += 1;
-                }
-            } else if !beta_is_one {
-                let mut i = start;
-                while i < stop {
-                    c[cj + i] = beta * c[cj + i];
-                    i += 1;
-                }
-            }
-            let mut l = 0;
-            while l < k {
-                let al = l * lda;
-                let bl = l * ldb;
-                let mut tmp = b[bl + j];
-                let mut tmp2 = a[al + j];
-                if !tmp.is_zero() || !tmp2.is_zero() {
-                    tmp *= alpha;
-                    tmp2 *= alpha;
-                    let mut i = start;
-                    while i < stop {
-                        c[cj + i] += a[al + i] * tmp + b[bl + i] * tmp2;
-                        i += 1;
-                    }
-                }
-                l += 1;
-            }
-            j += 1;
-        }
-    } else {
-        let mut j = 0;
-        while j < n {
-            let aj = j * lda;
-            let bj = j * ldb;
-            let cj = j * ldc;
-            let start = if upper { 0 } else { j };
-            let stop = if upper { j + 1 } else { n };
-            let mut i = start;
-            while i < stop {
-                let ai = i * lda;
-                let bi = i * ldb;
-                let mut tmp = Complex::zero();
-                let mut tmp2 = Complex::zero();
-                let mut l = 0;
-                while l < k {
-                    tmp += a[ai + l] * b[bj + l];
-                    tmp2 += a[aj + l] * b[bi + l];
-                    l += 1;
-                }
-                tmp = alpha * tmp + alpha * tmp2;
-                if !beta_is_zero {
-                    tmp += beta * c[cj + i];
-                }
-                c[cj + i] = tmp;
-                i += 1;
-            }
-            j += 1;
-        }
+    if panels::symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) {
+        return Ok(());
+    }
+
+    generic::symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+    Ok(())
+}
+
+/// SYR2K  performs one of the symmetric rank 2k operations
+/// C := alpha*A*B**T + alpha*B*A**T + beta*C, or C := alpha*A**T*B + alpha*B**T*A + beta*C,
+/// where  alpha and beta  are scalars,  C is an  n by n symmetric matrix
+/// and  A and B  are  n by k  matrices  in the  first  case  and  k by n matrices in the second case.
+/// This is [CSYR2K](http://www.netlib.org/lapack/explore-html/de/d7e/csyr2k_8f.html) and [ZSYR2K](http://www.netlib.org/lapack/explore-html/df/d20/zsyr2k_8f.html) comined in one function
+#[inline]
+pub fn syr2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) {
+    if let Err(err) = try_syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) {
+        xerbla::report(&err);
     }
 }
 
-/// CSYRK  performs one of the symmetric rank k operations
-/// C := alpha*A*A**T + beta*C, or C := alpha*A**T*A + beta*C,
-/// where  alpha and beta  are scalars,  C is an  n by n symmetric matrix
-/// and  A  is an  n by k  matrix in the first case and a  k by n  matrix in the second case.
-/// This is [CSYRK](http://www.netlib.org/lapack/explore-html/d3/d6a/csyrk_8f.html) and [ZSYRK](http://www.netlib.org/lapack/explore-html/de/d54/zsyrk_8f.html) comined in one function
+/// Checked variant of [`syr2k`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
 #[inline]
-pub fn syrk<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
+pub fn try_syr2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
     n: usize,
     k: usize,
     alpha: Complex<T>,
     a: &[Complex<T>],
     lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
     beta: Complex<T>,
     c: &mut [Complex<T>],
     ldc: usize,
-) {
-    let upper = uplo == 'u' || uplo == 'U';
-    let nrowa = if trans == 'n' || trans == 'N' { n } else { k };
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if lda < max(1, nrowa) {
-        info = 7;
-    } else if ldc < max(1, n) {
-        info = 10;
-    }
-
-    if info != 0 {
-        panic!("syrk {}", info);
+) -> Result<(), BlasError> {
+    let nrowa = if trans == Trans::NoTrans { n } else { k };
+    let upper = uplo == Uplo::Upper;
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "syr2k",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrowa) {
+        return Err(BlasError::new(
+            "syr2k",
+            9,
+            "ldb must be at least max(1, nrowa)",
+        ));
+    }
+    if ldc < max(1, n) {
+        return Err(BlasError::new(
+            "syr2k",
+            12,
+            "ldc must be at least max(1, n)",
+        ));
     }
 
     let alpha_is_zero = alpha.is_zero();
-    let beta_is_zero = beta.is_zero();
     let beta_is_one = beta.is_one();
+    let beta_is_zero = beta.is_zero();
 
     if n == 0 || ((alpha_is_zero || k == 0) && beta_is_one) {
-        return;
+        return Ok(());
+    }
+
+    if trans == Trans::NoTrans {
+        extent::matrix("syr2k", 6, a, n, k, lda)?;
+        extent::matrix("syr2k", 8, b, n, k, ldb)?;
+    } else {
+        extent::matrix("syr2k", 6, a, k, n, lda)?;
+        extent::matrix("syr2k", 8, b, k, n, ldb)?;
     }
+    extent::matrix("syr2k", 11, c, n, n, ldc)?;
 
     if alpha_is_zero {
         let mut j = 0;
@@ -710,10 +395,14 @@ pub fn syrk<T: Float + NumAssignOps>(
             }
             j += 1;
         }
-        return;
+        return Ok(());
+    }
+
+    if panels::syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) {
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
+    if trans == Trans::NoTrans {
         let mut j = 0;
         while j < n {
             let cj = j * ldc;
@@ -728,19 +417,22 @@ pub fn syrk<T: Float + NumAssignOps>(
             } else if !beta_is_one {
                 let mut i = start;
                 while i < stop {
-                    c[cj + i] *= beta;
+                    c[cj + i] = beta * c[cj + i];
                     i += 1;
                 }
             }
             let mut l = 0;
             while l < k {
                 let al = l * lda;
-                let mut tmp = a[al + j];
-                if !tmp.is_zero() {
+                let bl = l * ldb;
+                let mut tmp = b[bl + j];
+                let mut tmp2 = a[al + j];
+                if !tmp.is_zero() || !tmp2.is_zero() {
                     tmp *= alpha;
+                    tmp2 *= alpha;
                     let mut i = start;
                     while i < stop {
-                        c[cj + i] += tmp * a[al + i];
+                        c[cj + i] += a[al + i] * tmp + b[bl + i] * tmp2;
                         i += 1;
                     }
                 }
@@ -751,20 +443,24 @@ pub fn syrk<T: Float + NumAssignOps>(
     } else {
         let mut j = 0;
         while j < n {
-            let start = if upper { 0 } else { j };
-            let stop = if upper { j + 1 } else { n };
             let aj = j * lda;
+            let bj = j * ldb;
             let cj = j * ldc;
+            let start = if upper { 0 } else { j };
+            let stop = if upper { j + 1 } else { n };
             let mut i = start;
             while i < stop {
-                let mut tmp = Complex::zero();
                 let ai = i * lda;
+                let bi = i * ldb;
+                let mut tmp = Complex::zero();
+                let mut tmp2 = Complex::zero();
                 let mut l = 0;
                 while l < k {
-                    tmp += a[ai + l] * a[aj + l];
+                    tmp += a[ai + l] * b[bj + l];
+                    tmp2 += a[aj + l] * b[bi + l];
                     l += 1;
                 }
-                tmp *= alpha;
+                tmp = alpha * tmp + alpha * tmp2;
                 if !beta_is_zero {
                     tmp += beta * c[cj + i];
                 }
@@ -774,344 +470,173 @@ pub fn syrk<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
-/// TRMM  performs one of the matrix-matrix operations
-///  B := alpha*op( A )*B,   or   B := alpha*B*op( A )
-/// where  alpha  is a scalar,  B  is an m by n matrix,  A  is a unit, or non-unit,  upper or lower triangular matrix  and  op( A )  is one  of
-/// op( A ) = A   or   op( A ) = A**T   or   op( A ) = A**H.
-/// This is [CTRMM](http://www.netlib.org/lapack/explore-html/d4/d9b/ctrmm_8f.html) and [ZTRMM](http://www.netlib.org/lapack/explore-html/d8/de1/ztrmm_8f.html) comined in one function
+/// CSYRK  performs one of the symmetric rank k operations
+/// C := alpha*A*A**T + beta*C, or C := alpha*A**T*A + beta*C,
+/// where  alpha and beta  are scalars,  C is an  n by n symmetric matrix
+/// and  A  is an  n by k  matrix in the first case and a  k by n  matrix in the second case.
+/// This is [CSYRK](http://www.netlib.org/lapack/explore-html/d3/d6a/csyrk_8f.html) and [ZSYRK](http://www.netlib.org/lapack/explore-html/de/d54/zsyrk_8f.html) comined in one function
 #[inline]
-pub fn trmm<T: Float + NumAssignOps>(
-    side: char,
-    uplo: char,
-    trans: char,
-    diag: char,
-    m: usize,
+pub fn syrk<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
     n: usize,
+    k: usize,
     alpha: Complex<T>,
     a: &[Complex<T>],
     lda: usize,
-    b: &mut [Complex<T>],
-    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
 ) {
-    let lside = side == 'l' || side == 'L';
-
-    let nrowa = if lside { m } else { n };
-    let nounit = diag == 'n' || diag == 'N';
-    let upper = uplo == 'u' || uplo == 'U';
-    let alpha_is_zero = alpha.is_zero();
-    let mut info = 0;
-    if side != 'l' && side != 'L' && side != 'r' && side != 'R' {
-        info = 1;
-    } else if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 2;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 3;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 4;
-    } else if lda < max(1, nrowa) {
-        info = 9;
-    } else if ldb < max(1, m) {
-        info = 11;
-    }
-    if info != 0 {
-        panic!("trmm {}", info);
+    if let Err(err) = try_syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        xerbla::report(&err);
     }
+}
 
-    if m == 0 || n == 0 {
-        return;
+/// Checked variant of [`syrk`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syrk<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let upper = uplo == Uplo::Upper;
+    let nrowa = if trans == Trans::NoTrans { n } else { k };
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "syrk",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
     }
-
-    let noconj = trans == 't' || trans == 'T';
-
-    if alpha_is_zero {
-        zero(b, ldb, n, m);
-        return;
+    if ldc < max(1, n) {
+        return Err(BlasError::new("syrk", 10, "ldc must be at least max(1, n)"));
     }
 
-    if (side == 'l' || side == 'L') && (trans == 'n' || trans == 'N') {
-        if upper {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let mut k = 0;
-                while k < m {
-                    let mut tmp = b[bj + k];
-                    if !tmp.is_zero() {
-                        let ak = k * lda;
-                        tmp *= alpha;
-                        let mut i = 0;
-                        while i < k {
-                            b[bj + i] += tmp * a[ak + i];
-                            i += 1;
-                        }
-                        if nounit {
-                            tmp *= a[ak + k];
-                        }
-                        b[bj + k] = tmp;
-                    }
-                    k += 1;
-                }
-                j += 1;
-            }
-        } else {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let mut k = m;
-                while k >= 1 {
-                    k -= 1;
-                    let mut tmp = b[bj + k];
-                    if !tmp.is_zero() {
-                        let ak = k * lda;
-                        tmp *= alpha;
-                        b[bj + k] = tmp;
-                        if nounit {
-                            b[bj + k] *= a[ak + k];
-                        }
-                        let mut i = k + 1;
-                        while i < m {
-                            b[bj + i] += tmp * a[ak + i];
-                            i += 1;
-                        }
-                    }
-                }
-                j += 1;
-            }
-        }
-        return;
-    } else if (side == 'l' || side == 'L')
-        && (trans == 't' || trans == 'T' || trans == 'c' || trans == 'C')
-    {
-        if upper {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let mut i = m;
-                while i >= 1 {
-                    i -= 1;
-                    let ai = i * lda;
-                    let mut tmp = b[bj + i];
-                    if nounit {
-                        if noconj {
-                            tmp *= a[ai + i];
-                        } else {
-                            tmp *= a[ai + i].conj();
-                        }
-                    }
-                    let mut k = 0;
-                    while k < i {
-                        if noconj {
-                            tmp += a[ai + k] * b[bj + k];
-                        } else {
-                            tmp += a[ai + k].conj() * b[bj + k];
-                        }
-                        k += 1;
-                    }
-                    b[bj + i] = alpha * tmp;
-                }
-                j += 1;
-            }
-        } else {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let mut i = 0;
-                while i < m {
-                    let ai = i * lda;
-                    let mut tmp = b[bj + i];
-                    if nounit {
-                        if noconj {
-                            tmp *= a[ai + i];
-                        } else {
-                            tmp *= a[ai + i].conj();
-                        }
-                    }
-                    let mut k = i + 1;
-                    while k < m {
-                        if noconj {
-                            tmp += a[ai + k] * b[bj + k];
-                        } else {
-                            tmp += a[ai + k].conj() * b[bj + k];
-                        }
-                        k += 1;
-                    }
-                    b[bj + i] = alpha * tmp;
-
-                    i += 1;
-                }
-                j += 1;
-            }
-        }
-        return;
-    } else if (side == 'r' || side == 'R') && (trans == 'n' || trans == 'N') {
-        if upper {
-            let mut j = n;
-            while j >= 1 {
-                j -= 1;
-                let aj = j * lda;
-                let bj = j * ldb;
-                let mut tmp = alpha;
-                if nounit {
-                    tmp *= a[aj + j];
-                }
-                let mut i = 0;
-                while i < m {
-                    b[bj + i] *= tmp;
-                    i += 1;
-                }
-                let mut k = 0;
-                while k < j {
-                    let bk = k * ldb;
-                    let mut tmp = a[aj + k];
-                    if !tmp.is_zero() {
-                        tmp *= alpha;
-                        let mut i = 0;
-                        while i < m {
-                            b[bj + i] += tmp * b[bk + i];
-                            i += 1;
-                        }
-                    }
-                    k += 1;
-                }
-            }
-        } else {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let aj = j * lda;
-                let mut tmp = alpha;
-                if nounit {
-                    tmp *= a[aj + j];
-                }
-                let mut i = 0;
-                while i < m {
-                    b[bj + i] *= tmp;
-                    i += 1;
-                }
-                let mut k = j + 1;
-                while k < n {
-                    let bk = k * ldb;
-                    let tmp2 = a[aj + k];
-                    if !tmp2.is_zero() {
-                        tmp = alpha * tmp2;
-                        let mut i = 0;
-                        while i < m {
-                            b[bj + i] += tmp * b[bk + i];
-                            i += 1;
-                        }
-                    }
-                    k += 1;
+    let alpha_is_zero = alpha.is_zero();
+    let beta_is_zero = beta.is_zero();
+    let beta_is_one = beta.is_one();
+
+    if n == 0 || ((alpha_is_zero || k == 0) && beta_is_one) {
+        return Ok(());
+    }
+
+    if trans == Trans::NoTrans {
+        extent::matrix("syrk", 6, a, n, k, lda)?;
+    } else {
+        extent::matrix("syrk", 6, a, k, n, lda)?;
+    }
+    extent::matrix("syrk", 9, c, n, n, ldc)?;
+
+    if alpha_is_zero {
+        let mut j = 0;
+        while j < n {
+            let cj = j * ldc;
+            let start = if upper { 0 } else { j };
+            let stop = if upper { j + 1 } else { n };
+            let mut i = start;
+            while i < stop {
+                if beta_is_zero {
+                    c[cj + i] = Complex::zero();
+                } else {
+                    c[cj + i] *= beta;
                 }
-                j += 1;
+                i += 1;
             }
+            j += 1;
         }
-        return;
-    } else {
-        if upper {
-            let mut k = 0;
-            while k < n {
-                let ak = k * lda;
-                let bk = k * ldb;
-                let mut j = 0;
-                while j < k {
-                    let bj = j * ldb;
-                    let mut tmp = a[ak + j];
-                    if !tmp.is_zero() {
-                        if noconj {
-                            tmp *= alpha;
-                        } else {
-                            tmp = alpha * tmp.conj();
-                        }
-                        let mut i = 0;
-                        while i < m {
-                            b[bj + i] += tmp * b[bk + i];
-                            i += 1;
-                        }
-                    }
-                    j += 1;
+        return Ok(());
+    }
+
+    if panels::syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        return Ok(());
+    }
+
+    if trans == Trans::NoTrans {
+        let mut j = 0;
+        while j < n {
+            let cj = j * ldc;
+            let start = if upper { 0 } else { j };
+            let stop = if upper { j + 1 } else { n };
+            if beta_is_zero {
+                let mut i = start;
+                while i < stop {
+                    c[cj + i] = Complex::zero();
+                    i += 1;
                 }
-                let mut tmp = alpha;
-                if nounit {
-                    if noconj {
-                        tmp *= a[ak + k];
-                    } else {
-                        tmp *= a[ak + k].conj();
-                    }
+            } else if !beta_is_one {
+                let mut i = start;
+                while i < stop {
+                    c[cj + i] *= beta;
+                    i += 1;
                 }
-                if !tmp.is_one() {
-                    let mut i = 0;
-                    while i < m {
-                        b[bk + i] = tmp * b[bk + i];
+            }
+            let mut l = 0;
+            while l < k {
+                let al = l * lda;
+                let mut tmp = a[al + j];
+                if !tmp.is_zero() {
+                    tmp *= alpha;
+                    let mut i = start;
+                    while i < stop {
+                        c[cj + i] += tmp * a[al + i];
                         i += 1;
                     }
                 }
-                k += 1;
+                l += 1;
             }
-        } else {
-            let mut k = n;
-            while k >= 1 {
-                k -= 1;
-                let ak = k * lda;
-                let bk = k * ldb;
-                let mut j = k + 1;
-                while j < n {
-                    let bj = j * lda;
-                    let mut tmp = a[ak + j];
-                    if !tmp.is_zero() {
-                        if noconj {
-                            tmp *= alpha;
-                        } else {
-                            tmp = alpha * tmp.conj();
-                        };
-                        let mut i = 0;
-                        while i < m {
-                            b[bj + i] += tmp * b[bk + i];
-                            i += 1;
-                        }
-                    }
-                    j += 1;
-                }
-                let mut tmp = alpha;
-                if nounit {
-                    if noconj {
-                        tmp *= a[ak + k];
-                    } else {
-                        tmp *= a[ak + k].conj();
-                    };
+            j += 1;
+        }
+    } else {
+        let mut j = 0;
+        while j < n {
+            let start = if upper { 0 } else { j };
+            let stop = if upper { j + 1 } else { n };
+            let aj = j * lda;
+            let cj = j * ldc;
+            let mut i = start;
+            while i < stop {
+                let mut tmp = Complex::zero();
+                let ai = i * lda;
+                let mut l = 0;
+                while l < k {
+                    tmp += a[ai + l] * a[aj + l];
+                    l += 1;
                 }
-                if !tmp.is_one() {
-                    let mut i = 0;
-                    while i < m {
-                        b[bk + i] = tmp * b[bk + i];
-                        i += 1;
-                    }
+                tmp *= alpha;
+                if !beta_is_zero {
+                    tmp += beta * c[cj + i];
                 }
+                c[cj + i] = tmp;
+                i += 1;
             }
+            j += 1;
         }
-        return;
     }
+    Ok(())
 }
 
-/// TRSM  solves one of the matrix equations
-/// op( A )*X = alpha*B,   or   X*op( A ) = alpha*B,
-/// where alpha is a scalar, X and B are m by n matrices, A is a unit, or  non-unit,  upper or lower triangular matrix  and  op( A )  is one  of
+/// TRMM  performs one of the matrix-matrix operations
+///  B := alpha*op( A )*B,   or   B := alpha*B*op( A )
+/// where  alpha  is a scalar,  B  is an m by n matrix,  A  is a unit, or non-unit,  upper or lower triangular matrix  and  op( A )  is one  of
 /// op( A ) = A   or   op( A ) = A**T   or   op( A ) = A**H.
-/// The matrix X is overwritten on B.
-/// This is [CTRSM](http://www.netlib.org/lapack/explore-html/de/d30/ctrsm_8f.html) and [ZTRSM](http://www.netlib.org/lapack/explore-html/d1/d39/ztrsm_8f.html) comined in one function
+/// This is [CTRMM](http://www.netlib.org/lapack/explore-html/d4/d9b/ctrmm_8f.html) and [ZTRMM](http://www.netlib.org/lapack/explore-html/d8/de1/ztrmm_8f.html) comined in one function
 #[inline]
-pub fn trsm<T: Float + NumAssignOps>(
-    side: char,
-    uplo: char,
-    trans: char,
-    diag: char,
+pub fn trmm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     m: usize,
     n: usize,
     alpha: Complex<T>,
@@ -1120,75 +645,84 @@ pub fn trsm<T: Float + NumAssignOps>(
     b: &mut [Complex<T>],
     ldb: usize,
 ) {
-    let lside = side == 'l' || side == 'L';
+    if let Err(err) = try_trmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    let lside = side == Side::Left;
 
     let nrowa = if lside { m } else { n };
-    let noconj = trans == 't' || trans == 'T';
-    let nounit = diag == 'n' || diag == 'N';
-    let upper = uplo == 'u' || uplo == 'U';
+    let nounit = diag == Diag::NonUnit;
+    let upper = uplo == Uplo::Upper;
     let alpha_is_zero = alpha.is_zero();
-    let alpha_is_one = alpha.is_one();
-    let mut info = 0;
-    if side != 'l' && side != 'L' && side != 'r' && side != 'R' {
-        info = 1;
-    } else if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 2;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 3;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 4;
-    } else if lda < max(1, nrowa) {
-        info = 9;
-    } else if ldb < max(1, m) {
-        info = 11;
-    }
-    if info != 0 {
-        panic!("trsm {}", info);
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "trmm",
+            9,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, m) {
+        return Err(BlasError::new("trmm", 11, "ldb must be at least max(1, m)"));
     }
 
     if m == 0 || n == 0 {
-        return;
+        return Ok(());
     }
 
+    let ka = if side == Side::Left { m } else { n };
+    extent::matrix("trmm", 8, a, ka, ka, lda)?;
+    extent::matrix("trmm", 10, b, m, n, ldb)?;
+
+    let noconj = trans == Trans::Trans;
+
     if alpha_is_zero {
         zero(b, ldb, n, m);
-        return;
+        return Ok(());
+    }
+
+    if panels::trmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        return Ok(());
     }
 
-    if (side == 'l' || side == 'L') && (trans == 'n' || trans == 'N') {
+    if (side == Side::Left) && (trans == Trans::NoTrans) {
         if upper {
             let mut j = 0;
             while j < n {
                 let bj = j * ldb;
-                if !alpha_is_one {
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] = alpha * b[bj + i];
-                        i += 1;
-                    }
-                }
-                let mut k = m;
-                while k >= 1 {
-                    k -= 1;
-                    let ak = k * lda;
-                    let tmp = b[bj + k];
+                let mut k = 0;
+                while k < m {
+                    let mut tmp = b[bj + k];
                     if !tmp.is_zero() {
-                        if nounit {
-                            b[bj + k] = tmp / a[ak + k];
-                        }
-                        let tmp = b[bj + k];
+                        let ak = k * lda;
+                        tmp *= alpha;
                         let mut i = 0;
                         while i < k {
-                            b[bj + i] -= tmp * a[ak + i];
+                            b[bj + i] += tmp * a[ak + i];
                             i += 1;
                         }
+                        if nounit {
+                            tmp *= a[ak + k];
+                        }
+                        b[bj + k] = tmp;
                     }
+                    k += 1;
                 }
                 j += 1;
             }
@@ -1196,59 +730,55 @@ pub fn trsm<T: Float + NumAssignOps>(
             let mut j = 0;
             while j < n {
                 let bj = j * ldb;
-                if !alpha_is_one {
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] = alpha * b[bj + i];
-                        i += 1;
-                    }
-                }
-                let mut k = 0;
-                while k < m {
-                    let ak = k * lda;
-                    let tmp = b[bj + k];
+                let mut k = m;
+                while k >= 1 {
+                    k -= 1;
+                    let mut tmp = b[bj + k];
                     if !tmp.is_zero() {
+                        let ak = k * lda;
+                        tmp *= alpha;
+                        b[bj + k] = tmp;
                         if nounit {
-                            b[bj + k] = tmp / a[ak + k];
+                            b[bj + k] *= a[ak + k];
                         }
-                        let tmp = b[bj + k];
                         let mut i = k + 1;
                         while i < m {
-                            b[bj + i] -= tmp * a[ak + i];
+                            b[bj + i] += tmp * a[ak + i];
                             i += 1;
                         }
                     }
-                    k += 1;
                 }
                 j += 1;
             }
         }
-        return;
-    }
-
-    if (side == 'l' || side == 'L') && (trans != 'n' && trans != 'N') {
+        return Ok(());
+    } else if (side == Side::Left) && (trans == Trans::Trans || trans == Trans::ConjTrans) {
         if upper {
             let mut j = 0;
             while j < n {
                 let bj = j * ldb;
-                let mut i = 0;
-                while i < m {
+                let mut i = m;
+                while i >= 1 {
+                    i -= 1;
                     let ai = i * lda;
-                    let mut tmp = alpha * b[bj + i];
+                    let mut tmp = b[bj + i];
+                    if nounit {
+                        if noconj {
+                            tmp *= a[ai + i];
+                        } else {
+                            tmp *= a[ai + i].conj();
+                        }
+                    }
                     let mut k = 0;
                     while k < i {
                         if noconj {
-                            tmp -= a[ai + k] * b[bj + k];
+                            tmp += a[ai + k] * b[bj + k];
                         } else {
-                            tmp -= a[ai + k].conj() * b[bj + k];
+                            tmp += a[ai + k].conj() * b[bj + k];
                         }
                         k += 1;
                     }
-                    if nounit {
-                        tmp /= a[ai + i];
-                    }
-                    b[bj + i] = tmp;
-                    i += 1;
+                    b[bj + i] = alpha * tmp;
                 }
                 j += 1;
             }
@@ -1256,202 +786,254 @@ pub fn trsm<T: Float + NumAssignOps>(
             let mut j = 0;
             while j < n {
                 let bj = j * ldb;
-                let mut i = m;
-                while i >= 1 {
-                    i -= 1;
+                let mut i = 0;
+                while i < m {
                     let ai = i * lda;
-                    let mut tmp = alpha * b[bj + i];
-                    let mut k = i + 1;
-                    while k < m {
+                    let mut tmp = b[bj + i];
+                    if nounit {
                         if noconj {
-                            tmp -= a[ai + k] * b[bj + k];
+                            tmp *= a[ai + i];
                         } else {
-                            tmp -= a[ai + k].conj() * b[bj + k];
+                            tmp *= a[ai + i].conj();
                         }
-
-                        k += 1;
                     }
-                    if nounit {
+                    let mut k = i + 1;
+                    while k < m {
                         if noconj {
-                            tmp /= a[ai + i];
+                            tmp += a[ai + k] * b[bj + k];
                         } else {
-                            tmp /= a[ai + i].conj();
+                            tmp += a[ai + k].conj() * b[bj + k];
                         }
+                        k += 1;
                     }
-                    b[bj + i] = tmp;
+                    b[bj + i] = alpha * tmp;
+
+                    i += 1;
                 }
                 j += 1;
             }
         }
-        return;
-    }
-
-    if (side == 'r' || side == 'R') && (trans == 'n' || trans == 'N') {
+        return Ok(());
+    } else if (side == Side::Right) && (trans == Trans::NoTrans) {
         if upper {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
+            let mut j = n;
+            while j >= 1 {
+                j -= 1;
                 let aj = j * lda;
-                if !alpha_is_one {
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] = alpha * b[bj + i];
-                        i += 1;
-                    }
+                let bj = j * ldb;
+                let mut tmp = alpha;
+                if nounit {
+                    tmp *= a[aj + j];
+                }
+                let mut i = 0;
+                while i < m {
+                    b[bj + i] *= tmp;
+                    i += 1;
                 }
                 let mut k = 0;
                 while k < j {
                     let bk = k * ldb;
-                    let tmp = a[aj + k];
+                    let mut tmp = a[aj + k];
                     if !tmp.is_zero() {
+                        tmp *= alpha;
                         let mut i = 0;
                         while i < m {
-                            b[bj + i] -= tmp * b[bk + i];
+                            b[bj + i] += tmp * b[bk + i];
                             i += 1;
                         }
                     }
                     k += 1;
                 }
-                if nounit {
-                    let tmp = Complex::new(T::one(), T::zero()) / a[aj + j];
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] *= tmp;
-                        i += 1;
-                    }
-                }
-                j += 1;
             }
         } else {
-            let mut j = n;
-            while j >= 1 {
-                j -= 1;
+            let mut j = 0;
+            while j < n {
                 let bj = j * ldb;
                 let aj = j * lda;
-                if !alpha_is_one {
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] = alpha * b[bj + i];
-                        i += 1;
-                    }
+                let mut tmp = alpha;
+                if nounit {
+                    tmp *= a[aj + j];
+                }
+                let mut i = 0;
+                while i < m {
+                    b[bj + i] *= tmp;
+                    i += 1;
                 }
                 let mut k = j + 1;
                 while k < n {
                     let bk = k * ldb;
-                    let tmp = a[aj + k];
-                    if !tmp.is_zero() {
+                    let tmp2 = a[aj + k];
+                    if !tmp2.is_zero() {
+                        tmp = alpha * tmp2;
                         let mut i = 0;
                         while i < m {
-                            b[bj + i] -= tmp * b[bk + i];
+                            b[bj + i] += tmp * b[bk + i];
                             i += 1;
                         }
                     }
                     k += 1;
                 }
-                if nounit {
-                    let tmp = Complex::new(T::one(), T::zero()) / a[aj + j];
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] *= tmp;
-                        i += 1;
-                    }
-                }
+                j += 1;
             }
         }
-        return;
-    }
-
-    if upper {
-        let mut k = n;
-        while k >= 1 {
-            k -= 1;
-            let ak = k * lda;
-            let bk = k * ldb;
-            if nounit {
-                let mut tmp = Complex::new(T::one(), T::zero());
-                if noconj {
-                    tmp /= a[ak + k];
-                } else {
-                    tmp /= a[ak + k].conj();
-                }
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] = tmp * b[bk + i];
-                    i += 1;
+        return Ok(());
+    } else {
+        if upper {
+            let mut k = 0;
+            while k < n {
+                let ak = k * lda;
+                let bk = k * ldb;
+                let mut j = 0;
+                while j < k {
+                    let bj = j * ldb;
+                    let mut tmp = a[ak + j];
+                    if !tmp.is_zero() {
+                        if noconj {
+                            tmp *= alpha;
+                        } else {
+                            tmp = alpha * tmp.conj();
+                        }
+                        let mut i = 0;
+                        while i < m {
+                            b[bj + i] += tmp * b[bk + i];
+                            i += 1;
+                        }
+                    }
+                    j += 1;
                 }
-            }
-            let mut j = 0;
-            while j < k {
-                let bj = j * ldb;
-                let mut tmp = a[ak + j];
-                if !tmp.is_zero() {
-                    if !noconj {
-                        tmp = tmp.conj()
+                let mut tmp = alpha;
+                if nounit {
+                    if noconj {
+                        tmp *= a[ak + k];
+                    } else {
+                        tmp *= a[ak + k].conj();
                     }
+                }
+                if !tmp.is_one() {
                     let mut i = 0;
                     while i < m {
-                        b[bj + i] -= tmp * b[bk + i];
+                        b[bk + i] = tmp * b[bk + i];
                         i += 1;
                     }
                 }
-                j += 1;
-            }
-            if !alpha_is_one {
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] = alpha * b[bk + i];
-                    i += 1;
-                }
+                k += 1;
             }
-        }
-        return;
-    } else {
-        let mut k = 0;
-        while k < n {
-            let ak = k * lda;
-            let bk = k * ldb;
-            if nounit {
-                let mut tmp = Complex::new(T::one(), T::zero());
-                if noconj {
-                    tmp /= a[ak + k];
-                } else {
-                    tmp /= a[ak + k].conj();
+        } else {
+            let mut k = n;
+            while k >= 1 {
+                k -= 1;
+                let ak = k * lda;
+                let bk = k * ldb;
+                let mut j = k + 1;
+                while j < n {
+                    let bj = j * ldb;
+                    let mut tmp = a[ak + j];
+                    if !tmp.is_zero() {
+                        if noconj {
+                            tmp *= alpha;
+                        } else {
+                            tmp = alpha * tmp.conj();
+                        };
+                        let mut i = 0;
+                        while i < m {
+                            b[bj + i] += tmp * b[bk + i];
+                            i += 1;
+                        }
+                    }
+                    j += 1;
                 }
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] = tmp * b[bk + i];
-                    i += 1;
+                let mut tmp = alpha;
+                if nounit {
+                    if noconj {
+                        tmp *= a[ak + k];
+                    } else {
+                        tmp *= a[ak + k].conj();
+                    };
                 }
-            }
-            let mut j = k + 1;
-            while j < n {
-                let bj = j * ldb;
-                let mut tmp = a[ak + j];
-                if !tmp.is_zero() {
-                    if !noconj {
-                        tmp = tmp.conj()
-                    }
+                if !tmp.is_one() {
                     let mut i = 0;
                     while i < m {
-                        b[bj + i] -= tmp * b[bk + i];
-
+                        b[bk + i] = tmp * b[bk + i];
                         i += 1;
                     }
                 }
-                j += 1;
-            }
-            if !alpha_is_one {
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] = alpha * b[bk + i];
-
-                    i += 1;
-                }
             }
-            k += 1;
         }
-        return;
     }
+    Ok(())
+}
+
+/// TRSM  solves one of the matrix equations
+/// op( A )*X = alpha*B,   or   X*op( A ) = alpha*B,
+/// where alpha is a scalar, X and B are m by n matrices, A is a unit, or  non-unit,  upper or lower triangular matrix  and  op( A )  is one  of
+/// op( A ) = A   or   op( A ) = A**T   or   op( A ) = A**H.
+/// The matrix X is overwritten on B.
+/// This is [CTRSM](http://www.netlib.org/lapack/explore-html/de/d30/ctrsm_8f.html) and [ZTRSM](http://www.netlib.org/lapack/explore-html/d1/d39/ztrsm_8f.html) comined in one function
+#[inline]
+pub fn trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) {
+    if let Err(err) = try_trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trsm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    let nrowa = if side == Side::Left { m } else { n };
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "trsm",
+            9,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, m) {
+        return Err(BlasError::new("trsm", 11, "ldb must be at least max(1, m)"));
+    }
+
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+
+    let ka = if side == Side::Left { m } else { n };
+    extent::matrix("trsm", 8, a, ka, ka, lda)?;
+    extent::matrix("trsm", 10, b, m, n, ldb)?;
+
+    if alpha.is_zero() {
+        zero(b, ldb, n, m);
+        return Ok(());
+    }
+
+    if panels::trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        return Ok(());
+    }
+
+    generic::trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb);
+    Ok(())
 }
 
 /// HEMM  performs one of the matrix-matrix operations
@@ -1459,9 +1041,9 @@ pub fn trsm<T: Float + NumAssignOps>(
 /// here alpha and beta are scalars, A is an hermitian matrix and  B and C are m by n matrices.
 /// This is [CHEMM](http://www.netlib.org/lapack/explore-html/d3/d66/chemm_8f.html) and [ZHEMM](http://www.netlib.org/lapack/explore-html/d6/d3e/zhemm_8f.html) comined in one function
 #[inline]
-pub fn hemm<T: Float + NumAssignOps>(
-    side: char,
-    uplo: char,
+pub fn hemm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
     m: usize,
     n: usize,
     alpha: Complex<T>,
@@ -1473,37 +1055,65 @@ pub fn hemm<T: Float + NumAssignOps>(
     c: &mut [Complex<T>],
     ldc: usize,
 ) {
+    if let Err(err) = try_hemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`hemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_hemm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
     let beta_is_zero = beta.is_zero();
     let beta_is_one = beta.is_one();
     let alpha_is_zero = alpha.is_zero();
-    let nrowa = if side == 'l' || side == 'L' { m } else { n };
-    let upper = uplo == 'u' || uplo == 'U';
-    let mut info = 0;
-    if side != 'l' && side != 'L' && side != 'r' && side != 'R' {
-        info = 1;
-    } else if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 2;
-    } else if lda < max(1, nrowa) {
-        info = 7;
-    } else if ldb < max(1, m) {
-        info = 9;
-    } else if ldc < max(1, m) {
-        info = 12;
-    }
-    if info != 0 {
-        panic!("hemm {}", info);
+    let nrowa = if side == Side::Left { m } else { n };
+    let upper = uplo == Uplo::Upper;
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "hemm",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, m) {
+        return Err(BlasError::new("hemm", 9, "ldb must be at least max(1, m)"));
+    }
+    if ldc < max(1, m) {
+        return Err(BlasError::new("hemm", 12, "ldc must be at least max(1, m)"));
     }
 
     if m == 0 || n == 0 || (alpha_is_zero && beta_is_one) {
-        return;
+        return Ok(());
     }
 
+    let ka = if side == Side::Left { m } else { n };
+    extent::matrix("hemm", 6, a, ka, ka, lda)?;
+    extent::matrix("hemm", 8, b, m, n, ldb)?;
+    extent::matrix("hemm", 11, c, m, n, ldc)?;
+
     if alpha_is_zero {
         multiply(c, beta, ldc as usize, n as usize, m as usize);
-        return;
+        return Ok(());
+    }
+
+    if panels::hemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) {
+        return Ok(());
     }
 
-    if side == 'l' || side == 'L' {
+    if side == Side::Left {
         if upper {
             let mut j = 0;
             while j < n {
@@ -1606,6 +1216,7 @@ pub fn hemm<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// CHER2K  performs one of the hermitian rank 2k operations
@@ -1614,9 +1225,9 @@ pub fn hemm<T: Float + NumAssignOps>(
 /// hermitian matrix and  A and B  are  n by k matrices in the first case and  k by n  matrices in the second case.
 /// This is [CHER2K](http://www.netlib.org/lapack/explore-html/d1/d82/cher2k_8f.html) and [ZHER2K](http://www.netlib.org/lapack/explore-html/d7/dfa/zher2k_8f.html) comined in one function
 #[inline]
-pub fn her2k<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
+pub fn her2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
     n: usize,
     k: usize,
     alpha: Complex<T>,
@@ -1628,29 +1239,71 @@ pub fn her2k<T: Float + NumAssignOps>(
     c: &mut [Complex<T>],
     ldc: usize,
 ) {
-    let nrowa = if trans == 'n' || trans == 'N' { n } else { k };
-    let upper = uplo == 'u' || uplo == 'U';
-    let alpha_is_zero = alpha.is_zero();
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'n' && trans != 'N' && trans != 'c' && trans != 'C' {
-        info = 2;
-    } else if lda < max(1, nrowa) {
-        info = 7;
-    } else if ldb < max(1, nrowa) {
-        info = 9;
-    } else if ldc < max(1, n) {
-        info = 12;
+    if let Err(err) = try_her2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("her2k {}", info);
+/// Checked variant of [`her2k`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_her2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrowa = if trans == Trans::NoTrans { n } else { k };
+    let upper = uplo == Uplo::Upper;
+    let alpha_is_zero = alpha.is_zero();
+    if trans == Trans::Trans {
+        return Err(BlasError::new(
+            "her2k",
+            2,
+            "trans must be NoTrans or ConjTrans",
+        ));
+    }
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "her2k",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrowa) {
+        return Err(BlasError::new(
+            "her2k",
+            9,
+            "ldb must be at least max(1, nrowa)",
+        ));
+    }
+    if ldc < max(1, n) {
+        return Err(BlasError::new(
+            "her2k",
+            12,
+            "ldc must be at least max(1, n)",
+        ));
     }
 
     if n == 0 || ((alpha_is_zero || k == 0) && beta.is_one()) {
-        return;
+        return Ok(());
+    }
+
+    if trans == Trans::NoTrans {
+        extent::matrix("her2k", 6, a, n, k, lda)?;
+        extent::matrix("her2k", 8, b, n, k, ldb)?;
+    } else {
+        extent::matrix("her2k", 6, a, k, n, lda)?;
+        extent::matrix("her2k", 8, b, k, n, ldb)?;
     }
+    extent::matrix("her2k", 11, c, n, n, ldc)?;
 
     if alpha_is_zero {
         let mut j = 0;
@@ -1681,10 +1334,14 @@ pub fn her2k<T: Float + NumAssignOps>(
             c[cj + j] = ct;
             j += 1;
         }
-        return;
+        return Ok(());
+    }
+
+    if panels::her2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) {
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
+    if trans == Trans::NoTrans {
         if upper {
             let mut j = 0;
             while j < n {
@@ -1820,6 +1477,7 @@ pub fn her2k<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// HERK  performs one of the hermitian rank k operations
@@ -1828,9 +1486,9 @@ pub fn her2k<T: Float + NumAssignOps>(
 /// matrix and  A  is an  n by k  matrix in the  first case and a  k by n matrix in the second case.
 /// This is [CHERK](http://www.netlib.org/lapack/explore-html/d8/d52/cherk_8f.html) and [ZHERK](http://www.netlib.org/lapack/explore-html/d1/db1/zherk_8f.html) comined in one function
 #[inline]
-pub fn herk<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
+pub fn herk<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
     n: usize,
     k: usize,
     alpha: T,
@@ -1840,26 +1498,55 @@ pub fn herk<T: Float + NumAssignOps>(
     c: &mut [Complex<T>],
     ldc: usize,
 ) {
-    let nrowa = if trans == 'n' || trans == 'N' { n } else { k };
-    let upper = uplo == 'u' || uplo == 'U';
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'n' && trans != 'N' && trans != 'c' && trans != 'C' {
-        info = 2;
-    } else if lda < max(1, nrowa) {
-        info = 7;
-    } else if ldc < max(1, n) {
-        info = 10;
+    if let Err(err) = try_herk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("herk {}", info);
+/// Checked variant of [`herk`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_herk<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrowa = if trans == Trans::NoTrans { n } else { k };
+    let upper = uplo == Uplo::Upper;
+    if trans == Trans::Trans {
+        return Err(BlasError::new(
+            "herk",
+            2,
+            "trans must be NoTrans or ConjTrans",
+        ));
+    }
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "herk",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldc < max(1, n) {
+        return Err(BlasError::new("herk", 10, "ldc must be at least max(1, n)"));
     }
 
     if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
-        return;
+        return Ok(());
+    }
+
+    if trans == Trans::NoTrans {
+        extent::matrix("herk", 6, a, n, k, lda)?;
+    } else {
+        extent::matrix("herk", 6, a, k, n, lda)?;
     }
+    extent::matrix("herk", 9, c, n, n, ldc)?;
 
     if alpha.is_zero() {
         let mut j = 0;
@@ -1883,10 +1570,14 @@ pub fn herk<T: Float + NumAssignOps>(
             }
             j += 1;
         }
-        return;
+        return Ok(());
+    }
+
+    if panels::herk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
+    if trans == Trans::NoTrans {
         let mut j = 0;
         while j < n {
             let fs = if upper { 0 } else { j };
@@ -1930,7 +1621,7 @@ pub fn herk<T: Float + NumAssignOps>(
                         c[(cj as isize + i) as usize] += tmp * a[(al as isize + i) as usize];
                         i += 1;
                     }
-                    let mut ct = c[cj + j] + tmp * a[al + j];;
+                    let mut ct = c[cj + j] + tmp * a[al + j];
                     ct.im = T::zero();
                     c[cj + j] = ct;
                 }
@@ -2009,4 +1700,5 @@ pub fn herk<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
@@ -0,0 +1,446 @@
+//! The reference algorithms of the level 3 routines shared by the real and the complex routines.
+//!
+//! The arguments have been checked and the quick returns taken by the caller, which also tries the
+//! parallel and blocked algorithms first. `Trans::Trans` and `Trans::ConjTrans` only differ for complex scalars.
+//! Only `trsm` divides, the other algorithms work on any [`Ring`].
+use crate::{Diag, Ring, Scalar, Side, Trans, Uplo};
+
+/// `x`, or its conjugate when `conj` is true.
+#[inline]
+fn op<T: Scalar>(conj: bool, x: T) -> T {
+    if conj {
+        x.conj()
+    } else {
+        x
+    }
+}
+
+pub(crate) fn multiply<T: Ring>(left: &mut [T], right: T, ld: usize, n: usize, m: usize) {
+    if right.is_zero() {
+        zero(left, ld, n, m);
+    } else {
+        let mut j = 0;
+        while j < n {
+            let coords = j * ld;
+            let mut i = 0;
+            while i < m {
+                left[coords + i] = left[coords + i] * right;
+                i += 1;
+            }
+            j += 1;
+        }
+    }
+}
+
+pub(crate) fn zero<T: Ring>(left: &mut [T], ld: usize, n: usize, m: usize) {
+    let mut j = 0;
+    while j < n {
+        let coords = j * ld;
+        let mut i = 0;
+        while i < m {
+            left[coords + i] = T::zero();
+            i += 1;
+        }
+        j += 1;
+    }
+}
+
+/// C := beta*C on the `uplo` triangle of the n by n matrix C.
+pub(crate) fn multiply_triangle<T: Ring>(uplo: Uplo, c: &mut [T], beta: T, ldc: usize, n: usize) {
+    let mut j = 0;
+    while j < n {
+        let cj = j * ldc;
+        let (start, stop) = if uplo == Uplo::Upper {
+            (0, j + 1)
+        } else {
+            (j, n)
+        };
+        let mut i = start;
+        while i < stop {
+            c[cj + i] = if beta.is_zero() {
+                T::zero()
+            } else {
+                beta * c[cj + i]
+            };
+            i += 1;
+        }
+        j += 1;
+    }
+}
+
+/// Width of the diagonal blocks of [`triangle`].
+const TRIANGLE_BLOCK: usize = 64;
+
+/// Covers the `uplo` triangle of an n by n matrix with blocks, calling `f(i, j, rows, cols)` for the block of
+/// `rows` by `cols` elements whose first element is (i, j). The blocks off the diagonal are as large as possible,
+/// so that `gemmt` can hand them to the blocked and parallel `gemm`, the diagonal blocks are split into columns.
+pub(crate) fn triangle<F: FnMut(usize, usize, usize, usize)>(uplo: Uplo, n: usize, mut f: F) {
+    let mut j0 = 0;
+    while j0 < n {
+        let j1 = n.min(j0 + TRIANGLE_BLOCK);
+        if uplo == Uplo::Upper && j0 > 0 {
+            f(0, j0, j0, j1 - j0);
+        }
+        let mut j = j0;
+        while j < j1 {
+            if uplo == Uplo::Upper {
+                f(j0, j, j + 1 - j0, 1);
+            } else {
+                f(j, j, j1 - j, 1);
+            }
+            j += 1;
+        }
+        if uplo == Uplo::Lower && j1 < n {
+            f(j1, j0, n - j1, j1 - j0);
+        }
+        j0 = j1;
+    }
+}
+
+/// C := alpha*op( A )*op( B ) + beta*C, where [`Ring::conj`] conjugates an element for `Trans::ConjTrans`.
+pub(crate) fn gemm<T: Ring>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    let beta_is_zero = beta.is_zero();
+    // Element (i, l) of op( A ) for a transposed A, and element (l, j) of op( B ).
+    let op_a = |i: usize, l: usize| {
+        let x = a[i * lda + l];
+        if trans_a == Trans::ConjTrans {
+            x.conj()
+        } else {
+            x
+        }
+    };
+    let op_b = |l: usize, j: usize| match trans_b {
+        Trans::NoTrans => b[j * ldb + l],
+        Trans::Trans => b[l * ldb + j],
+        Trans::ConjTrans => b[l * ldb + j].conj(),
+    };
+
+    if trans_a == Trans::NoTrans {
+        let mut j = 0;
+        while j < n {
+            let cj = j * ldc;
+            if beta_is_zero {
+                let mut i = 0;
+                while i < m {
+                    c[cj + i] = T::zero();
+                    i += 1;
+                }
+            } else if !beta.is_one() {
+                let mut i = 0;
+                while i < m {
+                    c[cj + i] = c[cj + i] * beta;
+                    i += 1;
+                }
+            }
+            let mut l = 0;
+            while l < k {
+                let al = l * lda;
+                let tmp = alpha * op_b(l, j);
+                let mut i = 0;
+                while i < m {
+                    c[cj + i] = c[cj + i] + tmp * a[al + i];
+                    i += 1;
+                }
+                l += 1;
+            }
+            j += 1;
+        }
+    } else {
+        let mut j = 0;
+        while j < n {
+            let cj = j * ldc;
+            let mut i = 0;
+            while i < m {
+                let mut tmp = T::zero();
+                let mut l = 0;
+                while l < k {
+                    tmp = tmp + op_a(i, l) * op_b(l, j);
+                    l += 1;
+                }
+                tmp = tmp * alpha;
+                if !beta_is_zero {
+                    tmp = tmp + beta * c[cj + i];
+                }
+                c[cj + i] = tmp;
+                i += 1;
+            }
+            j += 1;
+        }
+    }
+}
+
+/// C := alpha*A*B + beta*C, or C := alpha*B*A + beta*C, where A is symmetric.
+pub(crate) fn symm<T: Ring>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    let upper = uplo == Uplo::Upper;
+    let beta_is_zero = beta.is_zero();
+
+    if side == Side::Left {
+        if upper {
+            let mut j = 0;
+            while j < n {
+                let bj = j * ldb;
+                let cj = j * ldc;
+                let mut i = 0;
+                while i < m {
+                    let ai = i * lda;
+                    let mut tmp = alpha * b[bj + i];
+                    let mut tmp2 = T::zero();
+                    let mut k = 0;
+                    while k < i {
+                        c[cj + k] = c[cj + k] + tmp * a[ai + k];
+                        tmp2 = tmp2 + b[bj + k] * a[ai + k];
+                        k += 1
+                    }
+                    tmp = tmp * a[ai + i] + alpha * tmp2;
+                    if !beta_is_zero {
+                        tmp = tmp + beta * c[cj + i];
+                    }
+                    c[cj + i] = tmp;
+                    i += 1;
+                }
+                j += 1;
+            }
+        } else {
+            let mut j = 0;
+            while j < n {
+                let bj = j * ldb;
+                let cj = j * ldc;
+                let mut i = m;
+                while i >= 1 {
+                    i -= 1;
+                    let ai = i * lda;
+                    let mut tmp = alpha * b[bj + i];
+                    let mut tmp2 = T::zero();
+                    let mut k = i + 1;
+                    while k < m {
+                        c[cj + k] = c[cj + k] + tmp * a[ai + k];
+                        tmp2 = tmp2 + b[bj + k] * a[ai + k];
+                        k += 1
+                    }
+                    tmp = tmp * a[ai + i] + alpha * tmp2;
+                    if !beta_is_zero {
+                        tmp = tmp + beta * c[cj + i];
+                    }
+                    c[cj + i] = tmp;
+                }
+                j += 1;
+            }
+        }
+    } else {
+        let mut j = 0;
+        while j < n {
+            let aj = j * lda;
+            let bj = j * ldb;
+            let cj = j * ldc;
+            let tmp = alpha * a[aj + j];
+            let mut i = 0;
+            while i < m {
+                let mut tmp2 = tmp * b[bj + i];
+                if !beta_is_zero {
+                    tmp2 = tmp2 + beta * c[cj + i];
+                }
+                c[cj + i] = tmp2;
+                i += 1;
+            }
+            let mut k = 0;
+            while k < n {
+                if k != j {
+                    let bk = k * ldb;
+                    // A(k, j) read from the referenced triangle.
+                    let tmp = if (k < j) == upper {
+                        alpha * a[aj + k]
+                    } else {
+                        alpha * a[k * lda + j]
+                    };
+                    let mut i = 0;
+                    while i < m {
+                        c[cj + i] = c[cj + i] + tmp * b[bk + i];
+                        i += 1;
+                    }
+                }
+                k += 1;
+            }
+            j += 1;
+        }
+    }
+}
+
+/// Solves op( A )*X = alpha*B, or X*op( A ) = alpha*B, where A is triangular, X is overwritten on B.
+pub(crate) fn trsm<T: Scalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) {
+    let conj = trans == Trans::ConjTrans;
+    let nounit = diag == Diag::NonUnit;
+    let upper = uplo == Uplo::Upper;
+    let alpha_is_one = alpha.is_one();
+
+    if side == Side::Left && trans == Trans::NoTrans {
+        let mut j = 0;
+        while j < n {
+            let bj = j * ldb;
+            if !alpha_is_one {
+                let mut i = 0;
+                while i < m {
+                    b[bj + i] *= alpha;
+                    i += 1;
+                }
+            }
+            let mut kk = 0;
+            while kk < m {
+                let k = if upper { m - 1 - kk } else { kk };
+                let ak = k * lda;
+                if !b[bj + k].is_zero() {
+                    if nounit {
+                        b[bj + k] /= a[ak + k];
+                    }
+                    let tmp = b[bj + k];
+                    let (start, end) = if upper { (0, k) } else { (k + 1, m) };
+                    let mut i = start;
+                    while i < end {
+                        b[bj + i] -= tmp * a[ak + i];
+                        i += 1;
+                    }
+                }
+                kk += 1;
+            }
+            j += 1;
+        }
+    } else if side == Side::Left {
+        let mut j = 0;
+        while j < n {
+            let bj = j * ldb;
+            let mut ii = 0;
+            while ii < m {
+                let i = if upper { ii } else { m - 1 - ii };
+                let ai = i * lda;
+                let mut tmp = alpha * b[bj + i];
+                let (start, end) = if upper { (0, i) } else { (i + 1, m) };
+                let mut k = start;
+                while k < end {
+                    tmp -= op(conj, a[ai + k]) * b[bj + k];
+                    k += 1;
+                }
+                if nounit {
+                    tmp /= op(conj, a[ai + i]);
+                }
+                b[bj + i] = tmp;
+                ii += 1;
+            }
+            j += 1;
+        }
+    } else if trans == Trans::NoTrans {
+        let mut jj = 0;
+        while jj < n {
+            let j = if upper { jj } else { n - 1 - jj };
+            let aj = j * lda;
+            let bj = j * ldb;
+            if !alpha_is_one {
+                let mut i = 0;
+                while i < m {
+                    b[bj + i] *= alpha;
+                    i += 1;
+                }
+            }
+            let (start, end) = if upper { (0, j) } else { (j + 1, n) };
+            let mut k = start;
+            while k < end {
+                let bk = k * ldb;
+                let tmp = a[aj + k];
+                if !tmp.is_zero() {
+                    let mut i = 0;
+                    while i < m {
+                        let tmp2 = tmp * b[bk + i];
+                        b[bj + i] -= tmp2;
+                        i += 1;
+                    }
+                }
+                k += 1;
+            }
+            if nounit {
+                let tmp = T::one() / a[aj + j];
+                let mut i = 0;
+                while i < m {
+                    b[bj + i] *= tmp;
+                    i += 1;
+                }
+            }
+            jj += 1;
+        }
+    } else {
+        let mut kk = 0;
+        while kk < n {
+            let k = if upper { n - 1 - kk } else { kk };
+            let ak = k * lda;
+            let bk = k * ldb;
+            if nounit {
+                let tmp = T::one() / op(conj, a[ak + k]);
+                let mut i = 0;
+                while i < m {
+                    b[bk + i] *= tmp;
+                    i += 1;
+                }
+            }
+            let (start, end) = if upper { (0, k) } else { (k + 1, n) };
+            let mut j = start;
+            while j < end {
+                let bj = j * ldb;
+                let tmp = a[ak + j];
+                if !tmp.is_zero() {
+                    let tmp = op(conj, tmp);
+                    let mut i = 0;
+                    while i < m {
+                        let tmp2 = tmp * b[bk + i];
+                        b[bj + i] -= tmp2;
+                        i += 1;
+                    }
+                }
+                j += 1;
+            }
+            if !alpha_is_one {
+                let mut i = 0;
+                while i < m {
+                    b[bk + i] *= alpha;
+                    i += 1;
+                }
+            }
+            kk += 1;
+        }
+    }
+}
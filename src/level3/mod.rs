@@ -1,47 +1,30 @@
-// use std::arch::x86_64::*;
-use num_traits::{Float, NumAssignOps};
+use self::generic::{multiply, zero};
+use crate::threading::ThreadSafe;
+use crate::{extent, xerbla, BlasError, Diag, RealScalar, Ring, Side, Trans, Uplo};
 use std::cmp::max;
 
+mod batch;
+pub mod blocking;
 pub mod complex;
+mod generic;
+mod panels;
+pub mod quantized;
 
-fn multiply<T: Float + NumAssignOps>(left: &mut [T], right: T, ld: usize, n: usize, m: usize) {
-    if right.is_zero() {
-        zero(left, ld, n, m);
-    } else {
-        let mut j = 0;
-        while j < n {
-            let coords = j * ld;
-            let mut i = 0;
-            while i < m {
-                left[coords + i] *= right;
-                i += 1;
-            }
-            j += 1;
-        }
-    }
-}
-
-fn zero<T: Float + NumAssignOps>(left: &mut [T], ld: usize, n: usize, m: usize) {
-    let mut j = 0;
-    while j < n {
-        let coords = j * ld;
-        let mut i = 0;
-        while i < m {
-            left[coords + i] = T::zero();
-            i += 1;
-        }
-        j += 1;
-    }
-}
+pub use self::batch::{
+    gemm_batch, gemm_batch_strided, trsm_batch, trsm_batch_strided, try_gemm_batch,
+    try_gemm_batch_strided, try_trsm_batch, try_trsm_batch_strided, GemmGroup, TrsmGroup,
+};
 
 /// GEMM  performs one of the matrix-matrix operations
 ///  C := alpha*op( A )*op( B ) + beta*C, where  op( X ) is one of op( X ) = X   or   op( X ) = X**T,
 /// alpha and beta are scalars, and A, B and C are matrices, with op( A ) an m by k matrix,  op( B )  a  k by n matrix and  C an m by n matrix.
 /// This is [SGEMM](http://www.netlib.org/lapack/explore-html/d4/de2/sgemm_8f.html) and [DGEMM](http://www.netlib.org/lapack/explore-html/d7/d2b/dgemm_8f.html) comined in one function
+/// Large products are computed on packed blocks of op( A ) and op( B ), see [`blocking`].
+/// `ConjTrans` conjugates the elements with [`Ring::conj`], so for real element types it is the same as `Trans`.
 #[inline]
-pub fn gemm<T: Float + NumAssignOps>(
-    trans_a: char,
-    trans_b: char,
+pub fn gemm<T: Ring + ThreadSafe + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
     m: usize,
     n: usize,
     k: usize,
@@ -54,154 +37,221 @@ pub fn gemm<T: Float + NumAssignOps>(
     c: &mut [T],
     ldc: usize,
 ) {
-    let not_a = trans_a == 'n' || trans_a == 'N';
-    let not_b = trans_b == 'n' || trans_b == 'N';
-    let nrow_a = if not_a { m } else { k };
-    let nrow_b = if not_a { k } else { n };
-
-    let mut info = 0;
-    if !(trans_a == 'T' || trans_a == 't' || trans_a == 'n' || trans_a == 'C' || trans_a == 'c') {
-        info = 1;
-    } else if !(trans_b == 'T'
-        || trans_b == 't'
-        || trans_b == 'n'
-        || trans_b == 'C'
-        || trans_b == 'c')
-    {
-        info = 2;
-    } else if lda < max(1, nrow_a) {
-        info = 8;
-    } else if ldb < max(1, nrow_b) {
-        info = 10;
-    } else if ldc < max(1, m) {
-        info = 13;
-    }
-    if info != 0 {
-        panic!("gemm {}", info);
+    if let Err(err) = try_gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
     }
+}
+
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemm<T: Ring + ThreadSafe + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    check_gemm(trans_a, trans_b, m, n, k, lda, ldb, ldc)?;
 
     if m == 0 || n == 0 || (alpha.is_zero() || k == 0) && beta.is_one() {
-        return;
+        return Ok(());
+    }
+
+    if trans_a == Trans::NoTrans {
+        extent::matrix("gemm", 7, a, m, k, lda)?;
+    } else {
+        extent::matrix("gemm", 7, a, k, m, lda)?;
+    }
+    if trans_b == Trans::NoTrans {
+        extent::matrix("gemm", 9, b, k, n, ldb)?;
+    } else {
+        extent::matrix("gemm", 9, b, n, k, ldb)?;
     }
+    extent::matrix("gemm", 12, c, m, n, ldc)?;
 
     if alpha.is_zero() {
         multiply(c, beta, ldc, n, m);
-        return;
+        return Ok(());
     }
 
-    if not_b {
-        if not_a {
-            let mut j = 0;
-            while j < n {
-                let cj = j * ldc;
-                let bj = j * ldb;
-                if beta.is_zero() {
-                    //Refactor
-                    let mut i = 0;
-                    while i < m {
-                        c[cj + i] = T::zero();
-                        i += 1;
-                    }
-                } else if !beta.is_one() {
-                    let mut i = 0;
-                    while i < m {
-                        c[cj + i] *= beta;
-                        i += 1;
-                    }
-                }
-                let mut l = 0;
-                while l < k {
-                    let tmp = alpha * b[bj + l];
-                    let aj = l * lda;
-                    let mut i = 0;
-                    while i < m {
-                        c[cj + i] += tmp * a[aj + i];
-                        i += 1;
-                    }
-                    l += 1;
-                }
-                j += 1;
-            }
-        } else {
-            let mut j = 0;
-            while j < n {
-                let cj = j * ldc;
-                let bj = j * ldb;
-                let mut i = 0;
-                while i < m {
-                    let mut tmp = T::zero();
-                    let coords = i * lda;
-                    let mut l = 0;
-                    while l < k {
-                        tmp += a[l + coords] * b[bj + l];
-                        l += 1;
-                    }
-                    if beta.is_zero() {
-                        c[cj + i] = alpha * tmp;
-                    } else {
-                        c[cj + i] = alpha * tmp + beta * c[cj + i];
-                    }
-                    i += 1;
-                }
-                j += 1;
-            }
-        }
-    } else if not_a {
-        let mut j = 0;
-        while j < n {
-            let cj = j * ldc;
-            if beta.is_zero() {
-                //Refactor
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] = T::zero();
-                    i += 1;
-                }
-            } else if !beta.is_one() {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] *= beta;
-                    i += 1;
-                }
-            }
-            let mut l = 0;
-            while l < k {
-                let bj = l * ldb;
-                let tmp = alpha * b[bj + j];
-                let aj = l * lda;
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * a[aj + i];
-                    i += 1;
-                }
-                l += 1;
-            }
-            j += 1;
-        }
+    if panels::gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        return Ok(());
+    }
+
+    if blocking::is_worth_it(m, n, k) {
+        blocking::gemm(
+            trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        );
+        return Ok(());
+    }
+
+    generic::gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    );
+    Ok(())
+}
+
+/// Checks the leading dimensions of [`gemm`].
+fn check_gemm(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    lda: usize,
+    ldb: usize,
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrow_a = if trans_a == Trans::NoTrans { m } else { k };
+    let nrow_b = if trans_b == Trans::NoTrans { k } else { n };
+
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "gemm",
+            8,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrow_b) {
+        return Err(BlasError::new(
+            "gemm",
+            10,
+            "ldb must be at least max(1, nrowb)",
+        ));
+    }
+    if ldc < max(1, m) {
+        return Err(BlasError::new("gemm", 13, "ldc must be at least max(1, m)"));
+    }
+    Ok(())
+}
+
+/// GEMMT  performs one of the matrix-matrix operations
+/// C := alpha*op( A )*op( B ) + beta*C, where  op( X ) is one of op( X ) = X   or   op( X ) = X**T,
+/// alpha and beta are scalars, op( A ) is an n by k matrix, op( B ) a k by n matrix and C an n by n matrix
+/// of which only the upper or lower triangle is computed, the other triangle isn't referenced.
+/// This is SGEMMT and DGEMMT of the reference BLAS 3.11 combined in one function
+/// The blocks off the diagonal are computed by [`gemm`].
+#[inline]
+pub fn gemmt<T: Ring + ThreadSafe + 'static>(
+    uplo: Uplo,
+    trans_a: Trans,
+    trans_b: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    if let Err(err) = try_gemmt(
+        uplo, trans_a, trans_b, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemmt`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemmt<T: Ring + ThreadSafe + 'static>(
+    uplo: Uplo,
+    trans_a: Trans,
+    trans_b: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let not_a = trans_a == Trans::NoTrans;
+    let not_b = trans_b == Trans::NoTrans;
+    let nrow_a = if not_a { n } else { k };
+    let nrow_b = if not_b { k } else { n };
+
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "gemmt",
+            8,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrow_b) {
+        return Err(BlasError::new(
+            "gemmt",
+            10,
+            "ldb must be at least max(1, nrowb)",
+        ));
+    }
+    if ldc < max(1, n) {
+        return Err(BlasError::new(
+            "gemmt",
+            13,
+            "ldc must be at least max(1, n)",
+        ));
+    }
+
+    if n == 0 || (alpha.is_zero() || k == 0) && beta.is_one() {
+        return Ok(());
+    }
+
+    if trans_a == Trans::NoTrans {
+        extent::matrix("gemmt", 7, a, n, k, lda)?;
     } else {
-        let mut j = 0;
-        while j < n {
-            let mut i = 0;
-            while i < m {
-                let mut tmp = T::zero();
-                let ai = i * lda;
-                let mut l = 0;
-                while l < k {
-                    let bl = l * ldb;
-                    tmp += a[ai + l] * b[bl + j];
-                    l += 1;
-                }
-                let cj = j * ldc;
-                if beta.is_zero() {
-                    c[cj + i] = alpha * tmp;
-                } else {
-                    c[cj + i] = alpha * tmp + beta * c[cj + i];
-                }
-                i += 1;
-            }
-            j += 1;
-        }
+        extent::matrix("gemmt", 7, a, k, n, lda)?;
+    }
+    if trans_b == Trans::NoTrans {
+        extent::matrix("gemmt", 9, b, k, n, ldb)?;
+    } else {
+        extent::matrix("gemmt", 9, b, n, k, ldb)?;
+    }
+    extent::matrix("gemmt", 12, c, n, n, ldc)?;
+
+    if alpha.is_zero() || k == 0 {
+        generic::multiply_triangle(uplo, c, beta, ldc, n);
+        return Ok(());
     }
+
+    generic::triangle(uplo, n, |i, j, rows, cols| {
+        let a = &a[if not_a { i } else { i * lda }..];
+        let b = &b[if not_b { j * ldb } else { j }..];
+        try_gemm(
+            trans_a,
+            trans_b,
+            rows,
+            cols,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            &mut c[j * ldc + i..],
+            ldc,
+        )
+        .expect("the arguments of a block are checked by gemmt")
+    });
+    Ok(())
 }
 
 /// SYMM  performs one of the matrix-matrix operations
@@ -209,9 +259,9 @@ pub fn gemm<T: Float + NumAssignOps>(
 /// where alpha and beta are scalars,  A is a symmetric matrix and  B and C are  m by n matrices.
 /// This is [SSYMM](http://www.netlib.org/lapack/explore-html/d7/d42/ssymm_8f.html) and [DSYMM](http://www.netlib.org/lapack/explore-html/d8/db0/dsymm_8f.html) comined in one function
 #[inline]
-pub fn symm<T: Float + NumAssignOps>(
-    side: char,
-    uplo: char,
+pub fn symm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
     m: usize,
     n: usize,
     alpha: T,
@@ -223,139 +273,62 @@ pub fn symm<T: Float + NumAssignOps>(
     c: &mut [T],
     ldc: usize,
 ) {
-    let nrow_a = if side == 'l' || side == 'L' { m } else { n };
-    let mut info = 0;
-    if side != 'l' && side != 'L' && side != 'r' && side != 'R' {
-        info = 1;
-    } else if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 2;
-    } else if lda < max(1, nrow_a) {
-        info = 7;
-    } else if ldb < max(1, m) {
-        info = 9;
-    } else if ldc < max(1, m) {
-        info = 12;
+    if let Err(err) = try_symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`symm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_symm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrow_a = if side == Side::Left { m } else { n };
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "symm",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, m) {
+        return Err(BlasError::new("symm", 9, "ldb must be at least max(1, m)"));
     }
-    if info != 0 {
-        panic!("symm {}", info);
+    if ldc < max(1, m) {
+        return Err(BlasError::new("symm", 12, "ldc must be at least max(1, m)"));
     }
 
     if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
-        return;
+        return Ok(());
     }
 
+    let ka = if side == Side::Left { m } else { n };
+    extent::matrix("symm", 6, a, ka, ka, lda)?;
+    extent::matrix("symm", 8, b, m, n, ldb)?;
+    extent::matrix("symm", 11, c, m, n, ldc)?;
+
     if alpha.is_zero() {
         multiply(c, beta, ldc, n, m);
-        return;
+        return Ok(());
     }
 
-    if side == 'l' || side == 'L' {
-        if uplo == 'u' || uplo == 'U' {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let cj = j * ldc;
-                let mut i = 0;
-                while i < m {
-                    let ai = i * ldc;
-                    let tmp = alpha * b[bj + i];
-                    let mut tmp2 = T::zero();
-                    let mut k = 0;
-                    while k < i {
-                        c[cj + k] += tmp * a[ai + k];
-                        tmp2 += b[bj + k] * a[ai + k];
-                        k += 1
-                    }
-                    let mut re = tmp * a[ai + i] + alpha * tmp2;
-                    if !beta.is_zero() {
-                        re += beta * c[cj + i];
-                    }
-                    c[cj + i] = re;
-                    i += 1
-                }
-                j += 1;
-            }
-        } else {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let cj = j * ldc;
-                let mut i = m;
-                while i >= 1 {
-                    i -= 1;
-                    let ai = i * ldc;
-                    let tmp = alpha * b[bj + i];
-                    let mut tmp2 = T::zero();
-                    let mut k = i + 1;
-                    while k < m {
-                        c[cj + k] += tmp * a[ai + k];
-                        tmp2 += b[bj + k] * a[ai + k];
-                        k += 1
-                    }
-                    let mut re = tmp * a[ai + i] + alpha * tmp2;
-                    if !beta.is_zero() {
-                        re += beta * c[cj + i];
-                    }
-                    c[cj + i] = re;
-                }
-                j += 1;
-            }
-        }
-    } else {
-        let mut j = 0;
-        while j < n {
-            let aj = j * lda;
-            let bj = j * ldb;
-            let cj = j * ldc;
-            let tmp = alpha * a[aj + j];
-            if beta.is_zero() {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] = tmp * b[bj + i];
-                    i += 1;
-                }
-            } else {
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] = beta * c[cj + i] + tmp * b[bj + i];
-                    i += 1;
-                }
-            }
-            let mut k = 0; //1
-            while k < j {
-                let ak = k * lda;
-                let bk = k * ldb;
-                let tmp = if uplo == 'u' || uplo == 'U' {
-                    alpha * a[aj + k]
-                } else {
-                    alpha * a[ak + j]
-                };
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * b[bk + i];
-                    i += 1;
-                }
-                k += 1;
-            }
-            let mut k = j + 1; //j + 1
-            while k < n {
-                let ak = k * lda;
-                let bk = k * ldb;
-                let tmp = if uplo == 'u' || uplo == 'U' {
-                    alpha * a[ak + j]
-                } else {
-                    alpha * a[aj + k]
-                };
-                let mut i = 0;
-                while i < m {
-                    c[cj + i] += tmp * b[bk + i];
-                    i += 1;
-                }
-                k += 1;
-            }
-            j += 1;
-        }
+    if panels::symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) {
+        return Ok(());
     }
+
+    generic::symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+    Ok(())
 }
 
 /// SYR2K  performs one of the symmetric rank 2k operations
@@ -364,9 +337,9 @@ pub fn symm<T: Float + NumAssignOps>(
 /// and  A and B  are  n by k  matrices  in the  first  case  and  k by n matrices in the second case.
 /// This is [SSYR2K](http://www.netlib.org/lapack/explore-html/df/d3d/ssyr2k_8f.html) and [DSYR2K](http://www.netlib.org/lapack/explore-html/d1/dec/dsyr2k_8f.html) comined in one function
 #[inline]
-pub fn syr2k<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
+pub fn syr2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
     n: usize,
     k: usize,
     alpha: T,
@@ -378,40 +351,69 @@ pub fn syr2k<T: Float + NumAssignOps>(
     c: &mut [T],
     ldc: usize,
 ) {
-    let nrow_a = if trans == 'n' || trans == 'N' { n } else { k };
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if lda < max(1, nrow_a) {
-        info = 7;
-    } else if ldb < max(1, nrow_a) {
-        info = 9;
-    } else if ldc < max(1, n) {
-        info = 12;
+    if let Err(err) = try_syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("syr2k {}", info);
+/// Checked variant of [`syr2k`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syr2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrow_a = if trans == Trans::NoTrans { n } else { k };
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "syr2k",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "syr2k",
+            9,
+            "ldb must be at least max(1, nrowa)",
+        ));
+    }
+    if ldc < max(1, n) {
+        return Err(BlasError::new(
+            "syr2k",
+            12,
+            "ldc must be at least max(1, n)",
+        ));
+    }
+
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return Ok(());
     }
 
-    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
-        return;
+    if trans == Trans::NoTrans {
+        extent::matrix("syr2k", 6, a, n, k, lda)?;
+        extent::matrix("syr2k", 8, b, n, k, ldb)?;
+    } else {
+        extent::matrix("syr2k", 6, a, k, n, lda)?;
+        extent::matrix("syr2k", 8, b, k, n, ldb)?;
     }
+    extent::matrix("syr2k", 11, c, n, n, ldc)?;
 
     //Refactor 001
     if alpha.is_zero() {
         let mut j = 0;
         while j < n {
-            let mut start = if uplo == 'u' || uplo == 'U' { 0 } else { j };
-            let stop = if uplo == 'u' || uplo == 'U' { j + 1 } else { n };
+            let mut start = if uplo == Uplo::Upper { 0 } else { j };
+            let stop = if uplo == Uplo::Upper { j + 1 } else { n };
             let cj = j * ldc;
             if beta.is_zero() {
                 while start < stop {
@@ -426,11 +428,15 @@ pub fn syr2k<T: Float + NumAssignOps>(
             }
             j += 1;
         }
-        return;
+        return Ok(());
+    }
+
+    if panels::syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) {
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
-        if uplo == 'u' || uplo == 'U' {
+    if trans == Trans::NoTrans {
+        if uplo == Uplo::Upper {
             let mut j = 0;
             while j < n {
                 let cj = j * ldc;
@@ -501,9 +507,10 @@ pub fn syr2k<T: Float + NumAssignOps>(
                 j += 1;
             }
         }
-    } else if uplo == 'u' || uplo == 'U' {
+    } else if uplo == Uplo::Upper {
         let mut j = 0;
         while j < n {
+            let aj = j * lda;
             let bj = j * ldb;
             let cj = j * ldc;
             let mut i = 0;
@@ -515,7 +522,7 @@ pub fn syr2k<T: Float + NumAssignOps>(
                 let mut l = 0;
                 while l < k {
                     tmp += a[ai + l] * b[bj + l];
-                    tmp2 += b[bi + l] * b[bj + l];
+                    tmp2 += b[bi + l] * a[aj + l];
                     l += 1;
                 }
                 if beta.is_zero() {
@@ -556,6 +563,7 @@ pub fn syr2k<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// SYRK  performs one of the symmetric rank k operations
@@ -564,9 +572,9 @@ pub fn syr2k<T: Float + NumAssignOps>(
 /// and  A  is an  n by k  matrix in the first case and a  k by n  matrix in the second case.
 /// This is [SSYRK](http://www.netlib.org/lapack/explore-html/d0/d40/ssyrk_8f.html) and [DSYRK](http://www.netlib.org/lapack/explore-html/dc/d05/dsyrk_8f.html) comined in one function
 #[inline]
-pub fn syrk<T: Float + NumAssignOps>(
-    uplo: char,
-    trans: char,
+pub fn syrk<T: Ring + ThreadSafe + 'static>(
+    uplo: Uplo,
+    trans: Trans,
     n: usize,
     k: usize,
     alpha: T,
@@ -576,39 +584,55 @@ pub fn syrk<T: Float + NumAssignOps>(
     c: &mut [T],
     ldc: usize,
 ) {
-    let nrow_a = if trans == 'n' || trans == 'N' { n } else { k };
-    let mut info = 0;
-    if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 1;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 2;
-    } else if lda < max(1, nrow_a) {
-        info = 7;
-    } else if ldc < max(1, n) {
-        info = 10;
+    if let Err(err) = try_syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        xerbla::report(&err);
     }
+}
 
-    if info != 0 {
-        panic!("syrk {}", info);
+/// Checked variant of [`syrk`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_syrk<T: Ring + ThreadSafe + 'static>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let nrow_a = if trans == Trans::NoTrans { n } else { k };
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "syrk",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldc < max(1, n) {
+        return Err(BlasError::new("syrk", 10, "ldc must be at least max(1, n)"));
     }
 
     if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
-        return;
+        return Ok(());
+    }
+
+    if trans == Trans::NoTrans {
+        extent::matrix("syrk", 6, a, n, k, lda)?;
+    } else {
+        extent::matrix("syrk", 6, a, k, n, lda)?;
     }
+    extent::matrix("syrk", 9, c, n, n, ldc)?;
 
     //Refactor 001
     if alpha.is_zero() {
         let mut j = 0;
         while j < n {
             let cj = j * ldc;
-            let start = if uplo == 'u' || uplo == 'U' { 0 } else { j };
-            let stop = if uplo == 'u' || uplo == 'U' { j + 1 } else { n };
+            let start = if uplo == Uplo::Upper { 0 } else { j };
+            let stop = if uplo == Uplo::Upper { j + 1 } else { n };
             let mut i = start;
             while i < stop {
                 c[cj + i] = if beta.is_zero() {
@@ -620,15 +644,19 @@ pub fn syrk<T: Float + NumAssignOps>(
             }
             j += 1;
         }
-        return;
+        return Ok(());
     }
 
-    if trans == 'n' || trans == 'N' {
+    if panels::syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) {
+        return Ok(());
+    }
+
+    if trans == Trans::NoTrans {
         let mut j = 0;
         while j < n {
             let cj = j * ldc;
-            let start = if uplo == 'u' || uplo == 'U' { 0 } else { j };
-            let stop = if uplo == 'u' || uplo == 'U' { j + 1 } else { n };
+            let start = if uplo == Uplo::Upper { 0 } else { j };
+            let stop = if uplo == Uplo::Upper { j + 1 } else { n };
             // Refactor #001
             if beta.is_zero() {
                 for i in cj + start..cj + stop {
@@ -638,7 +666,7 @@ pub fn syrk<T: Float + NumAssignOps>(
             } else if !beta.is_one() {
                 let mut i = start;
                 while i < stop {
-                    c[cj + i] *= beta;
+                    c[cj + i] = c[cj + i] * beta;
                     i += 1;
                 }
             }
@@ -649,7 +677,7 @@ pub fn syrk<T: Float + NumAssignOps>(
                     let tmp = alpha * a[al + j];
                     let mut i = start;
                     while i < stop {
-                        c[cj + i] += tmp * a[al + i];
+                        c[cj + i] = c[cj + i] + tmp * a[al + i];
                         i += 1;
                     }
                 }
@@ -660,8 +688,8 @@ pub fn syrk<T: Float + NumAssignOps>(
     } else {
         let mut j = 0;
         while j < n {
-            let start = if uplo == 'u' || uplo == 'U' { 0 } else { j };
-            let stop = if uplo == 'u' || uplo == 'U' { j + 1 } else { n };
+            let start = if uplo == Uplo::Upper { 0 } else { j };
+            let stop = if uplo == Uplo::Upper { j + 1 } else { n };
             let aj = j * lda;
             let cj = j * ldc;
             let mut i = start;
@@ -670,12 +698,12 @@ pub fn syrk<T: Float + NumAssignOps>(
                 let ai = i * lda;
                 let mut l = 0;
                 while l < k {
-                    tmp += a[ai + l] * a[aj + l];
+                    tmp = tmp + a[ai + l] * a[aj + l];
                     l += 1;
                 }
                 let mut re = alpha * tmp;
                 if !beta.is_zero() {
-                    re += beta * c[cj + i];
+                    re = re + beta * c[cj + i];
                 }
                 c[cj + i] = re;
                 i += 1;
@@ -683,6 +711,7 @@ pub fn syrk<T: Float + NumAssignOps>(
             j += 1;
         }
     }
+    Ok(())
 }
 
 /// TRMM  performs one of the matrix-matrix operations
@@ -690,12 +719,13 @@ pub fn syrk<T: Float + NumAssignOps>(
 /// where  alpha  is a scalar,  B  is an m by n matrix,  A  is a unit, or non-unit,  upper or lower triangular matrix  and  op( A )  is one  of
 ///  op( A ) = A   or   op( A ) = A**T.
 /// This is [STRMM](http://www.netlib.org/lapack/explore-html/df/d01/strmm_8f.html) and [DTRMM](http://www.netlib.org/lapack/explore-html/dd/d19/dtrmm_8f.html) comined in one function
+/// `ConjTrans` conjugates the elements of A with [`Ring::conj`], as in [`gemm`].
 #[inline]
-pub fn trmm<T: Float + NumAssignOps>(
-    side: char,
-    uplo: char,
-    trans: char,
-    diag: char,
+pub fn trmm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     m: usize,
     n: usize,
     alpha: T,
@@ -704,45 +734,69 @@ pub fn trmm<T: Float + NumAssignOps>(
     b: &mut [T],
     ldb: usize,
 ) {
-    let lside = side == 'l' || side == 'L';
+    if let Err(err) = try_trmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`trmm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trmm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    let lside = side == Side::Left;
     let nrowa = if lside { m } else { n };
-    let nounit = diag == 'n' || diag == 'N';
-    let upper = uplo == 'u' || uplo == 'U';
-    let mut info = 0;
-    if side != 'l' && side != 'L' && side != 'r' && side != 'R' {
-        info = 1;
-    } else if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 2;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 3;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 4;
-    } else if lda < max(1, nrowa) {
-        info = 9;
-    } else if ldb < max(1, m) {
-        info = 11;
+    let nounit = diag == Diag::NonUnit;
+    let upper = uplo == Uplo::Upper;
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "trmm",
+            9,
+            "lda must be at least max(1, nrowa)",
+        ));
     }
-    if info != 0 {
-        panic!("trmm {}", info);
+    if ldb < max(1, m) {
+        return Err(BlasError::new("trmm", 11, "ldb must be at least max(1, m)"));
     }
 
     if m == 0 || n == 0 {
-        return;
+        return Ok(());
     }
 
+    let ka = if side == Side::Left { m } else { n };
+    extent::matrix("trmm", 8, a, ka, ka, lda)?;
+    extent::matrix("trmm", 10, b, m, n, ldb)?;
+
     if alpha.is_zero() {
         zero(b, ldb, n, m);
-        return;
+        return Ok(());
+    }
+
+    if panels::trmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        return Ok(());
     }
 
+    // Element of A as read by op( A ) for Trans::Trans and Trans::ConjTrans.
+    let op = |x: T| {
+        if trans == Trans::ConjTrans {
+            x.conj()
+        } else {
+            x
+        }
+    };
+
     if lside {
-        if trans == 'n' || trans == 'N' {
+        if trans == Trans::NoTrans {
             if upper {
                 let mut j = 0;
                 while j < n {
@@ -754,11 +808,11 @@ pub fn trmm<T: Float + NumAssignOps>(
                             let mut tmp = alpha * b[bj + k];
                             let mut i = 0;
                             while i < k {
-                                b[bj + i] += tmp * a[ak + i];
+                                b[bj + i] = b[bj + i] + tmp * a[ak + i];
                                 i += 1;
                             }
                             if nounit {
-                                tmp *= a[ak + k]
+                                tmp = tmp * a[ak + k]
                             };
                             b[bj + k] = tmp;
                         }
@@ -778,11 +832,11 @@ pub fn trmm<T: Float + NumAssignOps>(
                             let tmp = alpha * b[bj + k];
                             b[bj + k] = tmp;
                             if nounit {
-                                b[bj + k] *= a[ak + k]
+                                b[bj + k] = b[bj + k] * a[ak + k]
                             };
                             let mut i = k + 1;
                             while i < m {
-                                b[bj + i] += tmp * a[ak + i];
+                                b[bj + i] = b[bj + i] + tmp * a[ak + i];
                                 i += 1;
                             }
                         }
@@ -800,11 +854,11 @@ pub fn trmm<T: Float + NumAssignOps>(
                     let mut tmp = b[bj + i];
                     let ai = i * lda;
                     if nounit {
-                        tmp *= a[ai + i]
+                        tmp = tmp * op(a[ai + i])
                     };
                     let mut k = 0;
                     while k < i {
-                        tmp += a[ai + k] * b[bj + k];
+                        tmp = tmp + op(a[ai + k]) * b[bj + k];
                         k += 1;
                     }
                     b[bj + i] = alpha * tmp;
@@ -820,11 +874,11 @@ pub fn trmm<T: Float + NumAssignOps>(
                     let ai = i * lda;
                     let mut tmp = b[bj + i];
                     if nounit {
-                        tmp *= a[ai + i]
+                        tmp = tmp * op(a[ai + i])
                     }
                     let mut k = i + 1;
                     while k < m {
-                        tmp += a[ai + k] * b[bj + k];
+                        tmp = tmp + op(a[ai + k]) * b[bj + k];
                         k += 1;
                     }
                     b[bj + i] = alpha * tmp;
@@ -833,7 +887,7 @@ pub fn trmm<T: Float + NumAssignOps>(
                 j += 1;
             }
         }
-    } else if trans == 'n' || trans == 'N' {
+    } else if trans == Trans::NoTrans {
         if upper {
             let mut j = n;
             while j >= 1 {
@@ -842,11 +896,11 @@ pub fn trmm<T: Float + NumAssignOps>(
                 let aj = j * lda;
                 let bj = j * ldb;
                 if nounit {
-                    tmp *= a[aj + j]
+                    tmp = tmp * a[aj + j]
                 };
                 let mut i = 0;
                 while i < m {
-                    b[bj + i] *= tmp;
+                    b[bj + i] = b[bj + i] * tmp;
                     i += 1;
                 }
                 let mut k = 0;
@@ -857,7 +911,7 @@ pub fn trmm<T: Float + NumAssignOps>(
                         let mut i = 0;
                         while i < m {
                             let tmp2 = tmp * b[bk + i];
-                            b[bj + i] += tmp2;
+                            b[bj + i] = b[bj + i] + tmp2;
                             i += 1;
                         }
                     }
@@ -871,11 +925,11 @@ pub fn trmm<T: Float + NumAssignOps>(
                 let bj = j * ldb;
                 let mut tmp = alpha;
                 if nounit {
-                    tmp *= a[aj + j]
+                    tmp = tmp * a[aj + j]
                 };
                 let mut i = 0;
                 while i < m {
-                    b[bj + i] *= tmp;
+                    b[bj + i] = b[bj + i] * tmp;
                     i += 1;
                 }
                 let mut k = j + 1;
@@ -886,7 +940,7 @@ pub fn trmm<T: Float + NumAssignOps>(
                         let mut i = 0;
                         while i < m {
                             let tmp2 = tmp * b[bk + i];
-                            b[bj + i] += tmp2;
+                            b[bj + i] = b[bj + i] + tmp2;
                             i += 1;
                         }
                     }
@@ -903,12 +957,12 @@ pub fn trmm<T: Float + NumAssignOps>(
             let mut j = 0;
             while j < k {
                 let bj = j * ldb;
-                if !a[ak + j].is_zero() {
-                    let tmp = alpha * a[ak + j];
+                if !op(a[ak + j]).is_zero() {
+                    let tmp = alpha * op(a[ak + j]);
                     let mut i = 0;
                     while i < m {
                         let tmp2 = tmp * b[bk + i];
-                        b[bj + i] += tmp2;
+                        b[bj + i] = b[bj + i] + tmp2;
                         i += 1;
                     }
                 }
@@ -916,7 +970,7 @@ pub fn trmm<T: Float + NumAssignOps>(
             }
             let mut tmp = alpha;
             if nounit {
-                tmp *= a[ak + k]
+                tmp = tmp * op(a[ak + k])
             };
             if tmp != T::one() {
                 let mut i = 0;
@@ -937,12 +991,12 @@ pub fn trmm<T: Float + NumAssignOps>(
             let mut j = k + 1;
             while j < n {
                 let bj = j * ldb;
-                if !a[ak + j].is_zero() {
-                    let tmp = alpha * a[ak + j];
+                if !op(a[ak + j]).is_zero() {
+                    let tmp = alpha * op(a[ak + j]);
                     let mut i = 0;
                     while i < m {
                         let tmp2 = tmp * b[bk + i];
-                        b[bj + i] += tmp2;
+                        b[bj + i] = b[bj + i] + tmp2;
                         i += 1;
                     }
                 }
@@ -950,17 +1004,18 @@ pub fn trmm<T: Float + NumAssignOps>(
             }
             let mut tmp = alpha;
             if nounit {
-                tmp *= a[ak + k]
+                tmp = tmp * op(a[ak + k])
             };
             if !tmp.is_zero() {
                 let mut i = 0;
                 while i < m {
-                    b[bk + i] *= tmp;
+                    b[bk + i] = b[bk + i] * tmp;
                     i += 1
                 }
             }
         }
     }
+    Ok(())
 }
 
 /// TRSM  solves one of the matrix equations
@@ -969,11 +1024,11 @@ pub fn trmm<T: Float + NumAssignOps>(
 /// op( A ) = A   or   op( A ) = A**T. The matrix X is overwritten on B.
 /// This is [STRSM](http://www.netlib.org/lapack/explore-html/d2/d8b/strsm_8f.html) and [DTRSM](http://www.netlib.org/lapack/explore-html/de/da7/dtrsm_8f.html) comined in one function
 #[inline]
-pub fn trsm<T: Float + NumAssignOps>(
-    side: char,
-    uplo: char,
-    trans: char,
-    diag: char,
+pub fn trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
     m: usize,
     n: usize,
     alpha: T,
@@ -982,291 +1037,61 @@ pub fn trsm<T: Float + NumAssignOps>(
     b: &mut [T],
     ldb: usize,
 ) {
-    let lside = side == 'l' || side == 'L';
-    let nrowa = if lside { m } else { n };
-    let nounit = diag == 'n' || diag == 'N';
-    let upper = uplo == 'u' || uplo == 'U';
-    let mut info = 0;
-    if side != 'l' && side != 'L' && side != 'r' && side != 'R' {
-        info = 1;
-    } else if uplo != 'l' && uplo != 'L' && uplo != 'u' && uplo != 'U' {
-        info = 2;
-    } else if trans != 'c'
-        && trans != 'C'
-        && trans != 'n'
-        && trans != 'N'
-        && trans != 't'
-        && trans != 'T'
-    {
-        info = 3;
-    } else if diag != 'n' && diag != 'N' && diag != 'u' && diag != 'U' {
-        info = 4;
-    } else if lda < max(1, nrowa) {
-        info = 9;
-    } else if ldb < max(1, m) {
-        info = 11;
-    }
-    if info != 0 {
-        panic!("trsm {}", info);
+    if let Err(err) = try_trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        xerbla::report(&err);
     }
+}
+
+/// Checked variant of [`trsm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> Result<(), BlasError> {
+    check_trsm(side, m, n, lda, ldb)?;
 
     if m == 0 || n == 0 {
-        return;
+        return Ok(());
     }
 
+    let ka = if side == Side::Left { m } else { n };
+    extent::matrix("trsm", 8, a, ka, ka, lda)?;
+    extent::matrix("trsm", 10, b, m, n, ldb)?;
+
     if alpha.is_zero() {
         zero(b, ldb, n, m);
-        return;
+        return Ok(());
     }
 
-    if lside {
-        if trans == 'n' || trans == 'N' {
-            if upper {
-                let mut j = 0;
-                while j < n {
-                    let bj = j * ldb;
-                    if !alpha.is_one() {
-                        let mut i = 0;
-                        while i < m {
-                            b[bj + i] *= alpha;
-                            i += 1;
-                        }
-                    }
-                    let mut k = m;
-                    while k >= 1 {
-                        k -= 1;
-                        let ak = k * lda;
-                        if !b[bj + k].is_zero() {
-                            if nounit {
-                                b[bj + k] /= a[ak + k]
-                            };
-                            let mut i = 0;
-                            while i < k {
-                                let tmp = b[bj + k] * a[ak + i];
-                                b[bj + i] -= tmp;
-                                i += 1;
-                            }
-                        }
-                    }
-                    j += 1;
-                }
-            } else {
-                let mut j = 0;
-                while j < n {
-                    let bj = j * ldb;
-                    if !alpha.is_one() {
-                        let mut i = 0;
-                        while i < m {
-                            b[bj + i] *= alpha;
-                            i += 1;
-                        }
-                    }
-                    let mut k = 0;
-                    while k < m {
-                        let ak = k * lda;
-                        if !b[bj + k].is_zero() {
-                            if nounit {
-                                b[bj + k] /= a[ak + k]
-                            };
-                            let mut i = k + 1;
-                            while i < m {
-                                let tmp = b[bj + k] * a[ak + i];
-                                b[bj + i] -= tmp;
-                                i += 1;
-                            }
-                        }
-                        k += 1;
-                    }
-                    j += 1;
-                }
-            }
-        } else if upper {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let mut i = 0;
-                while i < m {
-                    let ai = i * lda;
-                    let mut tmp = alpha * b[bj + i];
-                    let mut k = 0;
-                    while k < i {
-                        tmp -= a[ai + k] * b[bj + k];
-                        k += 1;
-                    }
-                    if nounit {
-                        tmp /= a[ai + i]
-                    };
-                    b[bj + i] = tmp;
-                    i += 1;
-                }
-                j += 1;
-            }
-        } else {
-            let mut j = 0;
-            while j < n {
-                let bj = j * ldb;
-                let mut i = m;
-                while i >= 1 {
-                    i -= 1;
-                    let ai = i * lda;
-                    let mut tmp = alpha * b[bj + i];
-                    let mut k = i + 1;
-                    while k < m {
-                        tmp -= a[ai + k] * b[bj + k];
-                        k += 1;
-                    }
-                    if nounit {
-                        tmp /= a[ai + i]
-                    };
-                    b[bj + i] = tmp;
-                }
-                j += 1;
-            }
-        }
-    } else if trans == 'n' || trans == 'N' {
-        if upper {
-            let mut j = 0;
-            while j < n {
-                let aj = j * lda;
-                let bj = j * ldb;
-                if !alpha.is_one() {
-                    let mut i = 0;
-                    while i < m {
-                        let tmp = alpha * b[bj + i];
-                        b[bj + i] = tmp;
-                        i += 1;
-                    }
-                }
-                let mut k = 0;
-                while k < j {
-                    let bk = k * lda;
-                    if !a[aj + k].is_zero() {
-                        let mut i = 0;
-                        while i < m {
-                            let tmp = a[aj + k] * b[bk + i];
-                            b[bj + i] -= tmp;
-                            i += 1;
-                        }
-                    }
-                    k += 1;
-                }
-                if nounit {
-                    let tmp = T::one() / a[aj + j];
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] *= tmp;
-                        i += 1;
-                    }
-                };
-                j += 1;
-            }
-        } else {
-            let mut j = n;
-            while j >= 1 {
-                j -= 1;
-                let aj = j * ldb;
-                let bj = j * ldb;
-                if !alpha.is_one() {
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] *= alpha;
-                        i += 1;
-                    }
-                }
-                let mut k = j + 1;
-                while k < n {
-                    let bk = k * ldb;
-                    if !a[aj + k].is_zero() {
-                        let mut i = 0;
-                        while i < m {
-                            let tmp = a[aj + k] * b[bk + i];
-                            b[bj + i] -= tmp;
-                            i += 1;
-                        }
-                    }
-                    k += 1;
-                }
-                if nounit {
-                    let tmp = T::one() / a[aj + j];
-                    let mut i = 0;
-                    while i < m {
-                        b[bj + i] *= tmp;
-                        i += 1;
-                    }
-                };
-            }
-        }
-    } else if upper {
-        let mut k = n;
-        while k >= 1 {
-            k -= 1;
-            let ak = k * lda;
-            let bk = k * ldb;
-            if nounit {
-                let tmp = T::one() / a[ak + k];
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] *= tmp;
-                    i += 1;
-                }
-            };
-            let mut j = 0;
-            while j < k {
-                let bj = j * lda;
-                if !a[ak + j].is_zero() {
-                    let tmp = a[ak + j];
-                    let mut i = 0;
-                    while i < m {
-                        let tmp2 = tmp * b[bk + i];
-                        b[bj + i] -= tmp2;
-                        i += 1;
-                    }
-                }
-                j += 1;
-            }
-            if !alpha.is_one() {
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] *= alpha;
-                    i += 1;
-                }
-            }
-        }
-    } else {
-        let mut k = 0;
-        while k < n {
-            let ak = k * lda;
-            let bk = k * ldb;
-            if nounit {
-                let tmp = T::one() / a[ak + k];
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] *= tmp;
-                    i += 1;
-                }
-            };
-            let mut j = k + 1;
-            while j < n {
-                let bj = j * ldb;
-                if !a[ak + j].is_zero() {
-                    let tmp = a[ak + j];
-                    let mut i = 0;
-                    while i < m {
-                        let tmp2 = tmp * b[bk + i];
-                        b[bj + i] -= tmp2;
-                        i += 1;
-                    }
-                }
-                j += 1;
-            }
-            if !alpha.is_one() {
-                let mut i = 0;
-                while i < m {
-                    b[bk + i] *= alpha;
-                    i += 1
-                }
-            }
-            k += 1;
-        }
+    if panels::trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb) {
+        return Ok(());
+    }
+
+    generic::trsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb);
+    Ok(())
+}
+
+/// Checks the leading dimensions of [`trsm`].
+fn check_trsm(side: Side, m: usize, n: usize, lda: usize, ldb: usize) -> Result<(), BlasError> {
+    let nrowa = if side == Side::Left { m } else { n };
+    if lda < max(1, nrowa) {
+        return Err(BlasError::new(
+            "trsm",
+            9,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, m) {
+        return Err(BlasError::new("trsm", 11, "ldb must be at least max(1, m)"));
     }
+    Ok(())
 }
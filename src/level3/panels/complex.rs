@@ -0,0 +1,512 @@
+use super::{checked, off_diagonal, row};
+use crate::level3::complex::{
+    try_gemm, try_hemm, try_her2k, try_herk, try_symm, try_syr2k, try_syrk, try_trmm, try_trsm,
+};
+use crate::{threading, Diag, RealScalar, Side, Trans, Uplo};
+use num_complex::Complex;
+use num_traits::One;
+
+pub(crate) fn gemm<T: RealScalar>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> bool {
+    threading::column_panels(
+        m.saturating_mul(n).saturating_mul(k),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let b = &b[if trans_b == Trans::NoTrans {
+                j * ldb
+            } else {
+                j
+            }..];
+            checked(try_gemm(
+                trans_a, trans_b, m, w, k, alpha, a, lda, b, ldb, beta, c, ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn symm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> bool {
+    symmetric(
+        side,
+        uplo,
+        Trans::Trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+        |m, w, a, b, beta, c| {
+            checked(try_symm(
+                side, uplo, m, w, alpha, a, lda, b, ldb, beta, c, ldc,
+            ))
+        },
+    )
+}
+
+pub(crate) fn hemm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> bool {
+    symmetric(
+        side,
+        uplo,
+        Trans::ConjTrans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+        |m, w, a, b, beta, c| {
+            checked(try_hemm(
+                side, uplo, m, w, alpha, a, lda, b, ldb, beta, c, ldc,
+            ))
+        },
+    )
+}
+
+/// Column panels of SYMM and HEMM, `multiply(m, w, a, b, beta, c)` runs the routine itself on a panel
+/// and `mirror` is the operation that reads the unstored triangle of A from the stored one.
+fn symmetric<T, F>(
+    side: Side,
+    uplo: Uplo,
+    mirror: Trans,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+    multiply: F,
+) -> bool
+where
+    T: RealScalar,
+    F: Fn(usize, usize, &[Complex<T>], &[Complex<T>], Complex<T>, &mut [Complex<T>]) + Sync,
+{
+    let nrowa = if side == Side::Left { m } else { n };
+    threading::column_panels(
+        m.saturating_mul(n).saturating_mul(nrowa),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            if side == Side::Left {
+                multiply(m, w, a, &b[j * ldb..], beta, c);
+                return;
+            }
+            // C(:, J) := alpha*B*A(:, J) + beta*C(:, J), see the real SYMM.
+            let upper = uplo == Uplo::Upper;
+            let (before, after) = if upper {
+                (Trans::NoTrans, mirror)
+            } else {
+                (mirror, Trans::NoTrans)
+            };
+            let a_before = if upper { j * lda } else { j };
+            checked(try_gemm(
+                Trans::NoTrans,
+                before,
+                m,
+                w,
+                j,
+                alpha,
+                b,
+                ldb,
+                &a[a_before..],
+                lda,
+                beta,
+                c,
+                ldc,
+            ));
+            multiply(m, w, &a[j * lda + j..], &b[j * ldb..], Complex::one(), c);
+            let end = j + w;
+            if end < n {
+                let a_after = if upper { end * lda + j } else { j * lda + end };
+                checked(try_gemm(
+                    Trans::NoTrans,
+                    after,
+                    m,
+                    w,
+                    n - end,
+                    alpha,
+                    &b[end * ldb..],
+                    ldb,
+                    &a[a_after..],
+                    lda,
+                    Complex::one(),
+                    c,
+                    ldc,
+                ));
+            }
+        },
+    )
+}
+
+/// The operations of the two operands of the `gemm` computing the off-diagonal part of a rank k update.
+fn operations(trans: Trans, conj: bool) -> (Trans, Trans) {
+    let other = if conj { Trans::ConjTrans } else { Trans::Trans };
+    if trans == Trans::NoTrans {
+        (Trans::NoTrans, other)
+    } else {
+        (other, Trans::NoTrans)
+    }
+}
+
+pub(crate) fn syrk<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> bool {
+    let (trans_a, trans_b) = operations(trans, false);
+    threading::column_panels(
+        n.saturating_mul(n).saturating_mul(k),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let aj = &a[row(trans, j, lda)..];
+            let (start, rows) = off_diagonal(uplo, n, j, w);
+            if rows > 0 {
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha,
+                    &a[row(trans, start, lda)..],
+                    lda,
+                    aj,
+                    lda,
+                    beta,
+                    &mut c[start..],
+                    ldc,
+                ));
+            }
+            checked(try_syrk(
+                uplo,
+                trans,
+                w,
+                k,
+                alpha,
+                aj,
+                lda,
+                beta,
+                &mut c[j..],
+                ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn herk<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[Complex<T>],
+    lda: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> bool {
+    let (trans_a, trans_b) = operations(trans, true);
+    threading::column_panels(
+        n.saturating_mul(n).saturating_mul(k),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let aj = &a[row(trans, j, lda)..];
+            let (start, rows) = off_diagonal(uplo, n, j, w);
+            if rows > 0 {
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    Complex::new(alpha, T::zero()),
+                    &a[row(trans, start, lda)..],
+                    lda,
+                    aj,
+                    lda,
+                    Complex::new(beta, T::zero()),
+                    &mut c[start..],
+                    ldc,
+                ));
+            }
+            checked(try_herk(
+                uplo,
+                trans,
+                w,
+                k,
+                alpha,
+                aj,
+                lda,
+                beta,
+                &mut c[j..],
+                ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn syr2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: Complex<T>,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> bool {
+    let (trans_a, trans_b) = operations(trans, false);
+    threading::column_panels(
+        n.saturating_mul(n).saturating_mul(k).saturating_mul(2),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let aj = &a[row(trans, j, lda)..];
+            let bj = &b[row(trans, j, ldb)..];
+            let (start, rows) = off_diagonal(uplo, n, j, w);
+            if rows > 0 {
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha,
+                    &a[row(trans, start, lda)..],
+                    lda,
+                    bj,
+                    ldb,
+                    beta,
+                    &mut c[start..],
+                    ldc,
+                ));
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha,
+                    &b[row(trans, start, ldb)..],
+                    ldb,
+                    aj,
+                    lda,
+                    Complex::one(),
+                    &mut c[start..],
+                    ldc,
+                ));
+            }
+            checked(try_syr2k(
+                uplo,
+                trans,
+                w,
+                k,
+                alpha,
+                aj,
+                lda,
+                bj,
+                ldb,
+                beta,
+                &mut c[j..],
+                ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn her2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &[Complex<T>],
+    ldb: usize,
+    beta: T,
+    c: &mut [Complex<T>],
+    ldc: usize,
+) -> bool {
+    let (trans_a, trans_b) = operations(trans, true);
+    threading::column_panels(
+        n.saturating_mul(n).saturating_mul(k).saturating_mul(2),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let aj = &a[row(trans, j, lda)..];
+            let bj = &b[row(trans, j, ldb)..];
+            let (start, rows) = off_diagonal(uplo, n, j, w);
+            if rows > 0 {
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha,
+                    &a[row(trans, start, lda)..],
+                    lda,
+                    bj,
+                    ldb,
+                    Complex::new(beta, T::zero()),
+                    &mut c[start..],
+                    ldc,
+                ));
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha.conj(),
+                    &b[row(trans, start, ldb)..],
+                    ldb,
+                    aj,
+                    lda,
+                    Complex::one(),
+                    &mut c[start..],
+                    ldc,
+                ));
+            }
+            checked(try_her2k(
+                uplo,
+                trans,
+                w,
+                k,
+                alpha,
+                aj,
+                lda,
+                bj,
+                ldb,
+                beta,
+                &mut c[j..],
+                ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn trmm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) -> bool {
+    side == Side::Left
+        && threading::column_panels(
+            m.saturating_mul(m).saturating_mul(n),
+            n,
+            b,
+            ldb,
+            |_, w, b| {
+                checked(try_trmm(
+                    side, uplo, trans, diag, m, w, alpha, a, lda, b, ldb,
+                ));
+            },
+        )
+}
+
+pub(crate) fn trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: Complex<T>,
+    a: &[Complex<T>],
+    lda: usize,
+    b: &mut [Complex<T>],
+    ldb: usize,
+) -> bool {
+    side == Side::Left
+        && threading::column_panels(
+            m.saturating_mul(m).saturating_mul(n),
+            n,
+            b,
+            ldb,
+            |_, w, b| {
+                checked(try_trsm(
+                    side, uplo, trans, diag, m, w, alpha, a, lda, b, ldb,
+                ));
+            },
+        )
+}
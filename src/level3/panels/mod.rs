@@ -0,0 +1,355 @@
+//! Splits the level 3 routines into column panels that are computed in parallel, see [`threading`](crate::threading).
+//!
+//! Every function returns false without touching its arguments when the problem should run on the calling thread.
+//! A panel is computed by calling the routine again on the columns of the panel, or for the symmetric updates
+//! on the diagonal block of the panel, with `gemm` covering the rest of the panel.
+//! The arguments have been checked by the caller, so the calls on the panels can't fail.
+use super::{try_gemm, try_symm, try_syr2k, try_syrk, try_trmm, try_trsm};
+use crate::threading::ThreadSafe;
+use crate::{threading, BlasError, Diag, RealScalar, Ring, Side, Trans, Uplo};
+
+pub mod complex;
+
+fn checked(result: Result<(), BlasError>) {
+    result.expect("the arguments of a panel are checked by the caller")
+}
+
+/// The offset of the i-th row of op( A ), where op( A ) is the n by k operand of a symmetric rank k update.
+fn row(trans: Trans, i: usize, lda: usize) -> usize {
+    if trans == Trans::NoTrans {
+        i
+    } else {
+        i * lda
+    }
+}
+
+pub(crate) fn gemm<T: Ring + ThreadSafe + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> bool {
+    threading::column_panels(
+        m.saturating_mul(n).saturating_mul(k),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let b = &b[if trans_b == Trans::NoTrans {
+                j * ldb
+            } else {
+                j
+            }..];
+            checked(try_gemm(
+                trans_a, trans_b, m, w, k, alpha, a, lda, b, ldb, beta, c, ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn symm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> bool {
+    let nrowa = if side == Side::Left { m } else { n };
+    threading::column_panels(
+        m.saturating_mul(n).saturating_mul(nrowa),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            if side == Side::Left {
+                checked(try_symm(
+                    side,
+                    uplo,
+                    m,
+                    w,
+                    alpha,
+                    a,
+                    lda,
+                    &b[j * ldb..],
+                    ldb,
+                    beta,
+                    c,
+                    ldc,
+                ));
+                return;
+            }
+            // C(:, J) := alpha*B*A(:, J) + beta*C(:, J), where only the diagonal block of A(:, J) is symmetric,
+            // the rows of A(:, J) outside the stored triangle are read from the transposed row panel A(J, :).
+            let upper = uplo == Uplo::Upper;
+            let (before, after) = if upper {
+                (Trans::NoTrans, Trans::Trans)
+            } else {
+                (Trans::Trans, Trans::NoTrans)
+            };
+            let a_before = if upper { j * lda } else { j };
+            checked(try_gemm(
+                Trans::NoTrans,
+                before,
+                m,
+                w,
+                j,
+                alpha,
+                b,
+                ldb,
+                &a[a_before..],
+                lda,
+                beta,
+                c,
+                ldc,
+            ));
+            checked(try_symm(
+                side,
+                uplo,
+                m,
+                w,
+                alpha,
+                &a[j * lda + j..],
+                lda,
+                &b[j * ldb..],
+                ldb,
+                T::one(),
+                c,
+                ldc,
+            ));
+            let end = j + w;
+            if end < n {
+                let a_after = if upper { end * lda + j } else { j * lda + end };
+                checked(try_gemm(
+                    Trans::NoTrans,
+                    after,
+                    m,
+                    w,
+                    n - end,
+                    alpha,
+                    &b[end * ldb..],
+                    ldb,
+                    &a[a_after..],
+                    lda,
+                    T::one(),
+                    c,
+                    ldc,
+                ));
+            }
+        },
+    )
+}
+
+pub(crate) fn syrk<T: Ring + ThreadSafe + 'static>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> bool {
+    let (trans_a, trans_b) = if trans == Trans::NoTrans {
+        (Trans::NoTrans, Trans::Trans)
+    } else {
+        (Trans::Trans, Trans::NoTrans)
+    };
+    threading::column_panels(
+        n.saturating_mul(n).saturating_mul(k),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let aj = &a[row(trans, j, lda)..];
+            let (start, rows) = off_diagonal(uplo, n, j, w);
+            if rows > 0 {
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha,
+                    &a[row(trans, start, lda)..],
+                    lda,
+                    aj,
+                    lda,
+                    beta,
+                    &mut c[start..],
+                    ldc,
+                ));
+            }
+            checked(try_syrk(
+                uplo,
+                trans,
+                w,
+                k,
+                alpha,
+                aj,
+                lda,
+                beta,
+                &mut c[j..],
+                ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn syr2k<T: RealScalar>(
+    uplo: Uplo,
+    trans: Trans,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> bool {
+    let (trans_a, trans_b) = if trans == Trans::NoTrans {
+        (Trans::NoTrans, Trans::Trans)
+    } else {
+        (Trans::Trans, Trans::NoTrans)
+    };
+    threading::column_panels(
+        n.saturating_mul(n).saturating_mul(k).saturating_mul(2),
+        n,
+        c,
+        ldc,
+        |j, w, c| {
+            let aj = &a[row(trans, j, lda)..];
+            let bj = &b[row(trans, j, ldb)..];
+            let (start, rows) = off_diagonal(uplo, n, j, w);
+            if rows > 0 {
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha,
+                    &a[row(trans, start, lda)..],
+                    lda,
+                    bj,
+                    ldb,
+                    beta,
+                    &mut c[start..],
+                    ldc,
+                ));
+                checked(try_gemm(
+                    trans_a,
+                    trans_b,
+                    rows,
+                    w,
+                    k,
+                    alpha,
+                    &b[row(trans, start, ldb)..],
+                    ldb,
+                    aj,
+                    lda,
+                    T::one(),
+                    &mut c[start..],
+                    ldc,
+                ));
+            }
+            checked(try_syr2k(
+                uplo,
+                trans,
+                w,
+                k,
+                alpha,
+                aj,
+                lda,
+                bj,
+                ldb,
+                beta,
+                &mut c[j..],
+                ldc,
+            ));
+        },
+    )
+}
+
+pub(crate) fn trmm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> bool {
+    side == Side::Left
+        && threading::column_panels(
+            m.saturating_mul(m).saturating_mul(n),
+            n,
+            b,
+            ldb,
+            |_, w, b| {
+                checked(try_trmm(
+                    side, uplo, trans, diag, m, w, alpha, a, lda, b, ldb,
+                ));
+            },
+        )
+}
+
+pub(crate) fn trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> bool {
+    side == Side::Left
+        && threading::column_panels(
+            m.saturating_mul(m).saturating_mul(n),
+            n,
+            b,
+            ldb,
+            |_, w, b| {
+                checked(try_trsm(
+                    side, uplo, trans, diag, m, w, alpha, a, lda, b, ldb,
+                ));
+            },
+        )
+}
+
+/// The first row and the number of rows of the part of the column panel (j, w) that lies outside its diagonal block.
+fn off_diagonal(uplo: Uplo, n: usize, j: usize, w: usize) -> (usize, usize) {
+    if uplo == Uplo::Upper {
+        (0, j)
+    } else {
+        (j + w, n - j - w)
+    }
+}
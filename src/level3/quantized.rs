@@ -0,0 +1,255 @@
+//! Quantized matrix products of `i8` and `u8` matrices accumulated in `i32`.
+//!
+//! An element `x` with zero point `z` stands for the integer `x - z`. [`gemm`] widens op( A ) and op( B )
+//! to `i32` less their zero points and hands them to [`level3::gemm`](super::gemm), so that the transposes,
+//! leading dimensions, blocking and threads are those of the other matrix products. The sums fit in an `i32` for k up to
+//! [`MAX_K`] whatever the elements and zero points are, and a larger k is rejected. `beta*C` is added to them in `i64`
+//! and saturated to `i32`. [`requantize`] scales the `i32` result back to `i8` with one scale per row,
+//! the output channels when A holds the weights, or one scale per column when B holds them.
+use crate::{extent, xerbla, BlasError, Side, Trans};
+use std::cmp::max;
+
+/// The largest k of [`gemm`], k products of at most 255*255 in absolute value fit in an `i32`.
+pub const MAX_K: usize = 33025;
+
+/// GEMM  performs the quantized matrix-matrix operation
+/// C := op( A - a_zero )*op( B - b_zero ) + beta*C,
+/// where A is an `i8` matrix, B is a `u8` matrix, C is an `i32` matrix and the zero points are subtracted from every element,
+/// with op( A ) an m by k matrix, op( B ) a k by n matrix and C an m by n matrix. `Trans::ConjTrans` is `Trans::Trans`.
+/// k must be at most [`MAX_K`], and the sum with `beta*C` saturates instead of overflowing.
+#[inline]
+pub fn gemm(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    a: &[i8],
+    lda: usize,
+    a_zero: i8,
+    b: &[u8],
+    ldb: usize,
+    b_zero: u8,
+    beta: i32,
+    c: &mut [i32],
+    ldc: usize,
+) {
+    if let Err(err) = try_gemm(
+        trans_a, trans_b, m, n, k, a, lda, a_zero, b, ldb, b_zero, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemm(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    a: &[i8],
+    lda: usize,
+    a_zero: i8,
+    b: &[u8],
+    ldb: usize,
+    b_zero: u8,
+    beta: i32,
+    c: &mut [i32],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let (nrow_a, ncol_a) = if trans_a == Trans::NoTrans {
+        (m, k)
+    } else {
+        (k, m)
+    };
+    let (nrow_b, ncol_b) = if trans_b == Trans::NoTrans {
+        (k, n)
+    } else {
+        (n, k)
+    };
+
+    if k > MAX_K {
+        return Err(BlasError::new(
+            "quantized::gemm",
+            5,
+            "k must be at most 33025 so that the sums fit in an i32",
+        ));
+    }
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "quantized::gemm",
+            7,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrow_b) {
+        return Err(BlasError::new(
+            "quantized::gemm",
+            10,
+            "ldb must be at least max(1, nrowb)",
+        ));
+    }
+    if ldc < max(1, m) {
+        return Err(BlasError::new(
+            "quantized::gemm",
+            14,
+            "ldc must be at least max(1, m)",
+        ));
+    }
+
+    if m == 0 || n == 0 || k == 0 && beta == 1 {
+        return Ok(());
+    }
+
+    extent::matrix("quantized::gemm", 6, a, nrow_a, ncol_a, lda)?;
+    extent::matrix("quantized::gemm", 9, b, nrow_b, ncol_b, ldb)?;
+    extent::matrix("quantized::gemm", 13, c, m, n, ldc)?;
+
+    let wa = widen(nrow_a, ncol_a, a, lda, |x| i32::from(x) - i32::from(a_zero));
+    let wb = widen(nrow_b, ncol_b, b, ldb, |x| i32::from(x) - i32::from(b_zero));
+    if beta == 0 {
+        super::gemm(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            1,
+            &wa,
+            max(1, nrow_a),
+            &wb,
+            max(1, nrow_b),
+            0,
+            c,
+            ldc,
+        );
+        return Ok(());
+    }
+
+    // beta*C may not fit in an i32 even though the product does, so they are added in i64.
+    let mut ab = vec![0; m * n];
+    super::gemm(
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        1,
+        &wa,
+        max(1, nrow_a),
+        &wb,
+        max(1, nrow_b),
+        0,
+        &mut ab,
+        m,
+    );
+    let beta = i64::from(beta);
+    let mut j = 0;
+    while j < n {
+        let cj = j * ldc;
+        let mut i = 0;
+        while i < m {
+            let tmp = i64::from(ab[j * m + i]) + beta * i64::from(c[cj + i]);
+            c[cj + i] = tmp.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+            i += 1;
+        }
+        j += 1;
+    }
+    Ok(())
+}
+
+/// The `rows` by `cols` matrix `a` with leading dimension `lda`, widened by `f` with leading dimension `rows`.
+fn widen<T: Copy, F: Fn(T) -> i32>(
+    rows: usize,
+    cols: usize,
+    a: &[T],
+    lda: usize,
+    f: F,
+) -> Vec<i32> {
+    let mut wide = Vec::with_capacity(rows * cols);
+    let mut j = 0;
+    while j < cols {
+        wide.extend(a[j * lda..j * lda + rows].iter().map(|&x| f(x)));
+        j += 1;
+    }
+    wide
+}
+
+/// REQUANTIZE  scales the m by n `i32` matrix C back to the `i8` matrix Q,
+/// Q(i, j) := min(max(round(scales(i)*C(i, j)) + zero, -128), 127)   when side = `Left`, or
+/// Q(i, j) := min(max(round(scales(j)*C(i, j)) + zero, -128), 127)   when side = `Right`,
+/// so that `scales` holds one scale per row, m of them, or one per column, n of them, and ties are rounded away from zero.
+#[inline]
+pub fn requantize(
+    side: Side,
+    m: usize,
+    n: usize,
+    c: &[i32],
+    ldc: usize,
+    scales: &[f32],
+    zero: i8,
+    q: &mut [i8],
+    ldq: usize,
+) {
+    if let Err(err) = try_requantize(side, m, n, c, ldc, scales, zero, q, ldq) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`requantize`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_requantize(
+    side: Side,
+    m: usize,
+    n: usize,
+    c: &[i32],
+    ldc: usize,
+    scales: &[f32],
+    zero: i8,
+    q: &mut [i8],
+    ldq: usize,
+) -> Result<(), BlasError> {
+    if ldc < max(1, m) {
+        return Err(BlasError::new(
+            "quantized::requantize",
+            5,
+            "ldc must be at least max(1, m)",
+        ));
+    }
+    let left = side == Side::Left;
+    if scales.len() != if left { m } else { n } {
+        return Err(BlasError::new(
+            "quantized::requantize",
+            6,
+            "scales must hold one scale per row, or per column when side is Right",
+        ));
+    }
+    if ldq < max(1, m) {
+        return Err(BlasError::new(
+            "quantized::requantize",
+            9,
+            "ldq must be at least max(1, m)",
+        ));
+    }
+
+    extent::matrix("quantized::requantize", 4, c, m, n, ldc)?;
+    extent::matrix("quantized::requantize", 8, q, m, n, ldq)?;
+
+    let zero = f64::from(zero);
+    let mut j = 0;
+    while j < n {
+        let cj = j * ldc;
+        let qj = j * ldq;
+        let mut i = 0;
+        while i < m {
+            let scale = if left { scales[i] } else { scales[j] };
+            let tmp = (f64::from(scale) * f64::from(c[cj + i])).round() + zero;
+            q[qj + i] = tmp.clamp(-128.0, 127.0) as i8;
+            i += 1;
+        }
+        j += 1;
+    }
+    Ok(())
+}
@@ -10,7 +10,26 @@
 
 extern crate num_complex;
 extern crate num_traits;
+pub mod cblas;
+mod error;
+mod extent;
+#[cfg(any(feature = "cblas", feature = "fortran"))]
+mod ffi;
+#[cfg(feature = "fortran")]
+pub mod fortran;
 pub mod level1;
 pub mod level2;
 pub mod level3;
+pub mod mixed;
+mod scalar;
+mod simd;
+pub mod threading;
+mod types;
 pub mod unstable;
+pub mod view;
+pub mod xerbla;
+
+pub use crate::error::BlasError;
+pub use crate::scalar::{RealScalar, Ring, Scalar};
+pub use crate::types::{Diag, InvalidFlag, Layout, NanPolicy, Side, Trans, Uplo};
+pub use crate::view::{MatMut, MatRef, StridedVec, StridedVecMut};
@@ -0,0 +1,382 @@
+//! Routines whose elements are stored in a narrow type and accumulated in a wider one.
+//!
+//! `f32` is accumulated in `f64`, like SDSDOT and DSDOT, and with the `half` feature `half::f16` and
+//! `half::bf16` are accumulated in `f32`. The elements are widened, the work is done by the routines of
+//! the accumulation type and the results are rounded back to the storage type once. alpha and beta
+//! are given in the accumulation type. Every matrix and vector of `gemm` and `gemv` has its own storage type,
+//! so that e.g. an `f32` A and B update an `f64` C, for which `f64` is its own accumulation type.
+use crate::{extent, level1, level3, xerbla, BlasError, RealScalar, Trans};
+use num_traits::{One, Zero};
+use std::cmp::{max, min};
+
+/// Storage is an element type that is widened to [`Acc`](Storage::Acc) for the arithmetic and narrowed again for the result.
+pub trait Storage: Copy + Send + Sync + 'static {
+    /// The type the products are accumulated in.
+    type Acc: RealScalar;
+
+    /// The value in the accumulation type, which is exact.
+    fn widen(self) -> Self::Acc;
+
+    /// The value rounded to the nearest storage value.
+    fn narrow(acc: Self::Acc) -> Self;
+}
+
+impl Storage for f32 {
+    type Acc = f64;
+
+    #[inline]
+    fn widen(self) -> f64 {
+        f64::from(self)
+    }
+
+    #[inline]
+    fn narrow(acc: f64) -> Self {
+        acc as f32
+    }
+}
+
+impl Storage for f64 {
+    type Acc = f64;
+
+    #[inline]
+    fn widen(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn narrow(acc: f64) -> Self {
+        acc
+    }
+}
+
+macro_rules! half_storage {
+    ($($t:ty),*) => {$(
+        #[cfg(feature = "half")]
+        impl Storage for $t {
+            type Acc = f32;
+
+            #[inline]
+            fn widen(self) -> f32 {
+                self.to_f32()
+            }
+
+            #[inline]
+            fn narrow(acc: f32) -> Self {
+                <$t>::from_f32(acc)
+            }
+        }
+    )*};
+}
+
+half_storage!(::half::f16, ::half::bf16);
+
+/// The index of the first of `n` elements stored with increment `inc`, the last one for a negative increment.
+fn first(n: usize, inc: isize) -> isize {
+    if inc < 0 {
+        (-(n as isize) * inc) + inc
+    } else {
+        0
+    }
+}
+
+/// The `n` elements of `x` stored with increment `incx`, widened into a contiguous vector.
+fn widen_vector<T: Storage>(n: usize, x: &[T], incx: isize) -> Vec<T::Acc> {
+    let mut ix = first(n, incx);
+    let mut wide = Vec::with_capacity(n);
+    let mut i = 0;
+    while i < n {
+        wide.push(x[ix as usize].widen());
+        ix += incx;
+        i += 1;
+    }
+    wide
+}
+
+/// Rounds the contiguous `wide` into the elements of `y` stored with increment `incy`.
+fn narrow_vector<T: Storage>(wide: &[T::Acc], y: &mut [T], incy: isize) {
+    let mut iy = first(wide.len(), incy);
+    for &w in wide {
+        y[iy as usize] = T::narrow(w);
+        iy += incy;
+    }
+}
+
+/// Columns of op( A ) and rows of op( B ) widened at a time by [`gemm`].
+const KC: usize = 256;
+/// Columns of op( B ) and C widened at a time by [`gemm`].
+const NC: usize = 256;
+
+/// Widens the `rows` by `cols` matrix `a` with leading dimension `lda` into `wide` with leading dimension `rows`.
+fn widen_matrix<T: Storage>(wide: &mut Vec<T::Acc>, rows: usize, cols: usize, a: &[T], lda: usize) {
+    wide.clear();
+    let mut j = 0;
+    while j < cols {
+        wide.extend(a[j * lda..j * lda + rows].iter().map(|x| x.widen()));
+        j += 1;
+    }
+}
+
+/// AXPY constant times a vector plus a vector, y := alpha*x + y, where every element of y is rounded once.
+#[inline]
+pub fn axpy<T: Storage>(n: usize, alpha: T::Acc, x: &[T], incx: isize, y: &mut [T], incy: isize) {
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+    let mut ix = first(n, incx);
+    let mut iy = first(n, incy);
+    let mut i = 0;
+    while i < n {
+        let tmp = y[iy as usize].widen() + alpha * x[ix as usize].widen();
+        y[iy as usize] = T::narrow(tmp);
+        ix += incx;
+        iy += incy;
+        i += 1;
+    }
+}
+
+/// DOT forms the dot product of two vectors, accumulated and returned in the accumulation type.
+#[inline]
+pub fn dot<T: Storage>(n: usize, x: &[T], incx: isize, y: &[T], incy: isize) -> T::Acc {
+    let mut dot = T::Acc::zero();
+    let mut ix = first(n, incx);
+    let mut iy = first(n, incy);
+    let mut i = 0;
+    while i < n {
+        dot += x[ix as usize].widen() * y[iy as usize].widen();
+        ix += incx;
+        iy += incy;
+        i += 1;
+    }
+    dot
+}
+
+/// GEMV  performs one of the matrix-vector operations
+/// y := alpha*A*x + beta*y,   or   y := alpha*A**T*x + beta*y,
+/// where A is widened one column at a time and every element of y is rounded once.
+/// x and y are widened whole, which takes m + n elements of the accumulation type.
+#[inline]
+pub fn gemv<A: Storage, X: Storage<Acc = A::Acc>, Y: Storage<Acc = A::Acc>>(
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: A::Acc,
+    a: &[A],
+    lda: usize,
+    x: &[X],
+    incx: isize,
+    beta: A::Acc,
+    y: &mut [Y],
+    incy: isize,
+) {
+    if let Err(err) = try_gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemv`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemv<A: Storage, X: Storage<Acc = A::Acc>, Y: Storage<Acc = A::Acc>>(
+    trans: Trans,
+    m: usize,
+    n: usize,
+    alpha: A::Acc,
+    a: &[A],
+    lda: usize,
+    x: &[X],
+    incx: isize,
+    beta: A::Acc,
+    y: &mut [Y],
+    incy: isize,
+) -> Result<(), BlasError> {
+    if lda < max(1, m) {
+        return Err(BlasError::new("gemv", 6, "lda must be at least max(1, m)"));
+    }
+    if incx == 0 {
+        return Err(BlasError::new("gemv", 8, "incx must not be zero"));
+    }
+    if incy == 0 {
+        return Err(BlasError::new("gemv", 11, "incy must not be zero"));
+    }
+
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
+    }
+
+    let not = trans == Trans::NoTrans;
+    let lenx = if not { n } else { m };
+    let leny = if not { m } else { n };
+    extent::matrix("gemv", 5, a, m, n, lda)?;
+    extent::vector("gemv", 7, x, lenx, incx)?;
+    extent::vector("gemv", 10, y, leny, incy)?;
+
+    let wx = widen_vector(lenx, x, incx);
+    let mut wy = if beta.is_zero() {
+        vec![A::Acc::zero(); leny]
+    } else {
+        let mut wy = widen_vector(leny, y, incy);
+        if !beta.is_one() {
+            wy.iter_mut().for_each(|v| *v *= beta);
+        }
+        wy
+    };
+
+    if !alpha.is_zero() {
+        let mut col = Vec::with_capacity(m);
+        let mut j = 0;
+        while j < n {
+            col.clear();
+            col.extend(a[j * lda..j * lda + m].iter().map(|v| v.widen()));
+            if not {
+                level1::axpy(m, alpha * wx[j], &col, 1, &mut wy, 1);
+            } else {
+                wy[j] += alpha * level1::dot(m, &col, 1, &wx, 1);
+            }
+            j += 1;
+        }
+    }
+    narrow_vector(&wy, y, incy);
+    Ok(())
+}
+
+/// GEMM  performs one of the matrix-matrix operations
+/// C := alpha*op( A )*op( B ) + beta*C,
+/// where the work is done by the blocked and parallel algorithms of the accumulation type
+/// and every element of C is rounded once.
+/// C is widened 256 columns at a time and A and B one 256 deep panel at a time, so the copies take
+/// at most 512*m + 65536 elements of the accumulation type however large n and k are.
+#[inline]
+pub fn gemm<A: Storage, B: Storage<Acc = A::Acc>, C: Storage<Acc = A::Acc>>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: A::Acc,
+    a: &[A],
+    lda: usize,
+    b: &[B],
+    ldb: usize,
+    beta: A::Acc,
+    c: &mut [C],
+    ldc: usize,
+) {
+    if let Err(err) = try_gemm(
+        trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        xerbla::report(&err);
+    }
+}
+
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`] when an argument is illegal.
+#[inline]
+pub fn try_gemm<A: Storage, B: Storage<Acc = A::Acc>, C: Storage<Acc = A::Acc>>(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: A::Acc,
+    a: &[A],
+    lda: usize,
+    b: &[B],
+    ldb: usize,
+    beta: A::Acc,
+    c: &mut [C],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let not_a = trans_a == Trans::NoTrans;
+    let not_b = trans_b == Trans::NoTrans;
+    let (nrow_a, ncol_a) = if not_a { (m, k) } else { (k, m) };
+    let (nrow_b, ncol_b) = if not_b { (k, n) } else { (n, k) };
+
+    if lda < max(1, nrow_a) {
+        return Err(BlasError::new(
+            "gemm",
+            8,
+            "lda must be at least max(1, nrowa)",
+        ));
+    }
+    if ldb < max(1, nrow_b) {
+        return Err(BlasError::new(
+            "gemm",
+            10,
+            "ldb must be at least max(1, nrowb)",
+        ));
+    }
+    if ldc < max(1, m) {
+        return Err(BlasError::new("gemm", 13, "ldc must be at least max(1, m)"));
+    }
+
+    if m == 0 || n == 0 || (alpha.is_zero() || k == 0) && beta.is_one() {
+        return Ok(());
+    }
+
+    extent::matrix("gemm", 7, a, nrow_a, ncol_a, lda)?;
+    extent::matrix("gemm", 9, b, nrow_b, ncol_b, ldb)?;
+    extent::matrix("gemm", 12, c, m, n, ldc)?;
+
+    let zero = A::Acc::zero();
+    let (mut wa, mut wb, mut wc) = (Vec::new(), Vec::new(), Vec::new());
+    let mut jc = 0;
+    while jc < n {
+        let nc = min(NC, n - jc);
+        let cj = &mut c[jc * ldc..];
+        if beta.is_zero() {
+            wc.clear();
+            wc.resize(m * nc, zero);
+        } else {
+            widen_matrix(&mut wc, m, nc, cj, ldc);
+        }
+        if alpha.is_zero() || k == 0 {
+            wc.iter_mut().for_each(|v| *v *= beta);
+        }
+
+        // C accumulates the panels in the accumulation type, so it is still rounded once.
+        let mut pc = 0;
+        while pc < k && !alpha.is_zero() {
+            let kc = min(KC, k - pc);
+            let rows_a = if not_a {
+                widen_matrix(&mut wa, m, kc, &a[pc * lda..], lda);
+                m
+            } else {
+                widen_matrix(&mut wa, kc, m, &a[pc..], lda);
+                kc
+            };
+            let rows_b = if not_b {
+                widen_matrix(&mut wb, kc, nc, &b[jc * ldb + pc..], ldb);
+                kc
+            } else {
+                widen_matrix(&mut wb, nc, kc, &b[pc * ldb + jc..], ldb);
+                nc
+            };
+            level3::gemm(
+                trans_a,
+                trans_b,
+                m,
+                nc,
+                kc,
+                alpha,
+                &wa,
+                rows_a,
+                &wb,
+                rows_b,
+                if pc == 0 { beta } else { A::Acc::one() },
+                &mut wc,
+                m,
+            );
+            pc += kc;
+        }
+
+        let mut j = 0;
+        while j < nc {
+            let mut i = 0;
+            while i < m {
+                cj[j * ldc + i] = C::narrow(wc[j * m + i]);
+                i += 1;
+            }
+            j += 1;
+        }
+        jc += nc;
+    }
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use num_complex::Complex;
+use num_traits::{Float, Num, NumAssignOps, One, Zero};
+use std::ops::Neg;
+
+/// Ring is an element type of the routines that only add and multiply, such as `axpy`, `dot`, `gemv` and `gemm`.
+/// Besides the floating point numbers it covers the integers, rationals or dual numbers, anything that is `Copy`
+/// with a zero, a one, addition and multiplication. `PartialEq` lets the routines skip the work when alpha or beta is zero or one.
+///
+/// It is implemented for the primitive numbers and for `Complex<T>` of a signed `T`, other types implement it with
+/// `impl Ring for Dual {}`. `conj` is what `Trans::ConjTrans` applies to the elements of op( A ).
+pub trait Ring: Zero + One + Copy + PartialEq {
+    /// The complex conjugate, the identity unless overridden.
+    #[inline]
+    fn conj(self) -> Self {
+        self
+    }
+}
+
+macro_rules! ring {
+    ($($t:ty),*) => {$(
+        impl Ring for $t {}
+    )*};
+}
+
+ring!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: Ring + Num + Neg<Output = T>> Ring for Complex<T> {
+    #[inline]
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+}
+
+/// Scalar is an element type of the routines shared by real and complex matrices, implemented for `f32`, `f64`,
+/// `Complex<f32>` and `Complex<f64>`. For a real scalar `abs1` is the absolute value, `conj` comes from [`Ring`].
+pub trait Scalar: Ring + Num + NumAssignOps + Send + Sync + 'static {
+    /// The type of the real and imaginary parts, `Self` for a real scalar.
+    type Real: RealScalar;
+
+    /// The sum of the absolute values of the real and imaginary parts, |Re(z)| + |Im(z)|, see SCABS1 and DCABS1.
+    fn abs1(self) -> Self::Real;
+
+    /// The real part.
+    fn real(self) -> Self::Real;
+
+    /// The scalar whose real part is `re` and whose imaginary part is zero.
+    fn from_real(re: Self::Real) -> Self;
+}
+
+/// RealScalar is a [`Scalar`] that is its own real part, i.e. `f32` and `f64`.
+/// The complex routines take the real type of their elements, e.g. `T` for `Complex<T>`.
+pub trait RealScalar: Scalar<Real = Self> + Float {}
+
+impl<T: Scalar<Real = T> + Float> RealScalar for T {}
+
+macro_rules! real_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            type Real = $t;
+
+            #[inline]
+            fn abs1(self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn real(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn from_real(re: Self) -> Self {
+                re
+            }
+        }
+    )*};
+}
+
+real_scalar!(f32, f64);
+
+impl<T: RealScalar> Scalar for Complex<T> {
+    type Real = T;
+
+    #[inline]
+    fn abs1(self) -> T {
+        self.re.abs() + self.im.abs()
+    }
+
+    #[inline]
+    fn real(self) -> T {
+        self.re
+    }
+
+    #[inline]
+    fn from_real(re: T) -> Self {
+        Complex::new(re, T::zero())
+    }
+}
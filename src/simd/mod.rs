@@ -0,0 +1,88 @@
+//! Explicit SIMD kernels for the f32 and f64 hot paths.
+//!
+//! The instruction set is selected at runtime, AVX-512 is preferred over AVX2 with FMA.
+//! Every kernel reports whether it ran, when `T` is neither f32 nor f64, or the CPU supports none of the instruction sets,
+//! the caller falls back to its portable scalar loop.
+use std::any::TypeId;
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+pub(crate) fn is<T: 'static, U: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<U>()
+}
+
+// The casts are only called after `is::<T, U>()` so T and U are the same type.
+
+pub(crate) unsafe fn scalar<T: 'static + Copy, U: 'static + Copy>(x: T) -> U {
+    *(&x as *const T as *const U)
+}
+
+pub(crate) unsafe fn slice<T: 'static, U: 'static>(x: &[T]) -> &[U] {
+    &*(x as *const [T] as *const [U])
+}
+
+pub(crate) unsafe fn slice_mut<T: 'static, U: 'static>(x: &mut [T]) -> &mut [U] {
+    &mut *(x as *mut [T] as *mut [U])
+}
+
+/// y := alpha*x + y, where x and y have the same length.
+#[allow(unused_variables)]
+pub(crate) fn axpy<T: 'static + Copy>(alpha: T, x: &[T], y: &mut [T]) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        if is::<T, f64>() {
+            return x86_64::daxpy(scalar(alpha), slice(x), slice_mut(y));
+        }
+        if is::<T, f32>() {
+            return x86_64::saxpy(scalar(alpha), slice(x), slice_mut(y));
+        }
+    }
+    false
+}
+
+/// Returns x**T*y, where x and y have the same length.
+#[allow(unused_variables)]
+pub(crate) fn dot<T: 'static + Copy>(x: &[T], y: &[T]) -> Option<T> {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        if is::<T, f64>() {
+            return x86_64::ddot(slice(x), slice(y)).map(|dot| scalar(dot));
+        }
+        if is::<T, f32>() {
+            return x86_64::sdot(slice(x), slice(y)).map(|dot| scalar(dot));
+        }
+    }
+    None
+}
+
+/// x := alpha*x.
+#[allow(unused_variables)]
+pub(crate) fn scal<T: 'static + Copy>(alpha: T, x: &mut [T]) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        if is::<T, f64>() {
+            return x86_64::dscal(scalar(alpha), slice_mut(x));
+        }
+        if is::<T, f32>() {
+            return x86_64::sscal(scalar(alpha), slice_mut(x));
+        }
+    }
+    false
+}
+
+/// The GEMM micro-kernel, acc := A*B for a packed MR by kb micro-panel A and a packed kb by NR micro-panel B,
+/// acc is stored column by column.
+#[allow(unused_variables)]
+pub(crate) fn gemm_kernel<T: 'static + Copy>(kb: usize, a: &[T], b: &[T], acc: &mut [T]) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        if is::<T, f64>() {
+            return x86_64::dgemm_kernel(kb, slice(a), slice(b), slice_mut(acc));
+        }
+        if is::<T, f32>() {
+            return x86_64::sgemm_kernel(kb, slice(a), slice(b), slice_mut(acc));
+        }
+    }
+    false
+}
@@ -0,0 +1,459 @@
+use crate::level3::blocking::{MR, NR};
+use std::arch::x86_64::*;
+
+// The GEMM kernels below hold an MR by NR block of C in registers.
+const _: () = assert!(MR == 8 && NR == 4);
+
+fn avx512() -> bool {
+    is_x86_feature_detected!("avx512f")
+}
+
+fn avx2() -> bool {
+    is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma")
+}
+
+pub(super) fn daxpy(alpha: f64, x: &[f64], y: &mut [f64]) -> bool {
+    assert_eq!(x.len(), y.len());
+    if avx512() {
+        unsafe { daxpy_avx512(alpha, x, y) };
+    } else if avx2() {
+        unsafe { daxpy_avx2(alpha, x, y) };
+    } else {
+        return false;
+    }
+    true
+}
+
+pub(super) fn saxpy(alpha: f32, x: &[f32], y: &mut [f32]) -> bool {
+    assert_eq!(x.len(), y.len());
+    if avx512() {
+        unsafe { saxpy_avx512(alpha, x, y) };
+    } else if avx2() {
+        unsafe { saxpy_avx2(alpha, x, y) };
+    } else {
+        return false;
+    }
+    true
+}
+
+pub(super) fn ddot(x: &[f64], y: &[f64]) -> Option<f64> {
+    assert_eq!(x.len(), y.len());
+    if avx512() {
+        Some(unsafe { ddot_avx512(x, y) })
+    } else if avx2() {
+        Some(unsafe { ddot_avx2(x, y) })
+    } else {
+        None
+    }
+}
+
+pub(super) fn sdot(x: &[f32], y: &[f32]) -> Option<f32> {
+    assert_eq!(x.len(), y.len());
+    if avx512() {
+        Some(unsafe { sdot_avx512(x, y) })
+    } else if avx2() {
+        Some(unsafe { sdot_avx2(x, y) })
+    } else {
+        None
+    }
+}
+
+pub(super) fn dscal(alpha: f64, x: &mut [f64]) -> bool {
+    if avx512() {
+        unsafe { dscal_avx512(alpha, x) };
+    } else if avx2() {
+        unsafe { dscal_avx2(alpha, x) };
+    } else {
+        return false;
+    }
+    true
+}
+
+pub(super) fn sscal(alpha: f32, x: &mut [f32]) -> bool {
+    if avx512() {
+        unsafe { sscal_avx512(alpha, x) };
+    } else if avx2() {
+        unsafe { sscal_avx2(alpha, x) };
+    } else {
+        return false;
+    }
+    true
+}
+
+pub(super) fn dgemm_kernel(kb: usize, a: &[f64], b: &[f64], acc: &mut [f64]) -> bool {
+    assert!(a.len() >= kb * MR && b.len() >= kb * NR && acc.len() >= MR * NR);
+    if avx512() {
+        unsafe { dgemm_kernel_avx512(kb, a, b, acc) };
+    } else if avx2() {
+        unsafe { dgemm_kernel_avx2(kb, a, b, acc) };
+    } else {
+        return false;
+    }
+    true
+}
+
+pub(super) fn sgemm_kernel(kb: usize, a: &[f32], b: &[f32], acc: &mut [f32]) -> bool {
+    assert!(a.len() >= kb * MR && b.len() >= kb * NR && acc.len() >= MR * NR);
+    // MR f32 fits in one AVX2 register, AVX-512 has nothing to add.
+    if avx2() {
+        unsafe { sgemm_kernel_avx2(kb, a, b, acc) };
+        return true;
+    }
+    false
+}
+
+// axpy rounds a*x before adding y, as the scalar loop does, so the results don't depend on the CPU.
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn daxpy_avx2(alpha: f64, x: &[f64], y: &mut [f64]) {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm256_set1_pd(alpha);
+    let mut i = 0;
+    while i + 4 <= n {
+        let ax = _mm256_mul_pd(va, _mm256_loadu_pd(px.add(i)));
+        let vy = _mm256_add_pd(ax, _mm256_loadu_pd(py.add(i)));
+        _mm256_storeu_pd(py.add(i), vy);
+        i += 4;
+    }
+    while i < n {
+        y[i] += alpha * x[i];
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn daxpy_avx512(alpha: f64, x: &[f64], y: &mut [f64]) {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm512_set1_pd(alpha);
+    let mut i = 0;
+    while i + 8 <= n {
+        let ax = _mm512_mul_pd(va, _mm512_loadu_pd(px.add(i)));
+        let vy = _mm512_add_pd(ax, _mm512_loadu_pd(py.add(i)));
+        _mm512_storeu_pd(py.add(i), vy);
+        i += 8;
+    }
+    while i < n {
+        y[i] += alpha * x[i];
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn saxpy_avx2(alpha: f32, x: &[f32], y: &mut [f32]) {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm256_set1_ps(alpha);
+    let mut i = 0;
+    while i + 8 <= n {
+        let ax = _mm256_mul_ps(va, _mm256_loadu_ps(px.add(i)));
+        let vy = _mm256_add_ps(ax, _mm256_loadu_ps(py.add(i)));
+        _mm256_storeu_ps(py.add(i), vy);
+        i += 8;
+    }
+    while i < n {
+        y[i] += alpha * x[i];
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn saxpy_avx512(alpha: f32, x: &[f32], y: &mut [f32]) {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm512_set1_ps(alpha);
+    let mut i = 0;
+    while i + 16 <= n {
+        let ax = _mm512_mul_ps(va, _mm512_loadu_ps(px.add(i)));
+        let vy = _mm512_add_ps(ax, _mm512_loadu_ps(py.add(i)));
+        _mm512_storeu_ps(py.add(i), vy);
+        i += 16;
+    }
+    while i < n {
+        y[i] += alpha * x[i];
+        i += 1;
+    }
+}
+
+// The dot products keep four accumulators to hide the latency of the FMA.
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn ddot_avx2(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc = [_mm256_setzero_pd(); 4];
+    let mut i = 0;
+    while i + 16 <= n {
+        let mut r = 0;
+        while r < 4 {
+            let j = i + 4 * r;
+            acc[r] = _mm256_fmadd_pd(
+                _mm256_loadu_pd(px.add(j)),
+                _mm256_loadu_pd(py.add(j)),
+                acc[r],
+            );
+            r += 1;
+        }
+        i += 16;
+    }
+    while i + 4 <= n {
+        acc[0] = _mm256_fmadd_pd(
+            _mm256_loadu_pd(px.add(i)),
+            _mm256_loadu_pd(py.add(i)),
+            acc[0],
+        );
+        i += 4;
+    }
+    let sum = _mm256_add_pd(_mm256_add_pd(acc[0], acc[1]), _mm256_add_pd(acc[2], acc[3]));
+    let mut lanes = [0.0; 4];
+    _mm256_storeu_pd(lanes.as_mut_ptr(), sum);
+    let mut dot = (lanes[0] + lanes[1]) + (lanes[2] + lanes[3]);
+    while i < n {
+        dot += x[i] * y[i];
+        i += 1;
+    }
+    dot
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn ddot_avx512(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc = [_mm512_setzero_pd(); 4];
+    let mut i = 0;
+    while i + 32 <= n {
+        let mut r = 0;
+        while r < 4 {
+            let j = i + 8 * r;
+            acc[r] = _mm512_fmadd_pd(
+                _mm512_loadu_pd(px.add(j)),
+                _mm512_loadu_pd(py.add(j)),
+                acc[r],
+            );
+            r += 1;
+        }
+        i += 32;
+    }
+    while i + 8 <= n {
+        acc[0] = _mm512_fmadd_pd(
+            _mm512_loadu_pd(px.add(i)),
+            _mm512_loadu_pd(py.add(i)),
+            acc[0],
+        );
+        i += 8;
+    }
+    let sum = _mm512_add_pd(_mm512_add_pd(acc[0], acc[1]), _mm512_add_pd(acc[2], acc[3]));
+    let mut dot = _mm512_reduce_add_pd(sum);
+    while i < n {
+        dot += x[i] * y[i];
+        i += 1;
+    }
+    dot
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sdot_avx2(x: &[f32], y: &[f32]) -> f32 {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc = [_mm256_setzero_ps(); 4];
+    let mut i = 0;
+    while i + 32 <= n {
+        let mut r = 0;
+        while r < 4 {
+            let j = i + 8 * r;
+            acc[r] = _mm256_fmadd_ps(
+                _mm256_loadu_ps(px.add(j)),
+                _mm256_loadu_ps(py.add(j)),
+                acc[r],
+            );
+            r += 1;
+        }
+        i += 32;
+    }
+    while i + 8 <= n {
+        acc[0] = _mm256_fmadd_ps(
+            _mm256_loadu_ps(px.add(i)),
+            _mm256_loadu_ps(py.add(i)),
+            acc[0],
+        );
+        i += 8;
+    }
+    let sum = _mm256_add_ps(_mm256_add_ps(acc[0], acc[1]), _mm256_add_ps(acc[2], acc[3]));
+    let mut lanes = [0.0; 8];
+    _mm256_storeu_ps(lanes.as_mut_ptr(), sum);
+    let mut dot = ((lanes[0] + lanes[1]) + (lanes[2] + lanes[3]))
+        + ((lanes[4] + lanes[5]) + (lanes[6] + lanes[7]));
+    while i < n {
+        dot += x[i] * y[i];
+        i += 1;
+    }
+    dot
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn sdot_avx512(x: &[f32], y: &[f32]) -> f32 {
+    let n = x.len();
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc = [_mm512_setzero_ps(); 4];
+    let mut i = 0;
+    while i + 64 <= n {
+        let mut r = 0;
+        while r < 4 {
+            let j = i + 16 * r;
+            acc[r] = _mm512_fmadd_ps(
+                _mm512_loadu_ps(px.add(j)),
+                _mm512_loadu_ps(py.add(j)),
+                acc[r],
+            );
+            r += 1;
+        }
+        i += 64;
+    }
+    while i + 16 <= n {
+        acc[0] = _mm512_fmadd_ps(
+            _mm512_loadu_ps(px.add(i)),
+            _mm512_loadu_ps(py.add(i)),
+            acc[0],
+        );
+        i += 16;
+    }
+    let sum = _mm512_add_ps(_mm512_add_ps(acc[0], acc[1]), _mm512_add_ps(acc[2], acc[3]));
+    let mut dot = _mm512_reduce_add_ps(sum);
+    while i < n {
+        dot += x[i] * y[i];
+        i += 1;
+    }
+    dot
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dscal_avx2(alpha: f64, x: &mut [f64]) {
+    let n = x.len();
+    let px = x.as_mut_ptr();
+    let va = _mm256_set1_pd(alpha);
+    let mut i = 0;
+    while i + 4 <= n {
+        _mm256_storeu_pd(px.add(i), _mm256_mul_pd(va, _mm256_loadu_pd(px.add(i))));
+        i += 4;
+    }
+    while i < n {
+        x[i] *= alpha;
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn dscal_avx512(alpha: f64, x: &mut [f64]) {
+    let n = x.len();
+    let px = x.as_mut_ptr();
+    let va = _mm512_set1_pd(alpha);
+    let mut i = 0;
+    while i + 8 <= n {
+        _mm512_storeu_pd(px.add(i), _mm512_mul_pd(va, _mm512_loadu_pd(px.add(i))));
+        i += 8;
+    }
+    while i < n {
+        x[i] *= alpha;
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sscal_avx2(alpha: f32, x: &mut [f32]) {
+    let n = x.len();
+    let px = x.as_mut_ptr();
+    let va = _mm256_set1_ps(alpha);
+    let mut i = 0;
+    while i + 8 <= n {
+        _mm256_storeu_ps(px.add(i), _mm256_mul_ps(va, _mm256_loadu_ps(px.add(i))));
+        i += 8;
+    }
+    while i < n {
+        x[i] *= alpha;
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn sscal_avx512(alpha: f32, x: &mut [f32]) {
+    let n = x.len();
+    let px = x.as_mut_ptr();
+    let va = _mm512_set1_ps(alpha);
+    let mut i = 0;
+    while i + 16 <= n {
+        _mm512_storeu_ps(px.add(i), _mm512_mul_ps(va, _mm512_loadu_ps(px.add(i))));
+        i += 16;
+    }
+    while i < n {
+        x[i] *= alpha;
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dgemm_kernel_avx2(kb: usize, a: &[f64], b: &[f64], acc: &mut [f64]) {
+    let (pa, pb, pc) = (a.as_ptr(), b.as_ptr(), acc.as_mut_ptr());
+    let mut c = [[_mm256_setzero_pd(); 2]; NR];
+    let mut p = 0;
+    while p < kb {
+        let a0 = _mm256_loadu_pd(pa.add(p * MR));
+        let a1 = _mm256_loadu_pd(pa.add(p * MR + 4));
+        let mut j = 0;
+        while j < NR {
+            let bj = _mm256_broadcast_sd(&*pb.add(p * NR + j));
+            c[j][0] = _mm256_fmadd_pd(a0, bj, c[j][0]);
+            c[j][1] = _mm256_fmadd_pd(a1, bj, c[j][1]);
+            j += 1;
+        }
+        p += 1;
+    }
+    let mut j = 0;
+    while j < NR {
+        _mm256_storeu_pd(pc.add(j * MR), c[j][0]);
+        _mm256_storeu_pd(pc.add(j * MR + 4), c[j][1]);
+        j += 1;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn dgemm_kernel_avx512(kb: usize, a: &[f64], b: &[f64], acc: &mut [f64]) {
+    let (pa, pb, pc) = (a.as_ptr(), b.as_ptr(), acc.as_mut_ptr());
+    let mut c = [_mm512_setzero_pd(); NR];
+    let mut p = 0;
+    while p < kb {
+        let a0 = _mm512_loadu_pd(pa.add(p * MR));
+        let mut j = 0;
+        while j < NR {
+            c[j] = _mm512_fmadd_pd(a0, _mm512_set1_pd(*pb.add(p * NR + j)), c[j]);
+            j += 1;
+        }
+        p += 1;
+    }
+    let mut j = 0;
+    while j < NR {
+        _mm512_storeu_pd(pc.add(j * MR), c[j]);
+        j += 1;
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sgemm_kernel_avx2(kb: usize, a: &[f32], b: &[f32], acc: &mut [f32]) {
+    let (pa, pb, pc) = (a.as_ptr(), b.as_ptr(), acc.as_mut_ptr());
+    let mut c = [_mm256_setzero_ps(); NR];
+    let mut p = 0;
+    while p < kb {
+        let a0 = _mm256_loadu_ps(pa.add(p * MR));
+        let mut j = 0;
+        while j < NR {
+            c[j] = _mm256_fmadd_ps(a0, _mm256_broadcast_ss(&*pb.add(p * NR + j)), c[j]);
+            j += 1;
+        }
+        p += 1;
+    }
+    let mut j = 0;
+    while j < NR {
+        _mm256_storeu_ps(pc.add(j * MR), c[j]);
+        j += 1;
+    }
+}
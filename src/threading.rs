@@ -0,0 +1,179 @@
+//! Multi-threading of the level 3 routines.
+//!
+//! With the `rayon` feature, large `gemm`, `symm`, `hemm`, `syrk`, `herk`, `syr2k`, `her2k`, `trmm` and `trsm` calls
+//! split C, or B for `trmm` and `trsm`, into column panels that are computed in parallel.
+//! Right-sided `trmm` and `trsm` have dependencies between the columns of B and always run on the calling thread,
+//! as does every call made from inside a rayon worker thread.
+//! Large batches of `gemm_batch`, `trsm_batch` and their strided variants are split between the threads instead,
+//! every problem being computed on one worker thread.
+//! Without the feature every routine runs on the calling thread and only the deterministic mode has an effect.
+//!
+//! By default there is one panel per thread, so the panel boundaries, and with them the rounding of the results,
+//! depend on the number of threads. In deterministic mode the panels have a fixed width and are always split,
+//! one after the other on the calling thread when there is a single thread or inside a rayon worker,
+//! so the results are the same for every number of threads, with or without the feature.
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Width of the panels in deterministic mode.
+const PANEL_WIDTH: usize = 64;
+/// Problems with fewer multiplications than this are not worth splitting.
+const MIN_WORK: usize = 64 * 64 * 64;
+
+/// ThreadSafe is `Send + Sync` with the `rayon` feature, which the level 3 routines need to split their work between threads.
+/// Without the feature it is implemented for every type.
+#[cfg(feature = "rayon")]
+pub trait ThreadSafe: Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync> ThreadSafe for T {}
+
+/// ThreadSafe is `Send + Sync` with the `rayon` feature, which the level 3 routines need to split their work between threads.
+/// Without the feature it is implemented for every type.
+#[cfg(not(feature = "rayon"))]
+pub trait ThreadSafe {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> ThreadSafe for T {}
+
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Sets the number of threads used by the level 3 routines and returns the previous setting.
+/// Zero, the default, uses the global rayon thread pool.
+pub fn set_num_threads(n: usize) -> usize {
+    NUM_THREADS.swap(n, Ordering::SeqCst)
+}
+
+/// Returns the number of threads used by the level 3 routines, always one without the `rayon` feature.
+pub fn num_threads() -> usize {
+    #[cfg(feature = "rayon")]
+    {
+        match NUM_THREADS.load(Ordering::SeqCst) {
+            0 => rayon::current_num_threads(),
+            n => n,
+        }
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        1
+    }
+}
+
+/// Turns deterministic mode on or off and returns the previous setting.
+pub fn set_deterministic(deterministic: bool) -> bool {
+    DETERMINISTIC.swap(deterministic, Ordering::SeqCst)
+}
+
+/// Returns whether deterministic mode is on.
+pub fn deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::SeqCst)
+}
+
+/// Calls `f(j, width, panel)` for the column panels of the n columns of c, where `panel` starts at column j.
+/// The panels are computed in parallel, or one after the other in deterministic mode when there is a single thread to run them on.
+/// Returns false without calling `f` when the problem, with `work` multiplications, should run unsplit on the calling thread.
+pub(crate) fn column_panels<T, F>(work: usize, n: usize, c: &mut [T], ldc: usize, f: F) -> bool
+where
+    T: ThreadSafe,
+    F: Fn(usize, usize, &mut [T]) + ThreadSafe,
+{
+    if work < MIN_WORK {
+        return false;
+    }
+    let threads = if in_worker() { 1 } else { num_threads() };
+    let width = if deterministic() {
+        PANEL_WIDTH
+    } else if threads < 2 {
+        return false;
+    } else {
+        n.div_ceil(threads).div_ceil(4) * 4
+    };
+    if width >= n {
+        return false;
+    }
+
+    let panel = |(p, panel): (usize, &mut [T])| {
+        let j = p * width;
+        if j < n {
+            f(j, width.min(n - j), panel);
+        }
+    };
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        if threads >= 2 {
+            let mut run = || c.par_chunks_mut(width * ldc).enumerate().for_each(panel);
+            match NUM_THREADS.load(Ordering::SeqCst) {
+                0 => run(),
+                n => pool(n).install(run),
+            }
+            return true;
+        }
+    }
+    c.chunks_mut(width * ldc).enumerate().for_each(panel);
+    true
+}
+
+/// Whether the calling thread is a rayon worker, whose calls are never split between threads.
+fn in_worker() -> bool {
+    #[cfg(feature = "rayon")]
+    {
+        rayon::current_thread_index().is_some()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        false
+    }
+}
+
+/// Calls `f` on every item of a batch, in parallel when the batch, with `work` multiplications in total, is worth splitting.
+#[cfg(feature = "rayon")]
+pub(crate) fn for_each<I, F>(work: usize, items: Vec<I>, f: F)
+where
+    I: Send,
+    F: Fn(I) + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    if work < MIN_WORK || items.len() < 2 || in_worker() || num_threads() < 2 {
+        items.into_iter().for_each(f);
+        return;
+    }
+    let run = || items.into_par_iter().for_each(&f);
+    match NUM_THREADS.load(Ordering::SeqCst) {
+        0 => run(),
+        n => pool(n).install(run),
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+pub(crate) fn for_each<I, F>(_work: usize, items: Vec<I>, f: F)
+where
+    F: Fn(I),
+{
+    items.into_iter().for_each(f)
+}
+
+/// Returns a thread pool with n threads, the pool is kept until the number of threads changes.
+#[cfg(feature = "rayon")]
+fn pool(n: usize) -> std::sync::Arc<rayon::ThreadPool> {
+    use std::sync::{Arc, Mutex};
+
+    static POOL: Mutex<Option<(usize, Arc<rayon::ThreadPool>)>> = Mutex::new(None);
+
+    let mut pool = POOL.lock().unwrap_or_else(|err| err.into_inner());
+    match &*pool {
+        Some((threads, pool)) if *threads == n => pool.clone(),
+        _ => {
+            let new = Arc::new(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(n)
+                    .build()
+                    .expect("failed to build the libblas thread pool"),
+            );
+            *pool = Some((n, new.clone()));
+            new
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Trans specifies the operation op( A ) applied to a matrix argument.
+/// This is the TRANS argument of the reference BLAS, where `'N'`, `'T'` and `'C'` maps to `NoTrans`, `Trans` and `ConjTrans`.
+/// For real routines `ConjTrans` is the same as `Trans`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trans {
+    /// op( A ) = A.
+    NoTrans,
+    /// op( A ) = A**T.
+    Trans,
+    /// op( A ) = A**H.
+    ConjTrans,
+}
+
+/// Uplo specifies whether the upper or lower triangular part of a matrix is to be referenced.
+/// This is the UPLO argument of the reference BLAS, where `'U'` and `'L'` maps to `Upper` and `Lower`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Uplo {
+    /// Only the upper triangular part is referenced.
+    Upper,
+    /// Only the lower triangular part is referenced.
+    Lower,
+}
+
+/// Diag specifies whether or not a triangular matrix is unit triangular.
+/// This is the DIAG argument of the reference BLAS, where `'N'` and `'U'` maps to `NonUnit` and `Unit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diag {
+    /// A is not assumed to be unit triangular.
+    NonUnit,
+    /// A is assumed to be unit triangular, the diagonal elements are not referenced.
+    Unit,
+}
+
+/// Side specifies whether a matrix argument appears on the left or the right of the operation.
+/// This is the SIDE argument of the reference BLAS, where `'L'` and `'R'` maps to `Left` and `Right`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// op( A ) appears on the left, e.g. B := alpha*op( A )*B.
+    Left,
+    /// op( A ) appears on the right, e.g. B := alpha*B*op( A ).
+    Right,
+}
+
+/// Layout specifies how the elements of a matrix are stored.
+/// This is the CBLAS_LAYOUT argument of CBLAS, the routines in [`level2`](crate::level2) and [`level3`](crate::level3) only understands `ColMajor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Consecutive elements of a row are contiguous in memory, the leading dimension is the distance between two rows.
+    RowMajor,
+    /// Consecutive elements of a column are contiguous in memory, the leading dimension is the distance between two columns.
+    ColMajor,
+}
+
+/// NanPolicy specifies which element [`level1::iamax_nan`](crate::level1::iamax_nan) and its complex and view variants return when `x` has NaNs.
+/// [`level1::iamax`](crate::level1::iamax) follows the reference BLAS instead, it compares with `>` so a NaN is only returned when it is the first element.
+/// An infinity is larger than every finite value under both policies, the first one is returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// NaNs are skipped, the index of the first maximum of the other elements is returned, or 1 when every element is NaN.
+    Ignore,
+    /// The index of the first NaN is returned, so that a NaN is never hidden by a later maximum.
+    First,
+}
+
+/// InvalidFlag is returned when a `char` can't be converted into one of [`Trans`], [`Uplo`], [`Diag`] or [`Side`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidFlag(pub char);
+
+impl fmt::Display for InvalidFlag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid flag {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidFlag {}
+
+impl TryFrom<char> for Trans {
+    type Error = InvalidFlag;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'n' | 'N' => Ok(Trans::NoTrans),
+            't' | 'T' => Ok(Trans::Trans),
+            'c' | 'C' => Ok(Trans::ConjTrans),
+            _ => Err(InvalidFlag(c)),
+        }
+    }
+}
+
+impl From<Trans> for char {
+    fn from(trans: Trans) -> char {
+        match trans {
+            Trans::NoTrans => 'N',
+            Trans::Trans => 'T',
+            Trans::ConjTrans => 'C',
+        }
+    }
+}
+
+impl TryFrom<char> for Uplo {
+    type Error = InvalidFlag;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'u' | 'U' => Ok(Uplo::Upper),
+            'l' | 'L' => Ok(Uplo::Lower),
+            _ => Err(InvalidFlag(c)),
+        }
+    }
+}
+
+impl From<Uplo> for char {
+    fn from(uplo: Uplo) -> char {
+        match uplo {
+            Uplo::Upper => 'U',
+            Uplo::Lower => 'L',
+        }
+    }
+}
+
+impl TryFrom<char> for Diag {
+    type Error = InvalidFlag;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'n' | 'N' => Ok(Diag::NonUnit),
+            'u' | 'U' => Ok(Diag::Unit),
+            _ => Err(InvalidFlag(c)),
+        }
+    }
+}
+
+impl From<Diag> for char {
+    fn from(diag: Diag) -> char {
+        match diag {
+            Diag::NonUnit => 'N',
+            Diag::Unit => 'U',
+        }
+    }
+}
+
+impl TryFrom<char> for Side {
+    type Error = InvalidFlag;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'l' | 'L' => Ok(Side::Left),
+            'r' | 'R' => Ok(Side::Right),
+            _ => Err(InvalidFlag(c)),
+        }
+    }
+}
+
+impl From<Side> for char {
+    fn from(side: Side) -> char {
+        match side {
+            Side::Left => 'L',
+            Side::Right => 'R',
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::Uplo;
 use num_complex::Complex;
 use num_traits::Float;
 use std::cmp::{max, min};
@@ -8,7 +9,7 @@ pub fn upper_band<T: Float>(
     cols: isize,
     k: isize,
 ) -> Vec<Complex<T>> {
-    band(mat, rows, cols, 'u', k)
+    band(mat, rows, cols, Uplo::Upper, k)
 }
 pub fn lower_band<T: Float>(
     mat: Vec<Complex<T>>,
@@ -16,7 +17,7 @@ pub fn lower_band<T: Float>(
     cols: isize,
     k: isize,
 ) -> Vec<Complex<T>> {
-    band(mat, rows, cols, 'l', k)
+    band(mat, rows, cols, Uplo::Lower, k)
 }
 pub fn pack_upper<T: Float>(
     mat: Vec<Complex<T>>,
@@ -24,7 +25,7 @@ pub fn pack_upper<T: Float>(
     cols: isize,
     k: isize,
 ) -> Vec<Complex<T>> {
-    pack(mat, rows, cols, 'u', k)
+    pack(mat, rows, cols, Uplo::Upper, k)
 }
 pub fn pack_lower<T: Float>(
     mat: Vec<Complex<T>>,
@@ -32,7 +33,7 @@ pub fn pack_lower<T: Float>(
     cols: isize,
     k: isize,
 ) -> Vec<Complex<T>> {
-    pack(mat, rows, cols, 'l', k)
+    pack(mat, rows, cols, Uplo::Lower, k)
 }
 
 pub fn set_lower<T: Float>(mat: &mut Vec<Complex<T>>, rows: isize, cols: isize, value: T) {
@@ -117,7 +118,7 @@ fn band<T: Float>(
     mat: Vec<Complex<T>>,
     rows: isize,
     cols: isize,
-    uplo: char,
+    uplo: Uplo,
     k: isize,
 ) -> Vec<Complex<T>> {
     let row_size = k + 1;
@@ -132,11 +133,23 @@ fn band<T: Float>(
     ];
     let mut j = 1;
     while j <= col_size {
-        let m = if uplo == 'u' { k + 1 - j } else { 1 - j };
+        let m = if uplo == Uplo::Upper {
+            k + 1 - j
+        } else {
+            1 - j
+        };
         let coords = (j - 1) * rows - 1;
         let base = (j - 1) * row_size - 1;
-        let start = if uplo == 'u' { max(1, j - k) } else { j };
-        let stop = if uplo == 'u' { j } else { min(col_size, j + k) };
+        let start = if uplo == Uplo::Upper {
+            max(1, j - k)
+        } else {
+            j
+        };
+        let stop = if uplo == Uplo::Upper {
+            j
+        } else {
+            min(col_size, j + k)
+        };
         let mut i = start;
         while i <= stop {
             data[(base + m + i) as usize] = mat[(coords + i) as usize];
@@ -151,7 +164,7 @@ fn pack<T: Float>(
     mat: Vec<Complex<T>>,
     rows: isize,
     cols: isize,
-    uplo: char,
+    uplo: Uplo,
     k: isize,
 ) -> Vec<Complex<T>> {
     let col_size = cols;
@@ -167,8 +180,16 @@ fn pack<T: Float>(
     let mut j = 1;
     while j <= col_size {
         let coords = (j - 1) * rows - 1;
-        let start = if uplo == 'u' { max(1, j - k) } else { j };
-        let stop = if uplo == 'u' { j } else { min(col_size, j + k) };
+        let start = if uplo == Uplo::Upper {
+            max(1, j - k)
+        } else {
+            j
+        };
+        let stop = if uplo == Uplo::Upper {
+            j
+        } else {
+            min(col_size, j + k)
+        };
         let mut i = start;
         while i <= stop {
             data[cursor] = mat[(coords + i) as usize];
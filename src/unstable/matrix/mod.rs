@@ -1,19 +1,20 @@
+use crate::Uplo;
 use num_traits::Float;
 use std::cmp::{max, min};
 
 pub mod complex;
 
 pub fn upper_band<T: Float>(mat: Vec<T>, rows: isize, cols: isize, k: isize) -> Vec<T> {
-    band(mat, rows, cols, 'u', k)
+    band(mat, rows, cols, Uplo::Upper, k)
 }
 pub fn lower_band<T: Float>(mat: Vec<T>, rows: isize, cols: isize, k: isize) -> Vec<T> {
-    band(mat, rows, cols, 'l', k)
+    band(mat, rows, cols, Uplo::Lower, k)
 }
 pub fn pack_upper<T: Float>(mat: Vec<T>, rows: isize, cols: isize, k: isize) -> Vec<T> {
-    pack(mat, rows, cols, 'u', k)
+    pack(mat, rows, cols, Uplo::Upper, k)
 }
 pub fn pack_lower<T: Float>(mat: Vec<T>, rows: isize, cols: isize, k: isize) -> Vec<T> {
-    pack(mat, rows, cols, 'l', k)
+    pack(mat, rows, cols, Uplo::Lower, k)
 }
 
 pub fn set_lower<T: Float>(mat: &mut Vec<T>, rows: isize, cols: isize, value: T) {
@@ -68,18 +69,30 @@ pub fn slice<T: Float>(
     data
 }
 
-fn band<T: Float>(mat: Vec<T>, rows: isize, cols: isize, uplo: char, k: isize) -> Vec<T> {
+fn band<T: Float>(mat: Vec<T>, rows: isize, cols: isize, uplo: Uplo, k: isize) -> Vec<T> {
     let row_size = k + 1;
     let col_size = cols;
     let size = (row_size * col_size) as usize;
     let mut data = vec![T::zero(); size];
     let mut j = 1;
     while j <= col_size {
-        let m = if uplo == 'u' { k + 1 - j } else { 1 - j };
+        let m = if uplo == Uplo::Upper {
+            k + 1 - j
+        } else {
+            1 - j
+        };
         let coords = (j - 1) * rows - 1;
         let base = (j - 1) * row_size - 1;
-        let start = if uplo == 'u' { max(1, j - k) } else { j };
-        let stop = if uplo == 'u' { j } else { min(col_size, j + k) };
+        let start = if uplo == Uplo::Upper {
+            max(1, j - k)
+        } else {
+            j
+        };
+        let stop = if uplo == Uplo::Upper {
+            j
+        } else {
+            min(col_size, j + k)
+        };
         let mut i = start;
         while i <= stop {
             data[(base + m + i) as usize] = mat[(coords + i) as usize];
@@ -90,7 +103,7 @@ fn band<T: Float>(mat: Vec<T>, rows: isize, cols: isize, uplo: char, k: isize) -
     data
 }
 
-fn pack<T: Float>(mat: Vec<T>, rows: isize, cols: isize, uplo: char, k: isize) -> Vec<T> {
+fn pack<T: Float>(mat: Vec<T>, rows: isize, cols: isize, uplo: Uplo, k: isize) -> Vec<T> {
     let col_size = cols;
     let size = (-(k + 1) * k / 2 + col_size * (k + 1)) as usize;
     let mut data = vec![T::zero(); size];
@@ -98,8 +111,16 @@ fn pack<T: Float>(mat: Vec<T>, rows: isize, cols: isize, uplo: char, k: isize) -
     let mut j = 1;
     while j <= col_size {
         let coords = (j - 1) * rows - 1;
-        let start = if uplo == 'u' { max(1, j - k) } else { j };
-        let stop = if uplo == 'u' { j } else { min(col_size, j + k) };
+        let start = if uplo == Uplo::Upper {
+            max(1, j - k)
+        } else {
+            j
+        };
+        let stop = if uplo == Uplo::Upper {
+            j
+        } else {
+            min(col_size, j + k)
+        };
         let mut i = start;
         while i <= stop {
             data[cursor] = mat[(coords + i) as usize];
@@ -0,0 +1,323 @@
+use super::{ascending, first_max};
+use crate::view::{check, report, same_len, StridedVec, StridedVecMut};
+use crate::{level1, BlasError, NanPolicy};
+use num_complex::Complex;
+use num_traits::{Float, NumAssignOps, Zero};
+
+/// IAMAX of a view, see [`level1::complex::iamax`]. The index is one-based, 0 means that `x` is empty.
+#[inline]
+pub fn iamax<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> usize {
+    if x.stride > 0 {
+        level1::complex::iamax(x.len, x.data, x.stride as usize)
+    } else {
+        first_max(x, |v| level1::complex::cabs1(*v))
+    }
+}
+
+/// IAMAX_NAN of a view, see [`level1::complex::iamax_nan`]. The index is one-based, 0 means that `x` is empty.
+#[inline]
+pub fn iamax_nan<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>, nan: NanPolicy) -> usize {
+    level1::first_max_nan(x.iter().map(|v| level1::complex::cabs1(*v)), nan)
+}
+
+/// IAMAX_NORM of a view, see [`level1::complex::iamax_norm`]. The index is one-based, 0 means that `x` is empty.
+#[inline]
+pub fn iamax_norm<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> usize {
+    if x.stride > 0 {
+        level1::complex::iamax_norm(x.len, x.data, x.stride as usize)
+    } else {
+        first_max(x, |v| v.norm())
+    }
+}
+
+/// IAMIN of a view, see [`level1::complex::iamin`]. The index is one-based, 0 means that `x` is empty.
+#[inline]
+pub fn iamin<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> usize {
+    if x.stride > 0 {
+        level1::complex::iamin(x.len, x.data, x.stride as usize)
+    } else {
+        // The first maximum of -(|Re(.)| + |Im(.)|) is the first minimum of |Re(.)| + |Im(.)|.
+        first_max(x, |v| -level1::complex::cabs1(*v))
+    }
+}
+
+/// AMAX of a view, see [`level1::complex::amax`].
+#[inline]
+pub fn amax<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> T {
+    let (data, incx) = ascending(x);
+    level1::complex::amax(x.len, data, incx)
+}
+
+/// AMIN of a view, see [`level1::complex::amin`].
+#[inline]
+pub fn amin<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> T {
+    let (data, incx) = ascending(x);
+    level1::complex::amin(x.len, data, incx)
+}
+
+/// ASUM of a view, see [`level1::complex::asum`].
+#[inline]
+pub fn asum<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> T {
+    let (data, incx) = ascending(x);
+    level1::complex::asum(x.len, data, incx)
+}
+
+/// SUM of a view, see [`level1::complex::sum`].
+#[inline]
+pub fn sum<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> T {
+    let (data, incx) = ascending(x);
+    level1::complex::sum(x.len, data, incx)
+}
+
+/// NRM2 of a view, see [`level1::complex::nrm2`].
+#[inline]
+pub fn nrm2<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>) -> T {
+    let (data, incx) = ascending(x);
+    level1::complex::nrm2(x.len, data, incx)
+}
+
+/// SCAL of a view, see [`level1::complex::scal`].
+#[inline]
+pub fn scal<T: Float + NumAssignOps>(a: Complex<T>, x: StridedVecMut<Complex<T>>) {
+    let incx = x.stride.unsigned_abs();
+    level1::complex::scal(x.len, a, x.data, incx)
+}
+
+/// SSCAL of a view, see [`level1::complex::sscal`].
+#[inline]
+pub fn sscal<T: Float + NumAssignOps>(a: T, x: StridedVecMut<Complex<T>>) {
+    let incx = x.stride.unsigned_abs();
+    level1::complex::sscal(x.len, a, x.data, incx)
+}
+
+/// AXPY of two views, see [`level1::complex::axpy`].
+#[inline]
+pub fn axpy<T: Float + NumAssignOps>(
+    a: &Complex<T>,
+    x: StridedVec<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+) {
+    report(try_axpy(a, x, y))
+}
+
+/// Checked variant of [`axpy`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_axpy<T: Float + NumAssignOps>(
+    a: &Complex<T>,
+    x: StridedVec<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    same_len("axpy", 3, x.len, y.len)?;
+    level1::complex::axpy(x.len, a, x.data, x.stride, y.data, y.stride);
+    Ok(())
+}
+
+/// AXPBY of two views, see [`level1::complex::axpby`].
+#[inline]
+pub fn axpby<T: Float + NumAssignOps>(
+    a: &Complex<T>,
+    x: StridedVec<Complex<T>>,
+    b: &Complex<T>,
+    y: StridedVecMut<Complex<T>>,
+) {
+    report(try_axpby(a, x, b, y))
+}
+
+/// Checked variant of [`axpby`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_axpby<T: Float + NumAssignOps>(
+    a: &Complex<T>,
+    x: StridedVec<Complex<T>>,
+    b: &Complex<T>,
+    y: StridedVecMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    same_len("axpby", 4, x.len, y.len)?;
+    level1::complex::axpby(x.len, a, x.data, x.stride, b, y.data, y.stride);
+    Ok(())
+}
+
+/// WAXPBY of three views, see [`level1::complex::waxpby`].
+#[inline]
+pub fn waxpby<T: Float + NumAssignOps>(
+    a: &Complex<T>,
+    x: StridedVec<Complex<T>>,
+    b: &Complex<T>,
+    y: StridedVec<Complex<T>>,
+    w: StridedVecMut<Complex<T>>,
+) {
+    report(try_waxpby(a, x, b, y, w))
+}
+
+/// Checked variant of [`waxpby`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_waxpby<T: Float + NumAssignOps>(
+    a: &Complex<T>,
+    x: StridedVec<Complex<T>>,
+    b: &Complex<T>,
+    y: StridedVec<Complex<T>>,
+    w: StridedVecMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    same_len("waxpby", 4, x.len, y.len)?;
+    check(
+        w.len == x.len,
+        "waxpby",
+        5,
+        "x and w must have the same length",
+    )?;
+    level1::complex::waxpby(
+        x.len, a, x.data, x.stride, b, y.data, y.stride, w.data, w.stride,
+    );
+    Ok(())
+}
+
+/// COPY of two views, see [`level1::complex::copy`].
+#[inline]
+pub fn copy<T: Float + NumAssignOps>(x: StridedVec<Complex<T>>, y: StridedVecMut<Complex<T>>) {
+    report(try_copy(x, y))
+}
+
+/// Checked variant of [`copy`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_copy<T: Float + NumAssignOps>(
+    x: StridedVec<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    same_len("copy", 2, x.len, y.len)?;
+    level1::complex::copy(x.len, x.data, x.stride, y.data, y.stride);
+    Ok(())
+}
+
+/// DOTC of two views, see [`level1::complex::dotc`]. Returns zero when the lengths differ and the handler returns.
+#[inline]
+pub fn dotc<T: Float + NumAssignOps>(
+    x: StridedVec<Complex<T>>,
+    y: StridedVec<Complex<T>>,
+) -> Complex<T> {
+    try_dotc(x, y).unwrap_or_else(|err| {
+        report(Err(err));
+        Complex::zero()
+    })
+}
+
+/// Checked variant of [`dotc`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_dotc<T: Float + NumAssignOps>(
+    x: StridedVec<Complex<T>>,
+    y: StridedVec<Complex<T>>,
+) -> Result<Complex<T>, BlasError> {
+    same_len("dotc", 2, x.len, y.len)?;
+    Ok(level1::complex::dotc(
+        x.len, x.data, x.stride, y.data, y.stride,
+    ))
+}
+
+/// DOTU of two views, see [`level1::complex::dotu`]. Returns zero when the lengths differ and the handler returns.
+#[inline]
+pub fn dotu<T: Float + NumAssignOps>(
+    x: StridedVec<Complex<T>>,
+    y: StridedVec<Complex<T>>,
+) -> Complex<T> {
+    try_dotu(x, y).unwrap_or_else(|err| {
+        report(Err(err));
+        Complex::zero()
+    })
+}
+
+/// Checked variant of [`dotu`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_dotu<T: Float + NumAssignOps>(
+    x: StridedVec<Complex<T>>,
+    y: StridedVec<Complex<T>>,
+) -> Result<Complex<T>, BlasError> {
+    same_len("dotu", 2, x.len, y.len)?;
+    Ok(level1::complex::dotu(
+        x.len, x.data, x.stride, y.data, y.stride,
+    ))
+}
+
+/// ROT of two views, see [`level1::complex::rot`].
+#[inline]
+pub fn rot<T: Float + NumAssignOps>(
+    x: StridedVecMut<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+    c: T,
+    s: T,
+) {
+    report(try_rot(x, y, c, s))
+}
+
+/// Checked variant of [`rot`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_rot<T: Float + NumAssignOps>(
+    x: StridedVecMut<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+    c: T,
+    s: T,
+) -> Result<(), BlasError> {
+    same_len("rot", 2, x.len, y.len)?;
+    level1::complex::rot(x.len, x.data, x.stride, y.data, y.stride, c, s);
+    Ok(())
+}
+
+/// CROT of two views, see [`level1::complex::crot`].
+#[inline]
+pub fn crot<T: Float + NumAssignOps>(
+    x: StridedVecMut<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+    c: T,
+    s: Complex<T>,
+) {
+    report(try_crot(x, y, c, s))
+}
+
+/// Checked variant of [`crot`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_crot<T: Float + NumAssignOps>(
+    x: StridedVecMut<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+    c: T,
+    s: Complex<T>,
+) -> Result<(), BlasError> {
+    same_len("crot", 2, x.len, y.len)?;
+    level1::complex::crot(x.len, x.data, x.stride, y.data, y.stride, c, s);
+    Ok(())
+}
+
+/// ROTM of two views, see [`level1::complex::rotm`].
+#[inline]
+pub fn rotm<T: Float + NumAssignOps>(
+    x: StridedVecMut<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+    param: &[Complex<T>],
+) {
+    report(try_rotm(x, y, param))
+}
+
+/// Checked variant of [`rotm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_rotm<T: Float + NumAssignOps>(
+    x: StridedVecMut<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+    param: &[Complex<T>],
+) -> Result<(), BlasError> {
+    same_len("rotm", 2, x.len, y.len)?;
+    level1::complex::rotm(x.len, x.data, x.stride, y.data, y.stride, param);
+    Ok(())
+}
+
+/// SWAP of two views, see [`level1::complex::swap`].
+#[inline]
+pub fn swap<T: Float + NumAssignOps>(x: StridedVecMut<Complex<T>>, y: StridedVecMut<Complex<T>>) {
+    report(try_swap(x, y))
+}
+
+/// Checked variant of [`swap`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_swap<T: Float + NumAssignOps>(
+    x: StridedVecMut<Complex<T>>,
+    y: StridedVecMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    same_len("swap", 2, x.len, y.len)?;
+    level1::complex::swap(x.len, x.data, x.stride, y.data, y.stride);
+    Ok(())
+}
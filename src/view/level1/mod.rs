@@ -0,0 +1,268 @@
+//! The routines of [`level1`] taking [`StridedVec`] and [`StridedVecMut`] views instead of `(n, x, incx)`.
+//!
+//! Routines taking two vectors report to [`xerbla`](crate::xerbla) when their lengths differ, and have a `try_` variant
+//! returning a [`BlasError`] instead. Parameters are numbered as in the view routine, e.g. `y` is parameter 3 of [`axpy`].
+//!
+//! Every routine accepts negative strides. [`asum`], [`nrm2`] and [`scal`] visit the elements of a view with a negative stride
+//! from the lowest address, which only changes the rounding of the sum, and [`iamax`] and [`iamin`] return the index in the order of the view.
+use super::{check, report, same_len, StridedVec, StridedVecMut};
+use crate::{level1, BlasError, NanPolicy, Ring};
+use num_traits::{Float, NumAssignOps};
+
+pub mod complex;
+
+/// The data and positive increment visiting the elements of `x` from the lowest address.
+fn ascending<T>(x: StridedVec<'_, T>) -> (&[T], usize) {
+    (x.data, x.stride.unsigned_abs())
+}
+
+/// The one-based index of the first element of `x` maximizing `f`, or 0 when `x` is empty.
+fn first_max<T, F: Fn(&T) -> R, R: PartialOrd>(x: StridedVec<T>, f: F) -> usize {
+    let mut iter = x.iter().map(f).enumerate();
+    let (mut iamax, mut max) = match iter.next() {
+        Some((_, v)) => (1, v),
+        None => return 0,
+    };
+    for (i, v) in iter {
+        if v > max {
+            iamax = i + 1;
+            max = v;
+        }
+    }
+    iamax
+}
+
+/// IAMAX of a view, see [`level1::iamax`]. The index is one-based, 0 means that `x` is empty.
+#[inline]
+pub fn iamax<T: Float + NumAssignOps>(x: StridedVec<T>) -> usize {
+    if x.stride > 0 {
+        level1::iamax(x.len, x.data, x.stride as usize)
+    } else {
+        first_max(x, |v| v.abs())
+    }
+}
+
+/// IAMAX_NAN of a view, see [`level1::iamax_nan`]. The index is one-based, 0 means that `x` is empty.
+#[inline]
+pub fn iamax_nan<T: Float + NumAssignOps>(x: StridedVec<T>, nan: NanPolicy) -> usize {
+    level1::first_max_nan(x.iter().map(|v| v.abs()), nan)
+}
+
+/// IAMIN of a view, see [`level1::iamin`]. The index is one-based, 0 means that `x` is empty.
+#[inline]
+pub fn iamin<T: Float + NumAssignOps>(x: StridedVec<T>) -> usize {
+    if x.stride > 0 {
+        level1::iamin(x.len, x.data, x.stride as usize)
+    } else {
+        // The first maximum of -|x| is the first minimum of |x|.
+        first_max(x, |v| -v.abs())
+    }
+}
+
+/// AMAX of a view, see [`level1::amax`].
+#[inline]
+pub fn amax<T: Float + NumAssignOps>(x: StridedVec<T>) -> T {
+    let (data, incx) = ascending(x);
+    level1::amax(x.len, data, incx)
+}
+
+/// AMIN of a view, see [`level1::amin`].
+#[inline]
+pub fn amin<T: Float + NumAssignOps>(x: StridedVec<T>) -> T {
+    let (data, incx) = ascending(x);
+    level1::amin(x.len, data, incx)
+}
+
+/// ASUM of a view, see [`level1::asum`].
+#[inline]
+pub fn asum<T: Float + NumAssignOps>(x: StridedVec<T>) -> T {
+    let (data, incx) = ascending(x);
+    level1::asum(x.len, data, incx)
+}
+
+/// SUM of a view, see [`level1::sum`].
+#[inline]
+pub fn sum<T: Ring>(x: StridedVec<T>) -> T {
+    let (data, incx) = ascending(x);
+    level1::sum(x.len, data, incx)
+}
+
+/// NRM2 of a view, see [`level1::nrm2`].
+#[inline]
+pub fn nrm2<T: Float + NumAssignOps>(x: StridedVec<T>) -> T {
+    let (data, incx) = ascending(x);
+    level1::nrm2(x.len, data, incx)
+}
+
+/// SCAL of a view, see [`level1::scal`].
+#[inline]
+pub fn scal<T: Ring + 'static>(a: T, x: StridedVecMut<T>) {
+    let incx = x.stride.unsigned_abs();
+    level1::scal(x.len, a, x.data, incx)
+}
+
+/// AXPY of two views, see [`level1::axpy`].
+#[inline]
+pub fn axpy<T: Ring + 'static>(a: T, x: StridedVec<T>, y: StridedVecMut<T>) {
+    report(try_axpy(a, x, y))
+}
+
+/// Checked variant of [`axpy`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_axpy<T: Ring + 'static>(
+    a: T,
+    x: StridedVec<T>,
+    y: StridedVecMut<T>,
+) -> Result<(), BlasError> {
+    same_len("axpy", 3, x.len, y.len)?;
+    level1::axpy(x.len, a, x.data, x.stride, y.data, y.stride);
+    Ok(())
+}
+
+/// AXPBY of two views, see [`level1::axpby`].
+#[inline]
+pub fn axpby<T: Ring>(a: T, x: StridedVec<T>, b: T, y: StridedVecMut<T>) {
+    report(try_axpby(a, x, b, y))
+}
+
+/// Checked variant of [`axpby`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_axpby<T: Ring>(
+    a: T,
+    x: StridedVec<T>,
+    b: T,
+    y: StridedVecMut<T>,
+) -> Result<(), BlasError> {
+    same_len("axpby", 4, x.len, y.len)?;
+    level1::axpby(x.len, a, x.data, x.stride, b, y.data, y.stride);
+    Ok(())
+}
+
+/// WAXPBY of three views, see [`level1::waxpby`].
+#[inline]
+pub fn waxpby<T: Ring>(a: T, x: StridedVec<T>, b: T, y: StridedVec<T>, w: StridedVecMut<T>) {
+    report(try_waxpby(a, x, b, y, w))
+}
+
+/// Checked variant of [`waxpby`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_waxpby<T: Ring>(
+    a: T,
+    x: StridedVec<T>,
+    b: T,
+    y: StridedVec<T>,
+    w: StridedVecMut<T>,
+) -> Result<(), BlasError> {
+    same_len("waxpby", 4, x.len, y.len)?;
+    check(
+        w.len == x.len,
+        "waxpby",
+        5,
+        "x and w must have the same length",
+    )?;
+    level1::waxpby(
+        x.len, a, x.data, x.stride, b, y.data, y.stride, w.data, w.stride,
+    );
+    Ok(())
+}
+
+/// COPY of two views, see [`level1::copy`].
+#[inline]
+pub fn copy<T: Ring>(x: StridedVec<T>, y: StridedVecMut<T>) {
+    report(try_copy(x, y))
+}
+
+/// Checked variant of [`copy`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_copy<T: Ring>(x: StridedVec<T>, y: StridedVecMut<T>) -> Result<(), BlasError> {
+    same_len("copy", 2, x.len, y.len)?;
+    level1::copy(x.len, x.data, x.stride, y.data, y.stride);
+    Ok(())
+}
+
+/// DOT of two views, see [`level1::dot`]. Returns zero when the lengths differ and the handler returns.
+#[inline]
+pub fn dot<T: Ring + 'static>(x: StridedVec<T>, y: StridedVec<T>) -> T {
+    try_dot(x, y).unwrap_or_else(|err| {
+        report(Err(err));
+        T::zero()
+    })
+}
+
+/// Checked variant of [`dot`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_dot<T: Ring + 'static>(x: StridedVec<T>, y: StridedVec<T>) -> Result<T, BlasError> {
+    same_len("dot", 2, x.len, y.len)?;
+    Ok(level1::dot(x.len, x.data, x.stride, y.data, y.stride))
+}
+
+/// DDOT of two views, see [`level1::ddot`]. Returns `b` when the lengths differ and the handler returns.
+#[inline]
+pub fn ddot<T: Float + NumAssignOps>(b: T, x: StridedVec<T>, y: StridedVec<T>) -> f64 {
+    try_ddot(b, x, y).unwrap_or_else(|err| {
+        report(Err(err));
+        b.to_f64().unwrap()
+    })
+}
+
+/// Checked variant of [`ddot`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_ddot<T: Float + NumAssignOps>(
+    b: T,
+    x: StridedVec<T>,
+    y: StridedVec<T>,
+) -> Result<f64, BlasError> {
+    same_len("ddot", 3, x.len, y.len)?;
+    Ok(level1::ddot(x.len, b, x.data, x.stride, y.data, y.stride))
+}
+
+/// ROT of two views, see [`level1::rot`].
+#[inline]
+pub fn rot<T: Float + NumAssignOps>(x: StridedVecMut<T>, y: StridedVecMut<T>, c: T, s: T) {
+    report(try_rot(x, y, c, s))
+}
+
+/// Checked variant of [`rot`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_rot<T: Float + NumAssignOps>(
+    x: StridedVecMut<T>,
+    y: StridedVecMut<T>,
+    c: T,
+    s: T,
+) -> Result<(), BlasError> {
+    same_len("rot", 2, x.len, y.len)?;
+    level1::rot(x.len, x.data, x.stride, y.data, y.stride, c, s);
+    Ok(())
+}
+
+/// ROTM of two views, see [`level1::rotm`].
+#[inline]
+pub fn rotm<T: Float + NumAssignOps>(x: StridedVecMut<T>, y: StridedVecMut<T>, param: &[T]) {
+    report(try_rotm(x, y, param))
+}
+
+/// Checked variant of [`rotm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_rotm<T: Float + NumAssignOps>(
+    x: StridedVecMut<T>,
+    y: StridedVecMut<T>,
+    param: &[T],
+) -> Result<(), BlasError> {
+    same_len("rotm", 2, x.len, y.len)?;
+    level1::rotm(x.len, x.data, x.stride, y.data, y.stride, param);
+    Ok(())
+}
+
+/// SWAP of two views, see [`level1::swap`].
+#[inline]
+pub fn swap<T: Ring>(x: StridedVecMut<T>, y: StridedVecMut<T>) {
+    report(try_swap(x, y))
+}
+
+/// Checked variant of [`swap`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when the lengths differ.
+#[inline]
+pub fn try_swap<T: Ring>(x: StridedVecMut<T>, y: StridedVecMut<T>) -> Result<(), BlasError> {
+    same_len("swap", 2, x.len, y.len)?;
+    level1::swap(x.len, x.data, x.stride, y.data, y.stride);
+    Ok(())
+}
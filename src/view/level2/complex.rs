@@ -0,0 +1,46 @@
+use crate::view::{check, op_dims, report, MatRef, StridedVec, StridedVecMut};
+use crate::{cblas, BlasError, Trans};
+use num_complex::Complex;
+use num_traits::{Float, NumAssignOps};
+
+/// GEMV of complex views, y := alpha*op( A )*x + beta*y, see [`crate::level2::complex::gemv`].
+#[inline]
+pub fn gemv<T: Float + NumAssignOps>(
+    trans: Trans,
+    alpha: Complex<T>,
+    a: MatRef<Complex<T>>,
+    x: StridedVec<Complex<T>>,
+    beta: Complex<T>,
+    y: StridedVecMut<Complex<T>>,
+) {
+    report(try_gemv(trans, alpha, a, x, beta, y))
+}
+
+/// Checked variant of [`gemv`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_gemv<T: Float + NumAssignOps>(
+    trans: Trans,
+    alpha: Complex<T>,
+    a: MatRef<Complex<T>>,
+    x: StridedVec<Complex<T>>,
+    beta: Complex<T>,
+    y: StridedVecMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    let (m, n) = op_dims(trans, &a);
+    check(
+        x.len == n,
+        "gemv",
+        4,
+        "x must have as many elements as op(a) has columns",
+    )?;
+    check(
+        y.len == m,
+        "gemv",
+        6,
+        "y must have as many elements as op(a) has rows",
+    )?;
+    cblas::level2::complex::try_gemv(
+        a.layout, trans, a.rows, a.cols, alpha, a.data, a.ld, x.data, x.stride, beta, y.data,
+        y.stride,
+    )
+}
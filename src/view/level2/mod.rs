@@ -0,0 +1,190 @@
+//! The most common routines of [`level2`](crate::level2) taking [`MatRef`], [`MatMut`] and strided vector views.
+//!
+//! The dimensions are inferred from the views, and the matrix is passed in its own [`Layout`](crate::Layout)
+//! to the routine of the same name in [`cblas::level2`]. Routines report to [`xerbla`](crate::xerbla) when the
+//! dimensions of the views don't agree, parameters are numbered as in the view routine.
+use super::{check, op_dims, report, MatMut, MatRef, StridedVec, StridedVecMut};
+use crate::{cblas, BlasError, Diag, Ring, Trans, Uplo};
+use num_traits::{Float, NumAssignOps};
+
+pub mod complex;
+
+/// GEMV of views, y := alpha*op( A )*x + beta*y, see [`crate::level2::gemv`].
+#[inline]
+pub fn gemv<T: Ring + 'static>(
+    trans: Trans,
+    alpha: T,
+    a: MatRef<T>,
+    x: StridedVec<T>,
+    beta: T,
+    y: StridedVecMut<T>,
+) {
+    report(try_gemv(trans, alpha, a, x, beta, y))
+}
+
+/// Checked variant of [`gemv`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_gemv<T: Ring + 'static>(
+    trans: Trans,
+    alpha: T,
+    a: MatRef<T>,
+    x: StridedVec<T>,
+    beta: T,
+    y: StridedVecMut<T>,
+) -> Result<(), BlasError> {
+    let (m, n) = op_dims(trans, &a);
+    check(
+        x.len == n,
+        "gemv",
+        4,
+        "x must have as many elements as op(a) has columns",
+    )?;
+    check(
+        y.len == m,
+        "gemv",
+        6,
+        "y must have as many elements as op(a) has rows",
+    )?;
+    cblas::level2::try_gemv(
+        a.layout, trans, a.rows, a.cols, alpha, a.data, a.ld, x.data, x.stride, beta, y.data,
+        y.stride,
+    )
+}
+
+/// GER of views, A := alpha*x*y**T + A, see [`crate::level2::ger`].
+#[inline]
+pub fn ger<T: Ring>(alpha: T, x: StridedVec<T>, y: StridedVec<T>, a: MatMut<T>) {
+    report(try_ger(alpha, x, y, a))
+}
+
+/// Checked variant of [`ger`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_ger<T: Ring>(
+    alpha: T,
+    x: StridedVec<T>,
+    y: StridedVec<T>,
+    a: MatMut<T>,
+) -> Result<(), BlasError> {
+    check(
+        x.len == a.rows,
+        "ger",
+        2,
+        "x must have as many elements as a has rows",
+    )?;
+    check(
+        y.len == a.cols,
+        "ger",
+        3,
+        "y must have as many elements as a has columns",
+    )?;
+    cblas::level2::try_ger(
+        a.layout, a.rows, a.cols, alpha, x.data, x.stride, y.data, y.stride, a.data, a.ld,
+    )
+}
+
+/// SYMV of views, y := alpha*A*x + beta*y, see [`crate::level2::symv`].
+#[inline]
+pub fn symv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    x: StridedVec<T>,
+    beta: T,
+    y: StridedVecMut<T>,
+) {
+    report(try_symv(uplo, alpha, a, x, beta, y))
+}
+
+/// Checked variant of [`symv`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_symv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    x: StridedVec<T>,
+    beta: T,
+    y: StridedVecMut<T>,
+) -> Result<(), BlasError> {
+    check(a.rows == a.cols, "symv", 3, "a must be square")?;
+    check(
+        x.len == a.rows,
+        "symv",
+        4,
+        "x must have as many elements as a has rows",
+    )?;
+    check(
+        y.len == a.rows,
+        "symv",
+        6,
+        "y must have as many elements as a has rows",
+    )?;
+    cblas::level2::try_symv(
+        a.layout, uplo, a.rows, alpha, a.data, a.ld, x.data, x.stride, beta, y.data, y.stride,
+    )
+}
+
+/// TRMV of views, x := op( A )*x, see [`crate::level2::trmv`].
+#[inline]
+pub fn trmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    a: MatRef<T>,
+    x: StridedVecMut<T>,
+) {
+    report(try_trmv(uplo, trans, diag, a, x))
+}
+
+/// Checked variant of [`trmv`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_trmv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    a: MatRef<T>,
+    x: StridedVecMut<T>,
+) -> Result<(), BlasError> {
+    check(a.rows == a.cols, "trmv", 4, "a must be square")?;
+    check(
+        x.len == a.rows,
+        "trmv",
+        5,
+        "x must have as many elements as a has rows",
+    )?;
+    cblas::level2::try_trmv(
+        a.layout, uplo, trans, diag, a.rows, a.data, a.ld, x.data, x.stride,
+    )
+}
+
+/// TRSV of views, solves op( A )*x = b where x holds b on entry, see [`crate::level2::trsv`].
+#[inline]
+pub fn trsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    a: MatRef<T>,
+    x: StridedVecMut<T>,
+) {
+    report(try_trsv(uplo, trans, diag, a, x))
+}
+
+/// Checked variant of [`trsv`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_trsv<T: Float + NumAssignOps>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    a: MatRef<T>,
+    x: StridedVecMut<T>,
+) -> Result<(), BlasError> {
+    check(a.rows == a.cols, "trsv", 4, "a must be square")?;
+    check(
+        x.len == a.rows,
+        "trsv",
+        5,
+        "x must have as many elements as a has rows",
+    )?;
+    cblas::level2::try_trsv(
+        a.layout, uplo, trans, diag, a.rows, a.data, a.ld, x.data, x.stride,
+    )
+}
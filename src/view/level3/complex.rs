@@ -0,0 +1,73 @@
+use super::{check_gemm, check_triangular};
+use crate::view::{in_layout, op_dims, report, uplo_in_layout, MatMut, MatRef};
+use crate::{cblas, BlasError, Diag, RealScalar, Side, Trans, Uplo};
+use num_complex::Complex;
+
+/// GEMM of complex views, C := alpha*op( A )*op( B ) + beta*C, see [`crate::level3::complex::gemm`].
+#[inline]
+pub fn gemm<T: RealScalar>(
+    trans_a: Trans,
+    trans_b: Trans,
+    alpha: Complex<T>,
+    a: MatRef<Complex<T>>,
+    b: MatRef<Complex<T>>,
+    beta: Complex<T>,
+    c: MatMut<Complex<T>>,
+) {
+    report(try_gemm(trans_a, trans_b, alpha, a, b, beta, c))
+}
+
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_gemm<T: RealScalar>(
+    trans_a: Trans,
+    trans_b: Trans,
+    alpha: Complex<T>,
+    a: MatRef<Complex<T>>,
+    b: MatRef<Complex<T>>,
+    beta: Complex<T>,
+    c: MatMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    check_gemm(trans_a, trans_b, &a, &b, &c)?;
+    let k = op_dims(trans_a, &a).1;
+    let (trans_a, a_data) = in_layout(trans_a, &a, c.layout);
+    let (trans_b, b_data) = in_layout(trans_b, &b, c.layout);
+    cblas::level3::complex::try_gemm(
+        c.layout, trans_a, trans_b, c.rows, c.cols, k, alpha, &a_data, a.ld, &b_data, b.ld, beta,
+        c.data, c.ld,
+    )
+}
+
+/// TRSM of complex views, solves op( A )*X = alpha*B or X*op( A ) = alpha*B where B is overwritten by X,
+/// see [`crate::level3::complex::trsm`].
+#[inline]
+pub fn trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    alpha: Complex<T>,
+    a: MatRef<Complex<T>>,
+    b: MatMut<Complex<T>>,
+) {
+    report(try_trsm(side, uplo, trans, diag, alpha, a, b))
+}
+
+/// Checked variant of [`trsm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    alpha: Complex<T>,
+    a: MatRef<Complex<T>>,
+    b: MatMut<Complex<T>>,
+) -> Result<(), BlasError> {
+    check_triangular("trsm", side, &a, &b)?;
+    let uplo = uplo_in_layout(uplo, &a, b.layout);
+    let (trans, a_data) = in_layout(trans, &a, b.layout);
+    cblas::level3::complex::try_trsm(
+        b.layout, side, uplo, trans, diag, b.rows, b.cols, alpha, &a_data, a.ld, b.data, b.ld,
+    )
+}
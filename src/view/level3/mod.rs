@@ -0,0 +1,245 @@
+//! The most common routines of [`level3`](crate::level3) taking [`MatRef`] and [`MatMut`] views.
+//!
+//! The dimensions are inferred from the views, and the matrices are passed in the [`Layout`](crate::Layout)
+//! of the output matrix to the routine of the same name in [`cblas::level3`]. An operand stored in the other layout,
+//! such as a [`MatRef::transpose`], is read as its transpose with `trans` swapped, and `uplo` swapped for a
+//! symmetric or triangular matrix. Only the B of [`symm`] has no `trans` and must have the layout of C.
+//! Routines report to [`xerbla`](crate::xerbla) when the layouts or dimensions of the views don't agree,
+//! parameters are numbered as in the view routine.
+use super::{check, in_layout, op_dims, report, same_layout, uplo_in_layout, MatMut, MatRef};
+use crate::threading::ThreadSafe;
+use crate::{cblas, BlasError, Diag, RealScalar, Ring, Side, Trans, Uplo};
+
+pub mod complex;
+
+/// Checks op( A ) and op( B ) of GEMM against C, `a` is parameter 4.
+fn check_gemm<T, U>(
+    trans_a: Trans,
+    trans_b: Trans,
+    a: &MatRef<T>,
+    b: &MatRef<T>,
+    c: &MatMut<U>,
+) -> Result<(), BlasError> {
+    let (am, ak) = op_dims(trans_a, a);
+    let (bk, bn) = op_dims(trans_b, b);
+    check(am == c.rows, "gemm", 4, "op(a) must have as many rows as c")?;
+    check(
+        bn == c.cols,
+        "gemm",
+        5,
+        "op(b) must have as many columns as c",
+    )?;
+    check(
+        bk == ak,
+        "gemm",
+        5,
+        "op(b) must have as many rows as op(a) has columns",
+    )
+}
+
+/// Checks that A is the triangular matrix of TRMM or TRSM for B, `a` is parameter 6.
+fn check_triangular<T>(
+    routine: &'static str,
+    side: Side,
+    a: &MatRef<T>,
+    b: &MatMut<T>,
+) -> Result<(), BlasError> {
+    let order = match side {
+        Side::Left => b.rows,
+        Side::Right => b.cols,
+    };
+    check(a.rows == a.cols, routine, 6, "a must be square")?;
+    check(
+        a.rows == order,
+        routine,
+        6,
+        "a must have as many rows as b has rows for Side::Left, or columns for Side::Right",
+    )
+}
+
+/// GEMM of views, C := alpha*op( A )*op( B ) + beta*C, see [`crate::level3::gemm`].
+#[inline]
+pub fn gemm<T: Ring + ThreadSafe + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) {
+    report(try_gemm(trans_a, trans_b, alpha, a, b, beta, c))
+}
+
+/// Checked variant of [`gemm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_gemm<T: Ring + ThreadSafe + 'static>(
+    trans_a: Trans,
+    trans_b: Trans,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    check_gemm(trans_a, trans_b, &a, &b, &c)?;
+    let k = op_dims(trans_a, &a).1;
+    let (trans_a, a_data) = in_layout(trans_a, &a, c.layout);
+    let (trans_b, b_data) = in_layout(trans_b, &b, c.layout);
+    cblas::level3::try_gemm(
+        c.layout, trans_a, trans_b, c.rows, c.cols, k, alpha, &a_data, a.ld, &b_data, b.ld, beta,
+        c.data, c.ld,
+    )
+}
+
+/// SYMM of views, C := alpha*A*B + beta*C or C := alpha*B*A + beta*C, see [`crate::level3::symm`].
+#[inline]
+pub fn symm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) {
+    report(try_symm(side, uplo, alpha, a, b, beta, c))
+}
+
+/// Checked variant of [`symm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_symm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let order = match side {
+        Side::Left => c.rows,
+        Side::Right => c.cols,
+    };
+    same_layout("symm", 5, b.layout, c.layout)?;
+    check(a.rows == a.cols, "symm", 4, "a must be square")?;
+    check(
+        a.rows == order,
+        "symm",
+        4,
+        "a must have as many rows as c has rows for Side::Left, or columns for Side::Right",
+    )?;
+    check(
+        (b.rows, b.cols) == (c.rows, c.cols),
+        "symm",
+        5,
+        "b must have the same dimensions as c",
+    )?;
+    let uplo = uplo_in_layout(uplo, &a, c.layout);
+    cblas::level3::try_symm(
+        c.layout, side, uplo, c.rows, c.cols, alpha, a.data, a.ld, b.data, b.ld, beta, c.data, c.ld,
+    )
+}
+
+/// SYRK of views, C := alpha*op( A )*op( A )**T + beta*C, see [`crate::level3::syrk`].
+#[inline]
+pub fn syrk<T: Ring + ThreadSafe + 'static>(
+    uplo: Uplo,
+    trans: Trans,
+    alpha: T,
+    a: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) {
+    report(try_syrk(uplo, trans, alpha, a, beta, c))
+}
+
+/// Checked variant of [`syrk`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_syrk<T: Ring + ThreadSafe + 'static>(
+    uplo: Uplo,
+    trans: Trans,
+    alpha: T,
+    a: MatRef<T>,
+    beta: T,
+    c: MatMut<T>,
+) -> Result<(), BlasError> {
+    let (n, k) = op_dims(trans, &a);
+    check(c.rows == c.cols, "syrk", 6, "c must be square")?;
+    check(n == c.rows, "syrk", 4, "op(a) must have as many rows as c")?;
+    // The update only transposes, ConjTrans is the same as Trans.
+    let trans = match (a.layout == c.layout, trans) {
+        (true, _) => trans,
+        (false, Trans::NoTrans) => Trans::Trans,
+        (false, Trans::Trans) | (false, Trans::ConjTrans) => Trans::NoTrans,
+    };
+    cblas::level3::try_syrk(
+        c.layout, uplo, trans, n, k, alpha, a.data, a.ld, beta, c.data, c.ld,
+    )
+}
+
+/// TRMM of views, B := alpha*op( A )*B or B := alpha*B*op( A ), see [`crate::level3::trmm`].
+#[inline]
+pub fn trmm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatMut<T>,
+) {
+    report(try_trmm(side, uplo, trans, diag, alpha, a, b))
+}
+
+/// Checked variant of [`trmm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_trmm<T: Ring + ThreadSafe + 'static>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatMut<T>,
+) -> Result<(), BlasError> {
+    check_triangular("trmm", side, &a, &b)?;
+    let uplo = uplo_in_layout(uplo, &a, b.layout);
+    let (trans, a_data) = in_layout(trans, &a, b.layout);
+    cblas::level3::try_trmm(
+        b.layout, side, uplo, trans, diag, b.rows, b.cols, alpha, &a_data, a.ld, b.data, b.ld,
+    )
+}
+
+/// TRSM of views, solves op( A )*X = alpha*B or X*op( A ) = alpha*B where B is overwritten by X, see [`crate::level3::trsm`].
+#[inline]
+pub fn trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatMut<T>,
+) {
+    report(try_trsm(side, uplo, trans, diag, alpha, a, b))
+}
+
+/// Checked variant of [`trsm`], returns a [`BlasError`] instead of calling [`xerbla`](crate::xerbla) when an argument is illegal.
+#[inline]
+pub fn try_trsm<T: RealScalar>(
+    side: Side,
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatMut<T>,
+) -> Result<(), BlasError> {
+    check_triangular("trsm", side, &a, &b)?;
+    let uplo = uplo_in_layout(uplo, &a, b.layout);
+    let (trans, a_data) = in_layout(trans, &a, b.layout);
+    cblas::level3::try_trsm(
+        b.layout, side, uplo, trans, diag, b.rows, b.cols, alpha, &a_data, a.ld, b.data, b.ld,
+    )
+}
@@ -0,0 +1,365 @@
+use super::{StridedVec, StridedVecMut};
+use crate::{BlasError, Layout};
+
+/// MatRef is a read-only view of a `rows` by `cols` matrix stored with leading dimension `ld` in `layout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatRef<'a, T> {
+    pub(super) data: &'a [T],
+    pub(super) rows: usize,
+    pub(super) cols: usize,
+    pub(super) ld: usize,
+    pub(super) layout: Layout,
+}
+
+/// MatMut is a mutable view of a `rows` by `cols` matrix stored with leading dimension `ld` in `layout`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MatMut<'a, T> {
+    pub(super) data: &'a mut [T],
+    pub(super) rows: usize,
+    pub(super) cols: usize,
+    pub(super) ld: usize,
+    pub(super) layout: Layout,
+}
+
+/// The lengths of a column and a row of the storage of a `rows` by `cols` matrix in `layout`.
+fn storage(rows: usize, cols: usize, layout: Layout) -> (usize, usize) {
+    match layout {
+        Layout::ColMajor => (rows, cols),
+        Layout::RowMajor => (cols, rows),
+    }
+}
+
+/// Checks that `data_len` elements hold the matrix, `routine` is the constructor.
+fn check_matrix(
+    routine: &'static str,
+    data_len: usize,
+    rows: usize,
+    cols: usize,
+    ld: usize,
+    layout: Layout,
+) -> Result<(), BlasError> {
+    let (inner, outer) = storage(rows, cols, layout);
+    if ld < inner.max(1) {
+        return Err(BlasError::new(
+            routine,
+            4,
+            "ld must be at least the number of rows, or columns for row-major",
+        ));
+    }
+    let required = match (inner, outer) {
+        (0, _) | (_, 0) => Some(0),
+        _ => (outer - 1)
+            .checked_mul(ld)
+            .and_then(|span| span.checked_add(inner)),
+    };
+    match required {
+        Some(required) if required <= data_len => Ok(()),
+        _ => Err(BlasError::new(
+            routine,
+            1,
+            "data must hold (cols - 1) * ld + rows elements, or (rows - 1) * ld + cols for row-major",
+        )),
+    }
+}
+
+/// The index into the data of element `(i, j)`, which may be one past the last row or column.
+fn index(ld: usize, layout: Layout, i: usize, j: usize) -> usize {
+    match layout {
+        Layout::ColMajor => i + j * ld,
+        Layout::RowMajor => i * ld + j,
+    }
+}
+
+fn transposed(layout: Layout) -> Layout {
+    match layout {
+        Layout::ColMajor => Layout::RowMajor,
+        Layout::RowMajor => Layout::ColMajor,
+    }
+}
+
+/// Panics unless the `rows` by `cols` submatrix at `(i, j)` is inside a `m` by `n` matrix.
+fn check_submatrix(m: usize, n: usize, i: usize, j: usize, rows: usize, cols: usize) {
+    let inside = i <= m && rows <= m - i && j <= n && cols <= n - j;
+    assert!(
+        inside,
+        "submatrix ({}, {}) of size {}x{} is out of bounds of a {}x{} matrix",
+        i, j, rows, cols, m, n
+    );
+}
+
+macro_rules! accessors {
+    () => {
+        /// The number of rows.
+        pub fn rows(&self) -> usize {
+            self.rows
+        }
+
+        /// The number of columns.
+        pub fn cols(&self) -> usize {
+            self.cols
+        }
+
+        /// The leading dimension, the distance between two columns, or two rows for row-major.
+        pub fn ld(&self) -> usize {
+            self.ld
+        }
+
+        /// How the elements are stored.
+        pub fn layout(&self) -> Layout {
+            self.layout
+        }
+
+        /// Returns element `(i, j)`, or `None` if it is out of bounds.
+        pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+            if i < self.rows && j < self.cols {
+                Some(&self.data[index(self.ld, self.layout, i, j)])
+            } else {
+                None
+            }
+        }
+
+        /// The stride of a column and of a row.
+        fn strides(&self) -> (isize, isize) {
+            match self.layout {
+                Layout::ColMajor => (1, self.ld as isize),
+                Layout::RowMajor => (self.ld as isize, 1),
+            }
+        }
+
+        /// The offset of `(i, j)` clamped to the data, so that empty views can start past the end.
+        fn start(&self, i: usize, j: usize) -> usize {
+            index(self.ld, self.layout, i, j).min(self.data.len())
+        }
+    };
+}
+
+impl<'a, T> MatRef<'a, T> {
+    /// Creates a view of a `rows` by `cols` matrix stored in `data` with leading dimension `ld`.
+    ///
+    /// # Panics
+    /// Panics when `ld` is smaller than the number of rows, or columns for `Layout::RowMajor`,
+    /// or `data` is too short to hold the matrix, see [`try_new`](Self::try_new).
+    pub fn new(data: &'a [T], rows: usize, cols: usize, ld: usize, layout: Layout) -> Self {
+        match Self::try_new(data, rows, cols, ld, layout) {
+            Ok(a) => a,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Checked variant of [`new`](Self::new), returns a [`BlasError`] instead of panicking.
+    pub fn try_new(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+        layout: Layout,
+    ) -> Result<Self, BlasError> {
+        check_matrix("MatRef::new", data.len(), rows, cols, ld, layout)?;
+        Ok(MatRef {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        })
+    }
+
+    accessors!();
+
+    /// The `rows` by `cols` submatrix whose first element is `(i, j)`.
+    ///
+    /// # Panics
+    /// Panics when the submatrix isn't inside the matrix.
+    pub fn submatrix(&self, i: usize, j: usize, rows: usize, cols: usize) -> MatRef<'a, T> {
+        check_submatrix(self.rows, self.cols, i, j, rows, cols);
+        MatRef {
+            data: &self.data[self.start(i, j)..],
+            rows,
+            cols,
+            ..*self
+        }
+    }
+
+    /// Column `j`.
+    ///
+    /// # Panics
+    /// Panics when `j` is out of bounds.
+    pub fn col(&self, j: usize) -> StridedVec<'a, T> {
+        let MatRef { data, rows, .. } = self.submatrix(0, j, self.rows, 1);
+        StridedVec {
+            data,
+            len: rows,
+            stride: self.strides().0,
+        }
+    }
+
+    /// Row `i`.
+    ///
+    /// # Panics
+    /// Panics when `i` is out of bounds.
+    pub fn row(&self, i: usize) -> StridedVec<'a, T> {
+        let MatRef { data, cols, .. } = self.submatrix(i, 0, 1, self.cols);
+        StridedVec {
+            data,
+            len: cols,
+            stride: self.strides().1,
+        }
+    }
+
+    /// The diagonal `(0, 0)`, `(1, 1)`, ... of the matrix.
+    pub fn diag(&self) -> StridedVec<'a, T> {
+        StridedVec {
+            data: self.data,
+            len: self.rows.min(self.cols),
+            stride: self.ld as isize + 1,
+        }
+    }
+
+    /// The number of elements of the data spanned by the matrix.
+    pub(super) fn extent(&self) -> usize {
+        let (inner, outer) = storage(self.rows, self.cols, self.layout);
+        if inner == 0 || outer == 0 {
+            0
+        } else {
+            (outer - 1) * self.ld + inner
+        }
+    }
+
+    /// The transpose, a view of the same data with the other layout.
+    pub fn transpose(&self) -> MatRef<'a, T> {
+        MatRef {
+            rows: self.cols,
+            cols: self.rows,
+            layout: transposed(self.layout),
+            ..*self
+        }
+    }
+}
+
+impl<'a, T> MatMut<'a, T> {
+    /// Creates a mutable view of a `rows` by `cols` matrix stored in `data` with leading dimension `ld`.
+    ///
+    /// # Panics
+    /// Panics when `ld` is smaller than the number of rows, or columns for `Layout::RowMajor`,
+    /// or `data` is too short to hold the matrix, see [`try_new`](Self::try_new).
+    pub fn new(data: &'a mut [T], rows: usize, cols: usize, ld: usize, layout: Layout) -> Self {
+        match Self::try_new(data, rows, cols, ld, layout) {
+            Ok(a) => a,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Checked variant of [`new`](Self::new), returns a [`BlasError`] instead of panicking.
+    pub fn try_new(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+        layout: Layout,
+    ) -> Result<Self, BlasError> {
+        check_matrix("MatMut::new", data.len(), rows, cols, ld, layout)?;
+        Ok(MatMut {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        })
+    }
+
+    accessors!();
+
+    /// Returns element `(i, j)` mutably, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i < self.rows && j < self.cols {
+            Some(&mut self.data[index(self.ld, self.layout, i, j)])
+        } else {
+            None
+        }
+    }
+
+    /// Reborrows the view as a read-only [`MatRef`].
+    pub fn as_view(&self) -> MatRef<'_, T> {
+        MatRef {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            layout: self.layout,
+        }
+    }
+
+    /// The `rows` by `cols` submatrix whose first element is `(i, j)`.
+    ///
+    /// # Panics
+    /// Panics when the submatrix isn't inside the matrix.
+    pub fn submatrix_mut(&mut self, i: usize, j: usize, rows: usize, cols: usize) -> MatMut<'_, T> {
+        check_submatrix(self.rows, self.cols, i, j, rows, cols);
+        let start = self.start(i, j);
+        MatMut {
+            data: &mut self.data[start..],
+            rows,
+            cols,
+            ld: self.ld,
+            layout: self.layout,
+        }
+    }
+
+    /// Column `j`.
+    ///
+    /// # Panics
+    /// Panics when `j` is out of bounds.
+    pub fn col_mut(&mut self, j: usize) -> StridedVecMut<'_, T> {
+        let stride = self.strides().0;
+        let rows = self.rows;
+        StridedVecMut {
+            data: self.submatrix_mut(0, j, rows, 1).data,
+            len: rows,
+            stride,
+        }
+    }
+
+    /// Row `i`.
+    ///
+    /// # Panics
+    /// Panics when `i` is out of bounds.
+    pub fn row_mut(&mut self, i: usize) -> StridedVecMut<'_, T> {
+        let stride = self.strides().1;
+        let cols = self.cols;
+        StridedVecMut {
+            data: self.submatrix_mut(i, 0, 1, cols).data,
+            len: cols,
+            stride,
+        }
+    }
+
+    /// The diagonal `(0, 0)`, `(1, 1)`, ... of the matrix.
+    pub fn diag_mut(&mut self) -> StridedVecMut<'_, T> {
+        StridedVecMut {
+            len: self.rows.min(self.cols),
+            stride: self.ld as isize + 1,
+            data: self.data,
+        }
+    }
+
+    /// The transpose, a view of the same data with the other layout.
+    pub fn transpose_mut(&mut self) -> MatMut<'_, T> {
+        MatMut {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            ld: self.ld,
+            layout: transposed(self.layout),
+        }
+    }
+
+    /// Turns the view into its transpose, see [`transpose_mut`](Self::transpose_mut).
+    pub fn into_transpose(self) -> MatMut<'a, T> {
+        MatMut {
+            rows: self.cols,
+            cols: self.rows,
+            layout: transposed(self.layout),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Views that carry the dimensions and increments of their data, and the routines that take them.
+//!
+//! A [`StridedVec`] or [`StridedVecMut`] is the `(n, x, incx)` triple of the reference BLAS, checked once when the view is
+//! created instead of on every call. Element `i` of a view with a negative stride is `x[(n - 1 - i) * -incx]`,
+//! the same convention the reference BLAS uses for negative increments.
+//!
+//! A [`MatRef`] or [`MatMut`] is the `(m, n, a, lda)` of a matrix together with its [`Layout`], and can be sliced into
+//! submatrices, rows, columns and diagonals without computing offsets by hand.
+//!
+//! [`level1`] takes views for every routine of [`crate::level1`] that takes a vector,
+//! [`level2`] and [`level3`] take them for the most common routines and infer the dimensions from the views.
+use crate::{xerbla, BlasError, Layout, Ring, Trans, Uplo};
+use std::borrow::Cow;
+
+pub mod level1;
+pub mod level2;
+pub mod level3;
+mod matrix;
+
+pub use self::matrix::{MatMut, MatRef};
+
+/// StridedVec is a read-only view of `len` elements of a slice, `stride` elements apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StridedVec<'a, T> {
+    data: &'a [T],
+    len: usize,
+    stride: isize,
+}
+
+/// StridedVecMut is a mutable view of `len` elements of a slice, `stride` elements apart.
+#[derive(Debug, PartialEq, Eq)]
+pub struct StridedVecMut<'a, T> {
+    data: &'a mut [T],
+    len: usize,
+    stride: isize,
+}
+
+/// Checks that `data_len` elements hold `len` elements `stride` apart, `routine` is the constructor.
+fn check_strided(
+    routine: &'static str,
+    data_len: usize,
+    len: usize,
+    stride: isize,
+) -> Result<(), BlasError> {
+    if stride == 0 {
+        return Err(BlasError::new(routine, 3, "stride must not be zero"));
+    }
+    let required = match len {
+        0 => Some(0),
+        _ => (len - 1)
+            .checked_mul(stride.unsigned_abs())
+            .and_then(|span| span.checked_add(1)),
+    };
+    match required {
+        Some(required) if required <= data_len => Ok(()),
+        _ => Err(BlasError::new(
+            routine,
+            1,
+            "data must hold 1 + (len - 1) * |stride| elements",
+        )),
+    }
+}
+
+/// The index into the data of element `i` of a view of `len` elements.
+fn offset(len: usize, stride: isize, i: usize) -> usize {
+    if stride > 0 {
+        i * stride as usize
+    } else {
+        (len - 1 - i) * stride.unsigned_abs()
+    }
+}
+
+impl<'a, T> StridedVec<'a, T> {
+    /// Creates a view of `len` elements of `data`, `stride` elements apart.
+    ///
+    /// # Panics
+    /// Panics when `stride` is zero or `data` is shorter than `1 + (len - 1) * |stride|`, see [`try_new`](Self::try_new).
+    pub fn new(data: &'a [T], len: usize, stride: isize) -> Self {
+        match Self::try_new(data, len, stride) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Checked variant of [`new`](Self::new), returns a [`BlasError`] instead of panicking.
+    pub fn try_new(data: &'a [T], len: usize, stride: isize) -> Result<Self, BlasError> {
+        check_strided("StridedVec::new", data.len(), len, stride)?;
+        Ok(StridedVec { data, len, stride })
+    }
+
+    /// The number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The distance between two consecutive elements of the view.
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+
+    /// Returns element `i`, or `None` if `i` is out of bounds.
+    pub fn get(&self, i: usize) -> Option<&'a T> {
+        if i < self.len {
+            Some(&self.data[offset(self.len, self.stride, i)])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the elements of the view in order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let StridedVec { data, len, stride } = *self;
+        (0..len).map(move |i| &data[offset(len, stride, i)])
+    }
+}
+
+impl<'a, T> From<&'a [T]> for StridedVec<'a, T> {
+    /// A view of every element of `data`.
+    fn from(data: &'a [T]) -> Self {
+        StridedVec {
+            len: data.len(),
+            data,
+            stride: 1,
+        }
+    }
+}
+
+impl<'a, T> StridedVecMut<'a, T> {
+    /// Creates a mutable view of `len` elements of `data`, `stride` elements apart.
+    ///
+    /// # Panics
+    /// Panics when `stride` is zero or `data` is shorter than `1 + (len - 1) * |stride|`, see [`try_new`](Self::try_new).
+    pub fn new(data: &'a mut [T], len: usize, stride: isize) -> Self {
+        match Self::try_new(data, len, stride) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Checked variant of [`new`](Self::new), returns a [`BlasError`] instead of panicking.
+    pub fn try_new(data: &'a mut [T], len: usize, stride: isize) -> Result<Self, BlasError> {
+        check_strided("StridedVecMut::new", data.len(), len, stride)?;
+        Ok(StridedVecMut { data, len, stride })
+    }
+
+    /// The number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The distance between two consecutive elements of the view.
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+
+    /// Returns element `i`, or `None` if `i` is out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i < self.len {
+            Some(&self.data[offset(self.len, self.stride, i)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns element `i` mutably, or `None` if `i` is out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i < self.len {
+            Some(&mut self.data[offset(self.len, self.stride, i)])
+        } else {
+            None
+        }
+    }
+
+    /// Reborrows the view as a read-only [`StridedVec`].
+    pub fn as_view(&self) -> StridedVec<'_, T> {
+        StridedVec {
+            data: self.data,
+            len: self.len,
+            stride: self.stride,
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for StridedVecMut<'a, T> {
+    /// A mutable view of every element of `data`.
+    fn from(data: &'a mut [T]) -> Self {
+        StridedVecMut {
+            len: data.len(),
+            data,
+            stride: 1,
+        }
+    }
+}
+
+/// Checks that `x` and `y` have the same length, `param` is the position of `y`.
+fn same_len(routine: &'static str, param: usize, x: usize, y: usize) -> Result<(), BlasError> {
+    if x == y {
+        Ok(())
+    } else {
+        Err(BlasError::new(
+            routine,
+            param,
+            "x and y must have the same length",
+        ))
+    }
+}
+
+/// Calls the handler of [`xerbla`] with the error of a `try_` routine.
+fn report(result: Result<(), BlasError>) {
+    if let Err(err) = result {
+        xerbla::report(&err);
+    }
+}
+
+/// Returns an error for parameter `param` unless `ok`.
+fn check(
+    ok: bool,
+    routine: &'static str,
+    param: usize,
+    reason: &'static str,
+) -> Result<(), BlasError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(BlasError::new(routine, param, reason))
+    }
+}
+
+/// Checks that `a`, parameter `param`, which has no `trans` argument, is stored in the same layout as the output matrix.
+fn same_layout(routine: &'static str, param: usize, a: Layout, c: Layout) -> Result<(), BlasError> {
+    check(
+        a == c,
+        routine,
+        param,
+        "a matrix without a trans argument must have the same layout as the output matrix",
+    )
+}
+
+/// The data of `a` read in `layout`, the layout of the output matrix, and the operation that gives op( A ) from it.
+///
+/// A matrix stored in the other layout reads as A**T, so `NoTrans` and `Trans` swap. `ConjTrans` of it would be
+/// the conjugate without a transpose, so the elements are conjugated into a copy that is read with `NoTrans`.
+fn in_layout<'a, T: Ring>(
+    trans: Trans,
+    a: &MatRef<'a, T>,
+    layout: Layout,
+) -> (Trans, Cow<'a, [T]>) {
+    if a.layout == layout {
+        return (trans, Cow::Borrowed(a.data));
+    }
+    match trans {
+        Trans::NoTrans => (Trans::Trans, Cow::Borrowed(a.data)),
+        Trans::Trans => (Trans::NoTrans, Cow::Borrowed(a.data)),
+        Trans::ConjTrans => (
+            Trans::NoTrans,
+            a.data[..a.extent()].iter().map(|x| x.conj()).collect(),
+        ),
+    }
+}
+
+/// The triangle of `a` read in `layout`, the upper triangle of a matrix stored in the other layout is the lower one of A**T.
+fn uplo_in_layout<T>(uplo: Uplo, a: &MatRef<T>, layout: Layout) -> Uplo {
+    match (a.layout == layout, uplo) {
+        (true, _) => uplo,
+        (false, Uplo::Upper) => Uplo::Lower,
+        (false, Uplo::Lower) => Uplo::Upper,
+    }
+}
+
+/// The number of rows and columns of op( A ).
+fn op_dims<T>(trans: Trans, a: &MatRef<T>) -> (usize, usize) {
+    match trans {
+        Trans::NoTrans => (a.rows, a.cols),
+        Trans::Trans | Trans::ConjTrans => (a.cols, a.rows),
+    }
+}
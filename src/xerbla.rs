@@ -0,0 +1,44 @@
+//! XERBLA is the error handler called by the level 2 and level 3 routines when an argument has an illegal value.
+//!
+//! Like `cblas_xerbla` the handler is process-wide and can be replaced with [`set_handler`].
+//! The default handler [`panic`] panics, [`log`] prints the error to stderr the same way the reference XERBLA does.
+//! When the handler returns, the routine returns without touching any of its output arguments.
+//! The `try_` variants of the routines never call the handler.
+
+use crate::BlasError;
+use std::sync::RwLock;
+
+/// Handler receives the error detected by a routine.
+pub type Handler = fn(&BlasError);
+
+static HANDLER: RwLock<Handler> = RwLock::new(panic);
+
+/// Replaces the process-wide handler and returns the previous one.
+pub fn set_handler(handler: Handler) -> Handler {
+    let mut current = HANDLER.write().unwrap_or_else(|err| err.into_inner());
+    std::mem::replace(&mut *current, handler)
+}
+
+/// Returns the current process-wide handler.
+pub fn handler() -> Handler {
+    *HANDLER.read().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Reports `err` to the current handler.
+pub fn report(err: &BlasError) {
+    handler()(err)
+}
+
+/// The default handler, panics with the error message.
+pub fn panic(err: &BlasError) {
+    panic!("{}", err);
+}
+
+/// Prints the error to stderr and returns.
+pub fn log(err: &BlasError) {
+    eprintln!(
+        " ** On entry to {} parameter number {} had an illegal value",
+        err.routine().to_uppercase(),
+        err.param()
+    );
+}
@@ -0,0 +1,430 @@
+use libblas::level3::{self, GemmGroup, TrsmGroup};
+use libblas::{threading, Diag, Side, Trans, Uplo};
+mod utils;
+
+#[test]
+fn gemm_batch() {
+    let mut rng = utils::Random::new(1);
+    threading::set_num_threads(4);
+    // Enough small problems for the batch to be split between the threads.
+    let groups = [
+        GemmGroup {
+            trans_a: Trans::NoTrans,
+            trans_b: Trans::Trans,
+            m: 16,
+            n: 12,
+            k: 20,
+            alpha: 1.5,
+            lda: 17,
+            ldb: 12,
+            beta: 0.0,
+            ldc: 16,
+            size: 50,
+        },
+        GemmGroup {
+            trans_a: Trans::Trans,
+            trans_b: Trans::NoTrans,
+            m: 9,
+            n: 30,
+            k: 7,
+            alpha: -1.0,
+            lda: 7,
+            ldb: 8,
+            beta: 0.5,
+            ldc: 10,
+            size: 40,
+        },
+    ];
+    let (mut a, mut b, mut c) = (Vec::new(), Vec::new(), Vec::new());
+    for g in &groups {
+        for _ in 0..g.size {
+            a.push(rng.reals(g.lda * g.m.max(g.k)));
+            b.push(rng.reals(g.ldb * g.n.max(g.k)));
+            c.push(rng.reals(g.ldc * g.n));
+        }
+    }
+
+    let mut expected = c.clone();
+    let mut p = 0;
+    for g in &groups {
+        for _ in 0..g.size {
+            level3::gemm(
+                g.trans_a,
+                g.trans_b,
+                g.m,
+                g.n,
+                g.k,
+                g.alpha,
+                &a[p],
+                g.lda,
+                &b[p],
+                g.ldb,
+                g.beta,
+                &mut expected[p],
+                g.ldc,
+            );
+            p += 1;
+        }
+    }
+
+    let a: Vec<&[f64]> = a.iter().map(|a| &a[..]).collect();
+    let b: Vec<&[f64]> = b.iter().map(|b| &b[..]).collect();
+    let mut views: Vec<&mut [f64]> = c.iter_mut().map(|c| &mut c[..]).collect();
+    level3::gemm_batch(&groups, &a, &b, &mut views);
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn gemm_batch_strided() {
+    let mut rng = utils::Random::new(2);
+    threading::set_num_threads(4);
+    let (m, n, k, batch) = (12, 10, 14, 100);
+    let (lda, ldb, ldc) = (13, 14, 12);
+    let (stride_b, stride_c) = (ldb * n + 3, ldc * n);
+    // A is shared between the problems.
+    let a = rng.reals(lda * k);
+    let b = rng.reals(stride_b * batch);
+    let mut c = rng.reals(stride_c * batch);
+
+    let mut expected = c.clone();
+    for p in 0..batch {
+        level3::gemm(
+            Trans::NoTrans,
+            Trans::NoTrans,
+            m,
+            n,
+            k,
+            2.0,
+            &a,
+            lda,
+            &b[p * stride_b..],
+            ldb,
+            -1.0,
+            &mut expected[p * stride_c..],
+            ldc,
+        );
+    }
+    level3::gemm_batch_strided(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        m,
+        n,
+        k,
+        2.0,
+        &a,
+        lda,
+        0,
+        &b,
+        ldb,
+        stride_b,
+        -1.0,
+        &mut c,
+        ldc,
+        stride_c,
+        batch,
+    );
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn trsm_batch() {
+    let mut rng = utils::Random::new(3);
+    threading::set_num_threads(4);
+    let groups = [
+        TrsmGroup {
+            side: Side::Left,
+            uplo: Uplo::Upper,
+            trans: Trans::NoTrans,
+            diag: Diag::NonUnit,
+            m: 20,
+            n: 8,
+            alpha: 2.0,
+            lda: 21,
+            ldb: 20,
+            size: 60,
+        },
+        TrsmGroup {
+            side: Side::Right,
+            uplo: Uplo::Lower,
+            trans: Trans::Trans,
+            diag: Diag::Unit,
+            m: 6,
+            n: 15,
+            alpha: 1.0,
+            lda: 15,
+            ldb: 7,
+            size: 30,
+        },
+    ];
+    let (mut a, mut b) = (Vec::new(), Vec::new());
+    for g in &groups {
+        let nrowa = if g.side == Side::Left { g.m } else { g.n };
+        for _ in 0..g.size {
+            a.push(rng.triangular(nrowa, g.lda));
+            b.push(rng.reals(g.ldb * g.n));
+        }
+    }
+
+    let mut expected = b.clone();
+    let mut p = 0;
+    for g in &groups {
+        for _ in 0..g.size {
+            level3::trsm(
+                g.side,
+                g.uplo,
+                g.trans,
+                g.diag,
+                g.m,
+                g.n,
+                g.alpha,
+                &a[p],
+                g.lda,
+                &mut expected[p],
+                g.ldb,
+            );
+            p += 1;
+        }
+    }
+
+    let a: Vec<&[f64]> = a.iter().map(|a| &a[..]).collect();
+    let mut views: Vec<&mut [f64]> = b.iter_mut().map(|b| &mut b[..]).collect();
+    level3::trsm_batch(&groups, &a, &mut views);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn trsm_batch_strided() {
+    let mut rng = utils::Random::new(4);
+    threading::set_num_threads(4);
+    let (m, n, batch) = (24, 6, 80);
+    let (lda, ldb) = (24, 25);
+    let (stride_a, stride_b) = (lda * m, ldb * n);
+    let a: Vec<f64> = (0..batch).flat_map(|_| rng.triangular(m, lda)).collect();
+    let mut b = rng.reals(stride_b * batch);
+
+    let mut expected = b.clone();
+    for p in 0..batch {
+        level3::trsm(
+            Side::Left,
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            m,
+            n,
+            0.5,
+            &a[p * stride_a..],
+            lda,
+            &mut expected[p * stride_b..],
+            ldb,
+        );
+    }
+    level3::trsm_batch_strided(
+        Side::Left,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        m,
+        n,
+        0.5,
+        &a,
+        lda,
+        stride_a,
+        &mut b,
+        ldb,
+        stride_b,
+        batch,
+    );
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn illegal_arguments() {
+    let group = GemmGroup {
+        trans_a: Trans::NoTrans,
+        trans_b: Trans::NoTrans,
+        m: 2,
+        n: 2,
+        k: 2,
+        alpha: 1.0,
+        lda: 2,
+        ldb: 2,
+        beta: 0.0,
+        ldc: 2,
+        size: 2,
+    };
+    let x = vec![1.0; 4];
+    let (mut c0, mut c1) = (vec![7.0; 4], vec![7.0; 4]);
+
+    let err =
+        level3::try_gemm_batch(&[group], &[&x], &[&x, &x], &mut [&mut c0, &mut c1]).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm_batch", 2));
+
+    // The second group is illegal, so the first one isn't computed either.
+    let illegal = GemmGroup {
+        ldc: 1,
+        size: 0,
+        ..group
+    };
+    let err = level3::try_gemm_batch(
+        &[group, illegal],
+        &[&x, &x],
+        &[&x, &x],
+        &mut [&mut c0, &mut c1],
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm", 13));
+    assert_eq!((c0, c1), (vec![7.0; 4], vec![7.0; 4]));
+
+    let mut c = vec![0.0; 8];
+    let err = level3::try_gemm_batch_strided(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &x,
+        2,
+        0,
+        &x,
+        2,
+        0,
+        0.0,
+        &mut c,
+        2,
+        3,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm_batch_strided", 16));
+
+    let err = level3::try_trsm_batch_strided(
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        2,
+        3,
+        1.0,
+        &x,
+        2,
+        0,
+        &mut c,
+        2,
+        6,
+        1,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("trsm_batch_strided", 9));
+}
+
+#[test]
+fn short_buffers() {
+    let group = GemmGroup {
+        trans_a: Trans::NoTrans,
+        trans_b: Trans::Trans,
+        m: 2,
+        n: 2,
+        k: 3,
+        alpha: 1.0,
+        lda: 2,
+        ldb: 2,
+        beta: 0.0,
+        ldc: 2,
+        size: 2,
+    };
+    let x = vec![1.0; 6];
+    let (mut c0, mut c1) = (vec![7.0; 4], vec![7.0; 3]);
+    let err = level3::try_gemm_batch(&[group], &[&x, &x], &[&x, &x[..5]], &mut [&mut c0, &mut c1])
+        .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm_batch", 3));
+    let err = level3::try_gemm_batch(&[group], &[&x, &x], &[&x, &x], &mut [&mut c0, &mut c1])
+        .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm_batch", 4));
+    assert_eq!((c0, c1), (vec![7.0; 4], vec![7.0; 3]));
+
+    // Two problems of A need 6 + 6 elements, the second one would read past the end of a.
+    let a = vec![1.0; 11];
+    let mut c = vec![7.0; 9];
+    let gemm = |a: &[f64], c: &mut [f64], stride_c| {
+        level3::try_gemm_batch_strided(
+            Trans::NoTrans,
+            Trans::Trans,
+            2,
+            2,
+            3,
+            1.0,
+            a,
+            2,
+            6,
+            &x,
+            2,
+            0,
+            0.0,
+            c,
+            2,
+            stride_c,
+            2,
+        )
+    };
+    let err = gemm(&a, &mut c, 4).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm_batch_strided", 7));
+    let a = vec![1.0; 12];
+    let err = gemm(&a, &mut c[..7], 4).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm_batch_strided", 14));
+    assert_eq!(c, vec![7.0; 9]);
+
+    // Every problem only gets its own columns, the elements between and after them are left alone.
+    gemm(&a, &mut c, 5).unwrap();
+    assert_eq!(c, [3.0, 3.0, 3.0, 3.0, 7.0, 3.0, 3.0, 3.0, 3.0]);
+    let mut c = vec![7.0; 10];
+    gemm(&a, &mut c, 5).unwrap();
+    assert_eq!(c[9], 7.0);
+
+    let group = TrsmGroup {
+        side: Side::Left,
+        uplo: Uplo::Upper,
+        trans: Trans::NoTrans,
+        diag: Diag::Unit,
+        m: 2,
+        n: 3,
+        alpha: 1.0,
+        lda: 2,
+        ldb: 2,
+        size: 2,
+    };
+    let (mut b0, mut b1) = (vec![1.0; 6], vec![1.0; 6]);
+    let err =
+        level3::try_trsm_batch(&[group], &[&x, &x[..3]], &mut [&mut b0, &mut b1]).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("trsm_batch", 2));
+    let err =
+        level3::try_trsm_batch(&[group], &[&x, &x], &mut [&mut b0, &mut b1[..5]]).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("trsm_batch", 3));
+
+    let trsm = |a: &[f64], b: &mut [f64]| {
+        level3::try_trsm_batch_strided(
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
+            2,
+            3,
+            1.0,
+            a,
+            2,
+            4,
+            b,
+            2,
+            6,
+            2,
+        )
+    };
+    let mut b = vec![1.0; 12];
+    let err = trsm(&x[..5], &mut b).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("trsm_batch_strided", 8));
+    let a = vec![0.0; 8];
+    let err = trsm(&a, &mut b[..11]).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("trsm_batch_strided", 11));
+    assert_eq!(b, vec![1.0; 12]);
+    trsm(&a, &mut b).unwrap();
+}
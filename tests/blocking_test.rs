@@ -0,0 +1,139 @@
+use libblas::level3;
+use libblas::level3::blocking::{self, BlockSizes};
+use libblas::Trans;
+mod utils;
+
+// C := alpha*op( A )*op( B ) + beta*C computed with the textbook triple loop.
+fn naive(
+    trans_a: Trans,
+    trans_b: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: &[f64],
+    lda: usize,
+    b: &[f64],
+    ldb: usize,
+    beta: f64,
+    c: &mut [f64],
+    ldc: usize,
+) {
+    for j in 0..n {
+        for i in 0..m {
+            let mut tmp = 0.0;
+            for l in 0..k {
+                let x = if trans_a == Trans::NoTrans {
+                    a[l * lda + i]
+                } else {
+                    a[i * lda + l]
+                };
+                let y = if trans_b == Trans::NoTrans {
+                    b[j * ldb + l]
+                } else {
+                    b[l * ldb + j]
+                };
+                tmp += x * y;
+            }
+            c[j * ldc + i] = if beta == 0.0 {
+                alpha * tmp
+            } else {
+                alpha * tmp + beta * c[j * ldc + i]
+            };
+        }
+    }
+}
+
+// A gemm running in a parallel test would pick up the block sizes set here, so this file has a single test.
+#[test]
+fn gemm() {
+    let mut rng = utils::Random::new(1);
+    assert_eq!(blocking::block_sizes(), BlockSizes::default());
+    let (m, n, k) = (67, 45, 53);
+    let (lda, ldb, ldc) = (70, 71, 69);
+    let a = rng.reals(lda * 70);
+    let b = rng.reals(ldb * 70);
+    let c = rng.reals(ldc * n);
+
+    for &sizes in &[
+        BlockSizes::default(),
+        BlockSizes {
+            mc: 8,
+            kc: 16,
+            nc: 12,
+        },
+        BlockSizes {
+            mc: 13,
+            kc: 7,
+            nc: 5,
+        },
+    ] {
+        blocking::set_block_sizes(sizes);
+        for &trans_a in &[Trans::NoTrans, Trans::Trans] {
+            for &trans_b in &[Trans::NoTrans, Trans::ConjTrans] {
+                for &beta in &[0.0, 1.0, -0.7] {
+                    let mut expected = c.clone();
+                    naive(
+                        trans_a,
+                        trans_b,
+                        m,
+                        n,
+                        k,
+                        1.3,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut expected,
+                        ldc,
+                    );
+                    let mut actual = c.clone();
+                    if beta == 0.0 {
+                        actual.iter_mut().for_each(|x| *x = std::f64::NAN);
+                        for j in 0..n {
+                            for i in m..ldc {
+                                actual[j * ldc + i] = expected[j * ldc + i];
+                            }
+                        }
+                    }
+                    level3::gemm(
+                        trans_a,
+                        trans_b,
+                        m,
+                        n,
+                        k,
+                        1.3,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut actual,
+                        ldc,
+                    );
+                    approximately!(actual, expected, 1.0e-12);
+                }
+            }
+        }
+    }
+
+    let previous = blocking::set_block_sizes(BlockSizes::default());
+    assert_eq!(
+        previous,
+        BlockSizes {
+            mc: 13,
+            kc: 7,
+            nc: 5
+        }
+    );
+    let result = std::panic::catch_unwind(|| {
+        blocking::set_block_sizes(BlockSizes {
+            mc: 0,
+            kc: 1,
+            nc: 1,
+        })
+    });
+    assert!(result.is_err());
+    assert_eq!(blocking::block_sizes(), BlockSizes::default());
+}
@@ -1,3 +1,4 @@
+use super::ieee;
 use num_complex::Complex;
 
 #[derive(Deserialize, Debug, Default)]
@@ -16,8 +17,10 @@ pub struct expect {
 #[allow(non_camel_case_types)]
 pub struct asum {
     pub n: usize,
+    #[serde(deserialize_with = "ieee::complexes")]
     pub x: Vec<Complex<f64>>,
     pub incx: usize,
+    #[serde(deserialize_with = "ieee::real")]
     pub expect: f64,
 }
 
@@ -59,6 +62,7 @@ pub struct dot {
 #[allow(non_camel_case_types)]
 pub struct iamax {
     pub n: usize,
+    #[serde(deserialize_with = "ieee::complexes")]
     pub x: Vec<Complex<f64>>,
     pub incx: usize,
     pub expect: usize,
@@ -68,11 +72,24 @@ pub struct iamax {
 #[allow(non_camel_case_types)]
 pub struct nrm2 {
     pub n: usize,
+    #[serde(deserialize_with = "ieee::complexes")]
     pub x: Vec<Complex<f64>>,
     pub incx: usize,
+    #[serde(deserialize_with = "ieee::real")]
     pub expect: f64,
 }
 
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct nrm2_f32 {
+    pub n: usize,
+    #[serde(deserialize_with = "ieee::complexes")]
+    pub x: Vec<Complex<f32>>,
+    pub incx: usize,
+    #[serde(deserialize_with = "ieee::real")]
+    pub expect: f32,
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[allow(non_camel_case_types)]
 pub struct rot {
@@ -280,6 +297,62 @@ pub struct hpr2 {
     pub expect: Vec<Complex<f64>>,
 }
 
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct spmv {
+    pub uplo: char,
+    pub n: usize,
+    pub alpha: Complex<f64>,
+    pub ap: Vec<Complex<f64>>,
+    pub x: Vec<Complex<f64>>,
+    pub incx: isize,
+    pub beta: Complex<f64>,
+    pub y: Vec<Complex<f64>>,
+    pub incy: isize,
+    pub expect: Vec<Complex<f64>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct spr {
+    pub uplo: char,
+    pub n: usize,
+    pub alpha: Complex<f64>,
+    pub x: Vec<Complex<f64>>,
+    pub incx: isize,
+    pub ap: Vec<Complex<f64>>,
+    pub expect: Vec<Complex<f64>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct symv {
+    pub uplo: char,
+    pub n: usize,
+    pub alpha: Complex<f64>,
+    pub a: Vec<Complex<f64>>,
+    pub lda: usize,
+    pub x: Vec<Complex<f64>>,
+    pub incx: isize,
+    pub beta: Complex<f64>,
+    pub y: Vec<Complex<f64>>,
+    pub incy: isize,
+    pub expect: Vec<Complex<f64>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct syr {
+    pub uplo: char,
+    pub n: usize,
+    pub alpha: Complex<f64>,
+    pub x: Vec<Complex<f64>>,
+    pub incx: isize,
+    pub a: Vec<Complex<f64>>,
+    pub lda: usize,
+    pub expect: Vec<Complex<f64>>,
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[allow(non_camel_case_types)]
 pub struct tbmv {
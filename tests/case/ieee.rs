@@ -0,0 +1,46 @@
+//! json-fortran writes non-finite reals as the strings `"NaN"`, `"Infinity"`
+//! and `"-Infinity"`, which serde_json can't read as numbers.
+use num_complex::Complex;
+use num_traits::Float;
+use serde::de::{Deserializer, Error};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Ieee {
+    Number(f64),
+    Special(String),
+}
+
+impl Ieee {
+    fn value<T: Float, E: Error>(self) -> Result<T, E> {
+        let v = match self {
+            Ieee::Number(v) => v,
+            Ieee::Special(s) => match s.as_str() {
+                "NaN" => f64::NAN,
+                "Infinity" => f64::INFINITY,
+                "-Infinity" => f64::NEG_INFINITY,
+                _ => return Err(E::custom(format!("invalid real {:?}", s))),
+            },
+        };
+        T::from(v).ok_or_else(|| E::custom(format!("{} out of range", v)))
+    }
+}
+
+pub fn real<'de, D: Deserializer<'de>, T: Float>(d: D) -> Result<T, D::Error> {
+    Ieee::deserialize(d)?.value()
+}
+
+pub fn reals<'de, D: Deserializer<'de>, T: Float>(d: D) -> Result<Vec<T>, D::Error> {
+    Vec::<Ieee>::deserialize(d)?
+        .into_iter()
+        .map(Ieee::value)
+        .collect()
+}
+
+pub fn complexes<'de, D: Deserializer<'de>, T: Float>(d: D) -> Result<Vec<Complex<T>>, D::Error> {
+    Vec::<(Ieee, Ieee)>::deserialize(d)?
+        .into_iter()
+        .map(|(re, im)| Ok(Complex::new(re.value()?, im.value()?)))
+        .collect()
+}
@@ -1,4 +1,5 @@
 pub mod complex;
+pub mod ieee;
 
 #[derive(Deserialize, Debug, Default)]
 #[allow(non_camel_case_types)]
@@ -23,8 +24,10 @@ pub struct expect {
 #[allow(non_camel_case_types)]
 pub struct asum {
     pub n: usize,
+    #[serde(deserialize_with = "ieee::reals")]
     pub x: Vec<f64>,
     pub incx: usize,
+    #[serde(deserialize_with = "ieee::real")]
     pub expect: f64,
 }
 
@@ -63,10 +66,25 @@ pub struct ddot {
     pub expect: f64,
 }
 
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct dot {
+    pub n: usize,
+    #[serde(deserialize_with = "ieee::reals")]
+    pub x: Vec<f64>,
+    pub incx: isize,
+    #[serde(deserialize_with = "ieee::reals")]
+    pub y: Vec<f64>,
+    pub incy: isize,
+    #[serde(deserialize_with = "ieee::real")]
+    pub expect: f64,
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[allow(non_camel_case_types)]
 pub struct iamax {
     pub n: usize,
+    #[serde(deserialize_with = "ieee::reals")]
     pub x: Vec<f64>,
     pub incx: usize,
     pub expect: usize,
@@ -76,11 +94,24 @@ pub struct iamax {
 #[allow(non_camel_case_types)]
 pub struct nrm2 {
     pub n: usize,
+    #[serde(deserialize_with = "ieee::reals")]
     pub x: Vec<f64>,
     pub incx: usize,
+    #[serde(deserialize_with = "ieee::real")]
     pub expect: f64,
 }
 
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct nrm2_f32 {
+    pub n: usize,
+    #[serde(deserialize_with = "ieee::reals")]
+    pub x: Vec<f32>,
+    pub incx: usize,
+    #[serde(deserialize_with = "ieee::real")]
+    pub expect: f32,
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[allow(non_camel_case_types)]
 pub struct rot {
@@ -0,0 +1,253 @@
+#![cfg(feature = "cblas")]
+use libblas::cblas::ffi::level1::complex::{cblas_izamax, cblas_zdotc_sub};
+use libblas::cblas::ffi::level1::{cblas_daxpy, cblas_ddot, cblas_idamax};
+use libblas::cblas::ffi::level2::cblas_dgemv;
+use libblas::cblas::ffi::level3::complex::cblas_zhemm;
+use libblas::cblas::ffi::level3::{cblas_dgemm, cblas_dtrsm};
+use libblas::cblas::ffi::*;
+use libblas::cblas::level3;
+use libblas::{xerbla, BlasError, Layout, Side, Trans, Uplo};
+use num_complex::Complex;
+use std::os::raw::c_void;
+use std::sync::Mutex;
+mod fixtures;
+
+static REPORTED: Mutex<Vec<(&'static str, usize)>> = Mutex::new(Vec::new());
+
+fn record(err: &BlasError) {
+    REPORTED.lock().unwrap().push((err.routine(), err.param()));
+}
+
+#[test]
+fn dgemm() {
+    let a = fixtures::matrix_mxn(3, 4);
+    let b = fixtures::matrix_mxn(4, 2);
+    let c = fixtures::matrix_mxn(3, 2);
+
+    let mut expected = c.clone();
+    level3::gemm(
+        Layout::RowMajor,
+        Trans::NoTrans,
+        Trans::Trans,
+        3,
+        2,
+        4,
+        0.3,
+        &a,
+        4,
+        &b,
+        4,
+        0.2,
+        &mut expected,
+        2,
+    );
+    let mut c = c;
+    unsafe {
+        cblas_dgemm(
+            CblasRowMajor,
+            CblasNoTrans,
+            CblasTrans,
+            3,
+            2,
+            4,
+            0.3,
+            a.as_ptr(),
+            4,
+            b.as_ptr(),
+            4,
+            0.2,
+            c.as_mut_ptr(),
+            2,
+        );
+    }
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn zhemm() {
+    let a = fixtures::complex::matrix_mxn(3, 3);
+    let b = fixtures::complex::matrix_mxn(3, 2);
+    let c = fixtures::complex::matrix_mxn(3, 2);
+    let alpha = Complex::new(0.5, -0.25);
+    let beta = Complex::new(1.0, 2.0);
+
+    let mut expected = c.clone();
+    level3::complex::hemm(
+        Layout::ColMajor,
+        Side::Left,
+        Uplo::Lower,
+        3,
+        2,
+        alpha,
+        &a,
+        3,
+        &b,
+        3,
+        beta,
+        &mut expected,
+        3,
+    );
+    let mut c = c;
+    unsafe {
+        cblas_zhemm(
+            CblasColMajor,
+            CblasLeft,
+            CblasLower,
+            3,
+            2,
+            &alpha as *const _ as *const c_void,
+            a.as_ptr() as *const c_void,
+            3,
+            b.as_ptr() as *const c_void,
+            3,
+            &beta as *const _ as *const c_void,
+            c.as_mut_ptr() as *mut c_void,
+            3,
+        );
+    }
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn level1() {
+    let x = vec![1.0, -7.0, 3.0, 2.0];
+    let y = vec![2.0, 1.0, 0.5, -1.0];
+    unsafe {
+        assert_eq!(cblas_ddot(4, x.as_ptr(), 1, y.as_ptr(), 1), -5.5);
+        assert_eq!(cblas_ddot(2, x.as_ptr(), 2, y.as_ptr(), -2), 6.5);
+        assert_eq!(cblas_ddot(0, x.as_ptr(), 1, y.as_ptr(), 1), 0.0);
+        assert_eq!(cblas_ddot(-1, x.as_ptr(), 1, y.as_ptr(), 1), 0.0);
+
+        // CBLAS indices are zero-based.
+        assert_eq!(cblas_idamax(4, x.as_ptr(), 1), 1);
+        assert_eq!(cblas_idamax(2, x.as_ptr(), 2), 1);
+        assert_eq!(cblas_idamax(0, x.as_ptr(), 1), 0);
+
+        let mut y = y;
+        cblas_daxpy(2, 2.0, x.as_ptr(), 1, y.as_mut_ptr(), -2);
+        assert_eq!(y, vec![-12.0, 1.0, 2.5, -1.0]);
+    }
+}
+
+#[test]
+fn complex_level1() {
+    let x = vec![
+        Complex::new(1.0, 1.0),
+        Complex::new(-3.0, 0.5),
+        Complex::new(0.0, 2.0),
+    ];
+    let mut dot = Complex::new(0.0, 0.0);
+    unsafe {
+        assert_eq!(cblas_izamax(3, x.as_ptr() as *const c_void, 1), 1);
+        cblas_zdotc_sub(
+            3,
+            x.as_ptr() as *const c_void,
+            1,
+            x.as_ptr() as *const c_void,
+            1,
+            &mut dot as *mut _ as *mut c_void,
+        );
+    }
+    assert_eq!(dot, Complex::new(15.25, 0.0));
+}
+
+// The handler is process-wide, so every illegal call lives in one test.
+#[test]
+fn illegal_arguments() {
+    let a = vec![1.0; 4];
+    let x = vec![1.0; 2];
+    let mut y = vec![1.0; 2];
+    let mut b = vec![1.0; 4];
+
+    let previous = xerbla::set_handler(record);
+    unsafe {
+        cblas_dgemv(
+            0,
+            CblasNoTrans,
+            2,
+            2,
+            1.0,
+            a.as_ptr(),
+            2,
+            x.as_ptr(),
+            1,
+            1.0,
+            y.as_mut_ptr(),
+            1,
+        );
+        cblas_dgemv(
+            CblasRowMajor,
+            110,
+            2,
+            2,
+            1.0,
+            a.as_ptr(),
+            2,
+            x.as_ptr(),
+            1,
+            1.0,
+            y.as_mut_ptr(),
+            1,
+        );
+        cblas_dtrsm(
+            CblasColMajor,
+            CblasLeft,
+            CblasUpper,
+            CblasNoTrans,
+            CblasUnit + 1,
+            2,
+            2,
+            1.0,
+            a.as_ptr(),
+            2,
+            b.as_mut_ptr(),
+            2,
+        );
+        cblas_dgemm(
+            CblasColMajor,
+            CblasNoTrans,
+            CblasNoTrans,
+            -2,
+            2,
+            2,
+            1.0,
+            a.as_ptr(),
+            2,
+            a.as_ptr(),
+            2,
+            1.0,
+            b.as_mut_ptr(),
+            2,
+        );
+        cblas_dgemm(
+            CblasColMajor,
+            CblasNoTrans,
+            CblasNoTrans,
+            2,
+            2,
+            2,
+            1.0,
+            a.as_ptr(),
+            1,
+            a.as_ptr(),
+            2,
+            1.0,
+            b.as_mut_ptr(),
+            2,
+        );
+    }
+    xerbla::set_handler(previous);
+
+    assert_eq!(y, vec![1.0; 2]);
+    assert_eq!(b, vec![1.0; 4]);
+    assert_eq!(
+        *REPORTED.lock().unwrap(),
+        vec![
+            ("cblas_dgemv", 1),
+            ("cblas_dgemv", 2),
+            ("cblas_dtrsm", 5),
+            ("cblas_dgemm", 4),
+            ("cblas_dgemm", 9),
+        ]
+    );
+}
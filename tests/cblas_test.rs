@@ -0,0 +1,1055 @@
+use libblas::cblas::{level2, level3};
+use libblas::{level2 as col2, level3 as col3};
+use libblas::{Diag, Layout, Side, Trans, Uplo};
+use num_complex::Complex;
+mod fixtures;
+mod utils;
+
+// Returns the column-major m x n matrix a stored row-major.
+fn row_major<T: Copy>(m: usize, n: usize, a: &[T]) -> Vec<T> {
+    let mut r = Vec::with_capacity(m * n);
+    for i in 0..m {
+        for j in 0..n {
+            r.push(a[j * m + i]);
+        }
+    }
+    r
+}
+
+#[test]
+fn gemm() {
+    let a = fixtures::matrix_mxn(3, 4);
+    let at = fixtures::matrix_mxn(4, 3);
+    let b = fixtures::matrix_mxn(4, 2);
+    let c = fixtures::matrix_mxn(3, 2);
+
+    let mut expected = c.clone();
+    col3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &a,
+        3,
+        &b,
+        4,
+        0.2,
+        &mut expected,
+        3,
+    );
+    let mut cr = row_major(3, 2, &c);
+    level3::gemm(
+        Layout::RowMajor,
+        Trans::NoTrans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &row_major(3, 4, &a),
+        4,
+        &row_major(4, 2, &b),
+        2,
+        0.2,
+        &mut cr,
+        2,
+    );
+    approximately!(cr, row_major(3, 2, &expected));
+
+    let mut expected = c.clone();
+    col3::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &at,
+        4,
+        &b,
+        4,
+        0.2,
+        &mut expected,
+        3,
+    );
+    let mut cr = row_major(3, 2, &c);
+    level3::gemm(
+        Layout::RowMajor,
+        Trans::Trans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &row_major(4, 3, &at),
+        3,
+        &row_major(4, 2, &b),
+        2,
+        0.2,
+        &mut cr,
+        2,
+    );
+    approximately!(cr, row_major(3, 2, &expected));
+
+    let mut cc = c.clone();
+    let err = level3::try_gemm(
+        Layout::RowMajor,
+        Trans::NoTrans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &row_major(3, 4, &a),
+        4,
+        &row_major(4, 2, &b),
+        1,
+        0.2,
+        &mut cc,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!(err.routine(), "gemm");
+    assert_eq!(err.param(), 11);
+    let err = level3::try_gemm(
+        Layout::ColMajor,
+        Trans::NoTrans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &a,
+        2,
+        &b,
+        4,
+        0.2,
+        &mut cc,
+        3,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 9);
+    let err = level3::try_gemm(
+        Layout::RowMajor,
+        Trans::NoTrans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &row_major(3, 4, &a)[1..],
+        4,
+        &row_major(4, 2, &b),
+        2,
+        0.2,
+        &mut cc,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 8);
+    let err = level3::try_gemm(
+        Layout::RowMajor,
+        Trans::NoTrans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &row_major(3, 4, &a),
+        4,
+        &row_major(4, 2, &b)[1..],
+        2,
+        0.2,
+        &mut cc,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 10);
+}
+
+#[test]
+fn symm_trsm() {
+    let a = fixtures::matrix_mxn(3, 3);
+    let b = fixtures::matrix_mxn(3, 2);
+    let c = fixtures::matrix_mxn(3, 2);
+
+    let mut expected = c.clone();
+    col3::symm(
+        Side::Left,
+        Uplo::Upper,
+        3,
+        2,
+        0.5,
+        &a,
+        3,
+        &b,
+        3,
+        0.7,
+        &mut expected,
+        3,
+    );
+    let mut cr = row_major(3, 2, &c);
+    level3::symm(
+        Layout::RowMajor,
+        Side::Left,
+        Uplo::Upper,
+        3,
+        2,
+        0.5,
+        &row_major(3, 3, &a),
+        3,
+        &row_major(3, 2, &b),
+        2,
+        0.7,
+        &mut cr,
+        2,
+    );
+    approximately!(cr, row_major(3, 2, &expected));
+
+    let a = fixtures::M6X6U();
+    let b = fixtures::matrix_mxn(6, 2);
+    for &trans in &[Trans::NoTrans, Trans::Trans] {
+        let mut expected = b.clone();
+        col3::trsm(
+            Side::Left,
+            Uplo::Upper,
+            trans,
+            Diag::NonUnit,
+            6,
+            2,
+            2.0,
+            &a,
+            6,
+            &mut expected,
+            6,
+        );
+        let mut br = row_major(6, 2, &b);
+        level3::trsm(
+            Layout::RowMajor,
+            Side::Left,
+            Uplo::Upper,
+            trans,
+            Diag::NonUnit,
+            6,
+            2,
+            2.0,
+            &row_major(6, 6, &a),
+            6,
+            &mut br,
+            2,
+        );
+        approximately!(br, row_major(6, 2, &expected));
+    }
+}
+
+#[test]
+fn gemv_ger_trmv() {
+    let a = fixtures::matrix_mxn(3, 4);
+    let ar = row_major(3, 4, &a);
+    let x = fixtures::vector(4);
+    let y = fixtures::vector(3);
+
+    let mut expected = y.clone();
+    col2::gemv(
+        Trans::NoTrans,
+        3,
+        4,
+        0.3,
+        &a,
+        3,
+        &x,
+        1,
+        0.2,
+        &mut expected,
+        1,
+    );
+    let mut yr = y.clone();
+    level2::gemv(
+        Layout::RowMajor,
+        Trans::NoTrans,
+        3,
+        4,
+        0.3,
+        &ar,
+        4,
+        &x,
+        1,
+        0.2,
+        &mut yr,
+        1,
+    );
+    approximately!(yr, expected);
+
+    let mut expected = x.clone();
+    col2::gemv(
+        Trans::Trans,
+        3,
+        4,
+        0.3,
+        &a,
+        3,
+        &y,
+        -1,
+        0.2,
+        &mut expected,
+        1,
+    );
+    let mut xr = x.clone();
+    level2::gemv(
+        Layout::RowMajor,
+        Trans::Trans,
+        3,
+        4,
+        0.3,
+        &ar,
+        4,
+        &y,
+        -1,
+        0.2,
+        &mut xr,
+        1,
+    );
+    approximately!(xr, expected);
+
+    let mut expected = a.clone();
+    col2::ger(3, 4, 0.5, &y, 1, &x, 1, &mut expected, 3);
+    let mut ar = ar.clone();
+    level2::ger(Layout::RowMajor, 3, 4, 0.5, &y, 1, &x, 1, &mut ar, 4);
+    approximately!(ar, row_major(3, 4, &expected));
+    let err = level2::try_ger(Layout::RowMajor, 3, 4, 0.5, &y, 0, &x, 1, &mut ar, 4).unwrap_err();
+    assert_eq!(err.param(), 6);
+    let err =
+        level2::try_ger(Layout::RowMajor, 3, 4, 0.5, &y[1..], 1, &x, 1, &mut ar, 4).unwrap_err();
+    assert_eq!(err.param(), 5);
+    let err =
+        level2::try_ger(Layout::RowMajor, 3, 4, 0.5, &y, 1, &x[1..], 1, &mut ar, 4).unwrap_err();
+    assert_eq!(err.param(), 7);
+
+    let t = fixtures::M6X6L();
+    let x = fixtures::vector(6);
+    let mut expected = x.clone();
+    col2::trmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        &t,
+        6,
+        &mut expected,
+        1,
+    );
+    let mut xr = x.clone();
+    level2::trmv(
+        Layout::RowMajor,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        &row_major(6, 6, &t),
+        6,
+        &mut xr,
+        1,
+    );
+    approximately!(xr, expected);
+}
+
+#[test]
+fn complex() {
+    let a = fixtures::complex::matrix_mxn(3, 4);
+    let ar = row_major(3, 4, &a);
+    let x = fixtures::complex::vector(3);
+    let y = fixtures::complex::vector(4);
+    let alpha = Complex::new(0.3, -0.4);
+    let beta = Complex::new(0.2, 0.1);
+
+    let mut expected = y.clone();
+    col2::complex::gemv(
+        Trans::ConjTrans,
+        3,
+        4,
+        alpha,
+        &a,
+        3,
+        &x,
+        -1,
+        beta,
+        &mut expected,
+        1,
+    );
+    let mut yr = y.clone();
+    level2::complex::gemv(
+        Layout::RowMajor,
+        Trans::ConjTrans,
+        3,
+        4,
+        alpha,
+        &ar,
+        4,
+        &x,
+        -1,
+        beta,
+        &mut yr,
+        1,
+    );
+    capproximately!(yr, expected);
+
+    let mut expected = a.clone();
+    col2::complex::gerc(3, 4, alpha, &x, 1, &y, 1, &mut expected, 3);
+    let mut ar = ar.clone();
+    level2::complex::gerc(Layout::RowMajor, 3, 4, alpha, &x, 1, &y, 1, &mut ar, 4);
+    capproximately!(ar, row_major(3, 4, &expected));
+
+    let h = fixtures::complex::matrix_mxn(4, 4);
+    let mut expected = y.clone();
+    col2::complex::hemv(Uplo::Upper, 4, alpha, &h, 4, &y, 1, beta, &mut expected, 1);
+    let mut yr = y.clone();
+    level2::complex::hemv(
+        Layout::RowMajor,
+        Uplo::Upper,
+        4,
+        alpha,
+        &row_major(4, 4, &h),
+        4,
+        &y,
+        1,
+        beta,
+        &mut yr,
+        1,
+    );
+    capproximately!(yr, expected);
+
+    let b = fixtures::complex::matrix_mxn(3, 4);
+    let c = fixtures::complex::matrix_mxn(3, 3);
+    for &(trans, ka) in &[(Trans::NoTrans, 3), (Trans::ConjTrans, 4)] {
+        let (m, n) = if trans == Trans::NoTrans {
+            (3, 4)
+        } else {
+            (4, 3)
+        };
+        let a = fixtures::complex::matrix_mxn(m, n);
+        let mut expected = c.clone();
+        col3::complex::her2k(
+            Uplo::Lower,
+            trans,
+            3,
+            4,
+            alpha,
+            &a,
+            ka,
+            &b[..m as usize * n as usize],
+            ka,
+            0.5,
+            &mut expected,
+            3,
+        );
+        let b = &b[..m as usize * n as usize];
+        let mut cr = row_major(3, 3, &c);
+        level3::complex::her2k(
+            Layout::RowMajor,
+            Uplo::Lower,
+            trans,
+            3,
+            4,
+            alpha,
+            &row_major(m as usize, n as usize, &a),
+            n as usize,
+            &row_major(m as usize, n as usize, b),
+            n as usize,
+            0.5,
+            &mut cr,
+            3,
+        );
+        let expected = row_major(3, 3, &expected);
+        for i in 0..3 {
+            for j in 0..=i {
+                capproximately!([cr[i * 3 + j]], [expected[i * 3 + j]]);
+            }
+        }
+    }
+
+    let err = level3::complex::try_herk(
+        Layout::RowMajor,
+        Uplo::Upper,
+        Trans::Trans,
+        3,
+        4,
+        1.0,
+        &a,
+        4,
+        1.0,
+        &mut c.clone(),
+        3,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 3);
+}
+
+// Returns the band storage in layout of the column-major m x n matrix a, with kl sub-diagonals and ku super-diagonals.
+fn band<T: Copy + Default>(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    a: &[T],
+) -> Vec<T> {
+    let lda = kl + ku + 1;
+    let mut s = vec![T::default(); lda * m.max(n)];
+    for j in 0..n {
+        for i in j.saturating_sub(ku)..m.min(j + kl + 1) {
+            match layout {
+                Layout::ColMajor => s[j * lda + ku + i - j] = a[j * m + i],
+                Layout::RowMajor => s[i * lda + kl + j - i] = a[j * m + i],
+            }
+        }
+    }
+    s
+}
+
+// Returns the uplo triangle of the column-major n x n matrix a packed in layout.
+fn packed<T: Copy>(layout: Layout, uplo: Uplo, n: usize, a: &[T]) -> Vec<T> {
+    let mut ap = Vec::with_capacity(n * (n + 1) / 2);
+    for p in 0..n {
+        for q in 0..n {
+            let (i, j) = match layout {
+                Layout::ColMajor => (q, p),
+                Layout::RowMajor => (p, q),
+            };
+            if (uplo == Uplo::Upper && i <= j) || (uplo == Uplo::Lower && i >= j) {
+                ap.push(a[j * n + i]);
+            }
+        }
+    }
+    ap
+}
+
+// The stored diagonals of a symmetric, Hermitian or triangular band matrix with k off-diagonals.
+fn diagonals(uplo: Uplo, k: usize) -> (usize, usize) {
+    match uplo {
+        Uplo::Upper => (0, k),
+        Uplo::Lower => (k, 0),
+    }
+}
+
+const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+const TRANS: [Trans; 3] = [Trans::NoTrans, Trans::Trans, Trans::ConjTrans];
+const LAYOUTS: [Layout; 2] = [Layout::ColMajor, Layout::RowMajor];
+
+#[test]
+fn band_row_major() {
+    let mut rng = utils::Random::new(1);
+    let (m, n, kl, ku) = (4, 5, 1, 2);
+    let a = rng.reals(m * n);
+    let x = rng.reals(2 * n);
+    let y = rng.reals(2 * n);
+    for &trans in &TRANS {
+        let (lenx, leny) = if trans == Trans::NoTrans {
+            (n, m)
+        } else {
+            (m, n)
+        };
+        let mut results = vec![];
+        for &layout in &LAYOUTS {
+            let mut yr = y.clone();
+            level2::gbmv(
+                layout,
+                trans,
+                m,
+                n,
+                kl,
+                ku,
+                0.3,
+                &band(layout, m, n, kl, ku, &a),
+                kl + ku + 1,
+                &x[..2 * lenx - 1],
+                -2,
+                0.2,
+                &mut yr[..leny],
+                1,
+            );
+            results.push(yr);
+        }
+        approximately!(results[1], results[0]);
+    }
+
+    let k = 2;
+    let s = rng.reals(n * n);
+    let t = rng.triangular(n, n);
+    for &uplo in &UPLOS {
+        let (kl, ku) = diagonals(uplo, k);
+        let mut results = vec![];
+        for &layout in &LAYOUTS {
+            let mut yr = y.clone();
+            level2::sbmv(
+                layout,
+                uplo,
+                n,
+                k,
+                0.3,
+                &band(layout, n, n, kl, ku, &s),
+                k + 1,
+                &x,
+                2,
+                0.2,
+                &mut yr,
+                1,
+            );
+            results.push(yr);
+        }
+        approximately!(results[1], results[0]);
+
+        for &trans in &TRANS {
+            let mut results = vec![];
+            for &layout in &LAYOUTS {
+                let ab = band(layout, n, n, kl, ku, &t);
+                let mut xm = x.clone();
+                level2::tbmv(
+                    layout,
+                    uplo,
+                    trans,
+                    Diag::NonUnit,
+                    n,
+                    k,
+                    &ab,
+                    k + 1,
+                    &mut xm,
+                    1,
+                );
+                let mut xs = x.clone();
+                level2::tbsv(
+                    layout,
+                    uplo,
+                    trans,
+                    Diag::Unit,
+                    n,
+                    k,
+                    &ab,
+                    k + 1,
+                    &mut xs,
+                    -2,
+                );
+                results.push((xm, xs));
+            }
+            approximately!(results[1].0, results[0].0);
+            approximately!(results[1].1, results[0].1);
+        }
+    }
+}
+
+#[test]
+fn packed_row_major() {
+    let mut rng = utils::Random::new(2);
+    let n = 5;
+    let s = rng.reals(n * n);
+    let t = rng.triangular(n, n);
+    let x = rng.reals(n);
+    let y = rng.reals(n);
+    for &uplo in &UPLOS {
+        let mut results = vec![];
+        for &layout in &LAYOUTS {
+            let mut yr = y.clone();
+            level2::spmv(
+                layout,
+                uplo,
+                n,
+                0.3,
+                &packed(layout, uplo, n, &s),
+                &x,
+                -1,
+                0.2,
+                &mut yr,
+                1,
+            );
+            results.push(yr);
+        }
+        approximately!(results[1], results[0]);
+
+        let mut syr = s.clone();
+        col2::syr(uplo, n, 0.3, &x, 1, &mut syr, n);
+        let mut syr2 = s.clone();
+        col2::syr2(uplo, n, 0.3, &x, 1, &y, -1, &mut syr2, n);
+        for &layout in &LAYOUTS {
+            let mut ap = packed(layout, uplo, n, &s);
+            level2::spr(layout, uplo, n, 0.3, &x, 1, &mut ap);
+            approximately!(ap, packed(layout, uplo, n, &syr));
+            let mut ap = packed(layout, uplo, n, &s);
+            level2::spr2(layout, uplo, n, 0.3, &x, 1, &y, -1, &mut ap);
+            approximately!(ap, packed(layout, uplo, n, &syr2));
+        }
+
+        for &trans in &TRANS {
+            let mut results = vec![];
+            for &layout in &LAYOUTS {
+                let ap = packed(layout, uplo, n, &t);
+                let mut xm = x.clone();
+                level2::tpmv(layout, uplo, trans, Diag::Unit, n, &ap, &mut xm, -1);
+                let mut xs = x.clone();
+                level2::tpsv(layout, uplo, trans, Diag::NonUnit, n, &ap, &mut xs, 1);
+                results.push((xm, xs));
+            }
+            approximately!(results[1].0, results[0].0);
+            approximately!(results[1].1, results[0].1);
+        }
+    }
+}
+
+#[test]
+fn complex_band_packed_row_major() {
+    let mut rng = utils::Random::new(3);
+    let (n, k) = (5, 2);
+    let h = rng.complexes(n * n);
+    let x = rng.complexes(2 * n);
+    let y = rng.complexes(n);
+    let alpha = Complex::new(0.3, -0.4);
+    let beta = Complex::new(0.2, 0.1);
+    for &uplo in &UPLOS {
+        let (kl, ku) = diagonals(uplo, k);
+        let mut results = vec![];
+        for &layout in &LAYOUTS {
+            let mut yb = y.clone();
+            level2::complex::hbmv(
+                layout,
+                uplo,
+                n,
+                k,
+                alpha,
+                &band(layout, n, n, kl, ku, &h),
+                k + 1,
+                &x,
+                -2,
+                beta,
+                &mut yb,
+                1,
+            );
+            let mut yp = y.clone();
+            level2::complex::hpmv(
+                layout,
+                uplo,
+                n,
+                alpha,
+                &packed(layout, uplo, n, &h),
+                &x,
+                2,
+                beta,
+                &mut yp,
+                1,
+            );
+            results.push((yb, yp));
+        }
+        capproximately!(results[1].0, results[0].0);
+        capproximately!(results[1].1, results[0].1);
+
+        let mut her = h.clone();
+        col2::complex::her(uplo, n, 0.3, &x, 2, &mut her, n);
+        let mut her2 = h.clone();
+        col2::complex::her2(uplo, n, alpha, &x, 2, &y, -1, &mut her2, n);
+        for &layout in &LAYOUTS {
+            let mut ap = packed(layout, uplo, n, &h);
+            level2::complex::hpr(layout, uplo, n, 0.3, &x, 2, &mut ap);
+            capproximately!(ap, packed(layout, uplo, n, &her));
+            let mut ap = packed(layout, uplo, n, &h);
+            level2::complex::hpr2(layout, uplo, n, alpha, &x, 2, &y, -1, &mut ap);
+            capproximately!(ap, packed(layout, uplo, n, &her2));
+        }
+    }
+}
+
+// Asserts that the uplo triangles of the column-major n x n matrix c and the row-major cr are equal.
+fn triangles_approximately<T: Copy + Into<Complex<f64>>>(uplo: Uplo, n: usize, cr: &[T], c: &[T]) {
+    for i in 0..n {
+        for j in 0..n {
+            if (uplo == Uplo::Upper && i <= j) || (uplo == Uplo::Lower && i >= j) {
+                capproximately!([cr[i * n + j].into()], [c[j * n + i].into()]);
+            }
+        }
+    }
+}
+
+#[test]
+fn level3_row_major() {
+    let mut rng = utils::Random::new(4);
+    let (m, n, k) = (4, 3, 5);
+    let t = rng.triangular(m.max(n), m.max(n));
+    let b = rng.reals(m * n);
+    for &side in &[Side::Left, Side::Right] {
+        let ka = if side == Side::Left { m } else { n };
+        let t = &t[..ka * ka];
+        for &uplo in &UPLOS {
+            for &trans in &TRANS {
+                let mut expected = b.clone();
+                level3::trmm(
+                    Layout::ColMajor,
+                    side,
+                    uplo,
+                    trans,
+                    Diag::NonUnit,
+                    m,
+                    n,
+                    0.5,
+                    t,
+                    ka,
+                    &mut expected,
+                    m,
+                );
+                let mut br = row_major(m, n, &b);
+                level3::trmm(
+                    Layout::RowMajor,
+                    side,
+                    uplo,
+                    trans,
+                    Diag::NonUnit,
+                    m,
+                    n,
+                    0.5,
+                    &row_major(ka, ka, t),
+                    ka,
+                    &mut br,
+                    n,
+                );
+                approximately!(br, row_major(m, n, &expected));
+            }
+        }
+    }
+
+    let a = rng.reals(n * k);
+    let b = rng.reals(n * k);
+    let c = rng.reals(n * n);
+    for &uplo in &UPLOS {
+        for &trans in &TRANS {
+            let (rows, cols) = if trans == Trans::NoTrans {
+                (n, k)
+            } else {
+                (k, n)
+            };
+            let (ar, br) = (row_major(rows, cols, &a), row_major(rows, cols, &b));
+            let mut expected = c.clone();
+            level3::syrk(
+                Layout::ColMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                0.3,
+                &a,
+                rows,
+                0.2,
+                &mut expected,
+                n,
+            );
+            let mut cr = row_major(n, n, &c);
+            level3::syrk(
+                Layout::RowMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                0.3,
+                &ar,
+                cols,
+                0.2,
+                &mut cr,
+                n,
+            );
+            triangles_approximately(uplo, n, &cr, &expected);
+
+            let mut expected = c.clone();
+            level3::syr2k(
+                Layout::ColMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                0.3,
+                &a,
+                rows,
+                &b,
+                rows,
+                0.2,
+                &mut expected,
+                n,
+            );
+            let mut cr = row_major(n, n, &c);
+            level3::syr2k(
+                Layout::RowMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                0.3,
+                &ar,
+                cols,
+                &br,
+                cols,
+                0.2,
+                &mut cr,
+                n,
+            );
+            triangles_approximately(uplo, n, &cr, &expected);
+        }
+    }
+}
+
+#[test]
+fn complex_level3_row_major() {
+    let mut rng = utils::Random::new(5);
+    let (m, n, k) = (4, 3, 5);
+    let alpha = Complex::new(0.3, -0.4);
+    let beta = Complex::new(0.2, 0.1);
+    let mut t = rng.complexes(m * m);
+    for i in 0..m {
+        t[i * m + i] += m as f64;
+    }
+    let b = rng.complexes(m * n);
+    let c = rng.complexes(m * n);
+    for &side in &[Side::Left, Side::Right] {
+        let ka = if side == Side::Left { m } else { n };
+        let t = &t[..ka * ka];
+        for &uplo in &UPLOS {
+            let mut expected = c.clone();
+            level3::complex::hemm(
+                Layout::ColMajor,
+                side,
+                uplo,
+                m,
+                n,
+                alpha,
+                t,
+                ka,
+                &b,
+                m,
+                beta,
+                &mut expected,
+                m,
+            );
+            let mut cr = row_major(m, n, &c);
+            level3::complex::hemm(
+                Layout::RowMajor,
+                side,
+                uplo,
+                m,
+                n,
+                alpha,
+                &row_major(ka, ka, t),
+                ka,
+                &row_major(m, n, &b),
+                n,
+                beta,
+                &mut cr,
+                n,
+            );
+            capproximately!(cr, row_major(m, n, &expected));
+
+            for &trans in &TRANS {
+                let mut expected = b.clone();
+                level3::complex::trsm(
+                    Layout::ColMajor,
+                    side,
+                    uplo,
+                    trans,
+                    Diag::NonUnit,
+                    m,
+                    n,
+                    alpha,
+                    t,
+                    ka,
+                    &mut expected,
+                    m,
+                );
+                let mut br = row_major(m, n, &b);
+                level3::complex::trsm(
+                    Layout::RowMajor,
+                    side,
+                    uplo,
+                    trans,
+                    Diag::NonUnit,
+                    m,
+                    n,
+                    alpha,
+                    &row_major(ka, ka, t),
+                    ka,
+                    &mut br,
+                    n,
+                );
+                capproximately!(br, row_major(m, n, &expected));
+            }
+        }
+    }
+
+    let a = rng.complexes(n * k);
+    let c = rng.complexes(n * n);
+    for &uplo in &UPLOS {
+        for &trans in &[Trans::NoTrans, Trans::Trans] {
+            let (rows, cols) = if trans == Trans::NoTrans {
+                (n, k)
+            } else {
+                (k, n)
+            };
+            let ar = row_major(rows, cols, &a);
+            let mut expected = c.clone();
+            level3::complex::syrk(
+                Layout::ColMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                alpha,
+                &a,
+                rows,
+                beta,
+                &mut expected,
+                n,
+            );
+            let mut cr = row_major(n, n, &c);
+            level3::complex::syrk(
+                Layout::RowMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                alpha,
+                &ar,
+                cols,
+                beta,
+                &mut cr,
+                n,
+            );
+            triangles_approximately(uplo, n, &cr, &expected);
+
+            let mut expected = c.clone();
+            level3::complex::syr2k(
+                Layout::ColMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                alpha,
+                &a,
+                rows,
+                &a[..],
+                rows,
+                beta,
+                &mut expected,
+                n,
+            );
+            let mut cr = row_major(n, n, &c);
+            level3::complex::syr2k(
+                Layout::RowMajor,
+                uplo,
+                trans,
+                n,
+                k,
+                alpha,
+                &ar,
+                cols,
+                &ar,
+                cols,
+                beta,
+                &mut cr,
+                n,
+            );
+            triangles_approximately(uplo, n, &cr, &expected);
+        }
+    }
+}
@@ -3,6 +3,8 @@ extern crate serde_derive;
 extern crate serde;
 extern crate serde_json;
 use libblas::{level1, level2, level3};
+use libblas::{Diag, Side, Trans, Uplo};
+use std::convert::TryFrom;
 use std::fs::File;
 use std::io::BufReader;
 
@@ -18,14 +20,14 @@ fn asum() {
     reader = BufReader::new(file);
     let tests: Vec<case::asum> = serde_json::from_reader(reader).unwrap();
     for t in tests {
-        assert_eq!(level1::asum(t.n, &t.x, t.incx), t.expect)
+        assert_ieee_eq!(level1::asum(t.n, &t.x, t.incx), t.expect)
     }
 
     file = File::open("./tests/fixtures/level1/complex/asum.json").unwrap();
     reader = BufReader::new(file);
     let tests: Vec<case::complex::asum> = serde_json::from_reader(reader).unwrap();
     for t in tests {
-        assert_eq!(level1::complex::asum(t.n, &t.x, t.incx), t.expect)
+        assert_ieee_eq!(level1::complex::asum(t.n, &t.x, t.incx), t.expect)
     }
 }
 
@@ -87,6 +89,16 @@ fn ddot() {
     }
 }
 
+#[test]
+fn dot() {
+    let file = File::open("./tests/fixtures/level1/dot.json").unwrap();
+    let reader = BufReader::new(file);
+    let tests: Vec<case::dot> = serde_json::from_reader(reader).unwrap();
+    for t in tests {
+        assert_ieee_eq!(level1::dot(t.n, &t.x, t.incx, &t.y, t.incy), t.expect)
+    }
+}
+
 #[test]
 fn dotc() {
     let mut reader;
@@ -144,14 +156,28 @@ fn nrm2() {
     reader = BufReader::new(file);
     let tests: Vec<case::nrm2> = serde_json::from_reader(reader).unwrap();
     for t in tests {
-        assert_eq!(level1::nrm2(t.n, &t.x, t.incx), t.expect)
+        assert_ieee_eq!(level1::nrm2(t.n, &t.x, t.incx), t.expect)
+    }
+
+    file = File::open("./tests/fixtures/level1/nrm2_f32.json").unwrap();
+    reader = BufReader::new(file);
+    let tests: Vec<case::nrm2_f32> = serde_json::from_reader(reader).unwrap();
+    for t in tests {
+        assert_ieee_eq!(level1::nrm2(t.n, &t.x, t.incx), t.expect)
     }
 
     file = File::open("./tests/fixtures/level1/complex/nrm2.json").unwrap();
     reader = BufReader::new(file);
     let tests: Vec<case::complex::nrm2> = serde_json::from_reader(reader).unwrap();
     for t in tests {
-        assert_eq!(level1::complex::nrm2(t.n, &t.x, t.incx), t.expect)
+        assert_ieee_eq!(level1::complex::nrm2(t.n, &t.x, t.incx), t.expect)
+    }
+
+    file = File::open("./tests/fixtures/level1/complex/nrm2_f32.json").unwrap();
+    reader = BufReader::new(file);
+    let tests: Vec<case::complex::nrm2_f32> = serde_json::from_reader(reader).unwrap();
+    for t in tests {
+        assert_ieee_eq!(level1::complex::nrm2(t.n, &t.x, t.incx), t.expect)
     }
 }
 
@@ -335,7 +361,18 @@ fn gbmv() {
     for t in tests {
         let mut y = t.y;
         level2::gbmv(
-            t.trans, t.m, t.n, t.kl, t.ku, t.alpha, &t.a, t.lda, &t.x, t.incx, t.beta, &mut y,
+            Trans::try_from(t.trans).unwrap(),
+            t.m,
+            t.n,
+            t.kl,
+            t.ku,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
             t.incy,
         );
         assert_eq!(y, t.expect);
@@ -347,7 +384,18 @@ fn gbmv() {
     for t in tests {
         let mut y = t.y;
         level2::complex::gbmv(
-            t.trans, t.m, t.n, t.kl, t.ku, t.alpha, &t.a, t.lda, &t.x, t.incx, t.beta, &mut y,
+            Trans::try_from(t.trans).unwrap(),
+            t.m,
+            t.n,
+            t.kl,
+            t.ku,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
             t.incy,
         );
         capproximately!(y, t.expect);
@@ -397,7 +445,17 @@ fn hbmv() {
     for t in tests {
         let mut y = t.y;
         level2::complex::hbmv(
-            t.uplo, t.n, t.k, t.alpha, &t.a, t.lda, &t.x, t.incx, t.beta, &mut y, t.incy,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
         );
         capproximately!(y, t.expect);
     }
@@ -413,7 +471,16 @@ fn hemv() {
     for t in tests {
         let mut y = t.y;
         level2::complex::hemv(
-            t.uplo, t.n, t.alpha, &t.a, t.lda, &t.x, t.incx, t.beta, &mut y, t.incy,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
         );
         capproximately!(y, t.expect);
     }
@@ -429,7 +496,15 @@ fn her() {
     for t in tests {
         let mut a = t.a;
         println!("{:?}", t.uplo);
-        level2::complex::her(t.uplo, t.n, t.alpha, &t.x, t.incx, &mut a, t.lda);
+        level2::complex::her(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &mut a,
+            t.lda,
+        );
         capproximately!(a, t.expect);
     }
 }
@@ -444,7 +519,15 @@ fn her2() {
     for t in tests {
         let mut a = t.a;
         level2::complex::her2(
-            t.uplo, t.n, t.alpha, &t.x, t.incx, &t.y, t.incy, &mut a, t.lda,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &t.y,
+            t.incy,
+            &mut a,
+            t.lda,
         );
         capproximately!(a, t.expect);
     }
@@ -460,7 +543,15 @@ fn hpmv() {
     for t in tests {
         let mut y = t.y;
         level2::complex::hpmv(
-            t.uplo, t.n, t.alpha, &t.ap, &t.x, t.incx, t.beta, &mut y, t.incy,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.ap,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
         );
         capproximately!(y, t.expect);
     }
@@ -476,7 +567,14 @@ fn hpr() {
     for t in tests {
         let mut ap = t.ap;
         println!("{:?}", t.uplo);
-        level2::complex::hpr(t.uplo, t.n, t.alpha, &t.x, t.incx, &mut ap);
+        level2::complex::hpr(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &mut ap,
+        );
         capproximately!(ap, t.expect);
     }
 }
@@ -490,7 +588,16 @@ fn hpr2() {
     let tests: Vec<case::complex::hpr2> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut ap = t.ap;
-        level2::complex::hpr2(t.uplo, t.n, t.alpha, &t.x, t.incx, &t.y, t.incy, &mut ap);
+        level2::complex::hpr2(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &t.y,
+            t.incy,
+            &mut ap,
+        );
         capproximately!(ap, t.expect);
     }
 }
@@ -505,7 +612,17 @@ fn sbmv() {
     for t in tests {
         let mut y = t.y;
         level2::sbmv(
-            t.uplo, t.n, t.k, t.alpha, &t.a, t.lda, &t.x, t.incx, t.beta, &mut y, t.incy,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
         );
         approximately!(y, t.expect);
     }
@@ -521,10 +638,37 @@ fn spmv() {
     for t in tests {
         let mut y = t.y;
         level2::spmv(
-            t.uplo, t.n, t.alpha, &t.ap, &t.x, t.incx, t.beta, &mut y, t.incy,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.ap,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
         );
         approximately!(y, t.expect);
     }
+
+    file = File::open("./tests/fixtures/level2/complex/spmv.json").unwrap();
+    reader = BufReader::new(file);
+    let tests: Vec<case::complex::spmv> = serde_json::from_reader(reader).unwrap();
+    for t in tests {
+        let mut y = t.y;
+        level2::complex::spmv(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.ap,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
+        );
+        capproximately!(y, t.expect);
+    }
 }
 
 #[test]
@@ -536,9 +680,32 @@ fn spr() {
     let tests: Vec<case::spr> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut ap = t.ap;
-        level2::spr(t.uplo, t.n, t.alpha, &t.x, t.incx, &mut ap);
+        level2::spr(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &mut ap,
+        );
         approximately!(ap, t.expect);
     }
+
+    file = File::open("./tests/fixtures/level2/complex/spr.json").unwrap();
+    reader = BufReader::new(file);
+    let tests: Vec<case::complex::spr> = serde_json::from_reader(reader).unwrap();
+    for t in tests {
+        let mut ap = t.ap;
+        level2::complex::spr(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &mut ap,
+        );
+        capproximately!(ap, t.expect);
+    }
 }
 
 #[test]
@@ -550,7 +717,16 @@ fn spr2() {
     let tests: Vec<case::spr2> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut ap = t.ap;
-        level2::spr2(t.uplo, t.n, t.alpha, &t.x, t.incx, &t.y, t.incy, &mut ap);
+        level2::spr2(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &t.y,
+            t.incy,
+            &mut ap,
+        );
         approximately!(ap, t.expect);
     }
 }
@@ -565,10 +741,39 @@ fn symv() {
     for t in tests {
         let mut y = t.y;
         level2::symv(
-            t.uplo, t.n, t.alpha, &t.a, t.lda, &t.x, t.incx, t.beta, &mut y, t.incy,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
         );
         approximately!(y, t.expect);
     }
+
+    file = File::open("./tests/fixtures/level2/complex/symv.json").unwrap();
+    reader = BufReader::new(file);
+    let tests: Vec<case::complex::symv> = serde_json::from_reader(reader).unwrap();
+    for t in tests {
+        let mut y = t.y;
+        level2::complex::symv(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.x,
+            t.incx,
+            t.beta,
+            &mut y,
+            t.incy,
+        );
+        capproximately!(y, t.expect);
+    }
 }
 
 #[test]
@@ -580,9 +785,34 @@ fn syr() {
     let tests: Vec<case::syr> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut a = t.a;
-        level2::syr(t.uplo, t.n, t.alpha, &t.x, t.incx, &mut a, t.lda);
+        level2::syr(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &mut a,
+            t.lda,
+        );
         approximately!(a, t.expect);
     }
+
+    file = File::open("./tests/fixtures/level2/complex/syr.json").unwrap();
+    reader = BufReader::new(file);
+    let tests: Vec<case::complex::syr> = serde_json::from_reader(reader).unwrap();
+    for t in tests {
+        let mut a = t.a;
+        level2::complex::syr(
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &mut a,
+            t.lda,
+        );
+        capproximately!(a, t.expect);
+    }
 }
 
 #[test]
@@ -595,7 +825,15 @@ fn syr2() {
     for t in tests {
         let mut a = t.a;
         level2::syr2(
-            t.uplo, t.n, t.alpha, &t.x, t.incx, &t.y, t.incy, &mut a, t.lda,
+            Uplo::try_from(t.uplo).unwrap(),
+            t.n,
+            t.alpha,
+            &t.x,
+            t.incx,
+            &t.y,
+            t.incy,
+            &mut a,
+            t.lda,
         );
         approximately!(a, t.expect);
     }
@@ -611,7 +849,15 @@ fn tbmv() {
     for t in tests {
         let mut x = t.x;
         level2::tbmv(
-            t.uplo, t.trans, t.diag, t.n, t.k, &t.a, t.lda, &mut x, t.incx,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            t.k,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
         );
         approximately!(x, t.expect);
     }
@@ -622,7 +868,15 @@ fn tbmv() {
     for t in tests {
         let mut x = t.x;
         level2::complex::tbmv(
-            t.uplo, t.trans, t.diag, t.n, t.k, &t.a, t.lda, &mut x, t.incx,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            t.k,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
         );
         capproximately!(x, t.expect);
     }
@@ -638,7 +892,15 @@ fn tbsv() {
     for t in tests {
         let mut x = t.x;
         level2::tbsv(
-            t.uplo, t.trans, t.diag, t.n, t.k, &t.a, t.lda, &mut x, t.incx,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            t.k,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
         );
         approximately!(x, t.expect);
     }
@@ -649,7 +911,15 @@ fn tbsv() {
     for t in tests {
         let mut x = t.x;
         level2::complex::tbsv(
-            t.uplo, t.trans, t.diag, t.n, t.k, &t.a, t.lda, &mut x, t.incx,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            t.k,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
         );
         capproximately!(x, t.expect);
     }
@@ -664,7 +934,15 @@ fn tpmv() {
     let tests: Vec<case::tpmv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::tpmv(t.uplo, t.trans, t.diag, t.n, &t.ap, &mut x, t.incx);
+        level2::tpmv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.ap,
+            &mut x,
+            t.incx,
+        );
         approximately!(x, t.expect);
     }
 
@@ -673,7 +951,15 @@ fn tpmv() {
     let tests: Vec<case::complex::tpmv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::complex::tpmv(t.uplo, t.trans, t.diag, t.n, &t.ap, &mut x, t.incx);
+        level2::complex::tpmv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.ap,
+            &mut x,
+            t.incx,
+        );
         capproximately!(x, t.expect);
     }
 }
@@ -687,7 +973,15 @@ fn tpsv() {
     let tests: Vec<case::tpsv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::tpsv(t.uplo, t.trans, t.diag, t.n, &t.ap, &mut x, t.incx);
+        level2::tpsv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.ap,
+            &mut x,
+            t.incx,
+        );
         approximately!(x, t.expect);
     }
 
@@ -696,7 +990,15 @@ fn tpsv() {
     let tests: Vec<case::complex::tpsv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::complex::tpsv(t.uplo, t.trans, t.diag, t.n, &t.ap, &mut x, t.incx);
+        level2::complex::tpsv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.ap,
+            &mut x,
+            t.incx,
+        );
         capproximately!(x, t.expect);
     }
 }
@@ -710,7 +1012,16 @@ fn trmv() {
     let tests: Vec<case::trmv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::trmv(t.uplo, t.trans, t.diag, t.n, &t.a, t.lda, &mut x, t.incx);
+        level2::trmv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
+        );
         approximately!(x, t.expect);
     }
 
@@ -719,7 +1030,16 @@ fn trmv() {
     let tests: Vec<case::complex::trmv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::complex::trmv(t.uplo, t.trans, t.diag, t.n, &t.a, t.lda, &mut x, t.incx);
+        level2::complex::trmv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
+        );
         capproximately!(x, t.expect);
     }
 }
@@ -733,7 +1053,16 @@ fn trsv() {
     let tests: Vec<case::trsv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::trsv(t.uplo, t.trans, t.diag, t.n, &t.a, t.lda, &mut x, t.incx);
+        level2::trsv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
+        );
         approximately!(x, t.expect);
     }
 
@@ -742,7 +1071,16 @@ fn trsv() {
     let tests: Vec<case::complex::trsv> = serde_json::from_reader(reader).unwrap();
     for t in tests {
         let mut x = t.x;
-        level2::complex::trsv(t.uplo, t.trans, t.diag, t.n, &t.a, t.lda, &mut x, t.incx);
+        level2::complex::trsv(
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.n,
+            &t.a,
+            t.lda,
+            &mut x,
+            t.incx,
+        );
         capproximately!(x, t.expect);
     }
 }
@@ -758,7 +1096,18 @@ fn gemm() {
         let mut c = t.c;
         println!("{:?}", t.m);
         level3::gemm(
-            t.transa, t.transb, t.m, t.n, t.k, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c,
+            Trans::try_from(t.transa).unwrap(),
+            Trans::try_from(t.transb).unwrap(),
+            t.m,
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
             t.ldc,
         );
         approximately!(c, t.expect, 1E-5);
@@ -770,7 +1119,18 @@ fn gemm() {
     for t in tests {
         let mut c = t.c;
         level3::complex::gemm(
-            t.transa, t.transb, t.m, t.n, t.k, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c,
+            Trans::try_from(t.transa).unwrap(),
+            Trans::try_from(t.transb).unwrap(),
+            t.m,
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
             t.ldc,
         );
         capproximately!(c, t.expect);
@@ -787,7 +1147,18 @@ fn hemm() {
     for t in tests {
         let mut c = t.c;
         level3::complex::hemm(
-            t.side, t.uplo, t.m, t.n, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c, t.ldc,
+            Side::try_from(t.side).unwrap(),
+            Uplo::try_from(t.uplo).unwrap(),
+            t.m,
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         capproximately!(c, t.expect);
     }
@@ -803,7 +1174,16 @@ fn herk() {
     for t in tests {
         let mut c = t.c;
         level3::complex::herk(
-            t.uplo, t.trans, t.n, t.k, t.alpha, &t.a, t.lda, t.beta, &mut c, t.ldc,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         capproximately!(c, t.expect);
     }
@@ -819,7 +1199,18 @@ fn her2k() {
     for t in tests {
         let mut c = t.c;
         level3::complex::her2k(
-            t.uplo, t.trans, t.n, t.k, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c, t.ldc,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         capproximately!(c, t.expect);
     }
@@ -835,7 +1226,18 @@ fn symm() {
     for t in tests {
         let mut c = t.c;
         level3::symm(
-            t.side, t.uplo, t.m, t.n, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c, t.ldc,
+            Side::try_from(t.side).unwrap(),
+            Uplo::try_from(t.uplo).unwrap(),
+            t.m,
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         approximately!(c, t.expect);
     }
@@ -846,7 +1248,18 @@ fn symm() {
     for t in tests {
         let mut c = t.c;
         level3::complex::symm(
-            t.side, t.uplo, t.m, t.n, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c, t.ldc,
+            Side::try_from(t.side).unwrap(),
+            Uplo::try_from(t.uplo).unwrap(),
+            t.m,
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         capproximately!(c, t.expect);
     }
@@ -861,7 +1274,16 @@ fn syrk() {
     for t in tests {
         let mut c = t.c;
         level3::syrk(
-            t.uplo, t.trans, t.n, t.k, t.alpha, &t.a, t.lda, t.beta, &mut c, t.ldc,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         approximately!(c, t.expect);
     }
@@ -872,7 +1294,16 @@ fn syrk() {
     for t in tests {
         let mut c = t.c;
         level3::complex::syrk(
-            t.uplo, t.trans, t.n, t.k, t.alpha, &t.a, t.lda, t.beta, &mut c, t.ldc,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         capproximately!(c, t.expect);
     }
@@ -888,7 +1319,18 @@ fn syr2k() {
     for t in tests {
         let mut c = t.c;
         level3::syr2k(
-            t.uplo, t.trans, t.n, t.k, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c, t.ldc,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         approximately!(c, t.expect);
     }
@@ -899,7 +1341,18 @@ fn syr2k() {
     for t in tests {
         let mut c = t.c;
         level3::complex::syr2k(
-            t.uplo, t.trans, t.n, t.k, t.alpha, &t.a, t.lda, &t.b, t.ldb, t.beta, &mut c, t.ldc,
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.trans).unwrap(),
+            t.n,
+            t.k,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &t.b,
+            t.ldb,
+            t.beta,
+            &mut c,
+            t.ldc,
         );
         capproximately!(c, t.expect);
     }
@@ -915,7 +1368,17 @@ fn trmm() {
     for t in tests {
         let mut b = t.b;
         level3::trmm(
-            t.side, t.uplo, t.transa, t.diag, t.m, t.n, t.alpha, &t.a, t.lda, &mut b, t.ldb,
+            Side::try_from(t.side).unwrap(),
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.transa).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.m,
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &mut b,
+            t.ldb,
         );
         approximately!(b, t.expect);
     }
@@ -926,7 +1389,17 @@ fn trmm() {
     for t in tests {
         let mut b = t.b;
         level3::complex::trmm(
-            t.side, t.uplo, t.transa, t.diag, t.m, t.n, t.alpha, &t.a, t.lda, &mut b, t.ldb,
+            Side::try_from(t.side).unwrap(),
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.transa).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.m,
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &mut b,
+            t.ldb,
         );
         capproximately!(b, t.expect);
     }
@@ -942,7 +1415,17 @@ fn trsm() {
     for t in tests {
         let mut b = t.b;
         level3::trsm(
-            t.side, t.uplo, t.transa, t.diag, t.m, t.n, t.alpha, &t.a, t.lda, &mut b, t.ldb,
+            Side::try_from(t.side).unwrap(),
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.transa).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.m,
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &mut b,
+            t.ldb,
         );
         approximately!(b, t.expect);
     }
@@ -953,7 +1436,17 @@ fn trsm() {
     for t in tests {
         let mut b = t.b;
         level3::complex::trsm(
-            t.side, t.uplo, t.transa, t.diag, t.m, t.n, t.alpha, &t.a, t.lda, &mut b, t.ldb,
+            Side::try_from(t.side).unwrap(),
+            Uplo::try_from(t.uplo).unwrap(),
+            Trans::try_from(t.transa).unwrap(),
+            Diag::try_from(t.diag).unwrap(),
+            t.m,
+            t.n,
+            t.alpha,
+            &t.a,
+            t.lda,
+            &mut b,
+            t.ldb,
         );
         capproximately!(b, t.expect);
     }
@@ -0,0 +1,80 @@
+!> \brief \b LA_CONSTANTS is a module for the scaling constants for the compiled Fortran single and double precisions
+!
+!  =========== DOCUMENTATION ===========
+!
+! Online html documentation available at
+!            http://www.netlib.org/lapack/explore-html/
+!
+!  Authors:
+!  ========
+!
+!> \author Edward Anderson, Lockheed Martin
+!
+!> \par Contributors:
+!  ==================
+!>
+!> Weslley Pereira, University of Colorado, Denver, USA
+!
+!> \par Further Details:
+!  =====================
+!>
+!> \verbatim
+!>
+!>  Anderson E. (2017)
+!>  Algorithm 978: Safe Scaling in the Level 1 BLAS
+!>  ACM Trans Math Softw 44:1--28
+!>  https://doi.org/10.1145/3061665
+!>
+!>  Blue, James L. (1978)
+!>  A Portable Fortran Program to Find the Euclidean Norm of a Vector
+!>  ACM Trans Math Softw 4:15--23
+!>  https://doi.org/10.1145/355769.355771
+!>
+!>  Only the constants used by the nrm2 routines of the reference BLAS 3.10 are kept.
+!> \endverbatim
+!
+module LA_CONSTANTS
+!  -- LAPACK auxiliary module (version 3.10.0) --
+!  -- LAPACK is a software package provided by Univ. of Tennessee,    --
+!  -- Univ. of California Berkeley, Univ. of Colorado Denver and NAG Ltd..--
+!     February 2021
+
+!  Standard constants for
+   integer, parameter :: sp = kind(1.e0)
+
+   real(sp), parameter :: szero = 0.0_sp
+   real(sp), parameter :: sone = 1.0_sp
+
+!  Blue's scaling constants
+   real(sp), parameter :: stsml = real(radix(0._sp), sp)**ceiling( &
+       (minexponent(0._sp) - 1) * 0.5_sp)
+   real(sp), parameter :: stbig = real(radix(0._sp), sp)**floor( &
+       (maxexponent(0._sp) - digits(0._sp) + 1) * 0.5_sp)
+!  ssml >= 1/s, where s was defined in https://doi.org/10.1145/355769.355771
+!  The correction was added in https://doi.org/10.1145/3061665 to scale denormalized numbers correctly
+   real(sp), parameter :: sssml = real(radix(0._sp), sp)**( - floor( &
+       (minexponent(0._sp) - digits(0._sp)) * 0.5_sp))
+!  sbig = 1/S, where S was defined in https://doi.org/10.1145/355769.355771
+   real(sp), parameter :: ssbig = real(radix(0._sp), sp)**( - ceiling( &
+       (maxexponent(0._sp) + digits(0._sp) - 1) * 0.5_sp))
+
+!  Standard constants for
+   integer, parameter :: dp = kind(1.d0)
+
+   real(dp), parameter :: dzero = 0.0_dp
+   real(dp), parameter :: done = 1.0_dp
+
+!  Blue's scaling constants
+   real(dp), parameter :: dtsml = real(radix(0._dp), dp)**ceiling( &
+       (minexponent(0._dp) - 1) * 0.5_dp)
+   real(dp), parameter :: dtbig = real(radix(0._dp), dp)**floor( &
+       (maxexponent(0._dp) - digits(0._dp) + 1) * 0.5_dp)
+!  ssml >= 1/s, where s was defined in https://doi.org/10.1145/355769.355771
+!  The correction was added in https://doi.org/10.1145/3061665 to scale denormalized numbers correctly
+   real(dp), parameter :: dssml = real(radix(0._dp), dp)**( - floor( &
+       (minexponent(0._dp) - digits(0._dp)) * 0.5_dp))
+!  sbig = 1/S, where S was defined in https://doi.org/10.1145/355769.355771
+   real(dp), parameter :: dsbig = real(radix(0._dp), dp)**( - ceiling( &
+       (maxexponent(0._dp) + digits(0._dp) - 1) * 0.5_dp))
+
+end module LA_CONSTANTS
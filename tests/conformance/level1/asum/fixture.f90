@@ -1,5 +1,6 @@
 program fixture
    use, intrinsic :: iso_fortran_env, only: wp => real64
+   use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf, ieee_negative_inf
    use json_module
    type(json_core) :: json
    type(json_value), pointer :: root
@@ -19,6 +20,15 @@ program fixture
    x = (/1, 2, 3, 4, 5, 6/)
    call addcase(root, '3', 3, x, 0)
 
+   x = (/1d0, ieee_value(0d0, ieee_quiet_nan), 3d0, 4d0, 5d0, 6d0/)
+   call addcase(root, '4', 6, x, 1)
+
+   x = (/1d0, 2d0, ieee_value(0d0, ieee_negative_inf), 4d0, ieee_value(0d0, ieee_positive_inf), 6d0/)
+   call addcase(root, '5', 6, x, 1)
+
+   x = (/1d0, ieee_value(0d0, ieee_quiet_nan), 3d0, ieee_value(0d0, ieee_positive_inf), 5d0, 6d0/)
+   call addcase(root, '6', 3, x, 2)
+
    call print(root)
 contains
    subroutine addcase(root, ncase, n, x, incx)
@@ -1,5 +1,6 @@
 program fixture
    use, intrinsic :: iso_fortran_env, only: wp => real64
+   use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf, ieee_negative_inf
    use json_module
    type(json_core) :: json
    type(json_value), pointer :: root
@@ -19,6 +20,17 @@ program fixture
    x = (/(1, 7), (2, 8), (3, 9), (4, 10), (5, 11), (6, 12)/)
    call addcase(root, '3', 0, x, 0)
 
+   x = (/(1, 7), (0, 0), (3, 9), (4, 10), (5, 11), (6, 12)/)
+   x(2) = cmplx(0d0, ieee_value(0d0, ieee_quiet_nan), kind=wp)
+   call addcase(root, '4', 6, x, 1)
+
+   x(2) = cmplx(ieee_value(0d0, ieee_negative_inf), 1d0, kind=wp)
+   x(5) = cmplx(2d0, ieee_value(0d0, ieee_positive_inf), kind=wp)
+   call addcase(root, '5', 6, x, 1)
+
+   x(4) = cmplx(ieee_value(0d0, ieee_quiet_nan), 1d0, kind=wp)
+   call addcase(root, '6', 3, x, 2)
+
    call print(root)
 contains
    subroutine addcase(root, ncase, n, x, incx)
@@ -1,5 +1,6 @@
 program fixture
    use, intrinsic :: iso_fortran_env, only: wp => real64
+   use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf
    use json_module
    type(json_core) :: json
    type(json_value), pointer :: root
@@ -23,6 +24,32 @@ program fixture
 
    call addcase(root, '2', 10, x, 0)
 
+   x = (0d0, 0d0)
+   x(1:4) = (/cmplx(huge(0d0), -huge(0d0), kind=wp), (1d0, 0d0), cmplx(0d0, huge(0d0), kind=wp), (1d300, -1d-300)/)
+   call addcase(root, '3', 4, x, 1)
+
+   x(1:4) = (/(1d300, -3d300), (4d300, 0d0), (0d0, 1d-300), (2d-300, 2d0)/)
+   call addcase(root, '4', 4, x, 1)
+
+   x(1:4) = (/cmplx(tiny(0d0), -tiny(0d0), kind=wp), (3d-300, 4d-300), (0d0, 0d0), (-1d-300, 0d0)/)
+   call addcase(root, '5', 4, x, 1)
+
+   x(1:4) = (/(5d-324, 0d0), (-2d-320, 4.9d-310), (0d0, 5d-324), (0d0, 0d0)/)
+   call addcase(root, '6', 4, x, 1)
+
+   x(1:4) = (/(1d0, 0d0), cmplx(2d0, ieee_value(0d0, ieee_positive_inf), kind=wp), (3d0, 0d0), (0d0, 4d0)/)
+   call addcase(root, '7', 4, x, 1)
+
+   x(1:4) = (/(1d0, 0d0), (2d0, 0d0), cmplx(ieee_value(0d0, ieee_quiet_nan), 1d0, kind=wp), (0d0, 4d0)/)
+   call addcase(root, '8', 4, x, 1)
+
+   x(1:4) = (/(1d300, 1d0), (2d0, 0d0), (3d-300, 4d-300), (0d0, 4d0)/)
+   call addcase(root, '9', 2, x, 2)
+
+   x(1:4) = (/cmplx(ieee_value(0d0, ieee_positive_inf), 1d0, kind=wp), (1d0, 0d0), &
+             &cmplx(0d0, ieee_value(0d0, ieee_quiet_nan), kind=wp), (0d0, 1d0)/)
+   call addcase(root, '10', 4, x, 1)
+
    call print(root)
 contains
    subroutine addcase(root, ncase, n, x, incx)
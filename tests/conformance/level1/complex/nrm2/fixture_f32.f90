@@ -0,0 +1,98 @@
+program fixture
+   use, intrinsic :: iso_fortran_env, only: wp => real32
+   use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf
+   use json_module
+   type(json_core) :: json
+   type(json_value), pointer :: root
+   complex x(10)
+   call json%initialize()
+   call json%create_array(root, '')
+
+   x = (/(0, 0.76359346114045956),&
+         &(0, 0),&
+         &(1.3297992629225006134, -1.14765700923635139),&
+         &(1.2724293214294046805, -0.28946157368822334),&
+         &(0.4146414344564082199, -0.29921511789731614),&
+         &(-1.5399500419037095433, -0.41151083279506701),&
+         &(-0.9285670347135380753, 0.25222344815613229),&
+         &(-0.2947204467905601977, -0.89192112728456863),&
+         &(-0.0057671727475369552, 0.43568329935571865),&
+         &(2.4046533888579508798, -1.23753842192995811)/)
+   call addcase(root, '0', 10, x, 1)
+
+   call addcase(root, '1', 0, x, 1)
+
+   call addcase(root, '2', 10, x, 0)
+
+   x = (0e0, 0e0)
+   x(1:4) = (/cmplx(huge(0e0), -huge(0e0), kind=wp), (1e0, 0e0), cmplx(0e0, huge(0e0), kind=wp), (1e30, -1e-30)/)
+   call addcase(root, '3', 4, x, 1)
+
+   x(1:4) = (/(1e30, -3e30), (4e30, 0e0), (0e0, 1e-30), (2e-30, 2e0)/)
+   call addcase(root, '4', 4, x, 1)
+
+   x(1:4) = (/cmplx(tiny(0e0), -tiny(0e0), kind=wp), (3e-30, 4e-30), (0e0, 0e0), (-1e-30, 0e0)/)
+   call addcase(root, '5', 4, x, 1)
+
+   x(1:4) = (/(1e-45, 0e0), (-2e-40, 4.9e-39), (0e0, 1e-45), (0e0, 0e0)/)
+   call addcase(root, '6', 4, x, 1)
+
+   x(1:4) = (/(1e0, 0e0), cmplx(2e0, ieee_value(0e0, ieee_positive_inf), kind=wp), (3e0, 0e0), (0e0, 4e0)/)
+   call addcase(root, '7', 4, x, 1)
+
+   x(1:4) = (/(1e0, 0e0), (2e0, 0e0), cmplx(ieee_value(0e0, ieee_quiet_nan), 1e0, kind=wp), (0e0, 4e0)/)
+   call addcase(root, '8', 4, x, 1)
+
+   x(1:4) = (/(1e30, 1e0), (2e0, 0e0), (3e-30, 4e-30), (0e0, 4e0)/)
+   call addcase(root, '9', 2, x, 2)
+
+   x(1:4) = (/cmplx(ieee_value(0e0, ieee_positive_inf), 1e0, kind=wp), (1e0, 0e0), &
+             &cmplx(0e0, ieee_value(0e0, ieee_quiet_nan), kind=wp), (0e0, 1e0)/)
+   call addcase(root, '10', 4, x, 1)
+
+   call print(root)
+contains
+   subroutine addcase(root, ncase, n, x, incx)
+      type(json_core) :: json
+      type(json_value), pointer :: root, case, array, data
+      external scnrm2
+      character(len=*) ncase
+      integer n, incx
+      complex x(10)
+      real scnrm2
+      call json%create_object(case, '')
+      call json%add(root, case)
+      call json%add(case, 'n', n)
+      call json%create_array(array, 'x')
+      do i = 1, 10
+         call json%create_array(data, '')
+         call json%add(data, '', real(x(i)))
+         call json%add(data, '', aimag(x(i)))
+         call json%add(array, data)
+         nullify (data)
+      enddo
+      call json%add(case, array)
+      nullify (array)
+      call json%add(case, 'incx', incx)
+      call json%add(case, 'expect', scnrm2(n, x, incx))
+      nullify (case)
+   end subroutine addcase
+
+   subroutine print(root)
+      use, intrinsic :: iso_fortran_env, only: real64
+      use json_module, CK => json_CK
+      implicit none
+
+      type(json_core) :: json
+      type(json_value), pointer :: root
+      logical :: status_ok
+      character(kind=CK, len=:), allocatable :: error_msg
+      call json%print(root, './tests/fixtures/level1/complex/nrm2_f32.json')
+      if (json%failed()) then
+         call json%check_for_errors(status_ok, error_msg)
+         write (*, *) 'Error: '//error_msg
+         call json%clear_exceptions()
+         call json%destroy(root)
+      end if
+   end subroutine print
+end
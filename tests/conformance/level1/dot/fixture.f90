@@ -1,5 +1,6 @@
 program fixture
    use, intrinsic :: iso_fortran_env, only: wp => real64
+   use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf, ieee_negative_inf
    use json_module
    type(json_core) :: json
    type(json_value), pointer :: root
@@ -31,6 +32,22 @@ program fixture
    y = (/5, 6, 7, 8, 5, 4, 3, 0, 0, 0/)
    call addcase(root, '5', 7, x, 1, y, 1)
 
+   x = (/1, 2, 3, 4, 5, 6, 7, 8, 9, 2/)
+   y = (/5, 6, 7, 8, 5, 4, 3, 3, -1, -9/)
+   x(3) = ieee_value(0d0, ieee_positive_inf)
+   call addcase(root, '6', 10, x, 1, y, 1)
+
+   y(3) = 0
+   call addcase(root, '7', 10, x, 1, y, 1)
+
+   y(3) = 7
+   y(6) = ieee_value(0d0, ieee_negative_inf)
+   call addcase(root, '8', 10, x, 1, y, 1)
+
+   y(6) = 4
+   x(8) = ieee_value(0d0, ieee_quiet_nan)
+   call addcase(root, '9', 5, x, -2, y, -2)
+
    call print(root)
 contains
    subroutine addcase(root, ncase, n, x, incx, y, incy)
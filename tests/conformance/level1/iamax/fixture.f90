@@ -1,5 +1,6 @@
       program fixture
          use, intrinsic :: iso_fortran_env, only: wp => real64
+         use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf, ieee_negative_inf
          use json_module
          type(json_core) :: json
          type(json_value), pointer :: root
@@ -22,6 +23,18 @@
          x = (/1, 0, 3, 4, 5, 6/)
          call addcase(root, '4', 1, x, 1)
 
+         x = (/1, 0, 3, 4, 5, 6/)
+         x(2) = ieee_value(0d0, ieee_quiet_nan)
+         call addcase(root, '5', 6, x, 1)
+
+         x(1) = ieee_value(0d0, ieee_quiet_nan)
+         call addcase(root, '6', 6, x, 1)
+
+         x = (/1, 0, 3, 4, 5, 6/)
+         x(3) = ieee_value(0d0, ieee_negative_inf)
+         x(5) = ieee_value(0d0, ieee_positive_inf)
+         call addcase(root, '7', 6, x, 1)
+
          call print(root)
       contains
          subroutine addcase(root, ncase, n, x, incx)
@@ -1,5 +1,6 @@
 program fixture
    use, intrinsic :: iso_fortran_env, only: wp => real64
+   use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf
    use json_module
    type(json_core) :: json
    type(json_value), pointer :: root
@@ -16,6 +17,30 @@ program fixture
    x = (/3, 2, 3, 4/)
    call addcase(root, '2', 1, x, 1)
 
+   x = (/huge(0d0), huge(0d0), 1d0, -huge(0d0)/)
+   call addcase(root, '3', 4, x, 1)
+
+   x = (/1d300, -3d300, 4d300, 1d-300/)
+   call addcase(root, '4', 4, x, 1)
+
+   x = (/tiny(0d0), -tiny(0d0), 3d-300, 4d-300/)
+   call addcase(root, '5', 4, x, 1)
+
+   x = (/5d-324, -2d-320, 4.9d-310, 0d0/)
+   call addcase(root, '6', 4, x, 1)
+
+   x = (/1d0, ieee_value(0d0, ieee_positive_inf), 2d0, 3d0/)
+   call addcase(root, '7', 4, x, 1)
+
+   x = (/1d0, 2d0, ieee_value(0d0, ieee_quiet_nan), 3d0/)
+   call addcase(root, '8', 4, x, 1)
+
+   x = (/1d300, 2d0, 3d-300, 4d0/)
+   call addcase(root, '9', 2, x, 2)
+
+   x = (/ieee_value(0d0, ieee_positive_inf), 1d0, ieee_value(0d0, ieee_quiet_nan), -ieee_value(0d0, ieee_positive_inf)/)
+   call addcase(root, '10', 4, x, 1)
+
    call print(root)
 contains
    subroutine addcase(root, ncase, n, x, incx)
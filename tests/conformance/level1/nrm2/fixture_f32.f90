@@ -0,0 +1,79 @@
+program fixture
+   use, intrinsic :: iso_fortran_env, only: wp => real32
+   use, intrinsic :: ieee_arithmetic, only: ieee_value, ieee_quiet_nan, ieee_positive_inf
+   use json_module
+   type(json_core) :: json
+   type(json_value), pointer :: root
+   real x(4)
+   call json%initialize()
+   call json%create_array(root, '')
+
+   x = (/3, 2, 3, 0/)
+   call addcase(root, '0', 3, x, 1)
+
+   x = (/1, 2, 3, 4/)
+   call addcase(root, '1', 4, x, 0)
+
+   x = (/3, 2, 3, 4/)
+   call addcase(root, '2', 1, x, 1)
+
+   x = (/huge(0e0), huge(0e0), 1e0, -huge(0e0)/)
+   call addcase(root, '3', 4, x, 1)
+
+   x = (/1e30, -3e30, 4e30, 1e-30/)
+   call addcase(root, '4', 4, x, 1)
+
+   x = (/tiny(0e0), -tiny(0e0), 3e-30, 4e-30/)
+   call addcase(root, '5', 4, x, 1)
+
+   x = (/1e-45, -2e-40, 4.9e-39, 0e0/)
+   call addcase(root, '6', 4, x, 1)
+
+   x = (/1e0, ieee_value(0e0, ieee_positive_inf), 2e0, 3e0/)
+   call addcase(root, '7', 4, x, 1)
+
+   x = (/1e0, 2e0, ieee_value(0e0, ieee_quiet_nan), 3e0/)
+   call addcase(root, '8', 4, x, 1)
+
+   x = (/1e30, 2e0, 3e-30, 4e0/)
+   call addcase(root, '9', 2, x, 2)
+
+   x = (/ieee_value(0e0, ieee_positive_inf), 1e0, ieee_value(0e0, ieee_quiet_nan), -ieee_value(0e0, ieee_positive_inf)/)
+   call addcase(root, '10', 4, x, 1)
+
+   call print(root)
+contains
+   subroutine addcase(root, ncase, n, x, incx)
+      type(json_core) :: json
+      type(json_value), pointer :: root, case
+      external snrm2
+      character(len=*) ncase
+      integer n, incx
+      real x(4), snrm2
+      call json%create_object(case, '')
+      call json%add(root, case)
+      call json%add(case, 'n', n)
+      call json%add(case, 'x', x)
+      call json%add(case, 'incx', incx)
+      call json%add(case, 'expect', snrm2(n, x, incx))
+      nullify (case)
+   end subroutine addcase
+
+   subroutine print(root)
+      use, intrinsic :: iso_fortran_env, only: real64
+      use json_module, CK => json_CK
+      implicit none
+
+      type(json_core) :: json
+      type(json_value), pointer :: root
+      logical :: status_ok
+      character(kind=CK, len=:), allocatable :: error_msg
+      call json%print(root, './tests/fixtures/level1/nrm2_f32.json')
+      if (json%failed()) then
+         call json%check_for_errors(status_ok, error_msg)
+         write (*, *) 'Error: '//error_msg
+         call json%clear_exceptions()
+         call json%destroy(root)
+      end if
+   end subroutine print
+end
@@ -78,6 +78,27 @@
          dx1 = 3
          dy1 = 2
          call addcase(root, '9', dd1, dd2, dx1, dy1, dparam)
+
+         dparam = dcparam
+         dd1 = 33554432
+         dd2 = 33554432
+         dx1 = 1
+         dy1 = 1
+         call addcase(root, '10', dd1, dd2, dx1, dy1, dparam)
+
+         dparam = dcparam
+         dd1 = 1.1920929d-7
+         dd2 = 1.1920929d-7
+         dx1 = 1
+         dy1 = 1
+         call addcase(root, '11', dd1, dd2, dx1, dy1, dparam)
+
+         dparam = dcparam
+         dd1 = 0
+         dd2 = 1
+         dx1 = 2
+         dy1 = 4
+         call addcase(root, '12', dd1, dd2, dx1, dy1, dparam)
          call print(root)
       contains
          subroutine addcase(root, ncase, dd1, dd2, dx1, dy1, dparam)
@@ -0,0 +1,259 @@
+#![cfg(feature = "fortran")]
+use libblas::fortran::level1::complex::{izamax_, zdotc_, zdotu_};
+use libblas::fortran::level1::{daxpy_, ddot_, idamax_};
+use libblas::fortran::level2::{dspr_, dsymv_, dsyr2_};
+use libblas::fortran::level3::complex::zgemm_;
+use libblas::fortran::level3::dgemm_;
+use libblas::{level3, xerbla, BlasError, Trans};
+use num_complex::Complex;
+use std::os::raw::{c_char, c_void};
+use std::sync::Mutex;
+mod fixtures;
+
+static REPORTED: Mutex<Vec<(&'static str, usize)>> = Mutex::new(Vec::new());
+
+fn record(err: &BlasError) {
+    REPORTED.lock().unwrap().push((err.routine(), err.param()));
+}
+
+fn flag(c: u8) -> c_char {
+    c as c_char
+}
+
+#[test]
+fn dgemm() {
+    let a = fixtures::matrix_mxn(4, 3);
+    let b = fixtures::matrix_mxn(4, 2);
+    let c = fixtures::matrix_mxn(3, 2);
+
+    let mut expected = c.clone();
+    level3::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        3,
+        2,
+        4,
+        0.3,
+        &a,
+        4,
+        &b,
+        4,
+        0.2,
+        &mut expected,
+        3,
+    );
+    let mut c = c;
+    unsafe {
+        dgemm_(
+            &flag(b't'),
+            &flag(b'N'),
+            &3,
+            &2,
+            &4,
+            &0.3,
+            a.as_ptr(),
+            &4,
+            b.as_ptr(),
+            &4,
+            &0.2,
+            c.as_mut_ptr(),
+            &3,
+        );
+    }
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn zgemm() {
+    let a = fixtures::complex::matrix_mxn(2, 3);
+    let b = fixtures::complex::matrix_mxn(2, 2);
+    let c = fixtures::complex::matrix_mxn(3, 2);
+    let alpha = Complex::new(0.5, -0.25);
+    let beta = Complex::new(1.0, 2.0);
+
+    let mut expected = c.clone();
+    level3::complex::gemm(
+        Trans::ConjTrans,
+        Trans::NoTrans,
+        3,
+        2,
+        2,
+        alpha,
+        &a,
+        2,
+        &b,
+        2,
+        beta,
+        &mut expected,
+        3,
+    );
+    let mut c = c;
+    unsafe {
+        zgemm_(
+            &flag(b'C'),
+            &flag(b'N'),
+            &3,
+            &2,
+            &2,
+            &alpha as *const _ as *const c_void,
+            a.as_ptr() as *const c_void,
+            &2,
+            b.as_ptr() as *const c_void,
+            &2,
+            &beta as *const _ as *const c_void,
+            c.as_mut_ptr() as *mut c_void,
+            &3,
+        );
+    }
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn level1() {
+    let x = [1.0, -7.0, 3.0, 2.0];
+    let mut y = [2.0, 1.0, 0.5, -1.0];
+    unsafe {
+        assert_eq!(ddot_(&4, x.as_ptr(), &1, y.as_ptr(), &1), -5.5);
+        assert_eq!(ddot_(&2, x.as_ptr(), &2, y.as_ptr(), &-2), 6.5);
+
+        // Fortran indices are one-based.
+        assert_eq!(idamax_(&4, x.as_ptr(), &1), 2);
+        assert_eq!(idamax_(&2, x.as_ptr(), &2), 2);
+        assert_eq!(idamax_(&0, x.as_ptr(), &1), 0);
+        assert_eq!(idamax_(&4, x.as_ptr(), &0), 0);
+
+        daxpy_(&2, &2.0, x.as_ptr(), &1, y.as_mut_ptr(), &-2);
+    }
+    assert_eq!(y, [-12.0, 1.0, 2.5, -1.0]);
+}
+
+#[test]
+fn complex_level1() {
+    let x = [
+        Complex::new(1.0, 1.0),
+        Complex::new(-3.0, 0.5),
+        Complex::new(0.0, 2.0),
+    ];
+    let y = [
+        Complex::new(2.0, 0.0),
+        Complex::new(0.0, 1.0),
+        Complex::new(1.0, -1.0),
+    ];
+    let (px, py) = (x.as_ptr() as *const c_void, y.as_ptr() as *const c_void);
+    unsafe {
+        assert_eq!(izamax_(&3, px, &1), 2);
+        assert_eq!(zdotc_(&3, px, &1, px, &1), Complex::new(15.25, 0.0));
+        assert_eq!(zdotu_(&3, px, &1, py, &1), Complex::new(3.5, 1.0));
+    }
+}
+
+// XERBLA reports go to the one installed handler, so the illegal calls can't be spread over tests running in parallel.
+#[test]
+fn illegal_arguments() {
+    let a = [1.0; 4];
+    let x = [1.0; 2];
+    let mut y = [1.0; 2];
+    let mut c = [1.0; 4];
+
+    let previous = xerbla::set_handler(record);
+    unsafe {
+        dgemm_(
+            &flag(b'X'),
+            &flag(b'N'),
+            &2,
+            &2,
+            &2,
+            &1.0,
+            a.as_ptr(),
+            &2,
+            a.as_ptr(),
+            &2,
+            &1.0,
+            c.as_mut_ptr(),
+            &2,
+        );
+        dgemm_(
+            &flag(b'N'),
+            &flag(b'N'),
+            &2,
+            &2,
+            &2,
+            &1.0,
+            a.as_ptr(),
+            &1,
+            a.as_ptr(),
+            &2,
+            &1.0,
+            c.as_mut_ptr(),
+            &2,
+        );
+        dgemm_(
+            &flag(b'N'),
+            &flag(b'N'),
+            &2,
+            &-1,
+            &2,
+            &1.0,
+            a.as_ptr(),
+            &2,
+            a.as_ptr(),
+            &2,
+            &1.0,
+            c.as_mut_ptr(),
+            &2,
+        );
+        dsymv_(
+            &flag(b'U'),
+            &2,
+            &1.0,
+            a.as_ptr(),
+            &2,
+            x.as_ptr(),
+            &1,
+            &1.0,
+            y.as_mut_ptr(),
+            &0,
+        );
+        dsyr2_(
+            &flag(b'L'),
+            &2,
+            &1.0,
+            x.as_ptr(),
+            &0,
+            x.as_ptr(),
+            &1,
+            c.as_mut_ptr(),
+            &2,
+        );
+        dsyr2_(
+            &flag(b'L'),
+            &2,
+            &1.0,
+            x.as_ptr(),
+            &1,
+            x.as_ptr(),
+            &0,
+            c.as_mut_ptr(),
+            &2,
+        );
+        dspr_(&flag(b'Q'), &2, &1.0, x.as_ptr(), &1, c.as_mut_ptr());
+        dspr_(&flag(b'U'), &2, &1.0, x.as_ptr(), &0, c.as_mut_ptr());
+    }
+    xerbla::set_handler(previous);
+
+    assert_eq!(y, [1.0; 2]);
+    assert_eq!(c, [1.0; 4]);
+    assert_eq!(
+        *REPORTED.lock().unwrap(),
+        [
+            ("DGEMM", 1),
+            ("DGEMM", 8),
+            ("DGEMM", 4),
+            ("DSYMV", 10),
+            ("DSYR2", 5),
+            ("DSYR2", 7),
+            ("DSPR", 1),
+            ("DSPR", 5),
+        ]
+    );
+}
@@ -0,0 +1,219 @@
+use libblas::level3::{self, complex};
+use libblas::{Trans, Uplo};
+use num_complex::Complex;
+mod utils;
+
+fn in_triangle(uplo: Uplo, i: usize, j: usize) -> bool {
+    if uplo == Uplo::Upper {
+        i <= j
+    } else {
+        i >= j
+    }
+}
+
+// C with NaN outside of the uplo triangle, which gemmt must not reference.
+fn with_nans<T: Copy>(uplo: Uplo, n: usize, ldc: usize, c: &mut [T], nan: T) {
+    for j in 0..n {
+        for i in 0..n {
+            if !in_triangle(uplo, i, j) {
+                c[j * ldc + i] = nan;
+            }
+        }
+    }
+}
+
+// Compares the uplo triangle of got with the gemm result, and checks that the rest of C, rows n to ldc included, is untouched.
+fn conforms<T: Copy + std::fmt::Debug>(
+    uplo: Uplo,
+    n: usize,
+    ldc: usize,
+    got: &[T],
+    gemm: &[T],
+    before: &[T],
+    close: impl Fn(T, T) -> bool,
+) {
+    for j in 0..n {
+        for i in 0..ldc {
+            let p = j * ldc + i;
+            if i < n && in_triangle(uplo, i, j) {
+                assert!(
+                    close(got[p], gemm[p]),
+                    "({}, {}): {:?} {:?}",
+                    i,
+                    j,
+                    got[p],
+                    gemm[p]
+                );
+            } else {
+                // Formatted, so that NaN equals NaN.
+                let (got, before) = (format!("{:?}", got[p]), format!("{:?}", before[p]));
+                assert_eq!(got, before, "({}, {}) was changed", i, j);
+            }
+        }
+    }
+}
+
+#[test]
+fn gemmt() {
+    let mut rng = utils::Random::new(1);
+    let trans = [Trans::NoTrans, Trans::Trans];
+    // The second size is split into several diagonal blocks, the off-diagonal blocks are blocked gemm calls.
+    for &(n, k) in &[(7, 5), (150, 40)] {
+        for &uplo in &[Uplo::Upper, Uplo::Lower] {
+            for &trans_a in &trans {
+                for &trans_b in &trans {
+                    for &(alpha, beta) in &[(1.0, 0.0), (-0.5, 1.5), (0.0, 2.0)] {
+                        let (lda, ldb, ldc) = (n.max(k) + 1, n.max(k) + 2, n + 3);
+                        let a = rng.reals(lda * n.max(k));
+                        let b = rng.reals(ldb * n.max(k));
+                        let mut c = rng.reals(ldc * n);
+                        with_nans(uplo, n, ldc, &mut c, f64::NAN);
+                        let before = c.clone();
+
+                        let mut expected = c.clone();
+                        level3::gemm(
+                            trans_a,
+                            trans_b,
+                            n,
+                            n,
+                            k,
+                            alpha,
+                            &a,
+                            lda,
+                            &b,
+                            ldb,
+                            beta,
+                            &mut expected,
+                            ldc,
+                        );
+                        level3::gemmt(
+                            uplo, trans_a, trans_b, n, k, alpha, &a, lda, &b, ldb, beta, &mut c,
+                            ldc,
+                        );
+                        conforms(uplo, n, ldc, &c, &expected, &before, |x: f64, y: f64| {
+                            (x - y).abs() <= 1e-12
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gemmt_complex() {
+    let mut rng = utils::Random::new(2);
+    let trans = [Trans::NoTrans, Trans::Trans, Trans::ConjTrans];
+    let nan = Complex::new(f64::NAN, f64::NAN);
+    for &(n, k) in &[(6, 4), (100, 20)] {
+        for &uplo in &[Uplo::Upper, Uplo::Lower] {
+            for &trans_a in &trans {
+                for &trans_b in &trans {
+                    let (alpha, beta) = (Complex::new(0.5, -1.0), Complex::new(-1.0, 0.25));
+                    let (lda, ldb, ldc) = (n.max(k), n.max(k) + 1, n + 1);
+                    let a = rng.complexes(lda * n.max(k));
+                    let b = rng.complexes(ldb * n.max(k));
+                    let mut c = rng.complexes(ldc * n);
+                    with_nans(uplo, n, ldc, &mut c, nan);
+                    let before = c.clone();
+
+                    let mut expected = c.clone();
+                    complex::gemm(
+                        trans_a,
+                        trans_b,
+                        n,
+                        n,
+                        k,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut expected,
+                        ldc,
+                    );
+                    complex::gemmt(
+                        uplo, trans_a, trans_b, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    conforms(
+                        uplo,
+                        n,
+                        ldc,
+                        &c,
+                        &expected,
+                        &before,
+                        |x: Complex<f64>, y: Complex<f64>| (x - y).norm() <= 1e-12,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gemmt_zero_k_scales_the_triangle() {
+    let nan = f64::NAN;
+    let mut c = vec![1.0, 2.0, nan, 3.0];
+    level3::gemmt(
+        Uplo::Lower,
+        Trans::Trans,
+        Trans::NoTrans,
+        2,
+        0,
+        1.0,
+        &[],
+        1,
+        &[],
+        1,
+        -2.0,
+        &mut c,
+        2,
+    );
+    assert_eq!(&c[..2], &[-2.0, -4.0]);
+    assert!(c[2].is_nan());
+    assert_eq!(c[3], -6.0);
+}
+
+#[test]
+fn illegal_arguments() {
+    let x = vec![0.0; 4];
+    let mut c = vec![0.0; 4];
+    let err = level3::try_gemmt(
+        Uplo::Upper,
+        Trans::Trans,
+        Trans::NoTrans,
+        2,
+        3,
+        1.0,
+        &x,
+        2,
+        &x,
+        3,
+        0.0,
+        &mut c,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemmt", 8));
+
+    let mut c = vec![Complex::new(0.0, 0.0); 4];
+    let z = vec![Complex::new(0.0, 0.0); 4];
+    let err = complex::try_gemmt(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        Complex::new(1.0, 0.0),
+        &z,
+        2,
+        &z,
+        2,
+        Complex::new(0.0, 0.0),
+        &mut c,
+        1,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemmt", 13));
+}
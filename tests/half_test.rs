@@ -0,0 +1,232 @@
+#![cfg(feature = "half")]
+use half::{bf16, f16};
+use libblas::mixed::{self, Storage};
+use libblas::{level1, level2, level3, Trans};
+use rand::Rng;
+mod utils;
+
+fn random<T: Storage<Acc = f32>>(rng: &mut utils::Random, len: usize) -> Vec<T> {
+    (0..len)
+        .map(|_| T::narrow(rng.gen::<f32>() * 4.0 - 2.0))
+        .collect()
+}
+
+fn widen<T: Storage<Acc = f32>>(x: &[T]) -> Vec<f32> {
+    x.iter().map(|v| v.widen()).collect()
+}
+
+// The f32 result of the same inputs, rounded once, is what the storage type must hold.
+fn assert_conforms<T: Storage<Acc = f32> + PartialEq + std::fmt::Debug>(got: &[T], f32s: &[f32]) {
+    let expected: Vec<T> = f32s.iter().map(|&v| T::narrow(v)).collect();
+    assert_eq!(got, &expected[..]);
+}
+
+fn gemm_conforms<T: Storage<Acc = f32> + PartialEq + std::fmt::Debug>() {
+    let mut rng = utils::Random::new(1);
+    let trans = [Trans::NoTrans, Trans::Trans, Trans::ConjTrans];
+    // The second size takes the blocked algorithm.
+    for &(m, n, k) in &[(7, 5, 3), (40, 36, 33)] {
+        for &trans_a in &trans {
+            for &trans_b in &trans {
+                for &(alpha, beta) in &[(1.0, 0.0), (0.5, -1.5), (0.0, 2.0)] {
+                    let (lda, ldb, ldc) = (m.max(k) + 1, n.max(k) + 2, m + 3);
+                    let a: Vec<T> = random(&mut rng, lda * m.max(k));
+                    let b: Vec<T> = random(&mut rng, ldb * n.max(k));
+                    let mut c: Vec<T> = random(&mut rng, ldc * n);
+                    let mut expected = widen(&c);
+                    level3::gemm(
+                        trans_a,
+                        trans_b,
+                        m,
+                        n,
+                        k,
+                        alpha,
+                        &widen(&a),
+                        lda,
+                        &widen(&b),
+                        ldb,
+                        beta,
+                        &mut expected,
+                        ldc,
+                    );
+                    mixed::gemm(
+                        trans_a, trans_b, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    assert_conforms(&c, &expected);
+                }
+            }
+        }
+    }
+}
+
+fn gemv_conforms<T: Storage<Acc = f32> + PartialEq + std::fmt::Debug>() {
+    let mut rng = utils::Random::new(2);
+    let (m, n, lda) = (9, 6, 11);
+    for &trans in &[Trans::NoTrans, Trans::Trans] {
+        for &(incx, incy) in &[(1, 1), (2, -3), (-1, 2)] {
+            for &(alpha, beta) in &[(1.0, 0.0), (-0.75, 1.25), (0.0, 0.5)] {
+                let a: Vec<T> = random(&mut rng, lda * n);
+                let x: Vec<T> = random(&mut rng, m.max(n) * 2);
+                let mut y: Vec<T> = random(&mut rng, m.max(n) * 3);
+                let mut expected = widen(&y);
+                level2::gemv(
+                    trans,
+                    m,
+                    n,
+                    alpha,
+                    &widen(&a),
+                    lda,
+                    &widen(&x),
+                    incx,
+                    beta,
+                    &mut expected,
+                    incy,
+                );
+                mixed::gemv(trans, m, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                assert_conforms(&y, &expected);
+            }
+        }
+    }
+}
+
+fn level1_conforms<T: Storage<Acc = f32> + PartialEq + std::fmt::Debug>() {
+    let mut rng = utils::Random::new(3);
+    for &(incx, incy) in &[(1, 1), (2, -1), (-3, 2)] {
+        let n = 17;
+        let x: Vec<T> = random(&mut rng, n * 3);
+        let mut y: Vec<T> = random(&mut rng, n * 2);
+        let expected = level1::dot(n, &widen(&x), incx, &widen(&y), incy);
+        let dot = mixed::dot(n, &x, incx, &y, incy);
+        assert!(
+            (dot - expected).abs() <= 1e-5 * n as f32,
+            "{} {}",
+            dot,
+            expected
+        );
+
+        let mut expected = widen(&y);
+        level1::axpy(n, 1.5, &widen(&x), incx, &mut expected, incy);
+        mixed::axpy(n, 1.5, &x, incx, &mut y, incy);
+        assert_conforms(&y, &expected);
+    }
+}
+
+#[test]
+fn f16_conforms_to_f32() {
+    gemm_conforms::<f16>();
+    gemv_conforms::<f16>();
+    level1_conforms::<f16>();
+}
+
+#[test]
+fn bf16_conforms_to_f32() {
+    gemm_conforms::<bf16>();
+    gemv_conforms::<bf16>();
+    level1_conforms::<bf16>();
+}
+
+#[test]
+fn accumulates_in_f32() {
+    // Summed in bf16, the ones would stop at 256 where 256 + 1 rounds back to 256.
+    let ones = vec![bf16::ONE; 1000];
+    assert_eq!(mixed::dot(1000, &ones, 1, &ones, 1), 1000.0);
+
+    let mut c = vec![bf16::ZERO];
+    mixed::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        1,
+        1,
+        1000,
+        1.0,
+        &ones,
+        1000,
+        &ones,
+        1000,
+        0.0,
+        &mut c,
+        1,
+    );
+    assert_eq!(c, vec![bf16::from_f32(1000.0)]);
+
+    let mut y = vec![f16::ZERO];
+    let a = vec![f16::from_f32(0.1); 4096];
+    mixed::gemv(
+        Trans::Trans,
+        4096,
+        1,
+        1.0,
+        &a,
+        4096,
+        &vec![f16::ONE; 4096],
+        1,
+        0.0,
+        &mut y,
+        1,
+    );
+    assert_eq!(y, vec![f16::from_f32(4096.0 * f16::from_f32(0.1).to_f32())]);
+}
+
+#[test]
+fn beta_zero_ignores_nan() {
+    let mut c = vec![f16::NAN; 4];
+    let a = vec![f16::ONE; 4];
+    mixed::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &a,
+        2,
+        0.0,
+        &mut c,
+        2,
+    );
+    assert_eq!(c, vec![f16::from_f32(2.0); 4]);
+
+    let mut y = vec![bf16::NAN; 2];
+    mixed::gemv(
+        Trans::NoTrans,
+        2,
+        2,
+        1.0,
+        &[bf16::ONE; 4],
+        2,
+        &[bf16::ONE; 2],
+        1,
+        0.0,
+        &mut y,
+        1,
+    );
+    assert_eq!(y, vec![bf16::from_f32(2.0); 2]);
+}
+
+#[test]
+fn illegal_arguments() {
+    let a = vec![f16::ONE; 4];
+    let mut c = vec![f16::ZERO; 4];
+    let err = mixed::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        1,
+        &a,
+        2,
+        0.0,
+        &mut c,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm", 8));
+
+    let err = mixed::try_gemv(Trans::NoTrans, 2, 2, 1.0, &a, 2, &a, 0, 0.0, &mut c, 1).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemv", 8));
+}
@@ -1,4 +1,5 @@
 use libblas::level1::complex;
+use libblas::NanPolicy;
 use num_complex::Complex;
 mod utils;
 
@@ -717,6 +718,43 @@ fn iamax() {
         Complex::new(6.0, 12.0),
     ];
     assert_eq!(complex::iamax(1, &x, 1), 1);
+
+    let x = vec![
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, 5.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(0.0, 0.0),
+        Complex::new(-3.0, 0.0),
+    ];
+    assert_eq!(complex::iamax(3, &x, 1), 2);
+    assert_eq!(complex::iamax(3, &x, 2), 3);
+}
+
+#[test]
+fn iamax_cabs1() {
+    // |Re| + |Im| picks (3, 3), the modulus picks (0, 5).
+    let x = [
+        Complex::new(3.0, -3.0),
+        Complex::new(0.0, 5.0),
+        Complex::new(-1.0, 4.0),
+    ];
+    assert_eq!(complex::cabs1(x[0]), 6.0);
+    assert_eq!(complex::cabs1(x[1]), 5.0);
+    assert_eq!(complex::iamax(3, &x, 1), 1);
+    assert_eq!(complex::iamax_norm(3, &x, 1), 2);
+    assert_eq!(complex::iamax_norm(2, &x, 2), 1);
+    assert_eq!(complex::iamax_norm(3, &x, 0), 0);
+    assert_eq!(complex::iamax_norm(0, &x, 1), 0);
+    assert_eq!(complex::asum(3, &x, 1), 16.0);
+
+    // CAXPY returns early when |Re(a)| + |Im(a)| is zero, so y keeps its values.
+    let x = [
+        Complex::new(f64::NAN, 1.0),
+        Complex::new(1.0, f64::INFINITY),
+    ];
+    let mut y = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+    complex::axpy(2, &Complex::new(0.0, -0.0), &x, 1, &mut y, 1);
+    assert_eq!(y, [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
 }
 
 #[test]
@@ -753,3 +791,248 @@ fn nrm2() {
     assert_eq!(complex::nrm2(0, &x, 1), 0.0);
     assert_eq!(complex::nrm2(10, &x, 0), 0.0);
 }
+
+#[test]
+fn iamin_amin_amax() {
+    // |Re(.)| + |Im(.)| picks the second element, the modulus would pick the first.
+    let x = vec![
+        Complex::new(2.0, 2.0),
+        Complex::new(0.0, -3.0),
+        Complex::new(-4.0, 1.0),
+        Complex::new(0.0, 3.0),
+    ];
+    assert_eq!(complex::iamin(4, &x, 1), 2);
+    assert_eq!(complex::amin(4, &x, 1), 3.0);
+    assert_eq!(complex::amax(4, &x, 1), 5.0);
+    assert_eq!(complex::iamin(2, &x, 2), 1);
+    assert_eq!(complex::amin(2, &x, 2), 4.0);
+    assert_eq!(complex::amax(2, &x, 3), 4.0);
+    assert_eq!(complex::iamin(0, &x, 1), 0);
+    assert_eq!(complex::iamin(4, &x, 0), 0);
+    assert_eq!(complex::amin(0, &x, 1), 0.0);
+    assert_eq!(complex::amax(4, &x, 0), 0.0);
+}
+
+#[test]
+fn sum() {
+    let x = vec![
+        Complex::new(2.0, 2.0),
+        Complex::new(0.0, -3.0),
+        Complex::new(-4.0, 1.0),
+    ];
+    assert_eq!(complex::sum(3, &x, 1), -2.0);
+    assert_eq!(complex::sum(2, &x, 2), 1.0);
+    assert_eq!(complex::sum(0, &x, 1), 0.0);
+}
+
+#[test]
+fn axpby() {
+    let a = Complex::new(0.0, 1.0);
+    let b = Complex::new(2.0, -1.0);
+    let x = vec![Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5)];
+    let mut y = vec![
+        Complex::new(4.0, 0.0),
+        Complex::new(0.0, 0.0),
+        Complex::new(1.0, 1.0),
+    ];
+    complex::axpby(2, &a, &x, 1, &b, &mut y, -2);
+    assert_eq!(
+        y,
+        vec![
+            a * x[1] + b * Complex::new(4.0, 0.0),
+            Complex::new(0.0, 0.0),
+            a * x[0] + b * Complex::new(1.0, 1.0),
+        ]
+    );
+
+    let mut y = vec![Complex::new(f64::NAN, 0.0); 2];
+    complex::axpby(2, &a, &x, 1, &Complex::new(0.0, 0.0), &mut y, 1);
+    assert_eq!(y, vec![a * x[0], a * x[1]]);
+}
+
+#[test]
+fn waxpby() {
+    let a = Complex::new(1.0, -1.0);
+    let b = Complex::new(0.5, 0.0);
+    let x = vec![Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5)];
+    let y = vec![Complex::new(4.0, 0.0), Complex::new(0.0, 2.0)];
+    let mut w = vec![Complex::new(0.0, 0.0); 2];
+    complex::waxpby(2, &a, &x, -1, &b, &y, 1, &mut w, 1);
+    assert_eq!(w, vec![a * x[1] + b * y[0], a * x[0] + b * y[1]]);
+}
+
+#[test]
+fn crot() {
+    let c = 0.6;
+    let s = Complex::new(0.48, -0.64);
+    let x0 = vec![
+        Complex::new(1.0, 2.0),
+        Complex::new(-0.5, 0.25),
+        Complex::new(3.0, -1.0),
+    ];
+    let y0 = vec![
+        Complex::new(0.0, 1.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(-1.0, -1.0),
+    ];
+    let (mut x, mut y) = (x0.clone(), y0.clone());
+    complex::crot(3, &mut x, 1, &mut y, -1, c, s);
+    for i in 0..3 {
+        let (xi, yi) = (x0[i], y0[2 - i]);
+        capproximately!([x[i], y[2 - i]], [xi * c + s * yi, yi * c - s.conj() * xi]);
+    }
+
+    // The rotation of rotg zeroes the second component.
+    let (mut a, mut b) = (Complex::new(3.0, -4.0), Complex::new(1.0, 2.0));
+    let (mut rc, mut rs) = (0.0, Complex::new(0.0, 0.0));
+    let (mut x, mut y) = (vec![a], vec![b]);
+    complex::rotg(&mut a, &mut b, &mut rc, &mut rs);
+    complex::crot(1, &mut x, 1, &mut y, 1, rc, rs);
+    capproximately!(x, [a]);
+    assert!(y[0].norm() < 1e-12);
+}
+
+// d1*|x|**2 + d2*|y|**2, which rotm keeps with the d1 and d2 of rotmg.
+fn weighted(d1: f64, d2: f64, x: Complex<f64>, y: Complex<f64>) -> f64 {
+    d1 * x.norm_sqr() + d2 * y.norm_sqr()
+}
+
+#[test]
+fn rotmg_rotm() {
+    let cases: [(f64, f64, Complex<f64>, Complex<f64>); 4] = [
+        // FLAG = 0, FLAG = 1, and a d1 small enough to be rescaled.
+        (2.0, 0.5, Complex::new(1.0, -2.0), Complex::new(0.5, 0.25)),
+        (0.5, 3.0, Complex::new(0.1, 0.2), Complex::new(-1.0, 2.0)),
+        (1e-9, 1.0, Complex::new(1.0, 1.0), Complex::new(0.5, 0.0)),
+        (0.0, 2.0, Complex::new(1.0, 1.0), Complex::new(0.0, 3.0)),
+    ];
+    let flags = [0.0, 1.0, -1.0, 1.0];
+    for (&(d1, d2, x1, y1), &flag) in cases.iter().zip(flags.iter()) {
+        let (mut e1, mut e2, mut r, mut y) = (d1, d2, x1, y1);
+        let mut param = [Complex::new(0.0, 0.0); 5];
+        complex::rotmg(&mut e1, &mut e2, &mut r, &mut y, &mut param);
+        assert_eq!(param[0], Complex::new(flag, 0.0));
+
+        // H*(x1, y1) = (x1', y1') with sqrt(d2')*y1' = 0.
+        let (mut x, mut y) = (vec![x1], vec![y1]);
+        complex::rotm(1, &mut x, 1, &mut y, 1, &param);
+        capproximately!([x[0] / r], [Complex::new(1.0, 0.0)]);
+        assert!(e2 * y[0].norm_sqr() <= 1e-24 * weighted(d1, d2, x1, y1));
+
+        // The scaled transformation is unitary on any pair.
+        let x0 = sample(4, 0);
+        let y0 = sample(4, 4);
+        let (mut x, mut y) = (x0.clone(), y0.clone());
+        complex::rotm(4, &mut x, -1, &mut y, -1, &param);
+        for i in 0..4 {
+            assert_approx!(
+                weighted(e1, e2, x[i], y[i]),
+                weighted(d1, d2, x0[i], y0[i]),
+                1e-12
+            );
+        }
+    }
+
+    // FLAG = -2 leaves the vectors alone, a negative d1 zeroes H.
+    let (mut d1, mut d2, mut x1, mut y1) =
+        (1.0, 1.0, Complex::new(1.0, 0.0), Complex::new(0.0, 0.0));
+    let mut param = [Complex::new(0.0, 0.0); 5];
+    complex::rotmg(&mut d1, &mut d2, &mut x1, &mut y1, &mut param);
+    assert_eq!(param[0], Complex::new(-2.0, 0.0));
+    let (mut x, mut y) = (vec![Complex::new(1.0, 2.0)], vec![Complex::new(3.0, 4.0)]);
+    complex::rotm(1, &mut x, 1, &mut y, 1, &param);
+    assert_eq!(
+        (x[0], y[0]),
+        (Complex::new(1.0, 2.0), Complex::new(3.0, 4.0))
+    );
+
+    let (mut d1, mut d2, mut x1, mut y1) =
+        (-1.0, 1.0, Complex::new(1.0, 0.0), Complex::new(1.0, 0.0));
+    complex::rotmg(&mut d1, &mut d2, &mut x1, &mut y1, &mut param);
+    assert_eq!(param[0], Complex::new(-1.0, 0.0));
+    assert!(param[1..].iter().all(|h| *h == Complex::new(0.0, 0.0)));
+    assert_eq!((d1, d2, x1), (0.0, 0.0, Complex::new(0.0, 0.0)));
+}
+
+fn sample(n: usize, offset: usize) -> Vec<Complex<f64>> {
+    (0..n)
+        .map(|i| {
+            Complex::new(
+                1.0 + (i + offset) as f64 * 0.5,
+                0.75 - (i * offset) as f64 * 0.25,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn nrm2_extreme_values() {
+    let x = [Complex::new(3e300, 4e300), Complex::new(0.0, 0.0)];
+    assert_eq!(complex::nrm2(2, &x, 1), 5e300);
+    let x: [Complex<f64>; 2] = [Complex::new(3e-300, 0.0), Complex::new(0.0, -4e-300)];
+    assert!((complex::nrm2(2, &x, 1) / 5e-300 - 1.0).abs() <= 2.0 * f64::EPSILON);
+    let x = [Complex::new(-3e30f32, 4e30)];
+    assert_eq!(complex::nrm2(1, &x, 1), 5e30);
+    let sub = f32::MIN_POSITIVE / 16.0;
+    let x = [Complex::new(3.0 * sub, 4.0 * sub)];
+    assert_eq!(complex::nrm2(1, &x, 1), 5.0 * sub);
+    let x = [Complex::new(5e-324, 1.0)];
+    assert_eq!(complex::nrm2(1, &x, 1), 1.0);
+
+    let x = [Complex::new(1.0, f64::INFINITY), Complex::new(2.0, 0.0)];
+    assert_eq!(complex::nrm2(2, &x, 1), f64::INFINITY);
+    let x = [
+        Complex::new(f64::INFINITY, 0.0),
+        Complex::new(0.0, f64::NAN),
+    ];
+    assert!(complex::nrm2(2, &x, 1).is_nan());
+    let x = [Complex::new(1e-40f32, f32::NAN)];
+    assert!(complex::nrm2(1, &x, 1).is_nan());
+}
+
+#[test]
+fn iamax_nan() {
+    let nan = f64::NAN;
+    let inf = f64::INFINITY;
+    let x = [
+        Complex::new(1.0, 1.0),
+        Complex::new(0.0, nan),
+        Complex::new(-3.0, 1.0),
+        Complex::new(nan, 0.0),
+        Complex::new(2.0, -2.0),
+    ];
+    assert_eq!(complex::iamax(5, &x, 1), 3);
+    assert_eq!(complex::iamax_nan(5, &x, 1, NanPolicy::Ignore), 3);
+    assert_eq!(complex::iamax_nan(5, &x, 1, NanPolicy::First), 2);
+    assert_eq!(complex::iamax_nan(3, &x, 2, NanPolicy::First), 2);
+    assert_eq!(complex::iamax_nan(2, &x[1..], 2, NanPolicy::Ignore), 1);
+    assert_eq!(complex::iamax_nan(5, &x, 0, NanPolicy::First), 0);
+
+    let x = [Complex::new(2.0, 0.0), Complex::new(1.0, -inf)];
+    assert_eq!(complex::iamax_nan(2, &x, 1, NanPolicy::First), 2);
+}
+
+#[test]
+fn reductions_nan_inf() {
+    let nan = f64::NAN;
+    let inf = f64::INFINITY;
+    let x = [
+        Complex::new(1.0, 2.0),
+        Complex::new(-inf, 0.0),
+        Complex::new(0.0, nan),
+    ];
+    assert_eq!(complex::asum(2, &x, 1), inf);
+    assert!(complex::asum(3, &x, 1).is_nan());
+    assert!(complex::asum(2, &x, 2).is_nan());
+
+    let y = [Complex::new(1.0, 0.0); 3];
+    let dot = complex::dotc(2, &x, 1, &y, 1);
+    // The imaginary part of -inf * 1 is -inf * 0, a NaN.
+    assert_eq!(dot.re, -inf);
+    assert!(dot.im.is_nan());
+    let y = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+    assert!(complex::dotu(2, &x, 1, &y, 1).re.is_nan());
+
+    assert!(complex::nrm2(3, &x, 1).is_nan());
+    assert_eq!(complex::nrm2(2, &x, 1), inf);
+}
@@ -1,4 +1,4 @@
-use libblas::level1;
+use libblas::{level1, NanPolicy};
 mod utils;
 
 #[test]
@@ -7,6 +7,8 @@ fn iamax() {
     assert_eq!(level1::iamax(3, &vec![1.0, 0.0, 3.0, 4.0, 5.0, 6.0], 2), 3);
     assert_eq!(level1::iamax(0, &vec![1.0, 0.0, 3.0, 4.0, 5.0, 6.0], 0), 0);
     assert_eq!(level1::iamax(1, &vec![1.0, 0.0, 3.0, 4.0, 5.0, 6.0], 1), 1);
+    assert_eq!(level1::iamax(3, &vec![1.0, 5.0, 2.0], 1), 2);
+    assert_eq!(level1::iamax(3, &vec![1.0, 0.0, 5.0, 0.0, 2.0], 2), 2);
 }
 
 #[test]
@@ -590,6 +592,115 @@ fn rotmg() {
     );
 }
 
+#[test]
+fn rotmg_with() {
+    use libblas::level1::RotmgAlgorithm::{Hopkins, Reference};
+
+    // Cases where the reference BLAS and the algorithm by Hopkins disagree.
+    let cases: Vec<(_, [f64; 4], [f64; 4], [f64; 5])> = vec![
+        // D1 and D2 end up equal to GAMSQ, the reference rescales twice and resets H12.
+        (
+            Reference,
+            [33554432.0, 33554432.0, 1.0, 1.0],
+            [1.0, 1.0, 8192.0, 1.0],
+            [-1.0, 4096.0, -4096.0, 1.0, 4096.0],
+        ),
+        (
+            Hopkins,
+            [33554432.0, 33554432.0, 1.0, 1.0],
+            [16777216.0, 16777216.0, 2.0, 1.0],
+            [1.0, 1.0, 0.0, 0.0, 1.0],
+        ),
+        // D1 and D2 end up equal to RGAMSQ of DROTMG, which is slightly larger than 2**-24.
+        (
+            Reference,
+            [1.1920929e-7, 1.1920929e-7, 1.0, 1.0],
+            [1.00000000376832, 1.00000000376832, 0.00048828125, 1.0],
+            [-1.0, 0.000244140625, -0.000244140625, 1.0, 0.000244140625],
+        ),
+        (
+            Hopkins,
+            [1.1920929e-7, 1.1920929e-7, 1.0, 1.0],
+            [1.00000000376832, 1.00000000376832, 0.00048828125, 1.0],
+            [
+                -1.0,
+                0.000244140625,
+                -0.000244140625,
+                0.000244140625,
+                0.000244140625,
+            ],
+        ),
+        // D1 is zero, Hopkins swaps D1 and D2 without computing H22.
+        (
+            Reference,
+            [0.0, 1.0, 2.0, 4.0],
+            [1.0, 0.0, 4.0, 4.0],
+            [1.0, 0.0, 0.0, 0.0, 0.5],
+        ),
+        (
+            Hopkins,
+            [0.0, 1.0, 2.0, 4.0],
+            [1.0, 0.0, 4.0, 4.0],
+            [1.0, 0.0, 0.0, 0.0, 0.0],
+        ),
+        // Both D1 and D2 are rescaled after FLAG=0.
+        (
+            Reference,
+            [5.960464477539063e-8, 2.9802322387695312e-8, 3.0, 8.0],
+            [
+                0.3902439024390244,
+                0.7804878048780488,
+                0.00250244140625,
+                8.0,
+            ],
+            [
+                -1.0,
+                0.00018310546875,
+                -0.000244140625,
+                1.0,
+                9.1552734375e-5,
+            ],
+        ),
+        (
+            Hopkins,
+            [5.960464477539063e-8, 2.9802322387695312e-8, 3.0, 8.0],
+            [
+                0.3902439024390244,
+                0.7804878048780488,
+                0.00250244140625,
+                8.0,
+            ],
+            [
+                -1.0,
+                0.00018310546875,
+                -0.000244140625,
+                0.000244140625,
+                9.1552734375e-5,
+            ],
+        ),
+    ];
+    for (algorithm, [d1, d2, x1, y1], expect, param) in cases {
+        let mut args = [d1, d2, x1, y1];
+        let mut p = [0.0; 5];
+        let [d1, d2, x1, y1] = &mut args;
+        level1::rotmg_with(algorithm, d1, d2, x1, y1, &mut p);
+        assert_eq!(args, expect, "{:?}", algorithm);
+        assert_eq!(p, param, "{:?}", algorithm);
+    }
+
+    // SROTMG rounds RGAMSQ to 2**-24, so D1 and D2 are rescaled when they end up equal to it.
+    for (algorithm, h12) in [(Reference, 1.0), (Hopkins, 0.000244140625)] {
+        let (mut d1, mut d2, mut x1, mut y1) = (1.1920929e-7f32, 1.1920929e-7f32, 1.0, 1.0);
+        let mut param = [0.0f32; 5];
+        level1::rotmg_with(algorithm, &mut d1, &mut d2, &mut x1, &mut y1, &mut param);
+        assert_eq!((d1, d2, x1), (1.0, 1.0, 0.00048828125));
+        assert_eq!(
+            param,
+            [-1.0, 0.000244140625, -0.000244140625, h12, 0.000244140625]
+        );
+    }
+}
+
 #[test]
 fn scal() {
     let mut x = vec![1.0, 2.0, 3.0, 4.0];
@@ -659,3 +770,166 @@ fn swap() {
     assert_eq!(x, vec![1.0, 2.0]);
     assert_eq!(y, vec![7.0, 8.0]);
 }
+
+#[test]
+fn iamin() {
+    assert_eq!(level1::iamin(6, &[1.0, -0.5, 3.0, 0.5, 5.0, 6.0], 1), 2);
+    assert_eq!(level1::iamin(3, &[4.0, 0.0, -3.0, 0.0, 3.0, 0.0], 2), 2);
+    assert_eq!(level1::iamin(0, &[1.0, 0.0], 1), 0);
+    assert_eq!(level1::iamin(2, &[1.0, 0.0], 0), 0);
+    assert_eq!(level1::iamin(1, &[-7.0], 1), 1);
+}
+
+#[test]
+fn amax_amin() {
+    let x = vec![1.0, -8.0, 3.0, 0.25, -5.0, 6.0];
+    assert_eq!(level1::amax(6, &x, 1), 8.0);
+    assert_eq!(level1::amin(6, &x, 1), 0.25);
+    assert_eq!(level1::amax(3, &x, 2), 5.0);
+    assert_eq!(level1::amin(3, &x, 2), 1.0);
+    assert_eq!(level1::amax(0, &x, 1), 0.0);
+    assert_eq!(level1::amin(6, &x, 0), 0.0);
+}
+
+#[test]
+fn sum() {
+    let x = vec![1.0, -8.0, 3.0, 0.25, -5.0, 6.0];
+    assert_eq!(level1::sum(6, &x, 1), -2.75);
+    assert_eq!(level1::sum(3, &x, 2), -1.0);
+    assert_eq!(level1::sum(0, &x, 1), 0.0);
+    assert_eq!(level1::sum(3, &[1, 2, 3], 1), 6);
+}
+
+#[test]
+fn axpby() {
+    let x = vec![1.0, 2.0, 3.0];
+    let mut y = vec![10.0, 20.0, 30.0];
+    level1::axpby(3, 2.0, &x, 1, 0.5, &mut y, 1);
+    assert_eq!(y, vec![7.0, 14.0, 21.0]);
+
+    // y is visited backwards, and a beta of zero ignores its NaN.
+    let mut y = vec![f64::NAN, 0.0, f64::NAN, 0.0, f64::NAN];
+    level1::axpby(3, -1.0, &x, 1, 0.0, &mut y, -2);
+    assert_eq!(y, vec![-3.0, 0.0, -2.0, 0.0, -1.0]);
+
+    // The same as scal followed by axpy.
+    let mut y = vec![10.0, 20.0, 30.0];
+    let mut expected = y.clone();
+    level1::scal(2, 3.0, &mut expected, 2);
+    level1::axpy(2, 0.5, &x, -1, &mut expected, 2);
+    level1::axpby(2, 0.5, &x, -1, 3.0, &mut y, 2);
+    assert_eq!(y, expected);
+}
+
+#[test]
+fn waxpby() {
+    let x = vec![1.0, 2.0, 3.0];
+    let y = vec![10.0, 20.0, 30.0, 40.0];
+    let mut w = vec![0.0; 3];
+    level1::waxpby(3, 2.0, &x, 1, -1.0, &y, 1, &mut w, 1);
+    assert_eq!(w, vec![-8.0, -16.0, -24.0]);
+
+    let mut w = vec![0.0; 4];
+    level1::waxpby(2, 1.0, &x, 2, 0.5, &y, -3, &mut w, 3);
+    assert_eq!(w, vec![21.0, 0.0, 0.0, 8.0]);
+
+    let mut w = vec![0.0; 2];
+    level1::waxpby(2, 1.0, &x, 1, 0.0, &[f64::NAN; 2], 1, &mut w, 1);
+    assert_eq!(w, vec![1.0, 2.0]);
+}
+
+#[test]
+fn nrm2_extreme_values() {
+    // The squares of these overflow or underflow, the norm doesn't.
+    assert_eq!(level1::nrm2(2, &[3e300, 4e300], 1), 5e300);
+    // Scaling by a power of two is exact, the squares of decimals are rounded.
+    let norm: f64 = level1::nrm2(2, &[3e-300, 4e-300], 1);
+    assert!((norm / 5e-300 - 1.0).abs() <= 2.0 * f64::EPSILON);
+    assert_eq!(level1::nrm2(2, &[3e30f32, 4e30], 1), 5e30);
+    assert_eq!(level1::nrm2(2, &[3e-30f32, 4e-30], 1), 5e-30);
+    let tiny = f64::MIN_POSITIVE;
+    assert_eq!(level1::nrm2(2, &[3.0 * tiny, -4.0 * tiny], 1), 5.0 * tiny);
+
+    // Subnormals, alone and next to a medium value.
+    let sub = 5e-324;
+    assert_eq!(level1::nrm2(3, &[sub, 0.0, -sub], 1), sub * 2f64.sqrt());
+    assert_eq!(level1::nrm2(2, &[sub, 2.0], 1), 2.0);
+    let sub = f32::MIN_POSITIVE / 8.0;
+    assert_eq!(level1::nrm2(2, &[3.0 * sub, 4.0 * sub], 1), 5.0 * sub);
+
+    // Small values are dropped next to big ones, medium ones are kept.
+    let big = 2f64.powi(600);
+    assert_eq!(
+        level1::nrm2(3, &[1e-300, 3.0 * big, 4.0 * big], 1),
+        5.0 * big
+    );
+    assert_eq!(level1::nrm2(2, &[1e200, 1.0], 1), 1e200);
+    let x = [3e-200, 4e-200, 1.0];
+    assert_eq!(level1::nrm2(3, &x, 1), 1.0);
+
+    assert_eq!(level1::nrm2(2, &[1.0, f64::INFINITY], 1), f64::INFINITY);
+    assert_eq!(
+        level1::nrm2(2, &[f32::NEG_INFINITY, 1e-40], 1),
+        f32::INFINITY
+    );
+    assert!(level1::nrm2(3, &[1.0, f64::NAN, 1e300], 1).is_nan());
+    assert!(level1::nrm2(3, &[f64::INFINITY, 0.0, f64::NAN], 1).is_nan());
+    assert!(level1::nrm2(2, &[f32::NAN, 1e-40], 1).is_nan());
+    assert_eq!(level1::nrm2(2, &[3.0, f64::NAN, 4.0], 2), 5.0);
+}
+
+#[test]
+fn iamax_nan() {
+    let nan = f64::NAN;
+    let inf = f64::INFINITY;
+
+    // The reference BLAS only returns a NaN in the first element.
+    assert_eq!(level1::iamax(4, &[1.0, nan, 3.0, -2.0], 1), 3);
+    assert_eq!(level1::iamax(3, &[nan, 5.0, 3.0], 1), 1);
+
+    let x = [1.0, nan, -3.0, nan, 3.0];
+    assert_eq!(level1::iamax_nan(5, &x, 1, NanPolicy::Ignore), 3);
+    assert_eq!(level1::iamax_nan(5, &x, 1, NanPolicy::First), 2);
+    assert_eq!(level1::iamax_nan(3, &x, 2, NanPolicy::First), 2);
+    assert_eq!(
+        level1::iamax_nan(3, &[nan, 5.0, 3.0], 1, NanPolicy::Ignore),
+        2
+    );
+    assert_eq!(level1::iamax_nan(2, &[nan, nan], 1, NanPolicy::Ignore), 1);
+    assert_eq!(level1::iamax_nan(5, &x, 0, NanPolicy::First), 0);
+    assert_eq!(level1::iamax_nan(0, &x, 1, NanPolicy::First), 0);
+
+    // The first infinity wins, unless a NaN comes first under `First`.
+    let x = [2.0, -inf, nan, inf];
+    assert_eq!(level1::iamax_nan(4, &x, 1, NanPolicy::Ignore), 2);
+    assert_eq!(level1::iamax_nan(4, &x, 1, NanPolicy::First), 3);
+    let x = [2.0f32, f32::MAX, f32::INFINITY];
+    assert_eq!(level1::iamax_nan(3, &x, 1, NanPolicy::First), 3);
+}
+
+#[test]
+fn reductions_nan_inf() {
+    let nan = f64::NAN;
+    let inf = f64::INFINITY;
+
+    assert!(level1::asum(3, &[1.0, nan, 2.0], 1).is_nan());
+    assert_eq!(level1::asum(3, &[1.0, -inf, inf], 1), inf);
+    assert!(level1::asum(3, &[nan, -inf, 2.0], 1).is_nan());
+    assert_eq!(level1::asum(2, &[1.0, nan, 2.0], 2), 3.0);
+
+    let x = [1.0, 2.0, inf, 4.0, 5.0, 6.0, 7.0];
+    let y = [1.0; 7];
+    assert_eq!(level1::dot(7, &x, 1, &y, 1), inf);
+    // inf * 0 and inf - inf are NaN, whatever the increments.
+    let y = [1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+    assert!(level1::dot(7, &x, 1, &y, 1).is_nan());
+    assert!(level1::dot(7, &x, -1, &y, -1).is_nan());
+    let y = [-inf, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    assert!(level1::dot(7, &x, 1, &y, 1).is_nan());
+    let y = [1.0, 1.0, nan, 1.0, 1.0, 1.0, 1.0];
+    assert!(level1::dot(7, &x, 1, &y, 1).is_nan());
+    assert_eq!(level1::dot(2, &x, 3, &y, 3), 5.0);
+
+    assert!(level1::nrm2(3, &[inf, nan, 1.0], 1).is_nan());
+    assert_eq!(level1::nrm2(3, &[-inf, 1.0, inf], 1), inf);
+}
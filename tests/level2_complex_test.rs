@@ -1,5 +1,6 @@
 use libblas::level2::complex;
 use libblas::unstable::matrix;
+use libblas::{Diag, Trans, Uplo};
 use num_complex::Complex;
 mod fixtures;
 
@@ -10,7 +11,7 @@ mod utils;
 fn gbmv() {
     let mut y = fixtures::complex::vector(6);
     complex::gbmv(
-        'n',
+        Trans::NoTrans,
         6,
         8,
         3,
@@ -38,7 +39,7 @@ fn gbmv() {
 
     let mut y = fixtures::complex::vector(6);
     complex::gbmv(
-        'n',
+        Trans::NoTrans,
         6,
         8,
         3,
@@ -66,7 +67,7 @@ fn gbmv() {
 
     let mut y = fixtures::complex::vector(8);
     complex::gbmv(
-        't',
+        Trans::Trans,
         6,
         8,
         3,
@@ -96,7 +97,7 @@ fn gbmv() {
 
     let mut y = fixtures::complex::vector(8);
     complex::gbmv(
-        'c',
+        Trans::ConjTrans,
         6,
         8,
         3,
@@ -126,7 +127,7 @@ fn gbmv() {
 
     let mut y = fixtures::complex::vector(8);
     complex::gbmv(
-        'c',
+        Trans::ConjTrans,
         6,
         8,
         3,
@@ -156,7 +157,7 @@ fn gbmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gbmv(
-            'x',
+            Trans::Trans,
             6,
             8,
             4,
@@ -175,26 +176,7 @@ fn gbmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gbmv(
-            't',
-            6,
-            8,
-            4,
-            4,
-            Complex::new(0.0, 0.0),
-            &vec![],
-            6,
-            &vec![],
-            1,
-            Complex::new(2.0, 0.5),
-            &mut vec![],
-            1,
-        )
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::gbmv(
-            't',
+            Trans::Trans,
             6,
             8,
             4,
@@ -213,7 +195,7 @@ fn gbmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gbmv(
-            't',
+            Trans::Trans,
             6,
             8,
             4,
@@ -235,7 +217,7 @@ fn gbmv() {
 fn gemv() {
     let mut y = fixtures::complex::vector(6);
     complex::gemv(
-        'n',
+        Trans::NoTrans,
         6,
         8,
         Complex::new(0.8, 0.2),
@@ -262,7 +244,7 @@ fn gemv() {
 
     let mut y = fixtures::complex::vector(6);
     complex::gemv(
-        'n',
+        Trans::NoTrans,
         6,
         8,
         Complex::new(0.8, 0.2),
@@ -288,7 +270,7 @@ fn gemv() {
 
     let mut y = fixtures::complex::vector(6);
     complex::gemv(
-        'n',
+        Trans::NoTrans,
         6,
         8,
         Complex::new(0.8, 0.2),
@@ -314,7 +296,7 @@ fn gemv() {
 
     let mut y = fixtures::complex::vector(8);
     complex::gemv(
-        't',
+        Trans::Trans,
         6,
         8,
         Complex::new(0.8, 0.2),
@@ -342,7 +324,7 @@ fn gemv() {
 
     let mut y = fixtures::complex::vector(8);
     complex::gemv(
-        'c',
+        Trans::ConjTrans,
         6,
         8,
         Complex::new(0.8, 0.2),
@@ -370,7 +352,7 @@ fn gemv() {
 
     let mut y = fixtures::complex::vector(6);
     complex::gemv(
-        'n',
+        Trans::NoTrans,
         6,
         8,
         Complex::new(0.0, 0.0),
@@ -397,7 +379,7 @@ fn gemv() {
     let mut y = fixtures::complex::vector(6);
     let expect = fixtures::complex::vector(6);
     complex::gemv(
-        'n',
+        Trans::NoTrans,
         6,
         8,
         Complex::new(0.0, 0.0),
@@ -413,24 +395,7 @@ fn gemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemv(
-            'x',
-            6,
-            8,
-            Complex::new(0.0, 0.0),
-            &vec![],
-            6,
-            &vec![],
-            1,
-            Complex::new(2.0, 0.5),
-            &mut vec![],
-            1,
-        )
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::gemv(
-            't',
+            Trans::Trans,
             6,
             8,
             Complex::new(0.0, 0.0),
@@ -447,7 +412,7 @@ fn gemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemv(
-            't',
+            Trans::Trans,
             6,
             8,
             Complex::new(0.0, 0.0),
@@ -464,7 +429,7 @@ fn gemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemv(
-            't',
+            Trans::Trans,
             6,
             8,
             Complex::new(0.0, 0.0),
@@ -481,7 +446,7 @@ fn gemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemv(
-            't',
+            Trans::Trans,
             6,
             8,
             Complex::new(0.0, 0.0),
@@ -498,7 +463,7 @@ fn gemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemv(
-            't',
+            Trans::Trans,
             6,
             8,
             Complex::new(0.0, 0.0),
@@ -944,7 +909,7 @@ fn hbmv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hbmv(
-        'u',
+        Uplo::Upper,
         6,
         3,
         Complex::new(0.2, 0.8),
@@ -977,7 +942,7 @@ fn hbmv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hbmv(
-        'u',
+        Uplo::Upper,
         6,
         3,
         Complex::new(0.2, 0.8),
@@ -1011,7 +976,7 @@ fn hbmv() {
     ];
 
     complex::hbmv(
-        'u',
+        Uplo::Upper,
         6,
         3,
         Complex::new(0.0, 0.0),
@@ -1052,7 +1017,7 @@ fn hbmv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hbmv(
-        'u',
+        Uplo::Upper,
         6,
         3,
         Complex::new(0.0, 0.0),
@@ -1075,7 +1040,7 @@ fn hbmv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hbmv(
-        'u',
+        Uplo::Upper,
         6,
         3,
         Complex::new(0.2, 0.8),
@@ -1114,7 +1079,7 @@ fn hbmv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hbmv(
-        'l',
+        Uplo::Lower,
         6,
         3,
         Complex::new(0.2, 0.8),
@@ -1140,7 +1105,7 @@ fn hbmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hbmv(
-            'l',
+            Uplo::Lower,
             6,
             4,
             Complex::new(0.0, 0.0),
@@ -1159,7 +1124,7 @@ fn hbmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hbmv(
-            'l',
+            Uplo::Lower,
             6,
             4,
             Complex::new(0.2, 0.8),
@@ -1176,7 +1141,7 @@ fn hbmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hbmv(
-            'l',
+            Uplo::Lower,
             6,
             4,
             Complex::new(0.0, 0.0),
@@ -1190,23 +1155,6 @@ fn hbmv() {
         );
     });
     assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::hbmv(
-            'x',
-            6,
-            4,
-            Complex::new(0.0, 0.0),
-            &a,
-            5,
-            &x,
-            1,
-            Complex::new(1.0, 0.0),
-            &mut fixtures::complex::vector(6),
-            1,
-        );
-    });
-    assert!(result.is_err());
 }
 
 #[test]
@@ -1223,7 +1171,7 @@ fn hemv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hemv(
-        'u',
+        Uplo::Upper,
         6,
         Complex::new(0.2, 0.8),
         &a,
@@ -1255,7 +1203,7 @@ fn hemv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hemv(
-        'u',
+        Uplo::Upper,
         6,
         Complex::new(-0.12, 0.88),
         &a,
@@ -1289,7 +1237,7 @@ fn hemv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hemv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(-0.12, 0.88),
         &a,
@@ -1322,7 +1270,7 @@ fn hemv() {
     ];
 
     complex::hemv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(0.0, 0.0),
         &a,
@@ -1355,7 +1303,7 @@ fn hemv() {
     ];
 
     complex::hemv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(0.0, 0.0),
         &a,
@@ -1387,7 +1335,7 @@ fn hemv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
     complex::hemv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(0.0, 1.0),
         &a,
@@ -1412,7 +1360,7 @@ fn hemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hemv(
-            'l',
+            Uplo::Lower,
             6,
             Complex::new(0.0, 0.0),
             &a,
@@ -1428,7 +1376,7 @@ fn hemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hemv(
-            'l',
+            Uplo::Lower,
             6,
             Complex::new(0.0, 0.0),
             &a,
@@ -1445,7 +1393,7 @@ fn hemv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hemv(
-            'l',
+            Uplo::Lower,
             6,
             Complex::new(0.2, 0.8),
             &a,
@@ -1458,29 +1406,12 @@ fn hemv() {
         );
     });
     assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::hemv(
-            'x',
-            6,
-            Complex::new(0.0, 0.0),
-            &a,
-            5,
-            &x,
-            1,
-            Complex::new(1.0, 0.0),
-            &mut fixtures::complex::vector(6),
-            1,
-        );
-    });
-    assert!(result.is_err());
 }
 
 #[test]
 fn her() {
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-;
     let x = vec![
         Complex::new(-0.6490100777088978, 0.7721421858045301),
         Complex::new(0.0, 0.0),
@@ -1490,7 +1421,7 @@ fn her() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
 
-    complex::her('u', 6, 0.2, &x, 1, &mut a, 6);
+    complex::her(Uplo::Upper, 6, 0.2, &x, 1, &mut a, 6);
     capproximately!(
         a,
         vec![
@@ -1535,7 +1466,7 @@ fn her() {
 
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-    complex::her('u', 6, 0.2, &x, -1, &mut a, 6);
+    complex::her(Uplo::Upper, 6, 0.2, &x, -1, &mut a, 6);
     capproximately!(
         a,
         vec![
@@ -1580,7 +1511,7 @@ fn her() {
 
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-    complex::her('u', 6, 0.0, &x, -1, &mut a, 6);
+    complex::her(Uplo::Upper, 6, 0.0, &x, -1, &mut a, 6);
     capproximately!(
         a,
         vec![
@@ -1625,7 +1556,7 @@ fn her() {
 
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_upper(&mut a, 6, 6, 0.0);
-    complex::her('l', 6, 1.234, &x, -1, &mut a, 6);
+    complex::her(Uplo::Lower, 6, 1.234, &x, -1, &mut a, 6);
     capproximately!(
         a,
         vec![
@@ -1669,18 +1600,12 @@ fn her() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::her('l', 6, 1.2, &x, 1, &mut vec![], 5);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::her('l', 6, 0.2, &x, 0, &mut vec![], 6);
+        complex::her(Uplo::Lower, 6, 1.2, &x, 1, &mut vec![], 5);
     });
-
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        complex::her('x', 6, 0.1, &x, 1, &mut vec![], 6);
+        complex::her(Uplo::Lower, 6, 0.2, &x, 0, &mut vec![], 6);
     });
 
     assert!(result.is_err());
@@ -1707,7 +1632,17 @@ fn her2() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
 
-    complex::her2('u', 6, Complex::new(0.2, 0.8), &x, 1, &y, 1, &mut a, 6);
+    complex::her2(
+        Uplo::Upper,
+        6,
+        Complex::new(0.2, 0.8),
+        &x,
+        1,
+        &y,
+        1,
+        &mut a,
+        6,
+    );
     capproximately!(
         a,
         vec![
@@ -1751,7 +1686,17 @@ fn her2() {
     );
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_upper(&mut a, 6, 6, 0.0);
-    complex::her2('l', 6, Complex::new(0.2, 0.8), &x, -1, &y, -1, &mut a, 6);
+    complex::her2(
+        Uplo::Lower,
+        6,
+        Complex::new(0.2, 0.8),
+        &x,
+        -1,
+        &y,
+        -1,
+        &mut a,
+        6,
+    );
     capproximately!(
         a,
         vec![
@@ -1798,27 +1743,22 @@ fn her2() {
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
     let mut expect = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut expect, 6, 6, 0.0);
-    complex::her2('l', 6, Complex::new(0.0, 0.0), &x, -1, &y, -1, &mut a, 6);
+    complex::her2(
+        Uplo::Lower,
+        6,
+        Complex::new(0.0, 0.0),
+        &x,
+        -1,
+        &y,
+        -1,
+        &mut a,
+        6,
+    );
     capproximately!(a, expect);
 
     let result = std::panic::catch_unwind(|| {
         complex::her2(
-            'x',
-            6,
-            Complex::new(0.0, 0.0),
-            &x,
-            -1,
-            &y,
-            -1,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::her2(
-            'l',
+            Uplo::Lower,
             6,
             Complex::new(0.0, 0.0),
             &x,
@@ -1833,7 +1773,7 @@ fn her2() {
 
     let result = std::panic::catch_unwind(|| {
         complex::her2(
-            'l',
+            Uplo::Lower,
             6,
             Complex::new(0.0, 0.0),
             &x,
@@ -1845,21 +1785,6 @@ fn her2() {
         );
     });
     assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::her2(
-            'x',
-            6,
-            Complex::new(0.0, 0.0),
-            &x,
-            -1,
-            &y,
-            -1,
-            &mut vec![],
-            4,
-        );
-    });
-    assert!(result.is_err());
 }
 
 #[test]
@@ -1886,7 +1811,7 @@ fn hpmv() {
     ];
 
     complex::hpmv(
-        'u',
+        Uplo::Upper,
         6,
         Complex::new(0.2, -0.8),
         &ap,
@@ -1918,7 +1843,7 @@ fn hpmv() {
     ];
 
     complex::hpmv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(0.2, -0.8),
         &ap,
@@ -1953,7 +1878,7 @@ fn hpmv() {
     ];
 
     complex::hpmv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(0.0, 0.0),
         &ap,
@@ -1985,7 +1910,7 @@ fn hpmv() {
     ];
 
     complex::hpmv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(0.0, 0.0),
         &ap,
@@ -2020,7 +1945,7 @@ fn hpmv() {
     ];
 
     complex::hpmv(
-        'l',
+        Uplo::Lower,
         6,
         Complex::new(1.0, 0.0),
         &ap,
@@ -2044,22 +1969,7 @@ fn hpmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hpmv(
-            'x',
-            6,
-            Complex::new(0.0, 0.0),
-            &vec![],
-            &vec![],
-            -1,
-            Complex::new(0.0, 0.0),
-            &mut vec![],
-            -1,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::hpmv(
-            'l',
+            Uplo::Lower,
             6,
             Complex::new(0.0, 0.0),
             &vec![],
@@ -2074,7 +1984,7 @@ fn hpmv() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hpmv(
-            'l',
+            Uplo::Lower,
             6,
             Complex::new(0.0, 0.0),
             &vec![],
@@ -2101,7 +2011,7 @@ fn hpr() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::hpr('u', 6, 0.2, &x, 1, &mut ap);
+    complex::hpr(Uplo::Upper, 6, 0.2, &x, 1, &mut ap);
     capproximately!(
         ap,
         vec![
@@ -2131,7 +2041,7 @@ fn hpr() {
     let mut ap = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut ap, 6, 6, 0.0);
     let mut ap = matrix::complex::pack_upper(ap, 6, 6, 5);
-    complex::hpr('l', 6, 0.2, &x, -1, &mut ap);
+    complex::hpr(Uplo::Lower, 6, 0.2, &x, -1, &mut ap);
     capproximately!(
         ap,
         vec![
@@ -2166,16 +2076,11 @@ fn hpr() {
     matrix::complex::set_lower(&mut expect, 6, 6, 0.0);
     let expect = matrix::complex::pack_upper(expect, 6, 6, 5);
 
-    complex::hpr('l', 6, 0.0, &x, -1, &mut ap);
+    complex::hpr(Uplo::Lower, 6, 0.0, &x, -1, &mut ap);
     capproximately!(ap, expect);
 
     let result = std::panic::catch_unwind(|| {
-        complex::hpr('x', 6, 0.0, &x, 1, &mut vec![]);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::hpr('l', 6, 0.0, &x, 0, &mut vec![]);
+        complex::hpr(Uplo::Lower, 6, 0.0, &x, 0, &mut vec![]);
     });
     assert!(result.is_err());
 }
@@ -2201,7 +2106,16 @@ fn hpr2() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::hpr2('u', 6, Complex::new(0.2, 0.8), &x, 1, &y, 1, &mut ap);
+    complex::hpr2(
+        Uplo::Upper,
+        6,
+        Complex::new(0.2, 0.8),
+        &x,
+        1,
+        &y,
+        1,
+        &mut ap,
+    );
     capproximately!(
         ap,
         vec![
@@ -2231,7 +2145,16 @@ fn hpr2() {
     let mut ap = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_upper(&mut ap, 6, 6, 0.0);
     let mut ap = matrix::complex::pack_lower(ap, 6, 6, 5);
-    complex::hpr2('l', 6, Complex::new(0.2, 0.8), &x, -1, &y, -1, &mut ap);
+    complex::hpr2(
+        Uplo::Lower,
+        6,
+        Complex::new(0.2, 0.8),
+        &x,
+        -1,
+        &y,
+        -1,
+        &mut ap,
+    );
     capproximately!(
         ap,
         vec![
@@ -2265,21 +2188,43 @@ fn hpr2() {
     matrix::complex::set_upper(&mut expect, 6, 6, 0.0);
     let expect = matrix::complex::pack_lower(expect, 6, 6, 5);
 
-    complex::hpr2('l', 6, Complex::new(0.0, 0.0), &x, -1, &y, -1, &mut ap);
+    complex::hpr2(
+        Uplo::Lower,
+        6,
+        Complex::new(0.0, 0.0),
+        &x,
+        -1,
+        &y,
+        -1,
+        &mut ap,
+    );
     capproximately!(ap, expect);
 
     let result = std::panic::catch_unwind(|| {
-        complex::hpr2('x', 6, Complex::new(0.0, 0.0), &x, 1, &y, 1, &mut vec![]);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::hpr2('l', 6, Complex::new(0.0, 0.0), &x, 0, &y, 1, &mut vec![]);
+        complex::hpr2(
+            Uplo::Lower,
+            6,
+            Complex::new(0.0, 0.0),
+            &x,
+            0,
+            &y,
+            1,
+            &mut vec![],
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        complex::hpr2('l', 6, Complex::new(0.0, 0.0), &x, 1, &y, 0, &mut vec![]);
+        complex::hpr2(
+            Uplo::Lower,
+            6,
+            Complex::new(0.0, 0.0),
+            &x,
+            1,
+            &y,
+            0,
+            &mut vec![],
+        );
     });
     assert!(result.is_err());
 }
@@ -2296,7 +2241,17 @@ fn tbmv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
 
-    complex::tbmv('u', 'n', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2317,7 +2272,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('u', 'n', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2338,7 +2303,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('l', 'n', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2359,7 +2334,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('l', 'n', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2380,7 +2365,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('u', 't', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2401,7 +2396,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('u', 't', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2422,7 +2427,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('l', 't', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2443,7 +2458,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('l', 't', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2464,7 +2489,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('u', 'c', 'n', 6, 3, &a, 6, &mut x, -1);
+    complex::tbmv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     capproximately!(
         x,
         vec![
@@ -2485,7 +2520,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('u', 'c', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2506,7 +2551,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('l', 'c', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2527,7 +2582,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('l', 'c', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2548,7 +2613,17 @@ fn tbmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbmv('l', 'c', 'u', 0, 3, &a, 6, &mut x, 1);
+    complex::tbmv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        0,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2562,27 +2637,32 @@ fn tbmv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::tbmv('x', 't', 'u', 6, 5, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tbmv('u', 'x', 'u', 6, 5, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tbmv('u', 't', 'c', 6, 5, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tbmv('u', 't', 'n', 6, 5, &a, 4, &mut vec![], 1);
+        complex::tbmv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &a,
+            4,
+            &mut vec![],
+            1,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        complex::tbmv('u', 't', 'n', 6, 5, &a, 4, &mut vec![], 0);
+        complex::tbmv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &a,
+            4,
+            &mut vec![],
+            0,
+        );
     });
     assert!(result.is_err());
 }
@@ -2599,7 +2679,17 @@ fn tbsv() {
         Complex::new(0.0, 0.0),
     ];
 
-    complex::tbsv('u', 'n', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2619,7 +2709,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('u', 'n', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2640,7 +2740,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('l', 'n', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2662,7 +2772,17 @@ fn tbsv() {
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
 
-    complex::tbsv('l', 'n', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2683,7 +2803,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('u', 't', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2704,7 +2834,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('u', 't', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2725,7 +2865,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('l', 't', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2746,7 +2896,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('l', 't', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2767,7 +2927,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('u', 'c', 'n', 6, 3, &a, 6, &mut x, -1);
+    complex::tbsv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     capproximately!(
         x,
         vec![
@@ -2788,7 +2958,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('u', 'c', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2808,7 +2988,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('l', 'c', 'n', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2829,7 +3019,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('l', 'c', 'u', 6, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2850,7 +3050,17 @@ fn tbsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tbsv('l', 'c', 'u', 0, 3, &a, 6, &mut x, 1);
+    complex::tbsv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        0,
+        3,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2864,27 +3074,32 @@ fn tbsv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::tbsv('x', 't', 'u', 6, 5, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tbsv('u', 'x', 'u', 6, 5, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tbsv('u', 't', 'c', 6, 5, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tbsv('u', 't', 'n', 6, 5, &a, 4, &mut vec![], 1);
+        complex::tbsv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &a,
+            4,
+            &mut vec![],
+            1,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        complex::tbsv('u', 't', 'n', 6, 5, &a, 4, &mut vec![], 0);
+        complex::tbsv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &a,
+            4,
+            &mut vec![],
+            0,
+        );
     });
     assert!(result.is_err());
 }
@@ -2902,7 +3117,15 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('u', 'n', 'n', 6, &ap, &mut x, 1);
+    complex::tpmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2923,7 +3146,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('u', 'n', 'u', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Upper, Trans::NoTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -2944,7 +3167,15 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('l', 'n', 'n', 6, &ap, &mut x, 1);
+    complex::tpmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -2965,7 +3196,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('l', 'n', 'u', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Lower, Trans::NoTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -2988,7 +3219,7 @@ fn tpmv() {
     for c in ap.iter() {
         println!("{:?}", c.im);
     }
-    complex::tpmv('u', 't', 'n', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Upper, Trans::Trans, Diag::NonUnit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3009,7 +3240,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('u', 't', 'u', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Upper, Trans::Trans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3030,7 +3261,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('l', 't', 'n', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Lower, Trans::Trans, Diag::NonUnit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3051,7 +3282,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('l', 't', 'u', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Lower, Trans::Trans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3071,7 +3302,15 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('u', 'c', 'n', 6, &ap, &mut x, -1);
+    complex::tpmv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        -1,
+    );
     capproximately!(
         x,
         vec![
@@ -3092,7 +3331,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('u', 'c', 'u', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Upper, Trans::ConjTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3112,7 +3351,15 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('l', 'c', 'n', 6, &ap, &mut x, 1);
+    complex::tpmv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3133,7 +3380,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('l', 'c', 'u', 6, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Lower, Trans::ConjTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3154,7 +3401,7 @@ fn tpmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpmv('l', 'c', 'u', 0, &ap, &mut x, 1);
+    complex::tpmv(Uplo::Lower, Trans::ConjTrans, Diag::Unit, 0, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3168,22 +3415,15 @@ fn tpmv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::tpmv('x', 't', 'u', 6, &ap, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tpmv('u', 'x', 'u', 6, &ap, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tpmv('u', 't', 'x', 6, &ap, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tpmv('u', 't', 'n', 6, &ap, &mut vec![], 0);
+        complex::tpmv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &ap,
+            &mut vec![],
+            0,
+        );
     });
     assert!(result.is_err());
 }
@@ -3201,7 +3441,15 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::tpsv('u', 'n', 'n', 6, &ap, &mut x, 1);
+    complex::tpsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3222,7 +3470,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::tpsv('u', 'n', 'u', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Upper, Trans::NoTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3242,7 +3490,15 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('l', 'n', 'n', 6, &ap, &mut x, 1);
+    complex::tpsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3263,7 +3519,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('l', 'n', 'u', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Lower, Trans::NoTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3284,7 +3540,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('u', 't', 'n', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Upper, Trans::Trans, Diag::NonUnit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3305,7 +3561,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('u', 't', 'u', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Upper, Trans::Trans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3326,7 +3582,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('l', 't', 'n', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Lower, Trans::Trans, Diag::NonUnit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3348,7 +3604,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('l', 't', 'u', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Lower, Trans::Trans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3369,7 +3625,15 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('u', 'c', 'n', 6, &ap, &mut x, -1);
+    complex::tpsv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        -1,
+    );
     capproximately!(
         x,
         vec![
@@ -3390,7 +3654,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('u', 'c', 'u', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Upper, Trans::ConjTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3411,7 +3675,15 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('l', 'c', 'n', 6, &ap, &mut x, 1);
+    complex::tpsv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3433,7 +3705,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('l', 'c', 'u', 6, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Lower, Trans::ConjTrans, Diag::Unit, 6, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3454,7 +3726,7 @@ fn tpsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::tpsv('l', 'c', 'u', 0, &ap, &mut x, 1);
+    complex::tpsv(Uplo::Lower, Trans::ConjTrans, Diag::Unit, 0, &ap, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3468,22 +3740,15 @@ fn tpsv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::tpsv('x', 't', 'u', 6, &ap, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tpsv('u', 'x', 'u', 6, &ap, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tpsv('u', 't', 'x', 6, &ap, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::tpsv('u', 't', 'n', 6, &ap, &mut vec![], 0);
+        complex::tpsv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &ap,
+            &mut vec![],
+            0,
+        );
     });
     assert!(result.is_err());
 }
@@ -3500,7 +3765,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('u', 'n', 'n', 6, &a, 6, &mut x, 1);
+    complex::trmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3521,7 +3795,7 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('u', 'n', 'u', 6, &a, 6, &mut x, 1);
+    complex::trmv(Uplo::Upper, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3545,7 +3819,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('l', 'n', 'n', 6, &a, 6, &mut x, 1);
+    complex::trmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3566,7 +3849,7 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('l', 'n', 'u', 6, &a, 6, &mut x, 1);
+    complex::trmv(Uplo::Lower, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3581,7 +3864,6 @@ fn trmv() {
 
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 6), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-;
     let mut x = vec![
         Complex::new(-0.6490100777088978, 0.7721421858045301),
         Complex::new(-0.11916876241803812, -0.21951562675343952),
@@ -3590,7 +3872,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('u', 't', 'n', 6, &a, 6, &mut x, 1);
+    complex::trmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3605,7 +3896,6 @@ fn trmv() {
 
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 6), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-;
     let mut x = vec![
         Complex::new(-0.6490100777088978, 0.7721421858045301),
         Complex::new(-0.11916876241803812, -0.21951562675343952),
@@ -3614,7 +3904,7 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('u', 't', 'u', 6, &a, 6, &mut x, 1);
+    complex::trmv(Uplo::Upper, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3638,7 +3928,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('l', 't', 'n', 6, &a, 6, &mut x, 1);
+    complex::trmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3659,7 +3958,7 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('l', 't', 'u', 6, &a, 6, &mut x, 1);
+    complex::trmv(Uplo::Lower, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3674,7 +3973,6 @@ fn trmv() {
 
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 6), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-;
     let mut x = vec![
         Complex::new(-0.6490100777088978, 0.7721421858045301),
         Complex::new(-0.11916876241803812, -0.21951562675343952),
@@ -3683,7 +3981,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('u', 'c', 'n', 6, &a, 6, &mut x, 1);
+    complex::trmv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3704,7 +4011,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('u', 'c', 'u', 6, &a, 6, &mut x, 1);
+    complex::trmv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3728,7 +4044,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('l', 'c', 'n', 6, &a, 6, &mut x, 1);
+    complex::trmv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3749,7 +4074,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('l', 'c', 'u', 6, &a, 6, &mut x, -1);
+    complex::trmv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     capproximately!(
         x,
         vec![
@@ -3770,7 +4104,16 @@ fn trmv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trmv('l', 'c', 'u', 0, &a, 6, &mut x, -1);
+    complex::trmv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        0,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     capproximately!(
         x,
         vec![
@@ -3784,27 +4127,30 @@ fn trmv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::trmv('x', 't', 'u', 6, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trmv('u', 'x', 'u', 6, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trmv('u', 't', 'x', 6, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trmv('u', 't', 'n', 6, &a, 6, &mut vec![], 0);
+        complex::trmv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &a,
+            6,
+            &mut vec![],
+            0,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        complex::trmv('u', 't', 'n', 6, &a, 5, &mut vec![], 1);
+        complex::trmv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &a,
+            5,
+            &mut vec![],
+            1,
+        );
     });
     assert!(result.is_err());
 }
@@ -3813,7 +4159,6 @@ fn trmv() {
 fn trsv() {
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-;
     let mut x = vec![
         Complex::new(-0.6490100777088978, 0.7721421858045301),
         Complex::new(-0.11916876241803812, -0.21951562675343952),
@@ -3822,7 +4167,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trsv('u', 'n', 'n', 6, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3842,7 +4196,7 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trsv('u', 'n', 'u', 6, &a, 6, &mut x, 1);
+    complex::trsv(Uplo::Upper, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3866,7 +4220,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::trsv('l', 'n', 'n', 6, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3887,7 +4250,7 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::trsv('l', 'n', 'u', 6, &a, 6, &mut x, 1);
+    complex::trsv(Uplo::Lower, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3902,7 +4265,6 @@ fn trsv() {
 
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-;
     let mut x = vec![
         Complex::new(0.0, 0.0),
         Complex::new(-0.11916876241803812, -0.21951562675343952),
@@ -3911,7 +4273,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::trsv('u', 't', 'n', 6, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3932,7 +4303,7 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::trsv('u', 't', 'u', 6, &a, 6, &mut x, 1);
+    complex::trsv(Uplo::Upper, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3956,7 +4327,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trsv('l', 't', 'n', 6, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -3977,7 +4357,7 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trsv('l', 't', 'u', 6, &a, 6, &mut x, 1);
+    complex::trsv(Uplo::Lower, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, 1);
     capproximately!(
         x,
         vec![
@@ -3991,7 +4371,6 @@ fn trsv() {
     );
     let mut a = matrix::complex::slice(fixtures::complex::matrix_mxn(6, 8), 6, 1, 6, 1, 6);
     matrix::complex::set_lower(&mut a, 6, 6, 0.0);
-;
     let mut x = vec![
         Complex::new(0.0, 0.0),
         Complex::new(-0.11916876241803812, -0.21951562675343952),
@@ -4000,7 +4379,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::trsv('u', 'c', 'n', 6, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -4021,7 +4409,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(-0.11775359816595128, -0.27577802908802723),
     ];
-    complex::trsv('u', 'c', 'u', 6, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -4045,7 +4442,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trsv('l', 'c', 'n', 6, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -4066,7 +4472,16 @@ fn trsv() {
         Complex::new(0.14377148075806995, 0.9969868609091059),
         Complex::new(0.0, 0.0),
     ];
-    complex::trsv('l', 'c', 'u', 6, &a, 6, &mut x, -1);
+    complex::trsv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        6,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     capproximately!(
         x,
         vec![
@@ -4087,7 +4502,16 @@ fn trsv() {
         Complex::new(0.14377148449420929, 0.99698686599731445),
         Complex::new(-0.11775359511375427, -0.27577802538871765),
     ];
-    complex::trsv('l', 'c', 'u', 0, &a, 6, &mut x, 1);
+    complex::trsv(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
+        0,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     capproximately!(
         x,
         vec![
@@ -4101,27 +4525,179 @@ fn trsv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::trsv('x', 't', 'u', 6, &a, 6, &mut vec![], 1);
+        complex::trsv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &a,
+            6,
+            &mut vec![],
+            0,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        complex::trsv('u', 'x', 'u', 6, &a, 6, &mut vec![], 1);
+        complex::trsv(
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &a,
+            5,
+            &mut vec![],
+            1,
+        );
     });
     assert!(result.is_err());
+}
 
-    let result = std::panic::catch_unwind(|| {
-        complex::trsv('u', 't', 'x', 6, &a, 6, &mut vec![], 1);
-    });
-    assert!(result.is_err());
+// The n by n complex symmetric matrix with the upper triangle of the fixture, stored in full and in packed uplo form.
+// The triangle that isn't referenced holds NaN in the full form.
+fn complex_symmetric(
+    uplo: Uplo,
+    n: usize,
+) -> (Vec<Complex<f64>>, Vec<Complex<f64>>, Vec<Complex<f64>>) {
+    let m = fixtures::complex::matrix_mxn(n as isize, n as isize);
+    let sym = |i: usize, j: usize| m[i.max(j) * n + i.min(j)];
+    let mut dense = vec![Complex::new(0.0, 0.0); n * n];
+    let mut stored = vec![Complex::new(f64::NAN, f64::NAN); n * n];
+    let mut packed = Vec::new();
+    for j in 0..n {
+        for i in 0..n {
+            dense[j * n + i] = sym(i, j);
+            if (uplo == Uplo::Upper && i <= j) || (uplo == Uplo::Lower && i >= j) {
+                stored[j * n + i] = sym(i, j);
+                packed.push(sym(i, j));
+            }
+        }
+    }
+    (dense, stored, packed)
+}
 
-    let result = std::panic::catch_unwind(|| {
-        complex::trsv('u', 't', 'n', 6, &a, 6, &mut vec![], 0);
-    });
-    assert!(result.is_err());
+// The n elements of x in the order of the increment, as in the reference BLAS.
+fn strided(x: &[Complex<f64>], n: usize, inc: isize) -> Vec<Complex<f64>> {
+    let start = if inc < 0 {
+        (n - 1) * inc.unsigned_abs()
+    } else {
+        0
+    };
+    (0..n)
+        .map(|i| x[(start as isize + i as isize * inc) as usize])
+        .collect()
+}
 
-    let result = std::panic::catch_unwind(|| {
-        complex::trsv('u', 't', 'n', 6, &a, 5, &mut vec![], 1);
-    });
-    assert!(result.is_err());
+#[test]
+fn symv_spmv() {
+    let n = 5;
+    let (alpha, beta) = (Complex::new(0.3, -0.6), Complex::new(-0.4, 0.9));
+    for &uplo in &[Uplo::Upper, Uplo::Lower] {
+        let (dense, stored, packed) = complex_symmetric(uplo, n);
+        for &(incx, incy) in &[(1, 1), (-1, 2), (2, -1)] {
+            let x = fixtures::complex::vector(9);
+            let y = fixtures::complex::vector(10)[1..].to_vec();
+            let (lx, ly) = (strided(&x, n, incx), strided(&y, n, incy));
+            let expected: Vec<Complex<f64>> = (0..n)
+                .map(|i| {
+                    let ax: Complex<f64> = (0..n).map(|j| dense[j * n + i] * lx[j]).sum();
+                    alpha * ax + beta * ly[i]
+                })
+                .collect();
+
+            let mut got = y.clone();
+            complex::symv(uplo, n, alpha, &stored, n, &x, incx, beta, &mut got, incy);
+            capproximately!(strided(&got, n, incy), expected);
+
+            let mut got = y.clone();
+            complex::spmv(uplo, n, alpha, &packed, &x, incx, beta, &mut got, incy);
+            capproximately!(strided(&got, n, incy), expected);
+        }
+    }
+
+    // beta = 0 doesn't read y.
+    let (_, stored, packed) = complex_symmetric(Uplo::Upper, n);
+    let x = fixtures::complex::vector(n);
+    let zero = Complex::new(0.0, 0.0);
+    let mut y = vec![Complex::new(f64::NAN, 0.0); n];
+    complex::symv(Uplo::Upper, n, alpha, &stored, n, &x, 1, zero, &mut y, 1);
+    let mut yp = vec![Complex::new(f64::NAN, 0.0); n];
+    complex::spmv(Uplo::Upper, n, alpha, &packed, &x, 1, zero, &mut yp, 1);
+    assert!(y.iter().all(|v| v.re.is_finite() && v.im.is_finite()));
+    capproximately!(y, yp);
+}
+
+#[test]
+fn syr_spr() {
+    let n = 5;
+    let alpha = Complex::new(-0.7, 0.2);
+    for &uplo in &[Uplo::Upper, Uplo::Lower] {
+        for &incx in &[1, -2] {
+            let (dense, stored, packed) = complex_symmetric(uplo, n);
+            let x = fixtures::complex::vector(9);
+            let lx = strided(&x, n, incx);
+
+            let mut got = stored.clone();
+            complex::syr(uplo, n, alpha, &x, incx, &mut got, n);
+            let mut got_packed = packed.clone();
+            complex::spr(uplo, n, alpha, &x, incx, &mut got_packed);
+
+            let mut expected = Vec::new();
+            let mut k = 0;
+            for j in 0..n {
+                for i in 0..n {
+                    let p = j * n + i;
+                    if (uplo == Uplo::Upper && i <= j) || (uplo == Uplo::Lower && i >= j) {
+                        // x*x**T, not x*x**H.
+                        let e = dense[p] + alpha * lx[i] * lx[j];
+                        capproximately!([got[p]], [e]);
+                        expected.push(e);
+                        k += 1;
+                    } else {
+                        assert!(got[p].re.is_nan(), "({}, {}) was changed", i, j);
+                    }
+                }
+            }
+            assert_eq!(k, got_packed.len());
+            capproximately!(got_packed, expected);
+        }
+    }
+}
+
+#[test]
+fn symmetric_real_matrix_is_hermitian() {
+    // With a real A, SYMV is HEMV and SPMV is HPMV.
+    let n = 5;
+    let (_, mut stored, mut packed) = complex_symmetric(Uplo::Lower, n);
+    for a in stored.iter_mut().chain(packed.iter_mut()) {
+        a.im = 0.0;
+    }
+    let x = fixtures::complex::vector(n);
+    let (alpha, beta) = (Complex::new(1.5, 0.5), Complex::new(0.0, -1.0));
+    let y = fixtures::complex::vector(10)[5..].to_vec();
+
+    let (mut symv, mut hemv) = (y.clone(), y.clone());
+    complex::symv(Uplo::Lower, n, alpha, &stored, n, &x, 1, beta, &mut symv, 1);
+    complex::hemv(Uplo::Lower, n, alpha, &stored, n, &x, 1, beta, &mut hemv, 1);
+    capproximately!(symv, hemv);
+
+    let (mut spmv, mut hpmv) = (y.clone(), y);
+    complex::spmv(Uplo::Lower, n, alpha, &packed, &x, 1, beta, &mut spmv, 1);
+    complex::hpmv(Uplo::Lower, n, alpha, &packed, &x, 1, beta, &mut hpmv, 1);
+    capproximately!(spmv, hpmv);
+}
+
+#[test]
+fn complex_symmetric_illegal_arguments() {
+    let z = vec![Complex::new(0.0, 0.0); 4];
+    let mut y = z.clone();
+    let one = Complex::new(1.0, 0.0);
+    let err = complex::try_symv(Uplo::Upper, 2, one, &z, 1, &z, 1, one, &mut y, 1).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("symv", 5));
+    let err = complex::try_spmv(Uplo::Upper, 2, one, &z, &z, 1, one, &mut y, 0).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("spmv", 9));
+    let err = complex::try_syr(Uplo::Lower, 2, one, &z, 0, &mut y, 2).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("syr", 5));
+    let err = complex::try_spr(Uplo::Lower, 2, one, &z, 0, &mut y).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("spr", 5));
 }
@@ -1,5 +1,6 @@
 use libblas::level2;
 use libblas::unstable::matrix;
+use libblas::{Diag, Trans, Uplo};
 mod fixtures;
 mod utils;
 
@@ -12,17 +13,59 @@ fn gbmv() {
     let x = vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 5.0];
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
 
-    level2::gbmv('n', 6, 9, 1, 1, 1.5, &a, 3, &x, 1, 2.5, &mut y, 1);
+    level2::gbmv(
+        Trans::NoTrans,
+        6,
+        9,
+        1,
+        1,
+        1.5,
+        &a,
+        3,
+        &x,
+        1,
+        2.5,
+        &mut y,
+        1,
+    );
     assert_eq!(y, vec![4.0, 1.0, 4.0, 1.0, 4.0, 1.0]);
 
     let a = vec![];
     let x = vec![];
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gbmv('n', 6, 0, 1, 1, 1.5, &a, 3, &x, 1, 2.5, &mut y, 1);
+    level2::gbmv(
+        Trans::NoTrans,
+        6,
+        0,
+        1,
+        1,
+        1.5,
+        &a,
+        3,
+        &x,
+        1,
+        2.5,
+        &mut y,
+        1,
+    );
     assert_eq!(y, vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gbmv('n', 6, 9, 1, 1, 0.0, &a, 3, &x, 1, 1.0, &mut y, 1);
+    level2::gbmv(
+        Trans::NoTrans,
+        6,
+        9,
+        1,
+        1,
+        0.0,
+        &a,
+        3,
+        &x,
+        1,
+        1.0,
+        &mut y,
+        1,
+    );
     assert_eq!(y, vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
 
     let a = vec![
@@ -31,20 +74,62 @@ fn gbmv() {
     ];
     let x = vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 5.0];
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gbmv('n', 3, 9, 1, 1, 1.5, &a, 3, &x, 1, 2.5, &mut y, 2);
+    level2::gbmv(
+        Trans::NoTrans,
+        3,
+        9,
+        1,
+        1,
+        1.5,
+        &a,
+        3,
+        &x,
+        1,
+        2.5,
+        &mut y,
+        2,
+    );
     assert_eq!(y, vec![4.0, 1.0, 1.0, 1.0, 4.0, 1.0]);
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gbmv('n', 3, 9, 1, 1, 1.5, &a, 3, &x, 1, 0.0, &mut y, 2);
+    level2::gbmv(
+        Trans::NoTrans,
+        3,
+        9,
+        1,
+        1,
+        1.5,
+        &a,
+        3,
+        &x,
+        1,
+        0.0,
+        &mut y,
+        2,
+    );
     assert_eq!(y, vec![1.5, 1.0, -1.5, 1.0, 1.5, 1.0]);
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gbmv('n', 6, 9, 1, 1, 1.5, &a, 3, &x, 1, 0.0, &mut y, 1);
+    level2::gbmv(
+        Trans::NoTrans,
+        6,
+        9,
+        1,
+        1,
+        1.5,
+        &a,
+        3,
+        &x,
+        1,
+        0.0,
+        &mut y,
+        1,
+    );
     assert_eq!(y, vec![1.5, -1.5, 1.5, -1.5, 1.5, -1.5]);
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
     level2::gbmv(
-        't',
+        Trans::Trans,
         6,
         9,
         1,
@@ -61,36 +146,45 @@ fn gbmv() {
     assert_eq!(y, vec![2.5, -0.5, 2.5, -0.5, 2.5, 5.5, -3.5, 1.0, 1.0]);
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gbmv('n', 6, 9, 1, 1, 0.0, &a, 3, &x, 1, 2.5, &mut y, 1);
+    level2::gbmv(
+        Trans::NoTrans,
+        6,
+        9,
+        1,
+        1,
+        0.0,
+        &a,
+        3,
+        &x,
+        1,
+        2.5,
+        &mut y,
+        1,
+    );
     assert_eq!(y, vec![2.5, 2.5, 2.5, 2.5, 2.5, 2.5]);
 
     let x = vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 3.0];
     let mut y = vec![9.0, 0.0, 0.0, 0.0, 0.0, -9.0];
-    level2::gbmv('n', 6, 9, 1, 1, 1.0, &a, 3, &x, -1, 0.0, &mut y, -1);
+    level2::gbmv(
+        Trans::NoTrans,
+        6,
+        9,
+        1,
+        1,
+        1.0,
+        &a,
+        3,
+        &x,
+        -1,
+        0.0,
+        &mut y,
+        -1,
+    );
     assert_eq!(y, vec![-1.0, 1.0, -1.0, 1.0, 1.0, 2.0]);
 
     let result = std::panic::catch_unwind(|| {
         level2::gbmv(
-            'x',
-            6,
-            9,
-            1,
-            1,
-            1.5,
-            &vec![],
-            3,
-            &vec![],
-            1,
-            2.5,
-            &mut vec![],
-            1,
-        )
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::gbmv(
-            'n',
+            Trans::NoTrans,
             6,
             1,
             1,
@@ -109,7 +203,7 @@ fn gbmv() {
 
     let result = std::panic::catch_unwind(|| {
         level2::gbmv(
-            'n',
+            Trans::NoTrans,
             6,
             1,
             1,
@@ -128,7 +222,7 @@ fn gbmv() {
 
     let result = std::panic::catch_unwind(|| {
         level2::gbmv(
-            'n',
+            Trans::NoTrans,
             6,
             1,
             1,
@@ -207,7 +301,7 @@ fn gemv() {
 
     let x = vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 5.0];
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gemv('n', 6, 9, 1.5, &a, 6, &x, 1, 2.5, &mut y, 1);
+    level2::gemv(Trans::NoTrans, 6, 9, 1.5, &a, 6, &x, 1, 2.5, &mut y, 1);
     approximately!(
         y,
         vec![
@@ -221,7 +315,7 @@ fn gemv() {
     );
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gemv('n', 6, 9, 1.5, &a, 6, &x, -1, 0.0, &mut y, -1);
+    level2::gemv(Trans::NoTrans, 6, 9, 1.5, &a, 6, &x, -1, 0.0, &mut y, -1);
     approximately!(
         y,
         vec![
@@ -235,7 +329,7 @@ fn gemv() {
     );
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-    level2::gemv('n', 6, 9, 1.5, &a, 6, &x, -1, 0.0, &mut y, 1);
+    level2::gemv(Trans::NoTrans, 6, 9, 1.5, &a, 6, &x, -1, 0.0, &mut y, 1);
     approximately!(
         y,
         vec![
@@ -250,7 +344,7 @@ fn gemv() {
 
     let x = vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0];
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 4.0, 4.0, 5.0];
-    level2::gemv('t', 6, 9, 1.5, &a, 6, &x, -1, 1.0, &mut y, 1);
+    level2::gemv(Trans::Trans, 6, 9, 1.5, &a, 6, &x, -1, 1.0, &mut y, 1);
     approximately!(
         y,
         vec![
@@ -267,15 +361,15 @@ fn gemv() {
     );
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 4.0, 4.0, 5.0];
-    level2::gemv('t', 6, 9, 0.0, &a, 6, &x, -1, 1.0, &mut y, 1);
+    level2::gemv(Trans::Trans, 6, 9, 0.0, &a, 6, &x, -1, 1.0, &mut y, 1);
     assert_eq!(y, vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 4.0, 4.0, 5.0]);
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 4.0, 4.0, 5.0];
-    level2::gemv('t', 6, 9, 0.0, &a, 6, &x, -1, 1.5, &mut y, 1);
+    level2::gemv(Trans::Trans, 6, 9, 0.0, &a, 6, &x, -1, 1.5, &mut y, 1);
     assert_eq!(y, vec![1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 6.0, 6.0, 7.5]);
 
     let mut y = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 4.0, 4.0, 5.0];
-    level2::gemv('t', 3, 4, 1.0, &a, 6, &x, -2, 0.0, &mut y, -2);
+    level2::gemv(Trans::Trans, 3, 4, 1.0, &a, 6, &x, -2, 0.0, &mut y, -2);
     approximately!(
         y,
         vec![
@@ -293,35 +387,89 @@ fn gemv() {
 
     let result = std::panic::catch_unwind(|| {
         level2::gemv(
-            'x',
-            3,
-            4,
-            1.0,
+            Trans::Trans,
+            1,
+            7,
+            0.0,
             &vec![],
             6,
             &vec![],
-            -2,
+            1,
             0.0,
             &mut vec![],
-            -2,
+            1,
         )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::gemv('t', 1, 7, 0.0, &vec![], 6, &vec![], 1, 0.0, &mut vec![], 1)
+        level2::gemv(
+            Trans::Trans,
+            1,
+            7,
+            0.0,
+            &vec![],
+            7,
+            &vec![],
+            0,
+            0.0,
+            &mut vec![],
+            1,
+        )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::gemv('t', 1, 7, 0.0, &vec![], 7, &vec![], 0, 0.0, &mut vec![], 1)
+        level2::gemv(
+            Trans::Trans,
+            1,
+            7,
+            0.0,
+            &vec![],
+            7,
+            &vec![],
+            1,
+            0.0,
+            &mut vec![],
+            0,
+        )
     });
     assert!(result.is_err());
 
-    let result = std::panic::catch_unwind(|| {
-        level2::gemv('t', 1, 7, 0.0, &vec![], 7, &vec![], 1, 0.0, &mut vec![], 0)
-    });
-    assert!(result.is_err());
+    let err =
+        level2::try_gemv(Trans::Trans, 1, 7, 0.0, &[], 7, &[], 1, 0.0, &mut [], 0).unwrap_err();
+    assert_eq!(err.routine(), "gemv");
+    assert_eq!(err.param(), 11);
+    assert_eq!(
+        err.to_string(),
+        "gemv parameter number 11 had an illegal value: incy must not be zero"
+    );
+
+    let a = fixtures::matrix_mxn(6, 8);
+    let x = fixtures::vector(5);
+    let mut y = fixtures::vector(8);
+    let err = level2::try_gemv(Trans::Trans, 6, 8, 1.0, &a, 6, &x, 1, 0.0, &mut y, 1).unwrap_err();
+    assert_eq!(err.param(), 7);
+    assert_eq!(
+        err.reason(),
+        "the slice must hold 1 + (n-1)*abs(inc) elements"
+    );
+    let x = vec![1.0; 15];
+    let err =
+        level2::try_gemv(Trans::NoTrans, 6, 8, 1.0, &a, 6, &x, 2, 0.0, &mut y, 2).unwrap_err();
+    assert_eq!(err.param(), 10);
+    let err =
+        level2::try_gemv(Trans::NoTrans, 6, 8, 1.0, &a[1..], 6, &x, 2, 0.0, &mut y, 1).unwrap_err();
+    assert_eq!(err.param(), 5);
+}
+
+#[test]
+fn gemv_negative_incy() {
+    let a = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let x = vec![1.0, 1.0];
+    let mut y = vec![0.0; 3];
+    level2::gemv(Trans::Trans, 2, 3, 1.0, &a, 2, &x, 1, 0.0, &mut y, -1);
+    assert_eq!(y, vec![11.0, 7.0, 3.0]);
 }
 
 #[test]
@@ -662,7 +810,7 @@ fn sbmv() {
         -0.9406491626186084,
     ];
 
-    level2::sbmv('u', 6, 5, 0.75, &a, 6, &x, 1, 0.25, &mut y, 1);
+    level2::sbmv(Uplo::Upper, 6, 5, 0.75, &a, 6, &x, 1, 0.25, &mut y, 1);
     approximately!(
         y,
         vec![
@@ -723,7 +871,7 @@ fn sbmv() {
         -0.9406491626186084,
     ];
 
-    level2::sbmv('l', 6, 5, 0.75, &a, 6, &x, 1, 0.25, &mut y, 1);
+    level2::sbmv(Uplo::Lower, 6, 5, 0.75, &a, 6, &x, 1, 0.25, &mut y, 1);
     approximately!(
         y,
         vec![
@@ -745,7 +893,7 @@ fn sbmv() {
         -0.9406491626186084,
     ];
 
-    level2::sbmv('l', 6, 5, 0.75, &a, 6, &x, -1, 0.25, &mut y, -1);
+    level2::sbmv(Uplo::Lower, 6, 5, 0.75, &a, 6, &x, -1, 0.25, &mut y, -1);
     approximately!(
         y,
         vec![
@@ -767,7 +915,7 @@ fn sbmv() {
         -0.9406491626186084,
     ];
 
-    level2::sbmv('l', 3, 5, 0.75, &a, 6, &x, 2, 0.25, &mut y, -2);
+    level2::sbmv(Uplo::Lower, 3, 5, 0.75, &a, 6, &x, 2, 0.25, &mut y, -2);
     approximately!(
         y,
         vec![
@@ -789,7 +937,7 @@ fn sbmv() {
         -0.9406491626186084,
     ];
 
-    level2::sbmv('l', 6, 5, 0.0, &a, 6, &x, 1, 1.0, &mut y, 1);
+    level2::sbmv(Uplo::Lower, 6, 5, 0.0, &a, 6, &x, 1, 1.0, &mut y, 1);
     assert_eq!(
         y,
         vec![
@@ -810,7 +958,7 @@ fn sbmv() {
         -0.011045478465663564,
         -0.9406491626186084,
     ];
-    level2::sbmv('l', 6, 5, 0.25, &a, 6, &x, 1, 1.0, &mut y, 1);
+    level2::sbmv(Uplo::Lower, 6, 5, 0.25, &a, 6, &x, 1, 1.0, &mut y, 1);
     approximately!(
         y,
         vec![
@@ -831,26 +979,57 @@ fn sbmv() {
         -0.011045478465663564,
         -0.9406491626186084,
     ];
-    level2::sbmv('l', 6, 5, 0.0, &a, 6, &x, 1, 0.0, &mut y, 1);
+    level2::sbmv(Uplo::Lower, 6, 5, 0.0, &a, 6, &x, 1, 0.0, &mut y, 1);
     assert_eq!(y, vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
 
     let result = std::panic::catch_unwind(|| {
-        level2::sbmv('x', 6, 5, 0.0, &vec![], 6, &vec![], 1, 0.0, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::sbmv('l', 1, 7, 0.0, &vec![], 6, &vec![], 1, 0.0, &mut vec![], 1)
+        level2::sbmv(
+            Uplo::Lower,
+            1,
+            7,
+            0.0,
+            &vec![],
+            6,
+            &vec![],
+            1,
+            0.0,
+            &mut vec![],
+            1,
+        )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::sbmv('l', 1, 3, 0.0, &vec![], 6, &vec![], 0, 0.0, &mut vec![], 1)
+        level2::sbmv(
+            Uplo::Lower,
+            1,
+            3,
+            0.0,
+            &vec![],
+            6,
+            &vec![],
+            0,
+            0.0,
+            &mut vec![],
+            1,
+        )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::sbmv('l', 1, 3, 0.0, &vec![], 6, &vec![], 1, 0.0, &mut vec![], 0)
+        level2::sbmv(
+            Uplo::Lower,
+            1,
+            3,
+            0.0,
+            &vec![],
+            6,
+            &vec![],
+            1,
+            0.0,
+            &mut vec![],
+            0,
+        )
     });
     assert!(result.is_err());
 }
@@ -877,7 +1056,17 @@ fn spmv() {
         0.310141376504687,
         1.7025705860144955,
     ];
-    level2::spmv('u', 8, 1.0, &fixtures::MSP8X8(), &x, 1, 0.25, &mut y, 1);
+    level2::spmv(
+        Uplo::Upper,
+        8,
+        1.0,
+        &fixtures::MSP8X8(),
+        &x,
+        1,
+        0.25,
+        &mut y,
+        1,
+    );
     approximately!(
         y,
         vec![
@@ -902,7 +1091,17 @@ fn spmv() {
         0.310141376504687,
         1.7025705860144955,
     ];
-    level2::spmv('u', 8, 1.0, &fixtures::MSP8X8(), &x, -1, 0.25, &mut y, -1);
+    level2::spmv(
+        Uplo::Upper,
+        8,
+        1.0,
+        &fixtures::MSP8X8(),
+        &x,
+        -1,
+        0.25,
+        &mut y,
+        -1,
+    );
     approximately!(
         y,
         vec![
@@ -927,7 +1126,17 @@ fn spmv() {
         0.310141376504687,
         1.7025705860144955,
     ];
-    level2::spmv('u', 8, 0.0, &fixtures::MSP8X8(), &x, -1, 1.0, &mut y, -1);
+    level2::spmv(
+        Uplo::Upper,
+        8,
+        0.0,
+        &fixtures::MSP8X8(),
+        &x,
+        -1,
+        1.0,
+        &mut y,
+        -1,
+    );
     approximately!(
         y,
         vec![
@@ -952,7 +1161,17 @@ fn spmv() {
         0.310141376504687,
         1.7025705860144955,
     ];
-    level2::spmv('u', 8, 0.0, &fixtures::MSP8X8(), &x, -1, 0.0, &mut y, -1);
+    level2::spmv(
+        Uplo::Upper,
+        8,
+        0.0,
+        &fixtures::MSP8X8(),
+        &x,
+        -1,
+        0.0,
+        &mut y,
+        -1,
+    );
     assert_eq!(y, vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
 
     let mut y = vec![
@@ -965,7 +1184,17 @@ fn spmv() {
         0.310141376504687,
         1.7025705860144955,
     ];
-    level2::spmv('l', 8, 1.0, &fixtures::MSP8X8(), &x, 1, 1.0, &mut y, 1);
+    level2::spmv(
+        Uplo::Lower,
+        8,
+        1.0,
+        &fixtures::MSP8X8(),
+        &x,
+        1,
+        1.0,
+        &mut y,
+        1,
+    );
     approximately!(
         y,
         vec![
@@ -981,27 +1210,32 @@ fn spmv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        level2::spmv('x', 8, 0.0, &vec![], &vec![], 1, 0.0, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::spmv('l', 8, 0.0, &vec![], &vec![], 0, 0.0, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::spmv('l', 8, 0.0, &vec![], &vec![], 1, 0.0, &mut vec![], 0)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::spmv('x', 8, 0.0, &vec![], &vec![], 0, 0.0, &mut vec![], 1)
+        level2::spmv(
+            Uplo::Lower,
+            8,
+            0.0,
+            &vec![],
+            &vec![],
+            0,
+            0.0,
+            &mut vec![],
+            1,
+        )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::spmv('x', 8, 0.0, &vec![], &vec![], 1, 0.0, &mut vec![], 0)
+        level2::spmv(
+            Uplo::Lower,
+            8,
+            0.0,
+            &vec![],
+            &vec![],
+            1,
+            0.0,
+            &mut vec![],
+            0,
+        )
     });
     assert!(result.is_err());
 }
@@ -1019,7 +1253,7 @@ fn spr() {
         1.4623515387464268,
     ];
     let mut ap = fixtures::MSP8X8();
-    level2::spr('u', 8, 1.0, &rand0_vector1x8, 1, &mut ap);
+    level2::spr(Uplo::Upper, 8, 1.0, &rand0_vector1x8, 1, &mut ap);
     approximately!(
         ap,
         vec![
@@ -1063,7 +1297,7 @@ fn spr() {
     );
 
     let mut ap = fixtures::MSP8X8();
-    level2::spr('l', 8, 1.0, &rand0_vector1x8, -1, &mut ap);
+    level2::spr(Uplo::Lower, 8, 1.0, &rand0_vector1x8, -1, &mut ap);
     approximately!(
         ap,
         vec![
@@ -1108,7 +1342,7 @@ fn spr() {
 
     let mut ap = fixtures::MSP8X8();
     let x = vec![1.0, 0.0, 2.0, 0.0, 3.0, 0.0, 4.0, 0.0];
-    level2::spr('l', 8, 1.0, &x, -1, &mut ap);
+    level2::spr(Uplo::Lower, 8, 1.0, &x, -1, &mut ap);
     approximately!(
         ap,
         vec![
@@ -1152,7 +1386,7 @@ fn spr() {
     );
 
     let mut ap = fixtures::MSP8X8();
-    level2::spr('u', 8, 1.0, &x, -1, &mut ap);
+    level2::spr(Uplo::Upper, 8, 1.0, &x, -1, &mut ap);
     approximately!(
         ap,
         vec![
@@ -1197,13 +1431,10 @@ fn spr() {
 
     let mut ap = fixtures::MSP8X8();
     let x = vec![];
-    level2::spr('u', 8, 0.0, &x, -1, &mut ap);
+    level2::spr(Uplo::Upper, 8, 0.0, &x, -1, &mut ap);
     assert_eq!(ap, fixtures::MSP8X8());
 
-    let result = std::panic::catch_unwind(|| level2::spr('x', 8, 1.0, &x, 1, &mut vec![]));
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| level2::spr('l', 8, 1.0, &x, 0, &mut vec![]));
+    let result = std::panic::catch_unwind(|| level2::spr(Uplo::Lower, 8, 1.0, &x, 0, &mut vec![]));
     assert!(result.is_err());
 }
 
@@ -1231,7 +1462,7 @@ fn spr2() {
         1.7025705860144955,
     ];
 
-    level2::spr2('u', 8, 1.0, &x, 1, &y, 1, &mut ap);
+    level2::spr2(Uplo::Upper, 8, 1.0, &x, 1, &y, 1, &mut ap);
     approximately!(
         ap,
         vec![
@@ -1277,7 +1508,7 @@ fn spr2() {
     let mut ap = fixtures::MSP8X8();
     let x = vec![1.0, 2.0, 0.0, 4.0, 0.0, 6.0, 7.0, 8.0];
     let y = vec![9.0, 12.0, 13.0, 14.0, 0.0, 16.0, 17.0, 18.0];
-    level2::spr2('l', 8, 0.25, &x, -1, &y, -1, &mut ap);
+    level2::spr2(Uplo::Lower, 8, 0.25, &x, -1, &y, -1, &mut ap);
     approximately!(
         ap,
         vec![
@@ -1321,7 +1552,7 @@ fn spr2() {
     );
 
     let mut ap = fixtures::MSP8X8();
-    level2::spr2('u', 8, 0.25, &x, -1, &y, -1, &mut ap);
+    level2::spr2(Uplo::Upper, 8, 0.25, &x, -1, &y, -1, &mut ap);
     approximately!(
         ap,
         vec![
@@ -1365,19 +1596,17 @@ fn spr2() {
     );
 
     let mut ap = fixtures::MSP8X8();
-    level2::spr2('u', 8, 0.0, &x, -1, &y, -1, &mut ap);
+    level2::spr2(Uplo::Upper, 8, 0.0, &x, -1, &y, -1, &mut ap);
     assert_eq!(ap, fixtures::MSP8X8());
 
-    let result =
-        std::panic::catch_unwind(|| level2::spr2('x', 8, 1.0, &vec![], 1, &vec![], 1, &mut vec![]));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::spr2('l', 8, 1.0, &vec![], 0, &vec![], 1, &mut vec![]));
+    let result = std::panic::catch_unwind(|| {
+        level2::spr2(Uplo::Lower, 8, 1.0, &vec![], 0, &vec![], 1, &mut vec![])
+    });
     assert!(result.is_err());
 
-    let result =
-        std::panic::catch_unwind(|| level2::spr2('l', 8, 1.0, &vec![], 1, &vec![], 0, &mut vec![]));
+    let result = std::panic::catch_unwind(|| {
+        level2::spr2(Uplo::Lower, 8, 1.0, &vec![], 1, &vec![], 0, &mut vec![])
+    });
     assert!(result.is_err());
 }
 
@@ -1400,7 +1629,7 @@ fn symv() {
         -1.7145022968458246,
         -0.4209978978166964,
     ];
-    level2::symv('u', 6, 1.0, &a, 6, &x, 1, 1.0, &mut y, 1);
+    level2::symv(Uplo::Upper, 6, 1.0, &a, 6, &x, 1, 1.0, &mut y, 1);
     approximately!(
         y,
         vec![
@@ -1421,7 +1650,7 @@ fn symv() {
         -1.7145022968458246,
         -0.4209978978166964,
     ];
-    level2::symv('u', 6, 0.0, &a, 6, &x, -1, 0.35, &mut y, -1);
+    level2::symv(Uplo::Upper, 6, 0.0, &a, 6, &x, -1, 0.35, &mut y, -1);
     approximately!(
         y,
         vec![
@@ -1442,7 +1671,7 @@ fn symv() {
         -1.7145022968458246,
         -0.4209978978166964,
     ];
-    level2::symv('u', 6, 0.5, &a, 6, &x, -1, 0.0, &mut y, -1);
+    level2::symv(Uplo::Upper, 6, 0.5, &a, 6, &x, -1, 0.0, &mut y, -1);
     approximately!(
         y,
         vec![
@@ -1463,7 +1692,7 @@ fn symv() {
         -1.7145022968458246,
         -0.4209978978166964,
     ];
-    level2::symv('l', 6, 0.5, &a, 6, &x, -1, 0.0, &mut y, -1);
+    level2::symv(Uplo::Lower, 6, 0.5, &a, 6, &x, -1, 0.0, &mut y, -1);
     approximately!(
         y,
         vec![
@@ -1484,7 +1713,7 @@ fn symv() {
         -1.7145022968458246,
         -0.4209978978166964,
     ];
-    level2::symv('l', 6, 0.0, &a, 6, &x, -1, 1.0, &mut y, -1);
+    level2::symv(Uplo::Lower, 6, 0.0, &a, 6, &x, -1, 1.0, &mut y, -1);
     approximately!(
         y,
         vec![
@@ -1497,23 +1726,30 @@ fn symv() {
         ]
     );
     let a = vec![];
-    let result = std::panic::catch_unwind(|| {
-        level2::symv('x', 6, 0.5, &a, 6, &vec![], -1, 1.0, &mut vec![], -1)
-    });
-    assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::symv('l', 7, 0.5, &a, 6, &vec![], -1, 1.0, &mut vec![], -1)
+        level2::symv(
+            Uplo::Lower,
+            7,
+            0.5,
+            &a,
+            6,
+            &vec![],
+            -1,
+            1.0,
+            &mut vec![],
+            -1,
+        )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::symv('l', 7, 0.5, &a, 7, &vec![], 0, 1.0, &mut vec![], -1)
+        level2::symv(Uplo::Lower, 7, 0.5, &a, 7, &vec![], 0, 1.0, &mut vec![], -1)
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::symv('l', 7, 0.5, &a, 7, &vec![], 4, 1.0, &mut vec![], 0)
+        level2::symv(Uplo::Lower, 7, 0.5, &a, 7, &vec![], 4, 1.0, &mut vec![], 0)
     });
     assert!(result.is_err());
 }
@@ -1530,7 +1766,7 @@ fn syr() {
         0.5503933584550523,
     ];
 
-    level2::syr('u', 6, 1.0, &x, 1, &mut a, 6);
+    level2::syr(Uplo::Upper, 6, 1.0, &x, 1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1574,7 +1810,7 @@ fn syr() {
     );
 
     let mut a = fixtures::M6X6U();
-    level2::syr('l', 6, 1.0, &x, -1, &mut a, 6);
+    level2::syr(Uplo::Lower, 6, 1.0, &x, -1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1627,7 +1863,7 @@ fn syr() {
         0.5503933584550523,
     ];
 
-    level2::syr('l', 6, 1.5, &x, 1, &mut a, 6);
+    level2::syr(Uplo::Lower, 6, 1.5, &x, 1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1671,7 +1907,7 @@ fn syr() {
     );
 
     let mut a = fixtures::M6X6U();
-    level2::syr('u', 6, 1.5, &x, 1, &mut a, 6);
+    level2::syr(Uplo::Upper, 6, 1.5, &x, 1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1715,16 +1951,15 @@ fn syr() {
     );
 
     let mut a = fixtures::M6X6U();
-    level2::syr('u', 6, 0.0, &x, 1, &mut a, 6);
+    level2::syr(Uplo::Upper, 6, 0.0, &x, 1, &mut a, 6);
     assert_eq!(a, fixtures::M6X6U());
 
-    let result = std::panic::catch_unwind(|| level2::syr('x', 6, 1.0, &vec![], 1, &mut vec![], 6));
+    let result =
+        std::panic::catch_unwind(|| level2::syr(Uplo::Lower, 0, 1.0, &vec![], 0, &mut vec![], 6));
     assert!(result.is_err());
 
-    let result = std::panic::catch_unwind(|| level2::syr('l', 0, 1.0, &vec![], 0, &mut vec![], 6));
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| level2::syr('l', 10, 1.0, &vec![], 2, &mut vec![], 6));
+    let result =
+        std::panic::catch_unwind(|| level2::syr(Uplo::Lower, 10, 1.0, &vec![], 2, &mut vec![], 6));
     assert!(result.is_err());
 }
 
@@ -1748,7 +1983,7 @@ fn syr2() {
         11.557443037629128,
     ];
 
-    level2::syr2('u', 6, 1.0, &x, 1, &y, 1, &mut a, 6);
+    level2::syr2(Uplo::Upper, 6, 1.0, &x, 1, &y, 1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1792,7 +2027,7 @@ fn syr2() {
     );
 
     let mut a = fixtures::M6X6U();
-    level2::syr2('l', 6, 1.0, &x, -1, &y, -1, &mut a, 6);
+    level2::syr2(Uplo::Lower, 6, 1.0, &x, -1, &y, -1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1852,7 +2087,7 @@ fn syr2() {
         0.0,
         0.5503933584550523,
     ];
-    level2::syr2('l', 6, 1.0, &x, -1, &y, -1, &mut a, 6);
+    level2::syr2(Uplo::Lower, 6, 1.0, &x, -1, &y, -1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1896,7 +2131,7 @@ fn syr2() {
     );
 
     let mut a = fixtures::M6X6U();
-    level2::syr2('u', 6, 1.0, &x, -1, &y, -1, &mut a, 6);
+    level2::syr2(Uplo::Upper, 6, 1.0, &x, -1, &y, -1, &mut a, 6);
     approximately!(
         a,
         vec![
@@ -1940,26 +2175,21 @@ fn syr2() {
     );
 
     let mut a = fixtures::M6X6U();
-    level2::syr2('u', 0, 1.0, &x, -1, &y, -1, &mut a, 6);
+    level2::syr2(Uplo::Upper, 0, 1.0, &x, -1, &y, -1, &mut a, 6);
     assert_eq!(a, fixtures::M6X6U().to_vec());
 
     let result = std::panic::catch_unwind(|| {
-        level2::syr2('x', 6, 1.0, &vec![], -1, &vec![], -1, &mut vec![], 6)
+        level2::syr2(Uplo::Lower, 0, 1.0, &vec![], 0, &vec![], 1, &mut vec![], 6)
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::syr2('l', 0, 1.0, &vec![], 0, &vec![], 1, &mut vec![], 6)
+        level2::syr2(Uplo::Lower, 0, 1.0, &vec![], 1, &vec![], 0, &mut vec![], 6)
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::syr2('l', 0, 1.0, &vec![], 1, &vec![], 0, &mut vec![], 6)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::syr2('l', 10, 1.0, &vec![], 1, &vec![], 1, &mut vec![], 6)
+        level2::syr2(Uplo::Lower, 10, 1.0, &vec![], 1, &vec![], 1, &mut vec![], 6)
     });
     assert!(result.is_err());
 }
@@ -1975,7 +2205,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('u', 'n', 'n', 6, 5, &a, 6, &mut x, 1);
+    level2::tbmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -1997,7 +2237,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('u', 'n', 'u', 6, 5, &a, 6, &mut x, 1);
+    level2::tbmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2020,7 +2270,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('l', 'n', 'n', 6, 5, &a, 6, &mut x, 1);
+    level2::tbmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2042,7 +2302,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('l', 'n', 'u', 6, 5, &a, 6, &mut x, 1);
+    level2::tbmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2065,7 +2335,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('u', 't', 'n', 6, 5, &a, 6, &mut x, -1);
+    level2::tbmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2087,7 +2367,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('u', 't', 'u', 6, 5, &a, 6, &mut x, -1);
+    level2::tbmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2110,7 +2400,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('l', 't', 'n', 6, 5, &a, 6, &mut x, -1);
+    level2::tbmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2132,7 +2432,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('l', 't', 'u', 6, 5, &a, 6, &mut x, -1);
+    level2::tbmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2154,7 +2464,17 @@ fn tbmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbmv('l', 't', 'u', 0, 5, &a, 6, &mut x, -1);
+    level2::tbmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        0,
+        5,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2169,27 +2489,32 @@ fn tbmv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        level2::tbmv('x', 'n', 'n', 6, 5, &vec![0.0], 6, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::tbmv('l', 'x', 'n', 6, 5, &vec![0.0], 6, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::tbmv('l', 't', 'x', 6, 5, &vec![0.0], 6, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::tbmv('l', 't', 'n', 6, 5, &vec![0.0], 4, &mut vec![], 1)
+        level2::tbmv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &vec![0.0],
+            4,
+            &mut vec![],
+            1,
+        )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::tbmv('l', 't', 'n', 6, 5, &vec![0.0], 6, &mut vec![], 0)
+        level2::tbmv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &vec![0.0],
+            6,
+            &mut vec![],
+            0,
+        )
     });
     assert!(result.is_err());
 }
@@ -2205,7 +2530,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('u', 'n', 'n', 6, 5, &a, 6, &mut x, -1);
+    level2::tbsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2227,7 +2562,17 @@ fn tbsv() {
         0.3528744733184766,
         0.0, /*0.5503933584550523*/
     ];
-    level2::tbsv('u', 'n', 'u', 6, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2249,7 +2594,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('u', 't', 'n', 6, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2271,7 +2626,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('u', 't', 'u', 6, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2294,7 +2659,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('l', 'n', 'n', 6, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2316,7 +2691,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('l', 'n', 'u', 6, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2338,7 +2723,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('l', 't', 'n', 6, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2360,7 +2755,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('l', 't', 'u', 6, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2382,7 +2787,17 @@ fn tbsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tbsv('l', 't', 'u', 0, 5, &a, 6, &mut x, 1);
+    level2::tbsv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        0,
+        5,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2397,27 +2812,32 @@ fn tbsv() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        level2::tbsv('x', 'n', 'n', 6, 5, &vec![0.0], 6, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::tbsv('l', 'x', 'n', 6, 5, &vec![0.0], 6, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::tbsv('l', 't', 'x', 6, 5, &vec![0.0], 6, &mut vec![], 1)
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level2::tbsv('l', 't', 'n', 6, 5, &vec![0.0], 4, &mut vec![], 1)
+        level2::tbsv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &vec![0.0],
+            4,
+            &mut vec![],
+            1,
+        )
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level2::tbsv('l', 't', 'n', 6, 5, &vec![0.0], 6, &mut vec![], 0)
+        level2::tbsv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            5,
+            &vec![0.0],
+            6,
+            &mut vec![],
+            0,
+        )
     });
     assert!(result.is_err());
 }
@@ -2432,7 +2852,15 @@ fn tpsv() {
         0.3528744733184766,
         0.0, /*0.5503933584550523*/
     ];
-    level2::tpsv('u', 'n', 'n', 6, &fixtures::M6X6PU(), &mut x, 1);
+    level2::tpsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2454,7 +2882,15 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('u', 'n', 'u', 6, &fixtures::M6X6PU(), &mut x, -1);
+    level2::tpsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2476,7 +2912,15 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('u', 't', 'n', 6, &fixtures::M6X6PU(), &mut x, 1);
+    level2::tpsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2498,7 +2942,15 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('u', 't', 'u', 6, &fixtures::M6X6PU(), &mut x, 1);
+    level2::tpsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2543,7 +2995,15 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('l', 'n', 'n', 6, &ap, &mut x, 1);
+    level2::tpsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &ap,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2588,7 +3048,7 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('l', 'n', 'u', 6, &ap, &mut x, 1);
+    level2::tpsv(Uplo::Lower, Trans::NoTrans, Diag::Unit, 6, &ap, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -2610,7 +3070,7 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('l', 't', 'n', 6, &ap, &mut x, 1);
+    level2::tpsv(Uplo::Lower, Trans::Trans, Diag::NonUnit, 6, &ap, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -2632,7 +3092,7 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('l', 't', 'u', 6, &ap, &mut x, 1);
+    level2::tpsv(Uplo::Lower, Trans::Trans, Diag::Unit, 6, &ap, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -2654,7 +3114,7 @@ fn tpsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpsv('l', 't', 'u', 0, &ap, &mut x, 1);
+    level2::tpsv(Uplo::Lower, Trans::Trans, Diag::Unit, 0, &ap, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -2668,20 +3128,17 @@ fn tpsv() {
         1E-5
     );
 
-    let result =
-        std::panic::catch_unwind(|| level2::tpsv('x', 'n', 'n', 6, &vec![], &mut vec![0.0], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::tpsv('l', 'x', 'n', 6, &vec![], &mut vec![0.0], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::tpsv('l', 't', 'x', 6, &vec![], &mut vec![0.0], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::tpsv('l', 't', 'n', 6, &vec![], &mut vec![0.0], 0));
+    let result = std::panic::catch_unwind(|| {
+        level2::tpsv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &vec![],
+            &mut vec![0.0],
+            0,
+        )
+    });
     assert!(result.is_err());
 }
 
@@ -2695,7 +3152,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('u', 'n', 'n', 6, &fixtures::M6X6PU(), &mut x, 1);
+    level2::tpmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2717,7 +3182,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('u', 'n', 'u', 6, &fixtures::M6X6PU(), &mut x, 1);
+    level2::tpmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2739,7 +3212,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('l', 'n', 'n', 6, &fixtures::M6X6PL(), &mut x, 1);
+    level2::tpmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &fixtures::M6X6PL(),
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2761,7 +3242,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('l', 'n', 'u', 6, &fixtures::M6X6PL(), &mut x, 1);
+    level2::tpmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        6,
+        &fixtures::M6X6PL(),
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2783,7 +3272,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('u', 't', 'n', 6, &fixtures::M6X6PU(), &mut x, -1);
+    level2::tpmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2805,7 +3302,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('u', 't', 'u', 6, &fixtures::M6X6PU(), &mut x, -1);
+    level2::tpmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        &fixtures::M6X6PU(),
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2827,7 +3332,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('l', 't', 'n', 6, &fixtures::M6X6PL(), &mut x, -1);
+    level2::tpmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &fixtures::M6X6PL(),
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2849,7 +3362,15 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('l', 't', 'u', 6, &fixtures::M6X6PL(), &mut x, -1);
+    level2::tpmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        6,
+        &fixtures::M6X6PL(),
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -2895,7 +3416,7 @@ fn tpmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::tpmv('l', 't', 'u', 0, &ap, &mut x, 1);
+    level2::tpmv(Uplo::Lower, Trans::Trans, Diag::Unit, 0, &ap, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -2909,20 +3430,17 @@ fn tpmv() {
         1E-5
     );
 
-    let result =
-        std::panic::catch_unwind(|| level2::tpmv('x', 'n', 'n', 6, &vec![], &mut vec![0.0], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::tpmv('l', 'x', 'n', 6, &vec![], &mut vec![0.0], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::tpmv('l', 't', 'x', 6, &vec![], &mut vec![0.0], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::tpmv('l', 't', 'n', 6, &vec![], &mut vec![0.0], 0));
+    let result = std::panic::catch_unwind(|| {
+        level2::tpmv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &vec![],
+            &mut vec![0.0],
+            0,
+        )
+    });
     assert!(result.is_err());
 }
 
@@ -2937,7 +3455,16 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('u', 'n', 'n', 6, &a, 6, &mut x, 1);
+    level2::trmv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -2959,7 +3486,7 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('u', 'n', 'u', 6, &a, 6, &mut x, 1);
+    level2::trmv(Uplo::Upper, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -2982,7 +3509,16 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('l', 'n', 'n', 6, &a, 6, &mut x, 1);
+    level2::trmv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -3004,7 +3540,7 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('l', 'n', 'u', 6, &a, 6, &mut x, 1);
+    level2::trmv(Uplo::Lower, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -3027,7 +3563,16 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('u', 't', 'n', 6, &a, 6, &mut x, -1);
+    level2::trmv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -3049,7 +3594,7 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('u', 't', 'u', 6, &a, 6, &mut x, -1);
+    level2::trmv(Uplo::Upper, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, -1);
     approximately!(
         x,
         vec![
@@ -3072,7 +3617,16 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('l', 't', 'n', 6, &a, 6, &mut x, -1);
+    level2::trmv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -3094,7 +3648,7 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('l', 't', 'u', 6, &a, 6, &mut x, -1);
+    level2::trmv(Uplo::Lower, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, -1);
     approximately!(
         x,
         vec![
@@ -3116,7 +3670,7 @@ fn trmv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trmv('l', 't', 'u', 0, &a, 6, &mut x, -1);
+    level2::trmv(Uplo::Lower, Trans::Trans, Diag::Unit, 0, &a, 6, &mut x, -1);
     approximately!(
         x,
         vec![
@@ -3130,24 +3684,32 @@ fn trmv() {
         1E-5
     );
 
-    let result =
-        std::panic::catch_unwind(|| level2::trmv('x', 'n', 'n', 6, &vec![0.0], 6, &mut vec![], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::trmv('l', 'x', 'n', 6, &vec![0.0], 6, &mut vec![], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::trmv('l', 't', 'x', 6, &vec![0.0], 6, &mut vec![], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::trmv('l', 't', 'n', 6, &vec![0.0], 6, &mut vec![], 0));
+    let result = std::panic::catch_unwind(|| {
+        level2::trmv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &vec![0.0],
+            6,
+            &mut vec![],
+            0,
+        )
+    });
     assert!(result.is_err());
 
-    let result =
-        std::panic::catch_unwind(|| level2::trmv('l', 't', 'n', 6, &vec![0.0], 5, &mut vec![], 1));
+    let result = std::panic::catch_unwind(|| {
+        level2::trmv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &vec![0.0],
+            5,
+            &mut vec![],
+            1,
+        )
+    });
     assert!(result.is_err());
 }
 
@@ -3162,7 +3724,16 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('u', 'n', 'n', 6, &a, 6, &mut x, -1);
+    level2::trsv(
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        -1,
+    );
     approximately!(
         x,
         vec![
@@ -3184,7 +3755,7 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('u', 'n', 'u', 6, &a, 6, &mut x, 1);
+    level2::trsv(Uplo::Upper, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -3206,7 +3777,16 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('u', 't', 'n', 6, &a, 6, &mut x, 1);
+    level2::trsv(
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -3228,7 +3808,7 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('u', 't', 'u', 6, &a, 6, &mut x, 1);
+    level2::trsv(Uplo::Upper, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -3251,7 +3831,16 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('l', 'n', 'n', 6, &a, 6, &mut x, 1);
+    level2::trsv(
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -3273,7 +3862,7 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('l', 'n', 'u', 6, &a, 6, &mut x, 1);
+    level2::trsv(Uplo::Lower, Trans::NoTrans, Diag::Unit, 6, &a, 6, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -3295,7 +3884,16 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('l', 't', 'n', 6, &a, 6, &mut x, 1);
+    level2::trsv(
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        6,
+        &a,
+        6,
+        &mut x,
+        1,
+    );
     approximately!(
         x,
         vec![
@@ -3317,7 +3915,7 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('l', 't', 'u', 6, &a, 6, &mut x, 1);
+    level2::trsv(Uplo::Lower, Trans::Trans, Diag::Unit, 6, &a, 6, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -3340,7 +3938,7 @@ fn trsv() {
         0.3528744733184766,
         0.5503933584550523,
     ];
-    level2::trsv('l', 't', 'u', 0, &a, 6, &mut x, 1);
+    level2::trsv(Uplo::Lower, Trans::Trans, Diag::Unit, 0, &a, 6, &mut x, 1);
     approximately!(
         x,
         vec![
@@ -3353,23 +3951,32 @@ fn trsv() {
         ],
         1E-4
     );
-    let result =
-        std::panic::catch_unwind(|| level2::trsv('x', 'n', 'n', 6, &vec![0.0], 6, &mut vec![], 1));
-    assert!(result.is_err());
 
-    let result =
-        std::panic::catch_unwind(|| level2::trsv('l', 'x', 'n', 6, &vec![0.0], 6, &mut vec![], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::trsv('l', 't', 'x', 6, &vec![0.0], 6, &mut vec![], 1));
-    assert!(result.is_err());
-
-    let result =
-        std::panic::catch_unwind(|| level2::trsv('l', 't', 'n', 6, &vec![0.0], 4, &mut vec![], 1));
+    let result = std::panic::catch_unwind(|| {
+        level2::trsv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &vec![0.0],
+            4,
+            &mut vec![],
+            1,
+        )
+    });
     assert!(result.is_err());
 
-    let result =
-        std::panic::catch_unwind(|| level2::trsv('l', 't', 'n', 6, &vec![0.0], 6, &mut vec![], 0));
+    let result = std::panic::catch_unwind(|| {
+        level2::trsv(
+            Uplo::Lower,
+            Trans::Trans,
+            Diag::NonUnit,
+            6,
+            &vec![0.0],
+            6,
+            &mut vec![],
+            0,
+        )
+    });
     assert!(result.is_err());
 }
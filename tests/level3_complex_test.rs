@@ -1,4 +1,5 @@
 use libblas::level3::complex;
+use libblas::{Diag, Side, Trans, Uplo};
 use num_complex::Complex;
 mod fixtures;
 mod utils;
@@ -9,8 +10,8 @@ fn gemm() {
     let b = fixtures::complex::matrix_mxn(8, 8);
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        'n',
+        Trans::NoTrans,
+        Trans::NoTrans,
         6,
         8,
         4,
@@ -95,8 +96,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        'n',
+        Trans::NoTrans,
+        Trans::NoTrans,
         6,
         8,
         4,
@@ -181,8 +182,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        'n',
+        Trans::NoTrans,
+        Trans::NoTrans,
         6,
         8,
         4,
@@ -266,8 +267,8 @@ fn gemm() {
     );
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        'c',
+        Trans::NoTrans,
+        Trans::ConjTrans,
         6,
         8,
         4,
@@ -352,8 +353,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        'c',
+        Trans::NoTrans,
+        Trans::ConjTrans,
         6,
         8,
         4,
@@ -438,8 +439,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        'c',
+        Trans::NoTrans,
+        Trans::ConjTrans,
         6,
         8,
         4,
@@ -524,8 +525,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        't',
-        'c',
+        Trans::Trans,
+        Trans::ConjTrans,
         6,
         8,
         4,
@@ -610,8 +611,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        't',
-        'c',
+        Trans::Trans,
+        Trans::ConjTrans,
         6,
         8,
         4,
@@ -695,8 +696,8 @@ fn gemm() {
     );
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        't',
-        'n',
+        Trans::Trans,
+        Trans::NoTrans,
         6,
         8,
         4,
@@ -781,8 +782,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        't',
-        'n',
+        Trans::Trans,
+        Trans::NoTrans,
         6,
         8,
         4,
@@ -867,8 +868,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        't',
+        Trans::NoTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -953,8 +954,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        't',
+        Trans::NoTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1039,8 +1040,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'n',
-        't',
+        Trans::NoTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1124,8 +1125,8 @@ fn gemm() {
     );
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        'n',
+        Trans::ConjTrans,
+        Trans::NoTrans,
         6,
         8,
         4,
@@ -1210,8 +1211,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        'n',
+        Trans::ConjTrans,
+        Trans::NoTrans,
         6,
         8,
         4,
@@ -1295,8 +1296,8 @@ fn gemm() {
     );
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        't',
-        't',
+        Trans::Trans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1381,8 +1382,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        't',
-        't',
+        Trans::Trans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1466,8 +1467,8 @@ fn gemm() {
     );
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        'c',
+        Trans::ConjTrans,
+        Trans::ConjTrans,
         6,
         8,
         4,
@@ -1552,8 +1553,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        'c',
+        Trans::ConjTrans,
+        Trans::ConjTrans,
         6,
         8,
         4,
@@ -1638,8 +1639,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        't',
+        Trans::ConjTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1724,8 +1725,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        't',
+        Trans::ConjTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1810,8 +1811,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        't',
+        Trans::ConjTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1896,8 +1897,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        't',
+        Trans::ConjTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -1982,8 +1983,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        't',
+        Trans::ConjTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -2068,8 +2069,8 @@ fn gemm() {
 
     let mut c = fixtures::complex::matrix_mxn(8, 8);
     complex::gemm(
-        'c',
-        't',
+        Trans::ConjTrans,
+        Trans::Trans,
         6,
         8,
         4,
@@ -2154,33 +2155,14 @@ fn gemm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemm(
-            'x',
-            't',
+            Trans::NoTrans,
+            Trans::NoTrans,
             6,
             8,
             4,
             Complex::new(0.0, 0.0),
             &a,
-            8,
-            &b,
-            8,
-            Complex::new(0.2, 0.0),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::gemm(
-            'c',
-            'x',
-            6,
-            8,
             4,
-            Complex::new(0.0, 0.0),
-            &a,
-            8,
             &b,
             8,
             Complex::new(0.2, 0.0),
@@ -2192,27 +2174,8 @@ fn gemm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemm(
-            'n',
-            'n',
-            6,
-            8,
-            4,
-            Complex::new(0.0, 0.0),
-            &a,
-            4,
-            &b,
-            8,
-            Complex::new(0.2, 0.0),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::gemm(
-            'n',
-            'n',
+            Trans::NoTrans,
+            Trans::NoTrans,
             6,
             8,
             4,
@@ -2230,8 +2193,8 @@ fn gemm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::gemm(
-            'n',
-            'n',
+            Trans::NoTrans,
+            Trans::NoTrans,
             6,
             8,
             4,
@@ -2255,10 +2218,10 @@ fn trmm() {
     let a = mat.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'u',
-        'n',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2311,10 +2274,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'u',
-        'n',
-        'u',
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2367,10 +2330,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        'n',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2423,10 +2386,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        'n',
-        'u',
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2483,10 +2446,10 @@ fn trmm() {
     let a = mat2.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'u',
-        'n',
-        'n',
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2540,10 +2503,10 @@ fn trmm() {
     let a = mat.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'u',
-        'n',
-        'u',
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2599,10 +2562,10 @@ fn trmm() {
     let a = mat2.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'l',
-        'n',
-        'n',
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2657,10 +2620,10 @@ fn trmm() {
     let a = mat.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'l',
-        'n',
-        'u',
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2717,10 +2680,10 @@ fn trmm() {
     let a = mat2.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'u',
-        't',
-        'n',
+        Side::Right,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2772,10 +2735,10 @@ fn trmm() {
     );
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'u',
-        'c',
-        'n',
+        Side::Right,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2829,10 +2792,10 @@ fn trmm() {
     let a = mat.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'u',
-        'c',
-        'u',
+        Side::Right,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2885,10 +2848,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'l',
-        't',
-        'n',
+        Side::Right,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -2945,10 +2908,10 @@ fn trmm() {
     let a = mat2.clone();
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'l',
-        't',
-        'u',
+        Side::Right,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3001,10 +2964,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'l',
-        'c',
-        'u',
+        Side::Right,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3057,10 +3020,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'l',
-        'c',
-        'n',
+        Side::Right,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3115,10 +3078,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'l',
-        'c',
-        'u',
+        Side::Right,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -3170,10 +3133,10 @@ fn trmm() {
     );
     let mut b = mat.clone();
     complex::trmm(
-        'r',
-        'u',
-        'c',
-        'u',
+        Side::Right,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -3226,10 +3189,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'u',
-        't',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3283,10 +3246,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'u',
-        'c',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3339,10 +3302,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'u',
-        'c',
-        'u',
+        Side::Left,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3395,10 +3358,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        'c',
-        'u',
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3451,10 +3414,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        't',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3507,10 +3470,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        'c',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -3563,10 +3526,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        'c',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -3619,10 +3582,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        'c',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         0,
         Complex::new(0.2, 0.8),
@@ -3635,10 +3598,10 @@ fn trmm() {
 
     let mut b = mat.clone();
     complex::trmm(
-        'l',
-        'l',
-        'c',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         0,
         6,
         Complex::new(0.2, 0.8),
@@ -3651,78 +3614,10 @@ fn trmm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::trmm(
-            'x',
-            'u',
-            'n',
-            'n',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trmm(
-            'l',
-            'x',
-            'n',
-            'n',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trmm(
-            'l',
-            'u',
-            'x',
-            'n',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trmm(
-            'l',
-            'u',
-            'n',
-            'x',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trmm(
-            'l',
-            'u',
-            'n',
-            'n',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             4,
             6,
             Complex::new(0.2, 0.8),
@@ -3736,10 +3631,10 @@ fn trmm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::trmm(
-            'l',
-            'u',
-            'n',
-            'n',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             4,
             6,
             Complex::new(0.2, 0.8),
@@ -3757,10 +3652,10 @@ fn trsm() {
     let a = fixtures::complex::matrix_mxn(6, 6);
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'l',
-        'u',
-        'n',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
         0,
         6,
         Complex::new(0.2, 0.8),
@@ -3773,10 +3668,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'l',
-        'u',
-        'n',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         0,
         Complex::new(0.2, 0.8),
@@ -3789,10 +3684,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'l',
-        'u',
-        'n',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -3845,10 +3740,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'l',
-        'u',
-        'n',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.2, 0.6),
@@ -3904,10 +3799,10 @@ fn trsm() {
     mat[0] = Complex { re: 0.0, im: 0.0 };
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'u',
-        'n',
-        'u',
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -3960,10 +3855,10 @@ fn trsm() {
 
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'l',
-        'n',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -4016,10 +3911,10 @@ fn trsm() {
 
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'l',
-        'n',
-        'u',
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4072,10 +3967,10 @@ fn trsm() {
     );
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'u',
-        't',
-        'n',
+        Side::Left,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4128,10 +4023,10 @@ fn trsm() {
 
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'u',
-        'c',
-        'u',
+        Side::Left,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4184,10 +4079,10 @@ fn trsm() {
 
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'l',
-        'c',
-        'u',
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4240,10 +4135,10 @@ fn trsm() {
 
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'l',
-        'c',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4296,10 +4191,10 @@ fn trsm() {
 
     let mut b = mat.clone();
     complex::trsm(
-        'l',
-        'l',
-        't',
-        'n',
+        Side::Left,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4355,10 +4250,10 @@ fn trsm() {
     let a = mat.clone();
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'u',
-        'n',
-        'n',
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -4412,10 +4307,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'u',
-        'n',
-        'u',
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4472,10 +4367,10 @@ fn trsm() {
     let a = mat.clone();
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'l',
-        'n',
-        'n',
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4528,10 +4423,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'l',
-        'n',
-        'u',
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -4587,10 +4482,10 @@ fn trsm() {
     let a = mat.clone();
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'u',
-        't',
-        'n',
+        Side::Right,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -4642,10 +4537,10 @@ fn trsm() {
     );
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'u',
-        'c',
-        'n',
+        Side::Right,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4699,10 +4594,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'u',
-        'c',
-        'u',
+        Side::Right,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4755,10 +4650,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'l',
-        'c',
-        'u',
+        Side::Right,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::Unit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4814,10 +4709,10 @@ fn trsm() {
     let a = mat.clone();
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'l',
-        'c',
-        'n',
+        Side::Right,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(0.5, 0.5),
@@ -4871,10 +4766,10 @@ fn trsm() {
 
     let mut b = fixtures::complex::matrix_mxn(6, 6);
     complex::trsm(
-        'r',
-        'l',
-        't',
-        'n',
+        Side::Right,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
         4,
         6,
         Complex::new(1.0, 0.0),
@@ -4925,80 +4820,13 @@ fn trsm() {
         ],
         1E-5
     );
-    let result = std::panic::catch_unwind(|| {
-        complex::trsm(
-            'x',
-            'u',
-            'n',
-            'n',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trsm(
-            'l',
-            'x',
-            'n',
-            'n',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
         complex::trsm(
-            'l',
-            'u',
-            'x',
-            'n',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trsm(
-            'l',
-            'u',
-            'n',
-            'x',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::trsm(
-            'l',
-            'u',
-            'n',
-            'n',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             4,
             6,
             Complex::new(0.2, 0.8),
@@ -5012,10 +4840,10 @@ fn trsm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::trsm(
-            'l',
-            'u',
-            'n',
-            'n',
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::NonUnit,
             4,
             6,
             Complex::new(0.2, 0.8),
@@ -5034,8 +4862,8 @@ fn hemm() {
     let b = fixtures::complex::matrix_mxn(6, 6);
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         0,
         Complex::new(0.2, 0.8),
@@ -5051,8 +4879,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         0,
         6,
         Complex::new(0.2, 0.8),
@@ -5068,8 +4896,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -5085,8 +4913,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -5142,8 +4970,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -5198,8 +5026,8 @@ fn hemm() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         6,
         Complex::new(0.2, -0.8),
@@ -5255,8 +5083,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         6,
         Complex::new(0.2, -0.8),
@@ -5312,8 +5140,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'l',
+        Side::Left,
+        Uplo::Lower,
         4,
         6,
         Complex::new(0.2, -0.8),
@@ -5368,8 +5196,8 @@ fn hemm() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'l',
-        'l',
+        Side::Left,
+        Uplo::Lower,
         4,
         6,
         Complex::new(0.2, -0.8),
@@ -5425,8 +5253,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'r',
-        'u',
+        Side::Right,
+        Uplo::Upper,
         4,
         6,
         Complex::new(0.2, -0.8),
@@ -5482,8 +5310,8 @@ fn hemm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::hemm(
-        'r',
-        'l',
+        Side::Right,
+        Uplo::Lower,
         4,
         6,
         Complex::new(0.2, -0.8),
@@ -5539,44 +5367,8 @@ fn hemm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hemm(
-            'x',
-            'u',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &b,
-            6,
-            Complex::new(0.7, -0.3),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::hemm(
-            'l',
-            'x',
-            4,
-            6,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &b,
-            6,
-            Complex::new(0.7, -0.3),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::hemm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             4,
             6,
             Complex::new(0.2, 0.8),
@@ -5593,8 +5385,8 @@ fn hemm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hemm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             4,
             6,
             Complex::new(0.2, 0.8),
@@ -5611,8 +5403,8 @@ fn hemm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::hemm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             4,
             6,
             Complex::new(0.2, 0.8),
@@ -5634,8 +5426,8 @@ fn her2k() {
     let b = fixtures::complex::matrix_mxn(6, 6);
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         5,
         6,
         Complex::new(0.2, 0.8),
@@ -5693,8 +5485,8 @@ fn her2k() {
     let a = mat.clone();
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -5751,8 +5543,8 @@ fn her2k() {
     let b = mat.clone();
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -5809,8 +5601,8 @@ fn her2k() {
     let a = mat.clone();
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -5866,8 +5658,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -5923,8 +5715,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -5982,8 +5774,8 @@ fn her2k() {
     let b = fixtures::complex::matrix_mxn(6, 6);
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -6039,8 +5831,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -6096,8 +5888,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -6152,8 +5944,8 @@ fn her2k() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'c',
+        Uplo::Upper,
+        Trans::ConjTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -6209,8 +6001,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'c',
+        Uplo::Lower,
+        Trans::ConjTrans,
         5,
         4,
         Complex::new(0.2, 0.8),
@@ -6266,8 +6058,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'c',
+        Uplo::Lower,
+        Trans::ConjTrans,
         5,
         4,
         Complex::new(0.0, 0.0),
@@ -6283,8 +6075,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'c',
+        Uplo::Upper,
+        Trans::ConjTrans,
         5,
         4,
         Complex::new(0.0, 0.0),
@@ -6340,8 +6132,8 @@ fn her2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'u',
-        'c',
+        Uplo::Upper,
+        Trans::ConjTrans,
         5,
         4,
         Complex::new(0.0, 0.0),
@@ -6396,8 +6188,8 @@ fn her2k() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'c',
+        Uplo::Lower,
+        Trans::ConjTrans,
         5,
         4,
         Complex::new(0.0, 0.0),
@@ -6452,8 +6244,8 @@ fn her2k() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::her2k(
-        'l',
-        'c',
+        Uplo::Lower,
+        Trans::ConjTrans,
         5,
         4,
         Complex::new(0.0, 0.0),
@@ -6509,44 +6301,8 @@ fn her2k() {
 
     let result = std::panic::catch_unwind(|| {
         complex::her2k(
-            'x',
-            'c',
-            5,
-            4,
-            Complex::new(0.0, 0.0),
-            &a,
-            6,
-            &b,
-            6,
-            0.0,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::her2k(
-            'l',
-            'x',
-            5,
-            4,
-            Complex::new(0.0, 0.0),
-            &a,
-            6,
-            &b,
-            6,
-            0.0,
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::her2k(
-            'l',
-            'n',
+            Uplo::Lower,
+            Trans::NoTrans,
             5,
             4,
             Complex::new(0.0, 0.0),
@@ -6563,8 +6319,8 @@ fn her2k() {
 
     let result = std::panic::catch_unwind(|| {
         complex::her2k(
-            'l',
-            'n',
+            Uplo::Lower,
+            Trans::NoTrans,
             5,
             4,
             Complex::new(0.0, 0.0),
@@ -6581,8 +6337,8 @@ fn her2k() {
 
     let result = std::panic::catch_unwind(|| {
         complex::her2k(
-            'l',
-            'n',
+            Uplo::Lower,
+            Trans::NoTrans,
             5,
             4,
             Complex::new(0.0, 0.0),
@@ -6602,7 +6358,18 @@ fn her2k() {
 fn herk() {
     let a = fixtures::complex::matrix_mxn(6, 6);
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('u', 'n', 4, 6, 0.0, &a, 6, 1.0, &mut c, 6);
+    complex::herk(
+        Uplo::Upper,
+        Trans::NoTrans,
+        4,
+        6,
+        0.0,
+        &a,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6645,7 +6412,18 @@ fn herk() {
         ]
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('u', 'n', 4, 6, 0.0, &a, 6, 0.0, &mut c, 6);
+    complex::herk(
+        Uplo::Upper,
+        Trans::NoTrans,
+        4,
+        6,
+        0.0,
+        &a,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6689,7 +6467,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('l', 'n', 4, 6, 0.0, &a, 6, 0.0, &mut c, 6);
+    complex::herk(
+        Uplo::Lower,
+        Trans::NoTrans,
+        4,
+        6,
+        0.0,
+        &a,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6733,7 +6522,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('l', 'n', 4, 6, 0.0, &a, 6, 0.5, &mut c, 6);
+    complex::herk(
+        Uplo::Lower,
+        Trans::NoTrans,
+        4,
+        6,
+        0.0,
+        &a,
+        6,
+        0.5,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6780,7 +6580,18 @@ fn herk() {
     mat[0] = Complex { re: 0.0, im: 0.0 };
     let a = mat.clone();
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('u', 'n', 4, 6, 0.2, &a, 6, 0.5, &mut c, 6);
+    complex::herk(
+        Uplo::Upper,
+        Trans::NoTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        0.5,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6824,7 +6635,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('u', 'n', 4, 6, 0.2, &a, 6, 0.0, &mut c, 6);
+    complex::herk(
+        Uplo::Upper,
+        Trans::NoTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6868,7 +6690,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('u', 'n', 4, 6, 0.2, &a, 6, 1.0, &mut c, 6);
+    complex::herk(
+        Uplo::Upper,
+        Trans::NoTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6912,7 +6745,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('l', 'n', 4, 6, 0.2, &a, 6, 1.0, &mut c, 6);
+    complex::herk(
+        Uplo::Lower,
+        Trans::NoTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6956,7 +6800,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('u', 'c', 4, 6, 0.2, &a, 6, 0.0, &mut c, 6);
+    complex::herk(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -6999,7 +6854,18 @@ fn herk() {
         ]
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('u', 'c', 4, 6, 0.2, &a, 6, 0.2, &mut c, 6);
+    complex::herk(
+        Uplo::Upper,
+        Trans::ConjTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -7043,7 +6909,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('l', 'c', 4, 6, 0.2, &a, 6, 0.0, &mut c, 6);
+    complex::herk(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -7087,7 +6964,18 @@ fn herk() {
     );
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
-    complex::herk('l', 'c', 4, 6, 0.2, &a, 6, 0.2, &mut c, 6);
+    complex::herk(
+        Uplo::Lower,
+        Trans::ConjTrans,
+        4,
+        6,
+        0.2,
+        &a,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     capproximately!(
         c,
         vec![
@@ -7131,24 +7019,41 @@ fn herk() {
     );
 
     let result = std::panic::catch_unwind(|| {
-        complex::herk('x', 'n', 4, 6, 0.1, &a, 6, 0.3, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::herk('u', 'x', 4, 6, 0.1, &a, 6, 0.3, &mut vec![], 6);
+        complex::herk(
+            Uplo::Upper,
+            Trans::NoTrans,
+            4,
+            6,
+            0.1,
+            &a,
+            3,
+            0.3,
+            &mut vec![],
+            6,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        complex::herk('u', 'n', 4, 6, 0.1, &a, 3, 0.3, &mut vec![], 6);
+        complex::herk(
+            Uplo::Upper,
+            Trans::NoTrans,
+            5,
+            6,
+            0.1,
+            &a,
+            6,
+            0.3,
+            &mut vec![],
+            4,
+        );
     });
     assert!(result.is_err());
 
-    let result = std::panic::catch_unwind(|| {
-        complex::herk('u', 'n', 5, 6, 0.1, &a, 6, 0.3, &mut vec![], 4);
-    });
-    assert!(result.is_err());
+    let err =
+        complex::try_herk(Uplo::Upper, Trans::Trans, 6, 6, 0.1, &a, 6, 0.3, &mut c, 6).unwrap_err();
+    assert_eq!(err.routine(), "herk");
+    assert_eq!(err.param(), 2);
 }
 
 #[test]
@@ -7157,8 +7062,8 @@ fn symm() {
     let b = fixtures::complex::matrix_mxn(6, 6);
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         5,
         Complex::new(0.0, 0.0),
@@ -7174,8 +7079,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         5,
         Complex::new(0.0, 0.0),
@@ -7231,8 +7136,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         5,
         Complex::new(0.0, 0.0),
@@ -7288,8 +7193,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         5,
         Complex::new(0.2, 0.8),
@@ -7345,8 +7250,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'l',
-        'u',
+        Side::Left,
+        Uplo::Upper,
         4,
         5,
         Complex::new(0.2, 0.8),
@@ -7402,8 +7307,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'l',
-        'l',
+        Side::Left,
+        Uplo::Lower,
         4,
         5,
         Complex::new(0.2, 0.8),
@@ -7459,8 +7364,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'l',
-        'l',
+        Side::Left,
+        Uplo::Lower,
         4,
         5,
         Complex::new(0.2, 0.8),
@@ -7516,8 +7421,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'r',
-        'u',
+        Side::Right,
+        Uplo::Upper,
         4,
         5,
         Complex::new(0.2, 0.8),
@@ -7573,8 +7478,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'r',
-        'u',
+        Side::Right,
+        Uplo::Upper,
         4,
         5,
         Complex::new(0.2, 0.8),
@@ -7630,8 +7535,8 @@ fn symm() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::symm(
-        'r',
-        'l',
+        Side::Right,
+        Uplo::Lower,
         4,
         5,
         Complex::new(0.2, 0.8),
@@ -7687,44 +7592,8 @@ fn symm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::symm(
-            'l',
-            'x',
-            4,
-            5,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &b,
-            6,
-            Complex::new(0.3, -0.7),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::symm(
-            'x',
-            'u',
-            4,
-            5,
-            Complex::new(0.2, 0.8),
-            &a,
-            6,
-            &b,
-            6,
-            Complex::new(0.3, -0.7),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::symm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             4,
             5,
             Complex::new(0.2, 0.8),
@@ -7741,8 +7610,8 @@ fn symm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::symm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             4,
             5,
             Complex::new(0.2, 0.8),
@@ -7759,8 +7628,8 @@ fn symm() {
 
     let result = std::panic::catch_unwind(|| {
         complex::symm(
-            'l',
-            'u',
+            Side::Left,
+            Uplo::Upper,
             4,
             5,
             Complex::new(0.2, 0.8),
@@ -7782,8 +7651,8 @@ fn syr2k() {
     let b = fixtures::complex::matrix_mxn(6, 6);
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -7799,8 +7668,8 @@ fn syr2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -7856,8 +7725,8 @@ fn syr2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -7916,8 +7785,8 @@ fn syr2k() {
     let b = mat.clone();
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.2, -0.6),
@@ -7972,8 +7841,8 @@ fn syr2k() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.2, -0.6),
@@ -8029,8 +7898,8 @@ fn syr2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.2, -0.6),
@@ -8086,8 +7955,8 @@ fn syr2k() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'u',
-        't',
+        Uplo::Upper,
+        Trans::Trans,
         4,
         6,
         Complex::new(0.2, -0.6),
@@ -8142,8 +8011,8 @@ fn syr2k() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syr2k(
-        'l',
-        't',
+        Uplo::Lower,
+        Trans::Trans,
         4,
         6,
         Complex::new(0.2, -0.6),
@@ -8199,44 +8068,8 @@ fn syr2k() {
 
     let result = std::panic::catch_unwind(|| {
         complex::syr2k(
-            'x',
-            'n',
-            4,
-            6,
-            Complex::new(0.0, 0.0),
-            &a,
-            6,
-            &b,
-            6,
-            Complex::new(1.0, 0.0),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::syr2k(
-            'u',
-            'x',
-            4,
-            6,
-            Complex::new(0.0, 0.0),
-            &a,
-            6,
-            &b,
-            6,
-            Complex::new(1.0, 0.0),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::syr2k(
-            'u',
-            't',
+            Uplo::Upper,
+            Trans::Trans,
             4,
             6,
             Complex::new(0.0, 0.0),
@@ -8253,8 +8086,8 @@ fn syr2k() {
 
     let result = std::panic::catch_unwind(|| {
         complex::syr2k(
-            'u',
-            't',
+            Uplo::Upper,
+            Trans::Trans,
             4,
             6,
             Complex::new(0.0, 0.0),
@@ -8271,8 +8104,8 @@ fn syr2k() {
 
     let result = std::panic::catch_unwind(|| {
         complex::syr2k(
-            'u',
-            't',
+            Uplo::Upper,
+            Trans::Trans,
             4,
             6,
             Complex::new(0.0, 0.0),
@@ -8293,8 +8126,8 @@ fn syrk() {
     let a = fixtures::complex::matrix_mxn(6, 6);
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -8308,8 +8141,8 @@ fn syrk() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -8363,8 +8196,8 @@ fn syrk() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -8418,8 +8251,8 @@ fn syrk() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.0, 0.0),
@@ -8476,8 +8309,8 @@ fn syrk() {
     let a = mat.clone();
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'u',
-        'n',
+        Uplo::Upper,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -8530,8 +8363,8 @@ fn syrk() {
     );
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -8585,8 +8418,8 @@ fn syrk() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'l',
-        'n',
+        Uplo::Lower,
+        Trans::NoTrans,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -8640,8 +8473,8 @@ fn syrk() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'u',
-        't',
+        Uplo::Upper,
+        Trans::Trans,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -8695,8 +8528,8 @@ fn syrk() {
 
     let mut c = fixtures::complex::matrix_mxn(6, 6);
     complex::syrk(
-        'l',
-        't',
+        Uplo::Lower,
+        Trans::Trans,
         4,
         6,
         Complex::new(0.2, 0.8),
@@ -8750,40 +8583,8 @@ fn syrk() {
 
     let result = std::panic::catch_unwind(|| {
         complex::syrk(
-            'x',
-            'n',
-            4,
-            6,
-            Complex::new(0.0, 0.0),
-            &a,
-            6,
-            Complex::new(1.0, 0.0),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::syrk(
-            'u',
-            'x',
-            4,
-            6,
-            Complex::new(0.0, 0.0),
-            &a,
-            6,
-            Complex::new(1.0, 0.0),
-            &mut vec![],
-            6,
-        );
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        complex::syrk(
-            'u',
-            't',
+            Uplo::Upper,
+            Trans::Trans,
             4,
             6,
             Complex::new(0.0, 0.0),
@@ -8798,8 +8599,8 @@ fn syrk() {
 
     let result = std::panic::catch_unwind(|| {
         complex::syrk(
-            'u',
-            't',
+            Uplo::Upper,
+            Trans::Trans,
             4,
             6,
             Complex::new(0.0, 0.0),
@@ -8812,3 +8613,29 @@ fn syrk() {
     });
     assert!(result.is_err());
 }
+
+#[test]
+fn trsm_conj_trans_upper() {
+    // The strictly lower part isn't referenced.
+    let a = vec![
+        Complex::new(1.0, 1.0),
+        Complex::new(9.0, 9.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(2.0, -1.0),
+    ];
+    let mut b = vec![Complex::new(1.0, -1.0), Complex::new(1.0, 2.0)];
+    complex::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        2,
+        1,
+        Complex::new(1.0, 0.0),
+        &a,
+        2,
+        &mut b,
+        2,
+    );
+    capproximately!(b, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+}
@@ -1,4 +1,5 @@
 use libblas::level3;
+use libblas::{Diag, Side, Trans, Uplo};
 mod fixtures;
 mod utils;
 
@@ -7,19 +8,75 @@ fn gemm() {
     let a = fixtures::matrix_mxn(8, 8);
     let b = fixtures::matrix_mxn(8, 8);
     let mut c = fixtures::matrix_mxn(8, 8);
-    level3::gemm('n', 'n', 6, 0, 4, 0.3, &a, 8, &b, 8, 0.2, &mut c, 8);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        6,
+        0,
+        4,
+        0.3,
+        &a,
+        8,
+        &b,
+        8,
+        0.2,
+        &mut c,
+        8,
+    );
     approximately!(c, fixtures::matrix_mxn(8, 8));
 
     let mut c = fixtures::matrix_mxn(8, 8);
-    level3::gemm('n', 'n', 0, 8, 4, 0.3, &a, 8, &b, 8, 0.2, &mut c, 8);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        0,
+        8,
+        4,
+        0.3,
+        &a,
+        8,
+        &b,
+        8,
+        0.2,
+        &mut c,
+        8,
+    );
     approximately!(c, fixtures::matrix_mxn(8, 8));
 
     let mut c = fixtures::matrix_mxn(8, 8);
-    level3::gemm('n', 'n', 6, 8, 4, 0.0, &a, 8, &b, 8, 1.0, &mut c, 8);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        6,
+        8,
+        4,
+        0.0,
+        &a,
+        8,
+        &b,
+        8,
+        1.0,
+        &mut c,
+        8,
+    );
     approximately!(c, fixtures::matrix_mxn(8, 8));
 
     let mut c = fixtures::matrix_mxn(8, 8);
-    level3::gemm('n', 'c', 6, 8, 4, 0.0, &a, 8, &b, 8, 0.0, &mut c, 8);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::ConjTrans,
+        6,
+        8,
+        4,
+        0.0,
+        &a,
+        8,
+        &b,
+        8,
+        0.0,
+        &mut c,
+        8,
+    );
     approximately!(
         c,
         vec![
@@ -92,7 +149,21 @@ fn gemm() {
     let a = fixtures::matrix_mxn(6, 6);
     let b = fixtures::matrix_mxn(6, 6);
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('n', 'c', 6, 6, 3, 0.0, &a, 6, &b, 6, 0.75, &mut c, 6);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::ConjTrans,
+        6,
+        6,
+        3,
+        0.0,
+        &a,
+        6,
+        &b,
+        6,
+        0.75,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -135,7 +206,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('n', 'n', 4, 6, 3, 0.3, &a, 6, &b, 6, -1.2, &mut c, 6);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        -1.2,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -178,7 +263,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('n', 'n', 4, 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -221,7 +320,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('n', 'n', 4, 6, 3, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -264,7 +377,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('c', 'n', 4, 6, 3, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::gemm(
+        Trans::ConjTrans,
+        Trans::NoTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -307,7 +434,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('c', 'n', 4, 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::gemm(
+        Trans::ConjTrans,
+        Trans::NoTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -350,7 +491,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('n', 'c', 4, 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::ConjTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -394,7 +549,21 @@ fn gemm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('n', 'c', 4, 6, 3, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::ConjTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -438,7 +607,21 @@ fn gemm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('n', 'c', 4, 6, 3, 0.3, &a, 6, &b, 6, 0.5, &mut c, 6);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::ConjTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.5,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -481,7 +664,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('c', 'c', 4, 6, 3, 0.3, &a, 6, &b, 6, 0.5, &mut c, 6);
+    level3::gemm(
+        Trans::ConjTrans,
+        Trans::ConjTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.5,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -524,7 +721,21 @@ fn gemm() {
         ]
     );
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::gemm('c', 'c', 4, 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::gemm(
+        Trans::ConjTrans,
+        Trans::ConjTrans,
+        4,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -568,30 +779,161 @@ fn gemm() {
     );
     let a = vec![];
     let b = vec![];
-    let result = std::panic::catch_unwind(|| {
-        level3::gemm('x', 't', 6, 8, 4, 0.0, &a, 8, &b, 8, 0.2, &mut vec![], 8);
-    });
-    assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::gemm('c', 'x', 6, 8, 4, 0.0, &a, 8, &b, 8, 0.2, &mut vec![], 8);
+        level3::gemm(
+            Trans::NoTrans,
+            Trans::NoTrans,
+            6,
+            8,
+            4,
+            0.0,
+            &a,
+            4,
+            &b,
+            8,
+            0.2,
+            &mut vec![],
+            8,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::gemm('n', 'n', 6, 8, 4, 0.0, &a, 4, &b, 8, 0.2, &mut vec![], 8);
+        level3::gemm(
+            Trans::NoTrans,
+            Trans::NoTrans,
+            6,
+            8,
+            4,
+            0.0,
+            &a,
+            8,
+            &b,
+            3,
+            0.2,
+            &mut vec![],
+            8,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::gemm('n', 'n', 6, 8, 4, 0.0, &a, 8, &b, 3, 0.2, &mut vec![], 8);
+        level3::gemm(
+            Trans::NoTrans,
+            Trans::NoTrans,
+            6,
+            8,
+            4,
+            0.0,
+            &a,
+            8,
+            &b,
+            8,
+            0.2,
+            &mut vec![],
+            5,
+        );
     });
     assert!(result.is_err());
 
-    let result = std::panic::catch_unwind(|| {
-        level3::gemm('n', 'n', 6, 8, 4, 0.0, &a, 8, &b, 8, 0.2, &mut vec![], 5);
-    });
-    assert!(result.is_err());
+    let mut c = vec![];
+    let err = level3::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        6,
+        8,
+        4,
+        0.0,
+        &a,
+        4,
+        &b,
+        8,
+        0.2,
+        &mut c,
+        8,
+    )
+    .unwrap_err();
+    assert_eq!(err.routine(), "gemm");
+    assert_eq!(err.param(), 8);
+    assert_eq!(err.reason(), "lda must be at least max(1, nrowa)");
+
+    let err = level3::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        6,
+        8,
+        4,
+        0.0,
+        &a,
+        8,
+        &b,
+        8,
+        0.2,
+        &mut c,
+        5,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 13);
+
+    let (a, b, mut c) = (vec![1.0; 4], vec![1.0; 4], vec![0.0; 4]);
+    let err = level3::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        4,
+        4,
+        4,
+        1.0,
+        &a,
+        4,
+        &b,
+        4,
+        0.0,
+        &mut c,
+        4,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 7);
+    assert_eq!(
+        err.reason(),
+        "the slice must hold (cols-1)*ld + rows elements"
+    );
+    let a = vec![1.0; 16];
+    let err = level3::try_gemm(
+        Trans::NoTrans,
+        Trans::Trans,
+        4,
+        4,
+        4,
+        1.0,
+        &a,
+        4,
+        &b,
+        4,
+        0.0,
+        &mut c,
+        4,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 9);
+    let b = vec![1.0; 16];
+    let err = level3::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        4,
+        4,
+        4,
+        1.0,
+        &a,
+        4,
+        &b,
+        4,
+        0.0,
+        &mut c,
+        4,
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), 12);
 }
 
 #[test]
@@ -599,19 +941,71 @@ fn symm() {
     let a = fixtures::matrix_mxn(6, 6);
     let b = fixtures::matrix_mxn(6, 6);
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'u', 6, 0, 0.3, &a, 6, &b, 6, 0.2, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        6,
+        0,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     approximately!(c, a);
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'u', 0, 0, 0.3, &a, 6, &b, 6, 0.2, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        0,
+        0,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     approximately!(c, b);
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'u', 6, 4, 0.0, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        6,
+        4,
+        0.0,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(c, a);
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'u', 6, 4, 0.0, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        6,
+        4,
+        0.0,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -655,7 +1049,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'u', 4, 6, 0.0, &a, 6, &b, 6, 0.2, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        4,
+        6,
+        0.0,
+        &a,
+        6,
+        &b,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -699,7 +1106,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'u', 4, 6, 0.3, &a, 6, &b, 6, 0.2, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        4,
+        6,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -743,7 +1163,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'u', 4, 6, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        4,
+        6,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -787,7 +1220,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'l', 4, 6, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Lower,
+        4,
+        6,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -831,7 +1277,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('l', 'l', 4, 6, 0.3, &a, 6, &b, 6, 0.2, &mut c, 6);
+    level3::symm(
+        Side::Left,
+        Uplo::Lower,
+        4,
+        6,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -875,7 +1334,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('r', 'u', 4, 6, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::symm(
+        Side::Right,
+        Uplo::Upper,
+        4,
+        6,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -919,7 +1391,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('r', 'u', 4, 6, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::symm(
+        Side::Right,
+        Uplo::Upper,
+        4,
+        6,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -963,7 +1448,20 @@ fn symm() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::symm('r', 'l', 4, 6, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::symm(
+        Side::Right,
+        Uplo::Lower,
+        4,
+        6,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1008,28 +1506,58 @@ fn symm() {
 
     let a = vec![];
     let b = vec![];
-    let result = std::panic::catch_unwind(|| {
-        level3::symm('x', 'u', 6, 6, 0.3, &a, 6, &b, 6, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::symm('l', 'x', 0, 8, 0.3, &a, 6, &b, 6, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::symm('l', 'u', 6, 4, 0.3, &a, 5, &b, 6, 0.2, &mut vec![], 6);
+        level3::symm(
+            Side::Left,
+            Uplo::Upper,
+            6,
+            4,
+            0.3,
+            &a,
+            5,
+            &b,
+            6,
+            0.2,
+            &mut vec![],
+            6,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::symm('l', 'u', 3, 4, 0.3, &a, 6, &b, 2, 0.2, &mut vec![], 6);
+        level3::symm(
+            Side::Left,
+            Uplo::Upper,
+            3,
+            4,
+            0.3,
+            &a,
+            6,
+            &b,
+            2,
+            0.2,
+            &mut vec![],
+            6,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::symm('l', 'u', 3, 4, 0.3, &a, 6, &b, 6, 0.2, &mut vec![], 2);
+        level3::symm(
+            Side::Left,
+            Uplo::Upper,
+            3,
+            4,
+            0.3,
+            &a,
+            6,
+            &b,
+            6,
+            0.2,
+            &mut vec![],
+            2,
+        );
     });
     assert!(result.is_err());
 }
@@ -1039,15 +1567,54 @@ fn syr2k() {
     let a = fixtures::matrix_mxn(6, 6);
     let b = fixtures::matrix_mxn(6, 6);
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 't', 0, 0, 0.2, &a, 6, &b, 6, 0.3, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::Trans,
+        0,
+        0,
+        0.2,
+        &a,
+        6,
+        &b,
+        6,
+        0.3,
+        &mut c,
+        6,
+    );
     approximately!(c, a);
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 't', 6, 0, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::Trans,
+        6,
+        0,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(c, b);
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 'n', 6, 3, 0.0, &a, 6, &b, 6, 0.25, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::NoTrans,
+        6,
+        3,
+        0.0,
+        &a,
+        6,
+        &b,
+        6,
+        0.25,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1091,7 +1658,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 'n', 6, 3, 0.0, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::NoTrans,
+        6,
+        3,
+        0.0,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1135,7 +1715,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 'n', 6, 3, 0.0, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::NoTrans,
+        6,
+        3,
+        0.0,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1182,7 +1775,20 @@ fn syr2k() {
     let a = mat.clone();
     let b = mat.clone();
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 'n', 6, 3, 0.3, &a, 6, &b, 6, -0.5, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::NoTrans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        -0.5,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1226,7 +1832,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 'n', 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::NoTrans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1270,7 +1889,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 'n', 6, 3, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::NoTrans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1314,7 +1946,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 'n', 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::NoTrans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1359,7 +2004,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 'n', 6, 3, 0.3, &a, 6, &b, 6, 0.5, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::NoTrans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.5,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1404,7 +2062,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 'n', 6, 3, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::NoTrans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1449,7 +2120,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 't', 6, 3, 0.3, &a, 6, &b, 6, 1.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::Trans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1493,7 +2177,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 't', 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::Trans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1537,7 +2234,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('u', 't', 6, 3, 0.3, &a, 6, &b, 6, 0.4, &mut c, 6);
+    level3::syr2k(
+        Uplo::Upper,
+        Trans::Trans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.4,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1581,7 +2291,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 't', 6, 3, 0.3, &a, 6, &b, 6, 0.0, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::Trans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1625,7 +2348,20 @@ fn syr2k() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syr2k('l', 't', 6, 3, 0.3, &a, 6, &b, 6, 0.2, &mut c, 6);
+    level3::syr2k(
+        Uplo::Lower,
+        Trans::Trans,
+        6,
+        3,
+        0.3,
+        &a,
+        6,
+        &b,
+        6,
+        0.2,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1667,48 +2403,35 @@ fn syr2k() {
             0.55320822917806267,
         ]
     );
-
-    let a = vec![];
-    let b = vec![];
-    let result = std::panic::catch_unwind(|| {
-        level3::syr2k('x', 'u', 6, 3, 0.3, &a, 6, &b, 6, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::syr2k('l', 'x', 6, 3, 0.3, &a, 6, &b, 6, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::syr2k('t', 'u', 6, 3, 0.3, &a, 2, &b, 6, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::syr2k('t', 'u', 6, 3, 0.3, &a, 6, &b, 2, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::syr2k('t', 'u', 6, 3, 0.3, &a, 6, &b, 6, 0.2, &mut vec![], 5);
-    });
-    assert!(result.is_err());
 }
 
+#[test]
+fn syr2k_distinct_operands() {
+    let a = vec![1.0, 2.0, 3.0, 4.0];
+    let b = vec![5.0, 6.0, 7.0, 8.0];
+    for &uplo in &[Uplo::Upper, Uplo::Lower] {
+        let mut c = vec![-1.0; 4];
+        level3::syr2k(uplo, Trans::Trans, 2, 2, 1.0, &a, 2, &b, 2, 0.0, &mut c, 2);
+        if uplo == Uplo::Upper {
+            approximately!(c, vec![34.0, -1.0, 62.0, 106.0]);
+        } else {
+            approximately!(c, vec![34.0, 62.0, -1.0, 106.0]);
+        }
+    }
+}
 #[test]
 fn syrk() {
     let a = fixtures::matrix_mxn(6, 6);
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 't', 0, 3, 0.2, &a, 6, 1.0, &mut c, 6);
+    level3::syrk(Uplo::Lower, Trans::Trans, 0, 3, 0.2, &a, 6, 1.0, &mut c, 6);
     approximately!(c, a);
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 't', 5, 0, 0.2, &a, 6, 1.0, &mut c, 6);
+    level3::syrk(Uplo::Lower, Trans::Trans, 5, 0, 0.2, &a, 6, 1.0, &mut c, 6);
     approximately!(c, a);
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 't', 5, 3, 0.0, &a, 6, 0.25, &mut c, 6);
+    level3::syrk(Uplo::Lower, Trans::Trans, 5, 3, 0.0, &a, 6, 0.25, &mut c, 6);
     approximately!(
         c,
         vec![
@@ -1752,7 +2475,7 @@ fn syrk() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('u', 't', 5, 3, 0.0, &a, 6, 0.0, &mut c, 6);
+    level3::syrk(Uplo::Upper, Trans::Trans, 5, 3, 0.0, &a, 6, 0.0, &mut c, 6);
     approximately!(
         c,
         vec![
@@ -1800,7 +2523,18 @@ fn syrk() {
     let a = mat;
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('u', 'n', 5, 3, 0.5, &a, 6, 0.0, &mut c, 6);
+    level3::syrk(
+        Uplo::Upper,
+        Trans::NoTrans,
+        5,
+        3,
+        0.5,
+        &a,
+        6,
+        0.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1844,7 +2578,18 @@ fn syrk() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 'n', 5, 3, 0.5, &a, 6, 0.1, &mut c, 6);
+    level3::syrk(
+        Uplo::Lower,
+        Trans::NoTrans,
+        5,
+        3,
+        0.5,
+        &a,
+        6,
+        0.1,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1888,7 +2633,18 @@ fn syrk() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 'n', 5, 3, 0.5, &a, 6, 1.0, &mut c, 6);
+    level3::syrk(
+        Uplo::Lower,
+        Trans::NoTrans,
+        5,
+        3,
+        0.5,
+        &a,
+        6,
+        1.0,
+        &mut c,
+        6,
+    );
     approximately!(
         c,
         vec![
@@ -1932,7 +2688,7 @@ fn syrk() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('u', 't', 5, 3, 0.5, &a, 6, 1.0, &mut c, 6);
+    level3::syrk(Uplo::Upper, Trans::Trans, 5, 3, 0.5, &a, 6, 1.0, &mut c, 6);
     approximately!(
         c,
         vec![
@@ -1976,7 +2732,7 @@ fn syrk() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 't', 5, 3, 0.5, &a, 6, 1.0, &mut c, 6);
+    level3::syrk(Uplo::Lower, Trans::Trans, 5, 3, 0.5, &a, 6, 1.0, &mut c, 6);
     approximately!(
         c,
         vec![
@@ -2020,7 +2776,7 @@ fn syrk() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 't', 5, 3, 0.5, &a, 6, -0.3, &mut c, 6);
+    level3::syrk(Uplo::Lower, Trans::Trans, 5, 3, 0.5, &a, 6, -0.3, &mut c, 6);
     approximately!(
         c,
         vec![
@@ -2064,7 +2820,7 @@ fn syrk() {
     );
 
     let mut c = fixtures::matrix_mxn(6, 6);
-    level3::syrk('l', 't', 5, 3, 0.5, &a, 6, 0., &mut c, 6);
+    level3::syrk(Uplo::Lower, Trans::Trans, 5, 3, 0.5, &a, 6, 0., &mut c, 6);
     approximately!(
         c,
         vec![
@@ -2106,42 +2862,57 @@ fn syrk() {
             1.1519117355346680,
         ]
     );
-
-    let a = vec![];
-    let result = std::panic::catch_unwind(|| {
-        level3::syrk('x', 't', 6, 3, 0.0, &a, 6, 0.0, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::syrk('l', 'x', 6, 3, 0.3, &a, 6, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::syrk('t', 'u', 6, 3, 0.3, &a, 2, 0.2, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::syrk('t', 'u', 6, 3, 0.3, &a, 6, 0.2, &mut vec![], 5);
-    });
-    assert!(result.is_err());
 }
 
 #[test]
 fn trmm() {
     let a = fixtures::matrix_mxn(6, 6);
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('l', 'u', 'n', 'u', 3, 0, 0.25, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        3,
+        0,
+        0.25,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(b, a);
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('l', 'u', 'n', 'u', 0, 4, 0.25, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        0,
+        4,
+        0.25,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(b, a);
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('l', 'u', 'n', 'u', 4, 5, 0.0, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        4,
+        5,
+        0.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2223,7 +2994,19 @@ fn trmm() {
         1.1519117540872,
     ];
     b = data2.clone();
-    level3::trmm('l', 'u', 'n', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2267,7 +3050,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('l', 'u', 'n', 'u', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2311,7 +3106,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('l', 'l', 'n', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2355,7 +3162,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('l', 'l', 'n', 'u', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2399,7 +3218,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('l', 'u', 't', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2443,7 +3274,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('l', 'u', 't', 'u', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2487,7 +3330,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('l', 'l', 't', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2531,7 +3386,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('l', 'l', 't', 'u', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2575,7 +3442,19 @@ fn trmm() {
     );
 
     b = data2.clone();
-    level3::trmm('r', 'u', 'n', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2658,7 +3537,19 @@ fn trmm() {
     ];
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('r', 'u', 'n', 'u', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2740,7 +3631,19 @@ fn trmm() {
         1.1519117540872,
     ];
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('r', 'l', 'n', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2784,7 +3687,19 @@ fn trmm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('r', 'l', 'n', 'u', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2866,7 +3781,19 @@ fn trmm() {
         1.1519117540872,
     ];
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('r', 'u', 't', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2910,7 +3837,19 @@ fn trmm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('r', 'u', 't', 'u', 4, 5, 1.0, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        4,
+        5,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -2993,7 +3932,19 @@ fn trmm() {
     ];
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('r', 'l', 't', 'n', 4, 5, 0.2, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        4,
+        5,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3037,7 +3988,19 @@ fn trmm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trmm('r', 'l', 't', 'u', 4, 5, 1.0, &a, 6, &mut b, 6);
+    level3::trmm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        4,
+        5,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3081,32 +4044,38 @@ fn trmm() {
     );
 
     let a = vec![];
-    let result = std::panic::catch_unwind(|| {
-        level3::trmm('x', 'u', 'n', 'u', 3, 5, 0.25, &a, 6, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::trmm('l', 'x', 'n', 'u', 3, 5, 0.25, &a, 6, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::trmm('l', 'u', 'x', 'u', 3, 5, 0.25, &a, 6, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-    let result = std::panic::catch_unwind(|| {
-        level3::trmm('l', 'u', 'n', 'x', 3, 5, 0.25, &a, 6, &mut vec![], 6);
-    });
-    assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::trmm('l', 'u', 'n', 'u', 3, 5, 0.25, &a, 2, &mut vec![], 6);
+        level3::trmm(
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
+            3,
+            5,
+            0.25,
+            &a,
+            2,
+            &mut vec![],
+            6,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::trmm('l', 'u', 'n', 'u', 4, 5, 0.25, &a, 6, &mut vec![], 3);
+        level3::trmm(
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
+            4,
+            5,
+            0.25,
+            &a,
+            6,
+            &mut vec![],
+            3,
+        );
     });
     assert!(result.is_err());
 }
@@ -3115,15 +4084,51 @@ fn trmm() {
 fn trsm() {
     let a = fixtures::matrix_mxn(6, 6);
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('l', 'l', 'n', 'n', 3, 0, 0.2, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        3,
+        0,
+        0.2,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(b, a);
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('l', 'u', 'n', 'u', 0, 4, 0.25, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        0,
+        4,
+        0.25,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(b, a);
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('l', 'u', 'n', 'u', 5, 4, 0.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        5,
+        4,
+        0.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3167,7 +4172,19 @@ fn trsm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('l', 'u', 'n', 'n', 5, 4, -0.3, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        5,
+        4,
+        -0.3,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3248,7 +4265,19 @@ fn trsm() {
         0.726750747385451,
         1.1519117540872,
     ];
-    level3::trsm('l', 'u', 'n', 'u', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3329,7 +4358,19 @@ fn trsm() {
         0.726750747385451,
         1.1519117540872,
     ];
-    level3::trsm('l', 'l', 'n', 'n', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3410,7 +4451,19 @@ fn trsm() {
         0.726750747385451,
         1.1519117540872,
     ];
-    level3::trsm('l', 'l', 'n', 'u', 5, 4, 0.4, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        5,
+        4,
+        0.4,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3454,7 +4507,19 @@ fn trsm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('l', 'u', 't', 'n', 5, 4, 0.4, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        5,
+        4,
+        0.4,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3535,7 +4600,19 @@ fn trsm() {
         0.726750747385451,
         1.1519117540872,
     ];
-    level3::trsm('l', 'u', 't', 'u', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3616,7 +4693,19 @@ fn trsm() {
         0.726750747385451,
         1.1519117540872,
     ];
-    level3::trsm('l', 'l', 't', 'n', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3698,7 +4787,19 @@ fn trsm() {
         0.726750747385451,
         1.1519117540872,
     ];
-    level3::trsm('l', 'l', 't', 'u', 5, 4, 0.4, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        5,
+        4,
+        0.4,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3742,7 +4843,19 @@ fn trsm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'u', 'n', 'n', 5, 4, 0.4, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        5,
+        4,
+        0.4,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3824,7 +4937,19 @@ fn trsm() {
     ];
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'u', 'n', 'u', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3868,7 +4993,19 @@ fn trsm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'l', 'n', 'n', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -3951,7 +5088,19 @@ fn trsm() {
     ];
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'l', 'n', 'u', 5, 4, 0.4, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        5,
+        4,
+        0.4,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -4034,7 +5183,19 @@ fn trsm() {
     ];
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'u', 't', 'n', 5, 4, 0.4, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        5,
+        4,
+        0.4,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -4078,7 +5239,19 @@ fn trsm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'u', 't', 'u', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::Unit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -4160,7 +5333,19 @@ fn trsm() {
         1.1519117540872,
     ];
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'l', 't', 'n', 5, 4, 1.0, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::NonUnit,
+        5,
+        4,
+        1.0,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -4205,7 +5390,19 @@ fn trsm() {
     );
 
     let mut b = fixtures::matrix_mxn(6, 6);
-    level3::trsm('r', 'l', 't', 'u', 5, 4, 0.4, &a, 6, &mut b, 6);
+    level3::trsm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::Trans,
+        Diag::Unit,
+        5,
+        4,
+        0.4,
+        &a,
+        6,
+        &mut b,
+        6,
+    );
     approximately!(
         b,
         vec![
@@ -4250,32 +5447,60 @@ fn trsm() {
     );
 
     let a = vec![];
-    let result = std::panic::catch_unwind(|| {
-        level3::trsm('x', 'u', 'n', 'u', 3, 5, 0.25, &a, 6, &mut vec![], 6);
-    });
-    assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::trsm('l', 'x', 'n', 'u', 3, 5, 0.25, &a, 6, &mut vec![], 6);
+        level3::trsm(
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
+            3,
+            5,
+            0.25,
+            &a,
+            2,
+            &mut vec![],
+            6,
+        );
     });
     assert!(result.is_err());
 
     let result = std::panic::catch_unwind(|| {
-        level3::trsm('l', 'u', 'x', 'u', 3, 5, 0.25, &a, 6, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-    let result = std::panic::catch_unwind(|| {
-        level3::trsm('l', 'u', 'n', 'x', 3, 5, 0.25, &a, 6, &mut vec![], 6);
-    });
-    assert!(result.is_err());
-
-    let result = std::panic::catch_unwind(|| {
-        level3::trsm('l', 'u', 'n', 'u', 3, 5, 0.25, &a, 2, &mut vec![], 6);
+        level3::trsm(
+            Side::Left,
+            Uplo::Upper,
+            Trans::NoTrans,
+            Diag::Unit,
+            4,
+            5,
+            0.25,
+            &a,
+            6,
+            &mut vec![],
+            3,
+        );
     });
     assert!(result.is_err());
+}
 
-    let result = std::panic::catch_unwind(|| {
-        level3::trsm('l', 'u', 'n', 'u', 4, 5, 0.25, &a, 6, &mut vec![], 3);
-    });
-    assert!(result.is_err());
+#[test]
+fn symm_lda_differs_from_ldc() {
+    let a = vec![2.0, 99.0, 99.0, 3.0, 5.0, 99.0];
+    let b = vec![1.0, 2.0];
+    let mut c = vec![0.0, 0.0];
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        2,
+        1,
+        1.0,
+        &a,
+        3,
+        &b,
+        2,
+        0.0,
+        &mut c,
+        2,
+    );
+    approximately!(c, vec![8.0, 13.0]);
 }
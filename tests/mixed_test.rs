@@ -0,0 +1,198 @@
+use libblas::{level2, level3, mixed, Trans};
+mod utils;
+
+fn widen(x: &[f32]) -> Vec<f64> {
+    x.iter().map(|&v| f64::from(v)).collect()
+}
+
+#[test]
+fn gemm_conforms_to_f64() {
+    let mut rng = utils::Random::new(1);
+    let trans = [Trans::NoTrans, Trans::Trans];
+    // The second size takes the blocked algorithm.
+    for &(m, n, k) in &[(6, 7, 5), (37, 40, 35)] {
+        for &trans_a in &trans {
+            for &trans_b in &trans {
+                for &(alpha, beta) in &[(1.0, 0.0), (-0.3, 1.7), (0.0, 0.25)] {
+                    let (lda, ldb, ldc) = (m.max(k) + 2, n.max(k) + 1, m + 1);
+                    let a = rng.singles(lda * m.max(k));
+                    let b = rng.singles(ldb * n.max(k));
+                    let c = rng.singles(ldc * n);
+                    let mut expected = widen(&c);
+                    level3::gemm(
+                        trans_a,
+                        trans_b,
+                        m,
+                        n,
+                        k,
+                        alpha,
+                        &widen(&a),
+                        lda,
+                        &widen(&b),
+                        ldb,
+                        beta,
+                        &mut expected,
+                        ldc,
+                    );
+
+                    let mut wide = widen(&c);
+                    mixed::gemm(
+                        trans_a, trans_b, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut wide, ldc,
+                    );
+                    assert_eq!(wide, expected);
+
+                    let mut narrow = c.clone();
+                    mixed::gemm(
+                        trans_a,
+                        trans_b,
+                        m,
+                        n,
+                        k,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut narrow,
+                        ldc,
+                    );
+                    let rounded: Vec<f32> = expected.iter().map(|&v| v as f32).collect();
+                    assert_eq!(narrow, rounded);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gemm_widens_panels_with_leading_dimensions() {
+    let mut rng = utils::Random::new(3);
+    let trans = [Trans::NoTrans, Trans::Trans];
+    // n and k span more than one widened panel, and every leading dimension is padded.
+    let (m, n, k) = (9, 300, 270);
+    for &trans_a in &trans {
+        for &trans_b in &trans {
+            for &(alpha, beta) in &[(1.0, 0.0), (-0.3, 1.7)] {
+                let (lda, ldb, ldc) = (m.max(k) + 3, n.max(k) + 5, m + 4);
+                let a = rng.singles(lda * m.max(k));
+                let b = rng.singles(ldb * n.max(k));
+                let c = rng.singles(ldc * n);
+                let mut expected = widen(&c);
+                level3::gemm(
+                    trans_a,
+                    trans_b,
+                    m,
+                    n,
+                    k,
+                    alpha,
+                    &widen(&a),
+                    lda,
+                    &widen(&b),
+                    ldb,
+                    beta,
+                    &mut expected,
+                    ldc,
+                );
+
+                let mut wide = widen(&c);
+                mixed::gemm(
+                    trans_a, trans_b, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut wide, ldc,
+                );
+                approximately!(wide, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn gemv_conforms_to_f64() {
+    let mut rng = utils::Random::new(2);
+    let (m, n, lda) = (8, 5, 10);
+    for &trans in &[Trans::NoTrans, Trans::Trans] {
+        for &(incx, incy) in &[(1, 1), (-2, 3), (3, -1)] {
+            for &(alpha, beta) in &[(1.0, 0.0), (0.6, -1.1)] {
+                let a = rng.singles(lda * n);
+                let x = widen(&rng.singles(m.max(n) * 3));
+                let y = widen(&rng.singles(m.max(n) * 3));
+                let mut expected = y.clone();
+                level2::gemv(
+                    trans,
+                    m,
+                    n,
+                    alpha,
+                    &widen(&a),
+                    lda,
+                    &x,
+                    incx,
+                    beta,
+                    &mut expected,
+                    incy,
+                );
+                let mut got = y.clone();
+                mixed::gemv(trans, m, n, alpha, &a, lda, &x, incx, beta, &mut got, incy);
+                assert_eq!(got, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn residual_keeps_the_low_bits() {
+    // r := b - A**T*x, where the f32 sum 1 + 2^-30 - 1 cancels to zero.
+    let tiny = (-30f32).exp2();
+    let a = vec![1.0f32, 1.0, -1.0];
+    let x = vec![1.0f32, tiny, 1.0];
+
+    let mut r = vec![0.0f64];
+    mixed::gemv(Trans::Trans, 3, 1, -1.0, &a, 3, &x, 1, 1.0, &mut r, 1);
+    assert_eq!(r, vec![-f64::from(tiny)]);
+
+    let mut r32 = vec![0.0f32];
+    level2::gemv(Trans::Trans, 3, 1, -1.0, &a, 3, &x, 1, 1.0, &mut r32, 1);
+    assert_eq!(r32, vec![0.0]);
+
+    let mut r = vec![0.0f64];
+    mixed::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        1,
+        1,
+        3,
+        -1.0,
+        &a,
+        3,
+        &x,
+        3,
+        1.0,
+        &mut r,
+        1,
+    );
+    assert_eq!(r, vec![-f64::from(tiny)]);
+}
+
+#[test]
+fn illegal_arguments() {
+    let a = vec![1.0f32; 4];
+    let mut c = vec![0.0f64; 4];
+    let err = mixed::try_gemm(
+        Trans::NoTrans,
+        Trans::Trans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &a,
+        1,
+        0.0,
+        &mut c,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm", 10));
+
+    let err = mixed::try_gemv(Trans::NoTrans, 2, 2, 1.0, &a, 2, &a, 1, 0.0, &mut c, 0).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemv", 11));
+}
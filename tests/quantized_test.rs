@@ -0,0 +1,234 @@
+use libblas::level3::quantized;
+use libblas::{Side, Trans};
+use rand::Rng;
+mod utils;
+
+// Element (i, j) of op( X ) for a column-major X with leading dimension ld.
+fn at<T: Copy>(x: &[T], ld: usize, trans: Trans, i: usize, j: usize) -> T {
+    if trans == Trans::NoTrans {
+        x[j * ld + i]
+    } else {
+        x[i * ld + j]
+    }
+}
+
+#[test]
+fn gemm() {
+    let mut rng = utils::Random::new(1);
+    let trans = [Trans::NoTrans, Trans::Trans, Trans::ConjTrans];
+    // The second size takes the blocked algorithm.
+    for &(m, n, k) in &[(5, 4, 7), (40, 36, 33)] {
+        for &trans_a in &trans {
+            for &trans_b in &trans {
+                for &beta in &[0, 1, -2] {
+                    let (lda, ldb, ldc) = (m.max(k) + 1, n.max(k) + 3, m + 2);
+                    let a: Vec<i8> = (0..lda * m.max(k)).map(|_| rng.gen()).collect();
+                    let b: Vec<u8> = (0..ldb * n.max(k)).map(|_| rng.gen()).collect();
+                    let mut c: Vec<i32> =
+                        (0..ldc * n).map(|_| rng.gen_range(-1000, 1000)).collect();
+                    let (a_zero, b_zero) = (rng.gen::<i8>(), rng.gen::<u8>());
+
+                    let mut expected = c.clone();
+                    for j in 0..n {
+                        for i in 0..m {
+                            let mut sum = 0;
+                            for l in 0..k {
+                                let x = i32::from(at(&a, lda, trans_a, i, l)) - i32::from(a_zero);
+                                let y = i32::from(at(&b, ldb, trans_b, l, j)) - i32::from(b_zero);
+                                sum += x * y;
+                            }
+                            expected[j * ldc + i] = sum + beta * c[j * ldc + i];
+                        }
+                    }
+
+                    quantized::gemm(
+                        trans_a, trans_b, m, n, k, &a, lda, a_zero, &b, ldb, b_zero, beta, &mut c,
+                        ldc,
+                    );
+                    assert_eq!(c, expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gemm_extremes() {
+    // (-128 - 127) * (255 - 0) is the largest product, k = 33025 of them still fit in an i32.
+    let k = 33025;
+    let a = vec![-128i8; k];
+    let b = vec![255u8; k];
+    let mut c = vec![0];
+    quantized::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        1,
+        1,
+        k,
+        &a,
+        k,
+        127,
+        &b,
+        k,
+        0,
+        0,
+        &mut c,
+        1,
+    );
+    assert_eq!(c, vec![-255 * 255 * k as i32]);
+
+    // One more product could overflow, so k is rejected.
+    let k = quantized::MAX_K + 1;
+    let a = vec![-128i8; k];
+    let b = vec![255u8; k];
+    let err = quantized::try_gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        1,
+        1,
+        k,
+        &a,
+        k,
+        127,
+        &b,
+        k,
+        0,
+        0,
+        &mut c,
+        1,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("quantized::gemm", 5));
+}
+
+#[test]
+fn gemm_saturates_beta_c() {
+    // 2*C saturates at the bounds of an i32.
+    let a = vec![1i8, 1];
+    let b = vec![1u8, 1];
+    let mut c = vec![i32::MAX, i32::MIN];
+    quantized::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        1,
+        1,
+        &a,
+        2,
+        -100,
+        &b,
+        1,
+        0,
+        2,
+        &mut c,
+        2,
+    );
+    assert_eq!(c, vec![i32::MAX, i32::MIN]);
+
+    // 2*C overflows an i32 on its own, but the product brings the first element back in range.
+    let mut c = vec![i32::MAX / 2 + 1, -1];
+    quantized::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        1,
+        1,
+        &a,
+        2,
+        101,
+        &b,
+        1,
+        0,
+        2,
+        &mut c,
+        2,
+    );
+    assert_eq!(c, vec![i32::MAX - 99, -102]);
+}
+
+#[test]
+fn gemm_zero_k_scales_c() {
+    let mut c = vec![3, -4];
+    quantized::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        1,
+        0,
+        &[],
+        2,
+        0,
+        &[],
+        1,
+        0,
+        -2,
+        &mut c,
+        2,
+    );
+    assert_eq!(c, vec![-6, 8]);
+}
+
+#[test]
+fn requantize() {
+    // Two rows with their own scales, stored with a leading dimension of three.
+    let c = vec![10, -10, 99, 5, 7, 99, 1000, -1000, 99];
+    let mut q = vec![0i8; 6];
+    quantized::requantize(Side::Left, 2, 3, &c, 3, &[0.25, 0.5], -3, &mut q, 2);
+    // 2.5 and -5 and 1.25 and 3.5 rounded away from zero, then clamped.
+    assert_eq!(q, vec![3 - 3, -5 - 3, 1 - 3, 4 - 3, 127, -128]);
+
+    // The same matrix with one scale per column, as when B holds the weights.
+    let mut q = vec![0i8; 6];
+    quantized::requantize(Side::Right, 2, 3, &c, 3, &[0.25, 0.5, -0.5], 1, &mut q, 2);
+    // 2.5 and -2.5 and 2.5 and 3.5 rounded away from zero, then -500 and 500 clamped.
+    assert_eq!(q, vec![3 + 1, -3 + 1, 3 + 1, 4 + 1, -128, 127]);
+}
+
+#[test]
+fn illegal_arguments() {
+    let mut c = vec![0; 4];
+    let err = quantized::try_gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        2,
+        2,
+        3,
+        &[0; 6],
+        2,
+        0,
+        &[0; 6],
+        3,
+        0,
+        0,
+        &mut c,
+        2,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("quantized::gemm", 7));
+
+    let err = quantized::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        &[0; 4],
+        2,
+        0,
+        &[0; 4],
+        2,
+        0,
+        0,
+        &mut c,
+        1,
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("quantized::gemm", 14));
+
+    let mut q = vec![0; 4];
+    let err = quantized::try_requantize(Side::Left, 2, 2, &c, 2, &[1.0], 0, &mut q, 2).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("quantized::requantize", 6));
+    let err = quantized::try_requantize(Side::Right, 2, 3, &[0; 6], 2, &[1.0, 1.0], 0, &mut q, 2)
+        .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("quantized::requantize", 6));
+}
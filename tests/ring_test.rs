@@ -0,0 +1,448 @@
+use libblas::{level1, level2, level3};
+use libblas::{Diag, Ring, Side, Trans, Uplo};
+use num_complex::Complex;
+use num_traits::{One, Zero};
+use std::ops::{Add, Mul};
+
+/// A forward-mode dual number v + d*e, where e*e = 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Dual {
+    v: f64,
+    d: f64,
+}
+
+impl Dual {
+    fn new(v: f64, d: f64) -> Self {
+        Dual { v, d }
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+    fn add(self, rhs: Dual) -> Dual {
+        Dual::new(self.v + rhs.v, self.d + rhs.d)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual::new(self.v * rhs.v, self.v * rhs.d + self.d * rhs.v)
+    }
+}
+
+impl Zero for Dual {
+    fn zero() -> Self {
+        Dual::new(0.0, 0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.v == 0.0 && self.d == 0.0
+    }
+}
+
+impl One for Dual {
+    fn one() -> Self {
+        Dual::new(1.0, 0.0)
+    }
+}
+
+impl Ring for Dual {}
+
+/// C := A*B computed with the textbook loop, column-major.
+fn naive_gemm(m: usize, n: usize, k: usize, a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut c = vec![0; m * n];
+    for j in 0..n {
+        for l in 0..k {
+            for i in 0..m {
+                c[j * m + i] += a[l * m + i] * b[j * k + l];
+            }
+        }
+    }
+    c
+}
+
+#[test]
+fn level1() {
+    let x = vec![1, -2, 3, 4];
+    let mut y = vec![5, 6, 7, 8];
+    level1::axpy(4, 3, &x, 1, &mut y, 1);
+    assert_eq!(y, vec![8, 0, 16, 20]);
+    level1::axpy(2, 3, &x, -2, &mut y, 2);
+    assert_eq!(y, vec![17, 0, 19, 20]);
+
+    assert_eq!(level1::dot(4, &x, 1, &y, 1), 17 + 57 + 80);
+    assert_eq!(level1::dot(2, &x, 2, &y, -2), 19 + 51);
+
+    let mut z = vec![0i32; 4];
+    level1::copy(4, &x, 1, &mut z, -1);
+    assert_eq!(z, vec![4, 3, -2, 1]);
+    level1::scal(4, -2, &mut z, 1);
+    assert_eq!(z, vec![-8, -6, 4, -2]);
+    let mut w = x.clone();
+    level1::swap(4, &mut w, 1, &mut z, 1);
+    assert_eq!((w, z), (vec![-8, -6, 4, -2], x));
+}
+
+#[test]
+fn level2() {
+    // 2 by 3 matrix [1 3 5; 2 4 6].
+    let a = vec![1, 2, 3, 4, 5, 6];
+    let mut y = vec![1, 1];
+    level2::gemv(Trans::NoTrans, 2, 3, 2, &a, 2, &[1, 0, -1], 1, 3, &mut y, 1);
+    assert_eq!(y, vec![2 * (1 - 5) + 3, 2 * (2 - 6) + 3]);
+    let mut y = vec![0, 0, 0];
+    level2::gemv(Trans::Trans, 2, 3, 1, &a, 2, &[1, 1], 1, 0, &mut y, 1);
+    assert_eq!(y, vec![3, 7, 11]);
+
+    let mut a = vec![0u64; 6];
+    level2::ger(2, 3, 2, &[1, 2], 1, &[1, 10, 100], 1, &mut a, 2);
+    assert_eq!(a, vec![2, 4, 20, 40, 200, 400]);
+}
+
+#[test]
+fn level3() {
+    // Large enough for the blocked algorithm.
+    let (m, n, k) = (40, 36, 33);
+    let a: Vec<i64> = (0..m * k).map(|v| v as i64 % 7 - 3).collect();
+    let b: Vec<i64> = (0..k * n).map(|v| v as i64 % 5 - 2).collect();
+    let mut c = vec![1; m * n];
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        m,
+        n,
+        k,
+        2,
+        &a,
+        m,
+        &b,
+        k,
+        -1,
+        &mut c,
+        m,
+    );
+    let expected: Vec<i64> = naive_gemm(m, n, k, &a, &b)
+        .iter()
+        .map(|v| 2 * v - 1)
+        .collect();
+    assert_eq!(c, expected);
+
+    // Symmetric [2 3; 3 5], only the upper triangle is referenced.
+    let s = vec![2, -99, 3, 5];
+    let mut c = vec![0, 0];
+    level3::symm(
+        Side::Left,
+        Uplo::Upper,
+        2,
+        1,
+        1,
+        &s,
+        2,
+        &[1, 2],
+        2,
+        0,
+        &mut c,
+        2,
+    );
+    assert_eq!(c, vec![8, 13]);
+
+    let mut c = vec![0, -99, 0, 0];
+    level3::syrk(Uplo::Upper, Trans::NoTrans, 2, 2, 1, &a, 2, 0, &mut c, 2);
+    let (a00, a10, a01, a11) = (a[0], a[1], a[2], a[3]);
+    assert_eq!(
+        c,
+        vec![
+            a00 * a00 + a01 * a01,
+            -99,
+            a00 * a10 + a01 * a11,
+            a10 * a10 + a11 * a11
+        ]
+    );
+
+    // Upper unit triangular [1 2; 0 1] times B.
+    let t = vec![-99, -99, 2, -99];
+    let mut b = vec![1, 1, 3, -1];
+    level3::trmm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::Unit,
+        2,
+        2,
+        1,
+        &t,
+        2,
+        &mut b,
+        2,
+    );
+    assert_eq!(b, vec![3, 1, 1, -1]);
+}
+
+#[test]
+fn dual_numbers() {
+    // d/dt of x(t).y, where x(t) = x + t*e1.
+    let x = vec![
+        Dual::new(1.0, 1.0),
+        Dual::new(2.0, 0.0),
+        Dual::new(3.0, 0.0),
+    ];
+    let y = vec![
+        Dual::new(4.0, 0.0),
+        Dual::new(5.0, 0.0),
+        Dual::new(6.0, 0.0),
+    ];
+    assert_eq!(level1::dot(3, &x, 1, &y, 1), Dual::new(32.0, 4.0));
+
+    // d/dt of (A + t*I)*x.
+    let a = vec![
+        Dual::new(1.0, 1.0),
+        Dual::new(3.0, 0.0),
+        Dual::new(2.0, 0.0),
+        Dual::new(4.0, 1.0),
+    ];
+    let mut y = vec![Dual::zero(); 2];
+    level2::gemv(
+        Trans::NoTrans,
+        2,
+        2,
+        Dual::one(),
+        &a,
+        2,
+        &[Dual::new(1.0, 0.0), Dual::new(1.0, 0.0)],
+        1,
+        Dual::zero(),
+        &mut y,
+        1,
+    );
+    assert_eq!(y, vec![Dual::new(3.0, 1.0), Dual::new(7.0, 1.0)]);
+}
+
+#[test]
+fn complex_conj_trans() {
+    // The Ring routines conjugate for ConjTrans like the complex ones.
+    let (m, n, k) = (5, 4, 3);
+    let z = |v: usize| Complex::new(v as f64 % 7.0 - 3.0, v as f64 % 4.0 - 1.5);
+    let a: Vec<_> = (0..k * m).map(z).collect();
+    let b: Vec<_> = (3..3 + n * k).map(z).collect();
+    let c: Vec<_> = (5..5 + m * n).map(z).collect();
+    let (alpha, beta) = (Complex::new(0.5, -1.0), Complex::new(2.0, 0.25));
+    for &(trans_a, trans_b) in &[
+        (Trans::ConjTrans, Trans::NoTrans),
+        (Trans::ConjTrans, Trans::ConjTrans),
+    ] {
+        let ldb = if trans_b == Trans::NoTrans { k } else { n };
+        let mut ring = c.clone();
+        level3::gemm(
+            trans_a, trans_b, m, n, k, alpha, &a, k, &b, ldb, beta, &mut ring, m,
+        );
+        let mut expected = c.clone();
+        level3::complex::gemm(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            &b,
+            ldb,
+            beta,
+            &mut expected,
+            m,
+        );
+        assert_eq!(ring, expected);
+        let mut plain = c.clone();
+        level3::complex::gemm(
+            Trans::Trans,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            &b,
+            ldb,
+            beta,
+            &mut plain,
+            m,
+        );
+        assert_ne!(ring, plain);
+    }
+
+    let x: Vec<_> = (1..1 + m).map(z).collect();
+    let mut ring: Vec<_> = (2..2 + k).map(z).collect();
+    let mut expected = ring.clone();
+    level2::gemv(
+        Trans::ConjTrans,
+        m,
+        k,
+        alpha,
+        &a[..],
+        m,
+        &x,
+        1,
+        beta,
+        &mut ring,
+        1,
+    );
+    level2::complex::gemv(
+        Trans::ConjTrans,
+        m,
+        k,
+        alpha,
+        &a[..],
+        m,
+        &x,
+        1,
+        beta,
+        &mut expected,
+        1,
+    );
+    assert_eq!(ring, expected);
+
+    let mut ring = c.clone();
+    let mut expected = c.clone();
+    level3::trmm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        3,
+        n,
+        alpha,
+        &a,
+        3,
+        &mut ring,
+        m,
+    );
+    level3::complex::trmm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::ConjTrans,
+        Diag::NonUnit,
+        3,
+        n,
+        alpha,
+        &a,
+        3,
+        &mut expected,
+        m,
+    );
+    assert_eq!(ring, expected);
+}
+
+#[test]
+fn integer_complex_conj_trans() {
+    // Large enough for the blocked algorithm, ConjTrans must conjugate while packing.
+    let (m, n, k) = (40, 36, 33);
+    let z = |v: usize| Complex::new(v as i64 % 7 - 3, v as i64 % 4 - 2);
+    let a: Vec<_> = (0..k * m).map(z).collect();
+    let b: Vec<_> = (3..3 + n * k).map(z).collect();
+    let c: Vec<_> = (5..5 + m * n).map(z).collect();
+    let conj = |x: &[Complex<i64>]| x.iter().map(|v| v.conj()).collect::<Vec<_>>();
+    let (alpha, beta) = (Complex::new(2, -1), Complex::new(1, 3));
+    for &(trans_a, trans_b) in &[
+        (Trans::ConjTrans, Trans::NoTrans),
+        (Trans::Trans, Trans::ConjTrans),
+        (Trans::ConjTrans, Trans::ConjTrans),
+    ] {
+        let ldb = if trans_b == Trans::NoTrans { k } else { n };
+        let mut ring = c.clone();
+        level3::gemm(
+            trans_a, trans_b, m, n, k, alpha, &a, k, &b, ldb, beta, &mut ring, m,
+        );
+        let (a, plain_a) = if trans_a == Trans::ConjTrans {
+            (conj(&a), Trans::Trans)
+        } else {
+            (a.clone(), trans_a)
+        };
+        let (b, plain_b) = if trans_b == Trans::ConjTrans {
+            (conj(&b), Trans::Trans)
+        } else {
+            (b.clone(), trans_b)
+        };
+        let mut expected = c.clone();
+        level3::gemm(
+            plain_a,
+            plain_b,
+            m,
+            n,
+            k,
+            alpha,
+            &a,
+            k,
+            &b,
+            ldb,
+            beta,
+            &mut expected,
+            m,
+        );
+        assert_eq!(ring, expected);
+    }
+
+    let x: Vec<_> = (1..1 + m).map(z).collect();
+    let mut ring: Vec<_> = (2..2 + k).map(z).collect();
+    let mut expected = ring.clone();
+    level2::gemv(
+        Trans::ConjTrans,
+        m,
+        k,
+        alpha,
+        &a,
+        m,
+        &x,
+        1,
+        beta,
+        &mut ring,
+        1,
+    );
+    level2::gemv(
+        Trans::Trans,
+        m,
+        k,
+        alpha,
+        &conj(&a),
+        m,
+        &x,
+        1,
+        beta,
+        &mut expected,
+        1,
+    );
+    assert_eq!(ring, expected);
+
+    let t: Vec<_> = (0..m * m).map(z).collect();
+    for &side in &[Side::Left, Side::Right] {
+        let mut ring = c.clone();
+        let mut expected = c.clone();
+        level3::trmm(
+            side,
+            Uplo::Upper,
+            Trans::ConjTrans,
+            Diag::NonUnit,
+            m,
+            n,
+            alpha,
+            &t,
+            m,
+            &mut ring,
+            m,
+        );
+        level3::trmm(
+            side,
+            Uplo::Upper,
+            Trans::Trans,
+            Diag::NonUnit,
+            m,
+            n,
+            alpha,
+            &conj(&t),
+            m,
+            &mut expected,
+            m,
+        );
+        assert_eq!(ring, expected);
+    }
+}
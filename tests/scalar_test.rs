@@ -0,0 +1,38 @@
+use libblas::{RealScalar, Ring, Scalar};
+use num_complex::Complex;
+use num_traits::Zero;
+
+/// The sum of |Re(x)| + |Im(x)| written once for the real and complex scalars.
+fn asum<T: Scalar>(x: &[T]) -> T::Real {
+    x.iter().fold(T::Real::zero(), |acc, &v| acc + v.abs1())
+}
+
+fn hermitian_product<T: Scalar>(x: T) -> T::Real {
+    (x.conj() * x).real()
+}
+
+fn real_scalar<T: RealScalar>(x: T) -> T {
+    T::from_real(x).conj()
+}
+
+#[test]
+fn real() {
+    assert_eq!((-2.5f64).conj(), -2.5);
+    assert_eq!((-2.5f64).abs1(), 2.5);
+    assert_eq!((-2.5f32).real(), -2.5);
+    assert_eq!(f32::from_real(1.5), 1.5);
+    assert_eq!(asum(&[1.0, -2.0, 3.0]), 6.0);
+    assert_eq!(hermitian_product(-3.0f32), 9.0);
+    assert_eq!(real_scalar(4.0f64), 4.0);
+}
+
+#[test]
+fn complex() {
+    let z = Complex::new(3.0f64, -4.0);
+    assert_eq!(Ring::conj(z), Complex::new(3.0, 4.0));
+    assert_eq!(z.abs1(), 7.0);
+    assert_eq!(z.real(), 3.0);
+    assert_eq!(Complex::<f32>::from_real(2.0), Complex::new(2.0, 0.0));
+    assert_eq!(asum(&[z, Complex::new(-1.0, 0.5)]), 8.5);
+    assert_eq!(hermitian_product(z), 25.0);
+}
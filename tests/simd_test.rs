@@ -0,0 +1,142 @@
+use libblas::{level1, level2, level3, Trans};
+mod utils;
+
+fn single(x: &[f64]) -> Vec<f32> {
+    x.iter().map(|&x| x as f32).collect()
+}
+
+// The lengths cover the vector bodies and the scalar tails of every kernel.
+#[test]
+fn level1() {
+    let mut rng = utils::Random::new(1);
+    for n in 0..70 {
+        let x = rng.reals(n);
+        let y = rng.reals(n);
+
+        let mut expected = y.clone();
+        for i in 0..n {
+            expected[i] += 0.7 * x[i];
+        }
+        let mut actual = y.clone();
+        level1::axpy(n, 0.7, &x, 1, &mut actual, 1);
+        approximately!(actual, expected, 1.0e-14);
+        let mut actual = single(&y);
+        level1::axpy(n, 0.7, &single(&x), 1, &mut actual, 1);
+        approximately!(actual, single(&expected), 1.0e-6);
+
+        let expected: f64 = x.iter().zip(&y).map(|(x, y)| x * y).sum();
+        assert_approx!(level1::dot(n, &x, 1, &y, 1), expected, 1.0e-13);
+        assert_approx!(
+            level1::dot(n, &single(&x), 1, &single(&y), 1),
+            expected as f32,
+            1.0e-5
+        );
+
+        let expected: Vec<f64> = x.iter().map(|x| -1.3 * x).collect();
+        let mut actual = x.clone();
+        level1::scal(n, -1.3, &mut actual, 1);
+        approximately!(actual, expected, 1.0e-15);
+        let mut actual = single(&x);
+        level1::scal(n, -1.3, &mut actual, 1);
+        approximately!(actual, single(&expected), 1.0e-6);
+    }
+}
+
+#[test]
+fn gemv() {
+    let mut rng = utils::Random::new(2);
+    let (m, n, lda) = (37, 29, 40);
+    let a = rng.reals(lda * n);
+    for &trans in &[Trans::NoTrans, Trans::Trans] {
+        let (lenx, leny) = if trans == Trans::NoTrans {
+            (n, m)
+        } else {
+            (m, n)
+        };
+        let x = rng.reals(lenx);
+        let y = rng.reals(leny);
+        let mut expected = y.clone();
+        for (i, e) in expected.iter_mut().enumerate() {
+            let mut tmp = 0.0;
+            for (l, x) in x.iter().enumerate() {
+                tmp += x * if trans == Trans::NoTrans {
+                    a[l * lda + i]
+                } else {
+                    a[i * lda + l]
+                };
+            }
+            *e = 0.4 * tmp - 0.9 * *e;
+        }
+        let mut actual = y.clone();
+        level2::gemv(trans, m, n, 0.4, &a, lda, &x, 1, -0.9, &mut actual, 1);
+        approximately!(actual, expected, 1.0e-13);
+        let mut actual = single(&y);
+        level2::gemv(
+            trans,
+            m,
+            n,
+            0.4,
+            &single(&a),
+            lda,
+            &single(&x),
+            1,
+            -0.9,
+            &mut actual,
+            1,
+        );
+        approximately!(actual, single(&expected), 1.0e-5);
+    }
+}
+
+#[test]
+fn gemm() {
+    let mut rng = utils::Random::new(3);
+    let (m, n, k) = (45, 38, 41);
+    let a = rng.reals(m * k);
+    let b = rng.reals(k * n);
+    let c = rng.reals(m * n);
+    let mut expected = c.clone();
+    for j in 0..n {
+        for i in 0..m {
+            let mut tmp = 0.0;
+            for l in 0..k {
+                tmp += a[l * m + i] * b[j * k + l];
+            }
+            expected[j * m + i] = 1.1 * tmp + 0.3 * expected[j * m + i];
+        }
+    }
+    let mut actual = c.clone();
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        m,
+        n,
+        k,
+        1.1,
+        &a,
+        m,
+        &b,
+        k,
+        0.3,
+        &mut actual,
+        m,
+    );
+    approximately!(actual, expected, 1.0e-13);
+    let mut actual = single(&c);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        m,
+        n,
+        k,
+        1.1,
+        &single(&a),
+        m,
+        &single(&b),
+        k,
+        0.3,
+        &mut actual,
+        m,
+    );
+    approximately!(actual, single(&expected), 1.0e-5);
+}
@@ -0,0 +1,201 @@
+use libblas::level3::{self, complex};
+use libblas::{threading, Diag, Side, Trans, Uplo};
+use num_complex::Complex;
+mod utils;
+
+fn close(x: &[f64], y: &[f64]) {
+    for (i, (x, y)) in x.iter().zip(y).enumerate() {
+        assert!(
+            (x - y).abs() <= 1e-9,
+            "{}: {} is not approximately equal to {}",
+            i,
+            x,
+            y
+        );
+    }
+}
+
+fn close_complex(x: &[Complex<f64>], y: &[Complex<f64>]) {
+    for (i, (x, y)) in x.iter().zip(y).enumerate() {
+        assert!(
+            (x - y).norm() <= 1e-9,
+            "{}: {} is not approximately equal to {}",
+            i,
+            x,
+            y
+        );
+    }
+}
+
+// Runs f with the given number of threads and returns its result.
+fn with_threads<R>(threads: usize, f: impl FnOnce() -> R) -> R {
+    threading::set_num_threads(threads);
+    f()
+}
+
+fn real(deterministic: bool) {
+    let mut rng = utils::Random::new(1);
+    threading::set_deterministic(deterministic);
+    let (m, n, k) = (150, 170, 130);
+    let ld = 180;
+    let a = rng.reals(ld * ld);
+    let b = rng.reals(ld * ld);
+    let c = rng.reals(ld * ld);
+    let t = rng.triangular(ld, ld);
+    let alpha = 0.7;
+
+    let gemm = |threads, trans_a, trans_b| {
+        with_threads(threads, || {
+            let mut c = c.clone();
+            level3::gemm(
+                trans_a, trans_b, m, n, k, alpha, &a, ld, &b, ld, -0.3, &mut c, ld,
+            );
+            c
+        })
+    };
+    let symm = |threads, side, uplo| {
+        with_threads(threads, || {
+            let mut c = c.clone();
+            level3::symm(side, uplo, m, n, alpha, &a, ld, &b, ld, 1.2, &mut c, ld);
+            c
+        })
+    };
+    let syrk = |threads, uplo, trans| {
+        with_threads(threads, || {
+            let mut c = c.clone();
+            level3::syrk(uplo, trans, n, k, alpha, &a, ld, 0.0, &mut c, ld);
+            level3::syr2k(uplo, trans, n, k, alpha, &a, ld, &b, ld, 0.4, &mut c, ld);
+            c
+        })
+    };
+    let trmm = |threads, uplo, trans| {
+        with_threads(threads, || {
+            let mut b = b.clone();
+            level3::trmm(
+                Side::Left,
+                uplo,
+                trans,
+                Diag::NonUnit,
+                m,
+                n,
+                alpha,
+                &t,
+                ld,
+                &mut b,
+                ld,
+            );
+            level3::trsm(
+                Side::Left,
+                uplo,
+                trans,
+                Diag::Unit,
+                m,
+                n,
+                alpha,
+                &t,
+                ld,
+                &mut b,
+                ld,
+            );
+            b
+        })
+    };
+
+    // Deterministic mode splits into the same panels on one thread, so even there the results are bitwise equal.
+    for &threads in &[2, 3, 4] {
+        let check = |x: &[f64], y: &[f64]| {
+            if deterministic {
+                assert_eq!(x, y);
+            } else {
+                close(x, y);
+            }
+        };
+        for &trans_a in &[Trans::NoTrans, Trans::Trans] {
+            for &trans_b in &[Trans::NoTrans, Trans::Trans] {
+                check(&gemm(threads, trans_a, trans_b), &gemm(1, trans_a, trans_b));
+            }
+        }
+        for &uplo in &[Uplo::Upper, Uplo::Lower] {
+            for &side in &[Side::Left, Side::Right] {
+                check(&symm(threads, side, uplo), &symm(1, side, uplo));
+            }
+            for &trans in &[Trans::NoTrans, Trans::Trans] {
+                check(&syrk(threads, uplo, trans), &syrk(1, uplo, trans));
+                check(&trmm(threads, uplo, trans), &trmm(1, uplo, trans));
+            }
+        }
+    }
+}
+
+fn complex() {
+    let mut rng = utils::Random::new(2);
+    threading::set_deterministic(false);
+    let (m, n, k) = (90, 110, 80);
+    let ld = 120;
+    let a = rng.complexes(ld * ld);
+    let b = rng.complexes(ld * ld);
+    let c = rng.complexes(ld * ld);
+    let alpha = Complex::new(0.6, -0.4);
+    let beta = Complex::new(-0.2, 0.9);
+
+    let run = |threads, side, uplo, trans| {
+        with_threads(threads, || {
+            let mut c = c.clone();
+            complex::gemm(
+                trans,
+                Trans::ConjTrans,
+                m,
+                n,
+                k,
+                alpha,
+                &a,
+                ld,
+                &b,
+                ld,
+                beta,
+                &mut c,
+                ld,
+            );
+            complex::symm(side, uplo, m, n, alpha, &a, ld, &b, ld, beta, &mut c, ld);
+            complex::hemm(side, uplo, m, n, alpha, &a, ld, &b, ld, beta, &mut c, ld);
+            let mut d = c.clone();
+            complex::syrk(uplo, trans, n, k, alpha, &a, ld, beta, &mut d, ld);
+            complex::syr2k(uplo, trans, n, k, alpha, &a, ld, &b, ld, beta, &mut d, ld);
+            let mut h = c.clone();
+            let conj = if trans == Trans::NoTrans {
+                Trans::NoTrans
+            } else {
+                Trans::ConjTrans
+            };
+            complex::herk(uplo, conj, n, k, 0.8, &a, ld, -0.5, &mut h, ld);
+            complex::her2k(uplo, conj, n, k, alpha, &a, ld, &b, ld, 1.5, &mut h, ld);
+            (c, d, h)
+        })
+    };
+
+    for &side in &[Side::Left, Side::Right] {
+        for &uplo in &[Uplo::Upper, Uplo::Lower] {
+            for &trans in &[Trans::NoTrans, Trans::Trans] {
+                let (c, d, h) = run(1, side, uplo, trans);
+                let (pc, pd, ph) = run(3, side, uplo, trans);
+                close_complex(&pc, &c);
+                close_complex(&pd, &d);
+                close_complex(&ph, &h);
+            }
+        }
+    }
+}
+
+// set_num_threads and set_deterministic apply to every later call in this binary, a second test would see them change under it.
+#[test]
+fn level3() {
+    if cfg!(not(feature = "rayon")) {
+        threading::set_num_threads(4);
+        assert_eq!(threading::num_threads(), 1);
+    }
+    real(false);
+    real(true);
+    complex();
+    threading::set_num_threads(0);
+    threading::set_deterministic(false);
+}
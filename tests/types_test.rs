@@ -0,0 +1,38 @@
+use libblas::{Diag, InvalidFlag, Side, Trans, Uplo};
+use std::convert::TryFrom;
+
+#[test]
+fn trans() {
+    assert_eq!(Trans::try_from('n'), Ok(Trans::NoTrans));
+    assert_eq!(Trans::try_from('N'), Ok(Trans::NoTrans));
+    assert_eq!(Trans::try_from('t'), Ok(Trans::Trans));
+    assert_eq!(Trans::try_from('T'), Ok(Trans::Trans));
+    assert_eq!(Trans::try_from('c'), Ok(Trans::ConjTrans));
+    assert_eq!(Trans::try_from('C'), Ok(Trans::ConjTrans));
+    assert_eq!(Trans::try_from('x'), Err(InvalidFlag('x')));
+    assert_eq!(char::from(Trans::ConjTrans), 'C');
+}
+
+#[test]
+fn uplo() {
+    assert_eq!(Uplo::try_from('u'), Ok(Uplo::Upper));
+    assert_eq!(Uplo::try_from('L'), Ok(Uplo::Lower));
+    assert_eq!(Uplo::try_from('n'), Err(InvalidFlag('n')));
+    assert_eq!(char::from(Uplo::Lower), 'L');
+}
+
+#[test]
+fn diag() {
+    assert_eq!(Diag::try_from('n'), Ok(Diag::NonUnit));
+    assert_eq!(Diag::try_from('U'), Ok(Diag::Unit));
+    assert_eq!(Diag::try_from('t'), Err(InvalidFlag('t')));
+    assert_eq!(char::from(Diag::Unit), 'U');
+}
+
+#[test]
+fn side() {
+    assert_eq!(Side::try_from('l'), Ok(Side::Left));
+    assert_eq!(Side::try_from('R'), Ok(Side::Right));
+    assert_eq!(Side::try_from('u'), Err(InvalidFlag('u')));
+    assert_eq!(char::from(Side::Right), 'R');
+}
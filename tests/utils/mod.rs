@@ -1,3 +1,60 @@
+#![allow(dead_code)]
+use num_complex::Complex;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::ops::{Deref, DerefMut};
+
+/// Random test data from a fixed seed, so that a failing test fails the same way every time it is run.
+/// It derefs to the generator for the tests that draw other values.
+pub struct Random(StdRng);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random(StdRng::seed_from_u64(seed))
+    }
+
+    /// `len` doubles uniform in [-0.5, 0.5).
+    pub fn reals(&mut self, len: usize) -> Vec<f64> {
+        (0..len).map(|_| self.0.gen::<f64>() - 0.5).collect()
+    }
+
+    /// `len` singles uniform in [-0.5, 0.5).
+    pub fn singles(&mut self, len: usize) -> Vec<f32> {
+        (0..len).map(|_| self.0.gen::<f32>() - 0.5).collect()
+    }
+
+    /// `len` complex numbers whose parts are uniform in [-0.5, 0.5).
+    pub fn complexes(&mut self, len: usize) -> Vec<Complex<f64>> {
+        (0..len)
+            .map(|_| Complex::new(self.0.gen::<f64>() - 0.5, self.0.gen::<f64>() - 0.5))
+            .collect()
+    }
+
+    /// An n by n matrix with leading dimension lda whose diagonal dominates,
+    /// so that either of its triangles is well conditioned.
+    pub fn triangular(&mut self, n: usize, lda: usize) -> Vec<f64> {
+        let mut a = self.reals(lda * n);
+        for i in 0..n {
+            a[i * lda + i] += n as f64;
+        }
+        a
+    }
+}
+
+impl Deref for Random {
+    type Target = StdRng;
+
+    fn deref(&self) -> &StdRng {
+        &self.0
+    }
+}
+
+impl DerefMut for Random {
+    fn deref_mut(&mut self) -> &mut StdRng {
+        &mut self.0
+    }
+}
+
 #[macro_export]
 macro_rules! assert_approx {
     ( $( $l:expr, $r:expr ),* ) => {
@@ -134,3 +191,16 @@ macro_rules! assert_approx_eq_cplx {
         );
     }};
 }
+
+#[macro_export]
+macro_rules! assert_ieee_eq {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            *a == *b || (a.is_nan() && b.is_nan()),
+            "assertion failed: `(left == right)` (left: `{:?}`, right: `{:?}`)",
+            *a,
+            *b
+        );
+    }};
+}
@@ -0,0 +1,642 @@
+use libblas::view::{self, level1};
+use libblas::{
+    Diag, Layout, MatMut, MatRef, NanPolicy, Side, StridedVec, StridedVecMut, Trans, Uplo,
+};
+use num_complex::Complex;
+mod fixtures;
+
+#[test]
+fn strided_vec() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let x = StridedVec::new(&data, 3, 2);
+    assert_eq!(x.len(), 3);
+    assert_eq!(x.iter().copied().collect::<Vec<_>>(), vec![1.0, 3.0, 5.0]);
+    assert_eq!(x.get(3), None);
+
+    // Element 0 of a negative stride is the last one in memory, as in the reference BLAS.
+    let x = StridedVec::new(&data, 3, -2);
+    assert_eq!(x.iter().copied().collect::<Vec<_>>(), vec![5.0, 3.0, 1.0]);
+    assert_eq!(x.get(0), Some(&5.0));
+
+    assert!(StridedVec::new(&data, 0, 7).is_empty());
+    assert_eq!(StridedVec::from(&data[..]).len(), 5);
+
+    let err = StridedVec::try_new(&data, 3, 0).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("StridedVec::new", 3));
+    let err = StridedVec::try_new(&data, 3, -3).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("StridedVec::new", 1));
+    assert!(StridedVec::try_new(&data, usize::MAX, isize::MAX).is_err());
+
+    let mut data = [1.0, 2.0, 3.0, 4.0];
+    let mut y = StridedVecMut::new(&mut data, 2, -3);
+    *y.get_mut(0).unwrap() = 9.0;
+    assert_eq!(y.as_view().get(1), Some(&1.0));
+    assert!(StridedVecMut::try_new(&mut data, 5, 1).is_err());
+    assert_eq!(data, [1.0, 2.0, 3.0, 9.0]);
+}
+
+#[test]
+#[should_panic(expected = "StridedVecMut::new parameter number 3")]
+fn strided_vec_zero_stride() {
+    StridedVecMut::new(&mut [1.0, 2.0], 2, 0);
+}
+
+#[test]
+fn level1() {
+    let x = [1.0, -7.0, 3.0, 2.0];
+    let mut y = [2.0, 1.0, 0.5, -1.0];
+
+    assert_eq!(level1::dot((&x[..]).into(), (&y[..]).into()), -5.5);
+    assert_eq!(
+        level1::dot(StridedVec::new(&x, 2, 2), StridedVec::new(&y, 2, -2)),
+        6.5
+    );
+    assert_eq!(
+        level1::ddot(1.0, StridedVec::new(&x, 2, 2), StridedVec::new(&y, 2, -2)),
+        7.5
+    );
+    level1::axpy(
+        2.0,
+        StridedVec::new(&x, 2, 1),
+        StridedVecMut::new(&mut y, 2, -2),
+    );
+    assert_eq!(y, [-12.0, 1.0, 2.5, -1.0]);
+
+    // asum, nrm2, scal and iamax take an unsigned increment in level1, the views accept negative strides.
+    let x = [3.0, 9.0, -4.0, 9.0, -1.0];
+    assert_eq!(level1::asum(StridedVec::new(&x, 3, -2)), 8.0);
+    assert_eq!(level1::nrm2(StridedVec::new(&x, 2, -2)), 5.0);
+    assert_eq!(level1::iamax(StridedVec::new(&x, 5, 1)), 2);
+    assert_eq!(level1::iamax(StridedVec::new(&x, 5, -1)), 2);
+    assert_eq!(level1::iamax(StridedVec::new(&x, 3, -2)), 2);
+    assert_eq!(level1::iamax(StridedVec::new(&x, 0, -2)), 0);
+    let z = [f64::NAN, 2.0, -4.0, f64::NAN];
+    assert_eq!(
+        level1::iamax_nan(StridedVec::new(&z, 4, 1), NanPolicy::First),
+        1
+    );
+    assert_eq!(
+        level1::iamax_nan(StridedVec::new(&z, 4, -1), NanPolicy::First),
+        1
+    );
+    assert_eq!(
+        level1::iamax_nan(StridedVec::new(&z, 4, -1), NanPolicy::Ignore),
+        2
+    );
+    assert_eq!(
+        level1::iamax_nan(StridedVec::new(&z, 0, -1), NanPolicy::Ignore),
+        0
+    );
+
+    let mut x = [1.0, 2.0, 3.0, 4.0];
+    level1::scal(2.0, StridedVecMut::new(&mut x, 2, -3));
+    assert_eq!(x, [2.0, 2.0, 3.0, 8.0]);
+
+    let mut y = [0.0; 3];
+    level1::copy(StridedVec::new(&x, 3, 1), StridedVecMut::new(&mut y, 3, -1));
+    assert_eq!(y, [3.0, 2.0, 2.0]);
+    level1::swap(
+        StridedVecMut::new(&mut x, 2, 2),
+        StridedVecMut::new(&mut y, 2, 1),
+    );
+    assert_eq!(x, [3.0, 2.0, 2.0, 8.0]);
+    assert_eq!(y, [2.0, 3.0, 2.0]);
+    level1::rot(
+        StridedVecMut::new(&mut x, 2, 1),
+        StridedVecMut::new(&mut y, 2, -1),
+        0.0,
+        1.0,
+    );
+    assert_eq!(x, [3.0, 2.0, 2.0, 8.0]);
+    assert_eq!(y, [-2.0, -3.0, 2.0]);
+    level1::rotm(
+        StridedVecMut::new(&mut x, 2, 1),
+        StridedVecMut::new(&mut y, 2, 1),
+        &[-1.0, 1.0, 0.0, 0.0, 1.0],
+    );
+    assert_eq!(x, [3.0, 2.0, 2.0, 8.0]);
+}
+
+#[test]
+fn complex_level1() {
+    let x = [
+        Complex::new(1.0, 1.0),
+        Complex::new(-3.0, 0.5),
+        Complex::new(0.0, 2.0),
+    ];
+    let y = [
+        Complex::new(2.0, 0.0),
+        Complex::new(0.0, 1.0),
+        Complex::new(1.0, -1.0),
+    ];
+    assert_eq!(
+        level1::complex::dotc((&x[..]).into(), (&x[..]).into()),
+        Complex::new(15.25, 0.0)
+    );
+    assert_eq!(
+        level1::complex::dotu(StridedVec::new(&x, 3, 1), StridedVec::new(&y, 3, -1)),
+        Complex::new(1.5, 1.0)
+    );
+    assert_eq!(level1::complex::iamax(StridedVec::new(&x, 3, -1)), 2);
+    let z = [
+        Complex::new(3.0, -3.0),
+        Complex::new(0.0, 5.0),
+        Complex::new(-1.0, 4.0),
+    ];
+    assert_eq!(level1::complex::iamax(StridedVec::new(&z, 3, -1)), 3);
+    assert_eq!(level1::complex::iamax_norm(StridedVec::new(&z, 3, -1)), 2);
+    assert_eq!(level1::complex::iamax_norm(StridedVec::new(&z, 3, 1)), 2);
+    let z = [
+        Complex::new(f64::NAN, 0.0),
+        Complex::new(0.0, 5.0),
+        Complex::new(0.0, f64::NAN),
+    ];
+    let nan = |stride, policy| level1::complex::iamax_nan(StridedVec::new(&z, 2, stride), policy);
+    assert_eq!(nan(1, NanPolicy::First), 1);
+    assert_eq!(nan(1, NanPolicy::Ignore), 2);
+    assert_eq!(nan(-1, NanPolicy::First), 2);
+    assert_eq!(nan(-2, NanPolicy::Ignore), 1);
+    assert_eq!(level1::complex::asum(StridedVec::new(&x, 2, -2)), 4.0);
+    assert_eq!(
+        level1::complex::nrm2(StridedVec::new(&x, 1, -1)),
+        2f64.sqrt()
+    );
+
+    let mut z = [Complex::new(0.0, 0.0); 3];
+    level1::complex::axpy(
+        &Complex::new(0.0, 1.0),
+        StridedVec::new(&x, 3, -1),
+        StridedVecMut::new(&mut z, 3, 1),
+    );
+    assert_eq!(
+        z,
+        [
+            Complex::new(-2.0, 0.0),
+            Complex::new(-0.5, -3.0),
+            Complex::new(-1.0, 1.0)
+        ]
+    );
+    level1::complex::sscal(2.0, StridedVecMut::new(&mut z, 2, -2));
+    level1::complex::scal(Complex::new(0.0, 1.0), StridedVecMut::new(&mut z, 1, 1));
+    assert_eq!(
+        z,
+        [
+            Complex::new(0.0, -4.0),
+            Complex::new(-0.5, -3.0),
+            Complex::new(-2.0, 2.0)
+        ]
+    );
+}
+
+#[test]
+fn mismatched_lengths() {
+    let x = [1.0, 2.0, 3.0];
+    let mut y = [1.0, 2.0];
+    let err = level1::try_axpy(1.0, (&x[..]).into(), (&mut y[..]).into()).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("axpy", 3));
+    let err = level1::try_dot((&x[..]).into(), StridedVec::new(&y, 2, -1)).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("dot", 2));
+    let err = level1::complex::try_dotc(
+        StridedVec::new(&[Complex::new(1.0, 0.0)], 1, 1),
+        StridedVec::new(&[], 0, 1),
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("dotc", 2));
+    assert_eq!(y, [1.0, 2.0]);
+}
+
+#[test]
+fn level1_extensions() {
+    let x = [3.0, -1.0, 0.5, -0.5, 2.0];
+    let back = StridedVec::new(&x, 5, -1);
+    // The index follows the view, the tie between 0.5 and -0.5 goes to the first one visited.
+    assert_eq!(level1::iamin((&x[..]).into()), 3);
+    assert_eq!(level1::iamin(back), 2);
+    assert_eq!(level1::amin(back), 0.5);
+    assert_eq!(level1::amax(back), 3.0);
+    assert_eq!(level1::sum(back), 4.0);
+
+    let mut y = [1.0, 1.0, 1.0, 1.0, 1.0];
+    level1::axpby(2.0, back, -1.0, (&mut y[..]).into());
+    assert_eq!(y, [3.0, -2.0, 0.0, -3.0, 5.0]);
+    let mut w = [0.0; 5];
+    level1::waxpby(1.0, (&x[..]).into(), 1.0, back, (&mut w[..]).into());
+    assert_eq!(w, [5.0, -1.5, 1.0, -1.5, 5.0]);
+
+    let z = [
+        Complex::new(1.0, -1.0),
+        Complex::new(0.0, 3.0),
+        Complex::new(-2.0, 0.0),
+    ];
+    let zback = StridedVec::new(&z, 3, -1);
+    assert_eq!(level1::complex::iamin(zback), 1);
+    assert_eq!(level1::complex::amin(zback), 2.0);
+    assert_eq!(level1::complex::amax(zback), 3.0);
+    assert_eq!(level1::complex::sum(zback), 1.0);
+
+    let err =
+        level1::try_waxpby(1.0, (&x[..]).into(), 1.0, back, (&mut w[..2]).into()).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("waxpby", 5));
+    let err = level1::try_axpby(1.0, (&x[..]).into(), 0.0, (&mut w[..2]).into()).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("axpby", 4));
+}
+
+#[test]
+fn complex_rotations() {
+    let (c, s) = (0.8, Complex::new(0.0, 0.6));
+    let mut x = [Complex::new(1.0, 0.0), Complex::new(0.0, 2.0)];
+    let mut y = [Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)];
+    let (mut ex, mut ey) = (x, y);
+    libblas::level1::complex::crot(2, &mut ex, 1, &mut ey, -1, c, s);
+    level1::complex::crot((&mut x[..]).into(), StridedVecMut::new(&mut y, 2, -1), c, s);
+    assert_eq!((x, y), (ex, ey));
+
+    let err =
+        level1::complex::try_rotm((&mut x[..]).into(), (&mut y[..1]).into(), &[]).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("rotm", 2));
+}
+
+#[test]
+#[should_panic(expected = "swap parameter number 2")]
+fn mismatched_lengths_panic() {
+    let mut x = [1.0, 2.0, 3.0];
+    let mut y = [1.0, 2.0];
+    level1::swap((&mut x[..]).into(), (&mut y[..]).into());
+}
+
+#[test]
+fn mat_ref() {
+    // 3x4 column-major matrix with ld 4, element (i, j) is 10 * i + j.
+    let data: Vec<f64> = (0..16).map(|k| (10 * (k % 4) + k / 4) as f64).collect();
+    let a = MatRef::new(&data, 3, 4, 4, Layout::ColMajor);
+    assert_eq!(a.get(2, 3), Some(&23.0));
+    assert_eq!(a.get(3, 0), None);
+
+    let s = a.submatrix(1, 1, 2, 3);
+    assert_eq!((s.rows(), s.cols(), s.ld()), (2, 3, 4));
+    assert_eq!(s.get(0, 0), Some(&11.0));
+    assert_eq!(s.get(1, 2), Some(&23.0));
+    assert_eq!(
+        s.col(1).iter().copied().collect::<Vec<_>>(),
+        vec![12.0, 22.0]
+    );
+    assert_eq!(
+        s.row(1).iter().copied().collect::<Vec<_>>(),
+        vec![21.0, 22.0, 23.0]
+    );
+    assert_eq!(
+        s.diag().iter().copied().collect::<Vec<_>>(),
+        vec![11.0, 22.0]
+    );
+    assert_eq!(a.submatrix(3, 4, 0, 0).rows(), 0);
+
+    let t = s.transpose();
+    assert_eq!((t.rows(), t.cols(), t.layout()), (3, 2, Layout::RowMajor));
+    assert_eq!(t.get(2, 1), Some(&23.0));
+    assert_eq!(
+        t.row(0).iter().copied().collect::<Vec<_>>(),
+        vec![11.0, 21.0]
+    );
+
+    let err = MatRef::try_new(&data, 5, 2, 4, Layout::ColMajor).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("MatRef::new", 4));
+    let err = MatRef::try_new(&data, 4, 5, 4, Layout::ColMajor).unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("MatRef::new", 1));
+    assert!(MatRef::try_new(&data, 5, 4, 4, Layout::RowMajor).is_err());
+    assert!(MatRef::try_new(&data, 4, 4, 4, Layout::RowMajor).is_ok());
+}
+
+#[test]
+fn mat_mut() {
+    let mut data = vec![0.0; 6];
+    let mut a = MatMut::new(&mut data, 2, 3, 3, Layout::RowMajor);
+    *a.get_mut(1, 2).unwrap() = 1.0;
+    *a.row_mut(0).get_mut(1).unwrap() = 2.0;
+    *a.col_mut(0).get_mut(1).unwrap() = 3.0;
+    *a.diag_mut().get_mut(1).unwrap() = 4.0;
+    *a.submatrix_mut(0, 2, 2, 1).get_mut(0, 0).unwrap() = 5.0;
+    *a.transpose_mut().get_mut(2, 1).unwrap() += 1.0;
+    assert_eq!(a.as_view().get(1, 2), Some(&2.0));
+    assert_eq!(data, vec![0.0, 2.0, 5.0, 3.0, 4.0, 2.0]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn submatrix_out_of_bounds() {
+    let data = [0.0; 6];
+    MatRef::new(&data, 2, 3, 2, Layout::ColMajor).submatrix(1, 1, 2, 1);
+}
+
+#[test]
+fn level3() {
+    // C(1..3, 1..4) := 0.5 * A**T * B(0..2, 1..4) + 2 * C(1..3, 1..4) on submatrices.
+    let a = fixtures::matrix_mxn(2, 2);
+    let b = fixtures::matrix_mxn(3, 4);
+    let mut c = fixtures::matrix_mxn(4, 5);
+
+    let mut expected = c.clone();
+    libblas::level3::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        2,
+        3,
+        2,
+        0.5,
+        &a,
+        2,
+        &b[3..],
+        3,
+        2.0,
+        &mut expected[5..],
+        4,
+    );
+    view::level3::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        0.5,
+        MatRef::new(&a, 2, 2, 2, Layout::ColMajor),
+        MatRef::new(&b, 3, 4, 3, Layout::ColMajor).submatrix(0, 1, 2, 3),
+        2.0,
+        MatMut::new(&mut c, 4, 5, 4, Layout::ColMajor).submatrix_mut(1, 1, 2, 3),
+    );
+    assert_eq!(c, expected);
+
+    // The transpose of a column-major view is row-major, and gives the same product.
+    let mut d = fixtures::matrix_mxn(4, 5);
+    view::level3::gemm(
+        Trans::NoTrans,
+        Trans::Trans,
+        0.5,
+        MatRef::new(&b, 3, 4, 3, Layout::ColMajor)
+            .submatrix(0, 1, 2, 3)
+            .transpose(),
+        MatRef::new(&a, 2, 2, 2, Layout::ColMajor).transpose(),
+        2.0,
+        MatMut::new(&mut d, 4, 5, 4, Layout::ColMajor)
+            .submatrix_mut(1, 1, 2, 3)
+            .into_transpose(),
+    );
+    assert_eq!(d, expected);
+
+    let mut x = fixtures::matrix_mxn(3, 2);
+    let mut expected = x.clone();
+    libblas::level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        2,
+        2,
+        2.0,
+        &a,
+        2,
+        &mut expected[1..],
+        3,
+    );
+    view::level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        2.0,
+        MatRef::new(&a, 2, 2, 2, Layout::ColMajor),
+        MatMut::new(&mut x, 3, 2, 3, Layout::ColMajor).submatrix_mut(1, 0, 2, 2),
+    );
+    assert_eq!(x, expected);
+}
+
+#[test]
+fn transposed_operands() {
+    // An operand in the other layout than C is read as its transpose, A is 3 by 4 and B is 3 by 5.
+    let a = fixtures::matrix_mxn(3, 4);
+    let b = fixtures::matrix_mxn(3, 5);
+    let a_view = MatRef::new(&a, 3, 4, 3, Layout::ColMajor);
+    let b_view = MatRef::new(&b, 3, 5, 3, Layout::ColMajor);
+    let mut expected = fixtures::matrix_mxn(4, 5);
+    let mut c = expected.clone();
+    libblas::level3::gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        4,
+        5,
+        3,
+        1.0,
+        &a,
+        3,
+        &b,
+        3,
+        0.5,
+        &mut expected,
+        4,
+    );
+    view::level3::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        1.0,
+        a_view.transpose(),
+        b_view,
+        0.5,
+        MatMut::new(&mut c, 4, 5, 4, Layout::ColMajor),
+    )
+    .unwrap();
+    assert_eq!(c, expected);
+
+    // C**T := B**T*A into a row-major C**T, both operands are in the other layout.
+    let mut d = fixtures::matrix_mxn(4, 5);
+    view::level3::try_gemm(
+        Trans::Trans,
+        Trans::NoTrans,
+        1.0,
+        b_view,
+        a_view,
+        0.5,
+        MatMut::new(&mut d, 5, 4, 4, Layout::RowMajor),
+    )
+    .unwrap();
+    assert_eq!(d, expected);
+
+    // The lower triangle of T**T is the upper triangle of T.
+    let t = fixtures::matrix_mxn(3, 3);
+    let mut expected = b.clone();
+    let mut x = b.clone();
+    libblas::level3::trsm(
+        Side::Left,
+        Uplo::Upper,
+        Trans::Trans,
+        Diag::NonUnit,
+        3,
+        5,
+        2.0,
+        &t,
+        3,
+        &mut expected,
+        3,
+    );
+    view::level3::try_trsm(
+        Side::Left,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::NonUnit,
+        2.0,
+        MatRef::new(&t, 3, 3, 3, Layout::ColMajor).transpose(),
+        MatMut::new(&mut x, 3, 5, 3, Layout::ColMajor),
+    )
+    .unwrap();
+    assert_eq!(x, expected);
+
+    let mut expected = b.clone();
+    let mut c = b.clone();
+    libblas::level3::symm(
+        Side::Left,
+        Uplo::Lower,
+        3,
+        5,
+        1.0,
+        &t,
+        3,
+        &b,
+        3,
+        -1.0,
+        &mut expected,
+        3,
+    );
+    view::level3::try_symm(
+        Side::Left,
+        Uplo::Upper,
+        1.0,
+        MatRef::new(&t, 3, 3, 3, Layout::ColMajor).transpose(),
+        b_view,
+        -1.0,
+        MatMut::new(&mut c, 3, 5, 3, Layout::ColMajor),
+    )
+    .unwrap();
+    assert_eq!(c, expected);
+
+    // ConjTrans of a transposed complex matrix is its conjugate.
+    let a = fixtures::complex::matrix_mxn(3, 4);
+    let b = fixtures::complex::matrix_mxn(4, 5);
+    let conj: Vec<_> = a.iter().map(|z| z.conj()).collect();
+    let mut expected = fixtures::complex::matrix_mxn(3, 5);
+    let mut c = expected.clone();
+    let (alpha, beta) = (Complex::new(0.5, -1.0), Complex::new(1.0, 0.25));
+    libblas::level3::complex::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        3,
+        5,
+        4,
+        alpha,
+        &conj,
+        3,
+        &b,
+        4,
+        beta,
+        &mut expected,
+        3,
+    );
+    view::level3::complex::try_gemm(
+        Trans::ConjTrans,
+        Trans::NoTrans,
+        alpha,
+        MatRef::new(&a, 3, 4, 3, Layout::ColMajor).transpose(),
+        MatRef::new(&b, 4, 5, 4, Layout::ColMajor),
+        beta,
+        MatMut::new(&mut c, 3, 5, 3, Layout::ColMajor),
+    )
+    .unwrap();
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn level2() {
+    let a = fixtures::matrix_mxn(3, 4);
+    let x = [1.0, -2.0, 0.5];
+    let mut y = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    let mut expected = y;
+    libblas::level2::gemv(
+        Trans::Trans,
+        3,
+        4,
+        2.0,
+        &a,
+        3,
+        &x,
+        -1,
+        0.5,
+        &mut expected,
+        2,
+    );
+    view::level2::gemv(
+        Trans::Trans,
+        2.0,
+        MatRef::new(&a, 3, 4, 3, Layout::ColMajor),
+        StridedVec::new(&x, 3, -1),
+        0.5,
+        StridedVecMut::new(&mut y, 4, 2),
+    );
+    assert_eq!(y, expected);
+
+    // Row 1 of A times its columns 1..3, through the row-major transpose.
+    let a = MatRef::new(&a, 3, 4, 3, Layout::ColMajor);
+    let mut y = [0.0; 2];
+    view::level2::gemv(
+        Trans::NoTrans,
+        1.0,
+        a.submatrix(0, 1, 3, 2).transpose(),
+        a.col(0),
+        0.0,
+        (&mut y[..]).into(),
+    );
+    let dot = |j| view::level1::dot(a.col(0), a.col(j));
+    assert_eq!(y, [dot(1), dot(2)]);
+}
+
+#[test]
+fn illegal_views() {
+    let a = [1.0; 6];
+    let mut c = [1.0; 6];
+    let err = view::level3::try_gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        1.0,
+        MatRef::new(&a, 2, 3, 2, Layout::ColMajor),
+        MatRef::new(&a, 2, 3, 2, Layout::ColMajor),
+        1.0,
+        MatMut::new(&mut c, 2, 3, 2, Layout::ColMajor),
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemm", 5));
+    let err = view::level3::try_symm(
+        Side::Left,
+        Uplo::Upper,
+        1.0,
+        MatRef::new(&a, 2, 2, 2, Layout::ColMajor),
+        MatRef::new(&a, 2, 3, 3, Layout::RowMajor),
+        1.0,
+        MatMut::new(&mut c, 2, 3, 2, Layout::ColMajor),
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("symm", 5));
+    let err = view::level3::try_trsm(
+        Side::Right,
+        Uplo::Lower,
+        Trans::NoTrans,
+        Diag::Unit,
+        1.0,
+        MatRef::new(&a, 2, 2, 2, Layout::ColMajor),
+        MatMut::new(&mut c, 2, 3, 2, Layout::ColMajor),
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("trsm", 6));
+    let err = view::level2::try_gemv(
+        Trans::NoTrans,
+        1.0,
+        MatRef::new(&a, 2, 3, 2, Layout::ColMajor),
+        StridedVec::new(&a, 2, 1),
+        1.0,
+        StridedVecMut::new(&mut c, 2, 1),
+    )
+    .unwrap_err();
+    assert_eq!((err.routine(), err.param()), ("gemv", 4));
+    assert_eq!(c, [1.0; 6]);
+}
@@ -0,0 +1,106 @@
+use libblas::{level3, xerbla, BlasError, Trans};
+use std::sync::Mutex;
+
+static REPORTED: Mutex<Vec<(&'static str, usize)>> = Mutex::new(Vec::new());
+
+fn record(err: &BlasError) {
+    REPORTED.lock().unwrap().push((err.routine(), err.param()));
+}
+
+// Installing a handler replaces it for every thread of this binary, so all the checks share one test.
+#[test]
+fn handler() {
+    let a = vec![1.0; 4];
+    let b = vec![1.0; 4];
+    let mut c = vec![2.0; 4];
+
+    let previous = xerbla::set_handler(record);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        1,
+        &b,
+        2,
+        1.0,
+        &mut c,
+        2,
+    );
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &b,
+        2,
+        1.0,
+        &mut c,
+        1,
+    );
+    assert_eq!(*REPORTED.lock().unwrap(), vec![("gemm", 8), ("gemm", 13)]);
+    assert_eq!(c, vec![2.0; 4]);
+
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &b,
+        2,
+        1.0,
+        &mut c,
+        2,
+    );
+    assert_eq!(REPORTED.lock().unwrap().len(), 2);
+    assert_eq!(c, vec![4.0; 4]);
+
+    xerbla::set_handler(xerbla::log);
+    level3::gemm(
+        Trans::NoTrans,
+        Trans::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        1,
+        &b,
+        2,
+        1.0,
+        &mut c,
+        2,
+    );
+    assert_eq!(c, vec![4.0; 4]);
+
+    xerbla::set_handler(previous);
+    let result = std::panic::catch_unwind(|| {
+        level3::gemm(
+            Trans::NoTrans,
+            Trans::NoTrans,
+            2,
+            2,
+            2,
+            1.0,
+            &a,
+            1,
+            &b,
+            2,
+            1.0,
+            &mut vec![],
+            2,
+        )
+    });
+    assert!(result.is_err());
+}